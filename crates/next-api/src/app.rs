@@ -61,6 +61,7 @@ use turbopack_core::{
         GraphEntries, ModuleGraph, SingleModuleGraph, VisitedModules,
         binding_usage_info::compute_binding_usage_info,
         chunk_group_info::{ChunkGroup, ChunkGroupEntry},
+        duplicate_packages::detect_duplicate_packages,
     },
     output::{OutputAsset, OutputAssets, OutputAssetsWithReferenced},
     reference::all_assets_from_entries,
@@ -87,7 +88,7 @@ use crate::{
     route::{
         AppPageRoute, Endpoint, EndpointOutput, EndpointOutputPaths, ModuleGraphs, Route, Routes,
     },
-    server_actions::{build_server_actions_loader, create_server_actions_manifest},
+    server_actions::{AllActions, build_server_actions_loader, create_server_actions_manifest},
     sri_manifest::get_sri_manifest_asset,
     webpack_stats::generate_webpack_stats,
 };
@@ -165,7 +166,7 @@ impl AppProject {
     }
 
     #[turbo_tasks::function]
-    fn app_entrypoints(&self) -> Vc<AppEntrypoints> {
+    pub fn app_entrypoints(&self) -> Vc<AppEntrypoints> {
         let conf = self.project.next_config();
         get_entrypoints(
             self.app_dir.clone(),
@@ -1280,6 +1281,18 @@ impl AppEndpoint {
 
         let client_chunking_context = project.client_chunking_context().to_resolved().await?;
 
+        if is_app_page {
+            detect_duplicate_packages(
+                *module_graphs.full,
+                project
+                    .next_config()
+                    .turbopack_allow_duplicate_packages()
+                    .owned()
+                    .await?,
+            )
+            .await?;
+        }
+
         let ssr_chunking_context = if process_ssr {
             Some(
                 match runtime {
@@ -1468,17 +1481,33 @@ impl AppEndpoint {
             }
         }
 
-        let actions = ServerActionsGraphs::new(*module_graphs.base, per_page_module_graph)
-            .get_server_actions_for_endpoint(
-                *rsc_entry,
-                match runtime {
-                    NextRuntime::Edge => Vc::upcast(this.app_project.edge_rsc_module_context()),
-                    NextRuntime::NodeJs => Vc::upcast(this.app_project.rsc_module_context()),
-                },
-            );
+        let server_actions_graphs =
+            ServerActionsGraphs::new(*module_graphs.base, per_page_module_graph);
+        let actions = server_actions_graphs.get_server_actions_for_endpoint(
+            *rsc_entry,
+            match runtime {
+                NextRuntime::Edge => Vc::upcast(this.app_project.edge_rsc_module_context()),
+                NextRuntime::NodeJs => Vc::upcast(this.app_project.rsc_module_context()),
+            },
+        );
+        // Reports the unused actions, even when they are kept in the manifest
+        let unreferenced_actions = server_actions_graphs
+            .get_unreferenced_server_actions(actions)
+            .to_resolved()
+            .await?;
+        let unreferenced_actions = if *project
+            .next_config()
+            .turbopack_prune_unused_server_actions()
+            .await?
+        {
+            *unreferenced_actions
+        } else {
+            AllActions::empty()
+        };
 
         let server_action_manifest = create_server_actions_manifest(
             actions,
+            unreferenced_actions,
             project.project_path().owned().await?,
             node_root.clone(),
             app_entry.original_name.clone(),
@@ -1582,7 +1611,7 @@ impl AppEndpoint {
                     .experimental_sri()
                     .await?
                     .as_ref()
-                    .is_some_and(|v| v.algorithm.is_some())
+                    .is_some_and(|v| !v.algorithms().is_empty())
                 {
                     file_paths_from_root.insert(rcstr!("server/subresource-integrity-manifest.js"));
                 }
@@ -2025,7 +2054,7 @@ impl Endpoint for AppEndpoint {
             let output_assets = output.output_assets();
             let output_assets = if let Some(sri) =
                 &*project.next_config().experimental_sri().await?
-                && let Some(algorithm) = sri.algorithm.clone()
+                && !sri.algorithms().is_empty()
             {
                 let sri_manifest = get_sri_manifest_asset(
                     node_root.join(&format!(
@@ -2034,7 +2063,7 @@ impl Endpoint for AppEndpoint {
                     ))?,
                     output_assets,
                     client_relative_root.clone(),
-                    algorithm,
+                    sri.algorithms().to_vec(),
                 );
                 output_assets.concat_asset(sri_manifest)
             } else {
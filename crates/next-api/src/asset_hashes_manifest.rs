@@ -1,7 +1,7 @@
 use anyhow::Result;
 use serde::{Serializer, ser::SerializeMap};
 use turbo_rcstr::RcStr;
-use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks::{FxIndexMap, ResolvedVc, TryJoinIterExt, Vc};
 use turbo_tasks_fs::{File, FileContent, FileSystemPath};
 use turbopack_core::{
     asset::{Asset, AssetContent},
@@ -19,12 +19,15 @@ use crate::paths::{AssetPath, AssetPaths};
 ///    ...
 /// }
 /// ```
+///
+/// When hashes from multiple algorithms are given, they are joined with a space per asset (e.g.
+/// `"sha256-contenthash1 sha384-contenthash1"`).
 #[turbo_tasks::value]
 pub struct AssetHashesManifestAsset {
     output_path: FileSystemPath,
-    asset_paths: ResolvedVc<AssetPaths>,
-    /// Optional prefix to add to the hash (e.g. "sha256-" for SRI hashes)
-    hash_prefix: Option<RcStr>,
+    /// The asset paths for every hash algorithm, each with an optional prefix to add to the hash
+    /// (e.g. "sha256-" for SRI hashes)
+    hashes: Vec<(ResolvedVc<AssetPaths>, Option<RcStr>)>,
 }
 
 #[turbo_tasks::value_impl]
@@ -32,13 +35,11 @@ impl AssetHashesManifestAsset {
     #[turbo_tasks::function]
     pub fn new(
         output_path: FileSystemPath,
-        asset_paths: ResolvedVc<AssetPaths>,
-        hash_prefix: Option<RcStr>,
+        hashes: Vec<(ResolvedVc<AssetPaths>, Option<RcStr>)>,
     ) -> Vc<Self> {
         AssetHashesManifestAsset {
             output_path,
-            asset_paths,
-            hash_prefix,
+            hashes,
         }
         .cell()
     }
@@ -59,38 +60,106 @@ impl OutputAsset for AssetHashesManifestAsset {
 impl Asset for AssetHashesManifestAsset {
     #[turbo_tasks::function]
     async fn content(&self) -> Result<Vc<AssetContent>> {
-        let files = self.asset_paths.await?;
+        let hashes = self
+            .hashes
+            .iter()
+            .map(async |(asset_paths, hash_prefix)| Ok((asset_paths.await?, hash_prefix)))
+            .try_join()
+            .await?;
 
-        struct Manifest<'a> {
-            asset_paths: &'a Vec<AssetPath>,
-            hash_prefix: &'a Option<RcStr>,
-        }
+        let files = hashes_by_path(
+            hashes
+                .iter()
+                .map(|(asset_paths, hash_prefix)| (&***asset_paths, hash_prefix.as_ref())),
+        );
+
+        struct Manifest<'a>(&'a FxIndexMap<&'a RcStr, String>);
 
         impl serde::Serialize for Manifest<'_> {
             fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                let mut map = serializer.serialize_map(Some(self.asset_paths.len()))?;
-                let mut buf = String::new();
-                for entry in self.asset_paths {
-                    if let Some(prefix) = self.hash_prefix {
-                        use std::fmt::Write;
-                        buf.clear();
-                        write!(buf, "{}{}", prefix, entry.content_hash).unwrap();
-                        map.serialize_entry(&entry.path, &buf)?;
-                    } else {
-                        map.serialize_entry(&entry.path, &entry.content_hash)?;
-                    }
+                let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                for (path, hash) in self.0 {
+                    map.serialize_entry(path, hash)?;
                 }
                 map.end()
             }
         }
 
-        let json = serde_json::to_string(&Manifest {
-            asset_paths: &files,
-            hash_prefix: &self.hash_prefix,
-        })?;
+        let json = serde_json::to_string(&Manifest(&files))?;
 
         Ok(AssetContent::file(
             FileContent::Content(File::from(json)).cell(),
         ))
     }
 }
+
+/// Joins the (prefixed) hashes of every algorithm per asset path, keeping the order in which the
+/// algorithms were given.
+fn hashes_by_path<'a>(
+    hashes: impl IntoIterator<Item = (&'a [AssetPath], Option<&'a RcStr>)>,
+) -> FxIndexMap<&'a RcStr, String> {
+    let mut files: FxIndexMap<&RcStr, String> = FxIndexMap::default();
+    for (asset_paths, hash_prefix) in hashes {
+        for AssetPath { path, content_hash } in asset_paths {
+            let hash = files.entry(path).or_default();
+            if !hash.is_empty() {
+                hash.push(' ');
+            }
+            if let Some(prefix) = hash_prefix {
+                hash.push_str(prefix);
+            }
+            hash.push_str(content_hash);
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::rcstr;
+
+    use super::*;
+
+    fn asset_path(path: RcStr, content_hash: RcStr) -> AssetPath {
+        AssetPath { path, content_hash }
+    }
+
+    #[test]
+    fn test_hashes_by_path() {
+        let sha256 = [
+            asset_path(rcstr!("static/chunks/main.js"), rcstr!("a")),
+            asset_path(rcstr!("static/media/font.woff2"), rcstr!("b")),
+        ];
+        let sha384 = [
+            asset_path(rcstr!("static/chunks/main.js"), rcstr!("c")),
+            asset_path(rcstr!("static/media/font.woff2"), rcstr!("d")),
+        ];
+        let sha256_prefix = rcstr!("sha256-");
+        let sha384_prefix = rcstr!("sha384-");
+
+        let files = hashes_by_path([
+            (&sha256[..], Some(&sha256_prefix)),
+            (&sha384[..], Some(&sha384_prefix)),
+        ]);
+
+        assert_eq!(
+            files
+                .into_iter()
+                .map(|(path, hash)| (path.as_str(), hash))
+                .collect::<Vec<_>>(),
+            vec![
+                ("static/chunks/main.js", "sha256-a sha384-c".to_string()),
+                ("static/media/font.woff2", "sha256-b sha384-d".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hashes_by_path_without_prefix() {
+        let hashes = [asset_path(rcstr!("static/chunks/main.js"), rcstr!("a"))];
+
+        let files = hashes_by_path([(&hashes[..], None)]);
+
+        assert_eq!(files[&rcstr!("static/chunks/main.js")], "a");
+    }
+}
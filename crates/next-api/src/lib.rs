@@ -22,8 +22,12 @@ mod pages;
 pub mod paths;
 pub mod project;
 pub mod route;
+pub mod route_preload_manifest;
+pub mod route_tree;
 pub mod routes_hashes_manifest;
 mod server_actions;
 mod sri_manifest;
+pub mod standalone;
+pub mod typed_routes;
 mod versioned_content_map;
 mod webpack_stats;
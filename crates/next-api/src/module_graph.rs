@@ -12,7 +12,7 @@ use next_core::{
     next_manifests::ActionLayer,
     next_server_utility::server_utility_module::NextServerUtilityModule,
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::Instrument;
 use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{
@@ -31,7 +31,8 @@ use crate::{
     client_references::{ClientManifestEntryType, ClientReferenceData, map_client_references},
     dynamic_imports::{DynamicImportEntries, DynamicImportEntriesMapType, map_next_dynamic},
     server_actions::{
-        ActionMeta, AllActions, AllModuleActions, map_server_actions, to_rsc_context,
+        ActionMeta, AllActions, AllModuleActions, UnusedServerActionIssue,
+        map_referenced_server_actions, map_server_actions, to_rsc_context,
     },
 };
 
@@ -314,6 +315,50 @@ impl ServerActionsGraphs {
         .instrument(span)
         .await
     }
+
+    /// Returns the given actions which are never imported by another module and emits a warning
+    /// for each of them. This needs the module graph of the whole app, so nothing is returned
+    /// when compiling a single page.
+    #[turbo_tasks::function]
+    pub async fn get_unreferenced_server_actions(
+        &self,
+        actions: Vc<AllActions>,
+    ) -> Result<Vc<AllActions>> {
+        let graphs = self
+            .0
+            .iter()
+            .map(async |graph| graph.await)
+            .try_join()
+            .await?;
+        if graphs.iter().any(|graph| graph.is_single_page) {
+            return Ok(AllActions::empty());
+        }
+        let referenced = graphs
+            .iter()
+            .map(async |graph| map_referenced_server_actions(*graph.graph, *graph.data).await)
+            .try_join()
+            .await?;
+
+        let mut reported = FxHashSet::default();
+        let unreferenced = actions
+            .await?
+            .iter()
+            .filter(|(hash_id, _)| !referenced.iter().any(|ids| ids.contains(hash_id)))
+            .cloned()
+            .collect::<Vec<_>>();
+        for (hash_id, (_, meta, module)) in &unreferenced {
+            // The action is listed for both the server and the client layer
+            if reported.insert(hash_id) {
+                UnusedServerActionIssue {
+                    module: *module,
+                    name: meta.name.as_str().into(),
+                }
+                .resolved_cell()
+                .emit();
+            }
+        }
+        Ok(Vc::cell(unreferenced))
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -384,6 +429,7 @@ impl ServerActionsGraph {
                                     ActionMeta {
                                         name: entry.name.clone(),
                                         source_path: actions.entry_path.clone(),
+                                        args: entry.args.clone(),
                                     },
                                     if *layer == ActionLayer::Rsc {
                                         *module
@@ -894,3 +940,229 @@ pub async fn validate_pages_css_imports(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use next_core::next_manifests::ActionLayer;
+    use turbo_rcstr::{RcStr, rcstr};
+    use turbo_tasks::{FxIndexMap, ResolvedVc, TryJoinIterExt, Vc};
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+    use turbo_tasks_fs::{FileSystem, FileSystemPath, VirtualFileSystem};
+    use turbopack_core::{
+        asset::{Asset, AssetContent},
+        ident::{AssetIdent, Layer},
+        issue::CollectibleIssuesExt,
+        module::{Module, ModuleSideEffects},
+        module_graph::{
+            GraphEntries, ModuleGraph, SingleModuleGraph, chunk_group_info::ChunkGroupEntry,
+        },
+        reference::{ModuleReference, ModuleReferences, SingleChunkableModuleReference},
+        resolve::ExportUsage,
+        source::OptionSource,
+    };
+
+    use super::{ServerActionsGraph, ServerActionsGraphs};
+    use crate::server_actions::{
+        ActionEntry, ActionMap, ActionMeta, AllActions, AllModuleActions, UnusedServerActionIssue,
+    };
+
+    /// A module in `layer` importing the given export of each of `imports`.
+    #[turbo_tasks::value]
+    struct MockModule {
+        path: FileSystemPath,
+        layer: RcStr,
+        imports: Vec<(ResolvedVc<Box<dyn Module>>, RcStr)>,
+    }
+
+    #[turbo_tasks::value_impl]
+    impl Asset for MockModule {
+        #[turbo_tasks::function]
+        fn content(&self) -> Vc<AssetContent> {
+            panic!("MockModule::content shouldn't be called")
+        }
+    }
+
+    #[turbo_tasks::value_impl]
+    impl Module for MockModule {
+        #[turbo_tasks::function]
+        fn ident(&self) -> Vc<AssetIdent> {
+            AssetIdent::from_path(self.path.clone()).with_layer(Layer::new(self.layer.clone()))
+        }
+
+        #[turbo_tasks::function]
+        fn source(&self) -> Vc<OptionSource> {
+            Vc::cell(None)
+        }
+
+        #[turbo_tasks::function]
+        async fn references(&self) -> anyhow::Result<Vc<ModuleReferences>> {
+            let references = self
+                .imports
+                .iter()
+                .map(|(module, export)| {
+                    Vc::upcast::<Box<dyn ModuleReference>>(SingleChunkableModuleReference::new(
+                        **module,
+                        rcstr!("import"),
+                        ExportUsage::named(export.clone()),
+                    ))
+                    .to_resolved()
+                })
+                .try_join()
+                .await?;
+            Ok(Vc::cell(references))
+        }
+
+        #[turbo_tasks::function]
+        fn side_effects(self: Vc<Self>) -> Vc<ModuleSideEffects> {
+            ModuleSideEffects::SideEffectful.cell()
+        }
+    }
+
+    /// A page (in the server layer) importing `serverOnly` from `actions.js` and rendering a Client
+    /// Component, which imports `used` from `actions.js`. `unused` isn't imported at all. Returns
+    /// the names of the unreferenced actions, followed by the names of the reported ones.
+    #[turbo_tasks::function(operation)]
+    async fn unreferenced_server_actions_operation() -> anyhow::Result<Vc<Vec<RcStr>>> {
+        let root = VirtualFileSystem::new().root().owned().await?;
+        let module = async |path: &str, layer: &str, imports: Vec<_>| {
+            anyhow::Ok(ResolvedVc::upcast::<Box<dyn Module>>(
+                MockModule {
+                    path: root.join(path)?,
+                    layer: layer.into(),
+                    imports,
+                }
+                .resolved_cell(),
+            ))
+        };
+        let server_actions = module("actions.js", "app-rsc", vec![]).await?;
+        let client_actions = module("actions.js", "app-client", vec![]).await?;
+        let button = module(
+            "button.js",
+            "app-client",
+            vec![(client_actions, rcstr!("used"))],
+        )
+        .await?;
+        let page = module(
+            "page.js",
+            "app-rsc",
+            vec![
+                (server_actions, rcstr!("serverOnly")),
+                (button, rcstr!("Button")),
+            ],
+        )
+        .await?;
+
+        let graph = ModuleGraph::from_single_graph(SingleModuleGraph::new_with_entries(
+            GraphEntries::resolved_cell(GraphEntries(vec![ChunkGroupEntry::Entry(vec![page])])),
+            false,
+            false,
+        ))
+        .connect();
+        let layer = graph.iter_graphs().await?[0]
+            .connect()
+            .to_resolved()
+            .await?;
+
+        let names = ["used", "unused", "serverOnly"];
+        let action_map = ActionMap {
+            actions: names
+                .iter()
+                .enumerate()
+                .map(|(id, name)| {
+                    (
+                        id.to_string(),
+                        ActionEntry {
+                            name: name.to_string(),
+                            args: None,
+                        },
+                    )
+                })
+                .collect(),
+            entry_path: "actions.js".to_string(),
+            entry_query: String::new(),
+        }
+        .resolved_cell();
+        let data = Vc::<AllModuleActions>::cell(FxIndexMap::from_iter([
+            (server_actions, (ActionLayer::Rsc, action_map)),
+            (client_actions, (ActionLayer::ActionBrowser, action_map)),
+        ]))
+        .to_resolved()
+        .await?;
+        let graphs = ServerActionsGraphs(vec![
+            ServerActionsGraph {
+                graph: layer,
+                is_single_page: false,
+                data,
+            }
+            .resolved_cell(),
+        ])
+        .cell();
+        let actions = Vc::<AllActions>::cell(
+            names
+                .iter()
+                .enumerate()
+                .map(|(id, name)| {
+                    (
+                        id.to_string(),
+                        (
+                            ActionLayer::Rsc,
+                            ActionMeta {
+                                name: name.to_string(),
+                                source_path: "actions.js".to_string(),
+                                args: None,
+                            },
+                            server_actions,
+                        ),
+                    )
+                })
+                .collect(),
+        );
+
+        let unreferenced = graphs.get_unreferenced_server_actions(actions);
+        let mut result = unreferenced
+            .await?
+            .iter()
+            .map(|(_, (_, meta, _))| RcStr::from(meta.name.as_str()))
+            .collect::<Vec<_>>();
+        let mut reported = unreferenced
+            .peek_issues()
+            .iter()
+            .map(async |issue| {
+                let issue = ResolvedVc::try_downcast_type::<UnusedServerActionIssue>(issue)
+                    .unwrap()
+                    .await?;
+                anyhow::Ok(issue.name.clone())
+            })
+            .try_join()
+            .await?;
+        reported.sort();
+        result.extend(reported);
+        Ok(Vc::cell(result))
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_get_unreferenced_server_actions() {
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            assert_eq!(
+                *unreferenced_server_actions_operation()
+                    .read_strongly_consistent()
+                    .await?,
+                // `serverOnly` is only imported in the server layer, so it can't be called from
+                // the client either
+                vec![
+                    rcstr!("unused"),
+                    rcstr!("serverOnly"),
+                    rcstr!("serverOnly"),
+                    rcstr!("unused"),
+                ]
+            );
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
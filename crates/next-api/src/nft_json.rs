@@ -16,6 +16,8 @@ use turbopack_core::{
     asset::{Asset, AssetContent},
     issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
     output::{OutputAsset, OutputAssets, OutputAssetsReference},
+    reference::all_assets_from_entries,
+    traced_asset::{TraceChains, trace_chains},
 };
 
 use crate::project::Project;
@@ -58,6 +60,16 @@ impl NftJsonAsset {
         }
         .cell()
     }
+
+    /// The assets the trace is computed from.
+    #[turbo_tasks::function]
+    pub fn trace_entries(&self) -> Vc<OutputAssets> {
+        Vc::cell(
+            std::iter::once(self.chunk)
+                .chain(self.additional_assets.iter().copied())
+                .collect(),
+        )
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -77,6 +89,39 @@ impl OutputAsset for NftJsonAsset {
     }
 }
 
+/// Explains why `path`, relative to the project root, is included in the `.nft.json` trace of the
+/// chunk `name`, which is relative to the node root, e.g. `server/app/page.js`. Returns every
+/// shortest reference chain from the traced chunk to the file.
+#[turbo_tasks::function]
+pub async fn why_traced(
+    project: Vc<Project>,
+    output_assets: Vc<OutputAssets>,
+    name: RcStr,
+    path: RcStr,
+) -> Result<Vc<TraceChains>> {
+    let nft_json_path = project
+        .node_root()
+        .await?
+        .join(&format!("{name}.nft.json"))?;
+    let nft_json = all_assets_from_entries(output_assets)
+        .await?
+        .iter()
+        .map(async |&asset| {
+            let Some(nft_json) = ResolvedVc::try_downcast_type::<NftJsonAsset>(asset) else {
+                return Ok(None);
+            };
+            Ok((*asset.path().await? == nft_json_path).then_some(nft_json))
+        })
+        .try_flat_join()
+        .await?;
+    let Some(nft_json) = nft_json.first() else {
+        bail!("There is no trace for {name}");
+    };
+
+    let path = project.project_root_path().await?.join(&path)?;
+    Ok(trace_chains(nft_json.trace_entries(), path))
+}
+
 fn get_output_specifier(
     path_ref: &FileSystemPath,
     ident_folder: &FileSystemPath,
@@ -151,7 +196,7 @@ impl Asset for NftJsonAsset {
             let project_path = this.project.project_path().owned().await?;
 
             let output_root_ref = this.project.output_fs().root().await?;
-            let project_root_ref = this.project.project_fs().root().await?;
+            let project_root_ref = this.project.project_root_path().await?;
             let next_config = this.project.next_config();
             let next_config_path = this
                 .project
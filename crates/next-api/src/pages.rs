@@ -55,6 +55,7 @@ use turbopack_core::{
         GraphEntries, ModuleGraph, SingleModuleGraph, VisitedModules,
         binding_usage_info::compute_binding_usage_info,
         chunk_group_info::{ChunkGroup, ChunkGroupEntry},
+        duplicate_packages::detect_duplicate_packages,
     },
     output::{OptionOutputAsset, OutputAsset, OutputAssets},
     reference::all_assets_from_entries,
@@ -277,7 +278,7 @@ impl PagesProject {
     }
 
     #[turbo_tasks::function]
-    async fn pages_structure(&self) -> Result<Vc<PagesStructure>> {
+    pub(crate) async fn pages_structure(&self) -> Result<Vc<PagesStructure>> {
         let next_router_fs = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new());
         let next_router_root = next_router_fs.root().owned().await?;
         Ok(find_pages_structure(
@@ -963,6 +964,16 @@ impl PageEndpoint {
                     .await?;
                 }
 
+                detect_duplicate_packages(
+                    client_module_graph,
+                    project
+                        .next_config()
+                        .turbopack_allow_duplicate_packages()
+                        .owned()
+                        .await?,
+                )
+                .await?;
+
                 let next_dynamic_imports =
                     NextDynamicGraphs::new(client_module_graph, per_page_module_graph)
                         .get_next_dynamic_imports_for_endpoint(self.client_module())
@@ -1610,7 +1621,7 @@ impl Endpoint for PageEndpoint {
             let output_assets = output.output_assets();
             let output_assets = if let Some(sri) =
                 &*project.next_config().experimental_sri().await?
-                && let Some(algorithm) = sri.algorithm.clone()
+                && !sri.algorithms().is_empty()
             {
                 let sri_manifest = get_sri_manifest_asset(
                     node_root.join(&format!(
@@ -1619,7 +1630,7 @@ impl Endpoint for PageEndpoint {
                     ))?,
                     output_assets,
                     client_relative_root.clone(),
-                    algorithm,
+                    sri.algorithms().to_vec(),
                 );
                 output_assets.concat_asset(sri_manifest)
             } else {
@@ -31,25 +31,30 @@ use serde::{Deserialize, Serialize};
 use tracing::{Instrument, field::Empty};
 use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{
-    Completion, Completions, FxIndexMap, IntoTraitRef, NonLocalValue, OperationValue, OperationVc,
-    ReadRef, ResolvedVc, State, TaskInput, TransientInstance, TryFlatJoinIterExt, Vc,
-    debug::ValueDebugFormat, fxindexmap, mark_root, trace::TraceRawVcs,
+    Completion, Completions, FxIndexMap, FxIndexSet, IntoTraitRef, NonLocalValue, OperationValue,
+    OperationVc, ReadRef, ResolvedVc, State, TaskInput, TransientInstance, TryFlatJoinIterExt,
+    TryJoinIterExt, Vc, debug::ValueDebugFormat, fxindexmap, mark_root, trace::TraceRawVcs,
 };
 use turbo_tasks_env::{EnvMap, ProcessEnv};
 use turbo_tasks_fs::{
-    DiskFileSystem, FileContent, FileSystem, FileSystemPath, VirtualFileSystem, invalidation,
+    DiskFileSystem, FileContent, FileSystem, FileSystemPath, OverlayFileSystem, VirtualFileSystem,
+    invalidation,
 };
 use turbo_unix_path::{join_path, unix_to_sys};
 use turbopack::{
     ModuleAssetContext, evaluate_context::node_build_environment,
     global_module_ids::get_global_module_id_strategy, transition::TransitionOptions,
 };
+use turbopack_browser::ecmascript::{
+    EcmascriptBrowserServiceWorkerEntrypoint, with_service_worker_precache_manifests,
+};
 use turbopack_core::{
     PROJECT_FILESYSTEM_NAME,
     changed::content_changed,
     chunk::{
         ChunkingContext, EvaluatableAssets, UnusedReferences,
         chunk_id_strategy::{ModuleIdFallback, ModuleIdStrategy},
+        property_name_cache::OptionPropertyNameCache,
     },
     compile_time_info::CompileTimeInfo,
     context::AssetContext,
@@ -79,6 +84,9 @@ use turbopack_core::{
         NotFoundVersion, OptionVersionedContent, Update, Version, VersionState, VersionedContent,
     },
 };
+use turbopack_ecmascript::property_mangling::{
+    PropertyNameCacheAsset, get_global_property_name_cache, read_property_name_cache,
+};
 use turbopack_node::execution_context::ExecutionContext;
 use turbopack_nodejs::NodeJsChunkingContext;
 
@@ -1020,6 +1028,14 @@ impl Project {
         ))
     }
 
+    /// The project file system with the unsaved editor buffers set through
+    /// [OverlayFileSystem::set_content] layered on top of it. All project files are read through
+    /// this file system.
+    #[turbo_tasks::function]
+    pub fn project_overlay_fs(self: Vc<Self>) -> Vc<OverlayFileSystem> {
+        OverlayFileSystem::new(self.project_fs())
+    }
+
     #[turbo_tasks::function]
     pub fn client_fs(self: Vc<Self>) -> Vc<Box<dyn FileSystem>> {
         let virtual_fs = VirtualFileSystem::new_with_name(rcstr!("client-fs"));
@@ -1066,7 +1082,7 @@ impl Project {
 
     #[turbo_tasks::function]
     pub fn project_root_path(self: Vc<Self>) -> Vc<FileSystemPath> {
-        self.project_fs().root()
+        self.project_overlay_fs().root()
     }
 
     #[turbo_tasks::function]
@@ -1168,6 +1184,11 @@ impl Project {
         Ok(Vc::cell(*self.mode.await? == NextMode::Development))
     }
 
+    #[turbo_tasks::function]
+    pub(super) fn build_id(&self) -> Vc<RcStr> {
+        Vc::cell(self.build_id.clone())
+    }
+
     #[turbo_tasks::function]
     pub(super) fn encryption_key(&self) -> Vc<RcStr> {
         Vc::cell(self.encryption_key.clone())
@@ -1510,15 +1531,19 @@ impl Project {
             export_usage: self.export_usage(),
             unused_references: self.unused_references(),
             minify: self.next_config().turbo_minify(self.next_mode()),
+            minify_options: self.next_config().turbo_minify_options(),
+            property_name_cache: self.property_name_cache(),
             source_maps: self.next_config().client_source_maps(self.next_mode()),
             no_mangling: self.no_mangling(),
             scope_hoisting: self.next_config().turbo_scope_hoisting(self.next_mode()),
             nested_async_chunking: self
                 .next_config()
                 .turbo_nested_async_chunking(self.next_mode(), true),
+            profile_module_evaluation: self.next_config().turbopack_profile_module_evaluation(),
             debug_ids: self.next_config().turbopack_debug_ids(),
             should_use_absolute_url_references: self.next_config().inline_css(),
             css_url_suffix,
+            service_worker_url: self.next_config().turbopack_service_worker_url(),
         }))
     }
 
@@ -1538,12 +1563,15 @@ impl Project {
             export_usage: self.export_usage(),
             unused_references: self.unused_references(),
             minify: self.next_config().turbo_minify(self.next_mode()),
+            minify_options: self.next_config().turbo_minify_options(),
+            property_name_cache: self.property_name_cache(),
             source_maps: self.next_config().server_source_maps(),
             no_mangling: self.no_mangling(),
             scope_hoisting: self.next_config().turbo_scope_hoisting(self.next_mode()),
             nested_async_chunking: self
                 .next_config()
                 .turbo_nested_async_chunking(self.next_mode(), false),
+            profile_module_evaluation: self.next_config().turbopack_profile_module_evaluation(),
             debug_ids: self.next_config().turbopack_debug_ids(),
             client_root: self.client_relative_path().owned().await?,
             asset_prefix: self.next_config().computed_asset_prefix().owned().await?,
@@ -1572,12 +1600,15 @@ impl Project {
             export_usage: self.export_usage(),
             unused_references: self.unused_references(),
             turbo_minify: self.next_config().turbo_minify(self.next_mode()),
+            turbo_minify_options: self.next_config().turbo_minify_options(),
+            property_name_cache: self.property_name_cache(),
             turbo_source_maps: self.next_config().server_source_maps(),
             no_mangling: self.no_mangling(),
             scope_hoisting: self.next_config().turbo_scope_hoisting(self.next_mode()),
             nested_async_chunking: self
                 .next_config()
                 .turbo_nested_async_chunking(self.next_mode(), false),
+            profile_module_evaluation: self.next_config().turbopack_profile_module_evaluation(),
             client_root: self.client_relative_path().owned().await?,
             asset_prefix: self.next_config().computed_asset_prefix().owned().await?,
             css_url_suffix,
@@ -2154,6 +2185,8 @@ impl Project {
             let node_root = self.node_root().owned().await?;
 
             if let Some(map) = self.await?.versioned_content_map {
+                // `next dev` only compiles the requested routes, so service workers are emitted
+                // with an empty precache manifest.
                 map.insert_output_assets(
                     all_output_assets,
                     node_root.clone(),
@@ -2166,7 +2199,7 @@ impl Project {
                 Ok(())
             } else {
                 emit_assets(
-                    all_output_assets.connect(),
+                    with_app_service_workers(all_output_assets.connect(), self),
                     node_root.clone(),
                     client_relative_path.clone(),
                     node_root.clone(),
@@ -2329,6 +2362,108 @@ impl Project {
         }
     }
 
+    /// The mangled property names of the build, when property mangling is enabled in the minifier
+    /// options.
+    #[turbo_tasks::function]
+    pub async fn property_name_cache(self: Vc<Self>) -> Result<Vc<OptionPropertyNameCache>> {
+        let Some(options) = self
+            .next_config()
+            .turbo_minify_options()
+            .await?
+            .mangle_properties
+            .clone()
+        else {
+            return Ok(Vc::cell(None));
+        };
+        if !*self.next_config().turbo_minify(self.next_mode()).await? || *self.no_mangling().await?
+        {
+            return Ok(Vc::cell(None));
+        }
+
+        let module_graphs = self.whole_app_module_graphs().await?;
+        Ok(Vc::cell(Some(
+            get_global_property_name_cache(
+                *module_graphs.full,
+                options,
+                self.previous_property_name_cache(),
+            )
+            .to_resolved()
+            .await?,
+        )))
+    }
+
+    /// The property name cache written by the previous build.
+    ///
+    /// INVALIDATION: The cache is read untracked and copied, so writing the cache of this build
+    /// doesn't invalidate [Project::property_name_cache], and it's never read back in the same
+    /// session.
+    #[turbo_tasks::function]
+    async fn previous_property_name_cache(self: Vc<Self>) -> Result<Vc<OptionPropertyNameCache>> {
+        let path = self.property_name_cache_path().owned().await?;
+        let Some(cache) = *read_property_name_cache(path).untracked().await? else {
+            return Ok(Vc::cell(None));
+        };
+        let names = cache.untracked().await?;
+        Ok(Vc::cell(Some(ResolvedVc::cell((*names).clone()))))
+    }
+
+    /// The property name cache is kept in the `cache` directory, which isn't cleared between
+    /// builds, so the mangled names stay stable.
+    #[turbo_tasks::function]
+    async fn property_name_cache_path(self: Vc<Self>) -> Result<Vc<FileSystemPath>> {
+        Ok(self
+            .node_root()
+            .await?
+            .join("cache/turbopack/property-names.json")?
+            .cell())
+    }
+
+    /// The service workers of all routes, with a precache manifest listing the client output
+    /// assets of the whole app. Like the other app-wide manifests, it's built once instead of for
+    /// the output assets of each endpoint.
+    #[turbo_tasks::function]
+    pub async fn service_worker_assets(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
+        let endpoint_groups = self.get_all_endpoint_groups(false).await?;
+        let output_assets = endpoint_groups
+            .iter()
+            .map(async |(_, group)| group.output_assets().await)
+            .try_join()
+            .await?
+            .iter()
+            .flat_map(|assets| assets.iter().copied())
+            .collect::<FxIndexSet<_>>();
+        let assets = with_service_worker_precache_manifests(all_assets_from_entries(Vc::cell(
+            output_assets.into_iter().collect(),
+        )))
+        .await?;
+        Ok(Vc::cell(
+            assets
+                .iter()
+                .copied()
+                .filter(|&asset| {
+                    ResolvedVc::try_downcast_type::<EcmascriptBrowserServiceWorkerEntrypoint>(asset)
+                        .is_some()
+                })
+                .collect(),
+        ))
+    }
+
+    /// Writes the [Project::property_name_cache] for the next build. It must only be emitted after
+    /// all other output assets, see [Project::previous_property_name_cache].
+    #[turbo_tasks::function]
+    pub async fn property_name_cache_assets(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
+        let assets = if let Some(cache) = *self.property_name_cache().await? {
+            let path = self.property_name_cache_path().owned().await?;
+            let asset = PropertyNameCacheAsset::new(path, *cache)
+                .to_resolved()
+                .await?;
+            vec![ResolvedVc::upcast(asset)]
+        } else {
+            vec![]
+        };
+        Ok(Vc::cell(assets))
+    }
+
     /// Compute the used exports and unused imports for each module.
     #[turbo_tasks::function]
     async fn binding_usage_info(self: Vc<Self>) -> Result<Vc<BindingUsageInfo>> {
@@ -2379,10 +2514,12 @@ impl Project {
     }
 }
 
+/// The module graphs of all routes. Unlike [Project::whole_app_module_graphs], the issues of the
+/// graph are kept in development mode, so they can be collected from the operation.
 // This is a performance optimization. This function is a root aggregation function that
 // aggregates over the whole subgraph.
 #[turbo_tasks::function(operation)]
-async fn whole_app_module_graph_operation(
+pub async fn whole_app_module_graph_operation(
     project: ResolvedVc<Project>,
 ) -> Result<Vc<BaseAndFullModuleGraph>> {
     mark_root();
@@ -2508,3 +2645,29 @@ fn all_assets_from_entries_operation(
     let assets = operation.connect();
     Ok(all_assets_from_entries(assets))
 }
+
+/// Replaces the service workers in `assets` with [Project::service_worker_assets], so a service
+/// worker is emitted with the precache manifest of the whole app regardless of which endpoint
+/// references it.
+#[turbo_tasks::function]
+async fn with_app_service_workers(
+    assets: ResolvedVc<ExpandedOutputAssets>,
+    project: Vc<Project>,
+) -> Result<Vc<ExpandedOutputAssets>> {
+    let is_service_worker = |asset: ResolvedVc<Box<dyn OutputAsset>>| {
+        ResolvedVc::try_downcast_type::<EcmascriptBrowserServiceWorkerEntrypoint>(asset).is_some()
+    };
+    let assets_ref = assets.await?;
+    if !assets_ref.iter().any(|&asset| is_service_worker(asset)) {
+        return Ok(*assets);
+    }
+    let service_workers = project.service_worker_assets().await?;
+    Ok(Vc::cell(
+        assets_ref
+            .iter()
+            .copied()
+            .filter(|&asset| !is_service_worker(asset))
+            .chain(service_workers.iter().copied())
+            .collect(),
+    ))
+}
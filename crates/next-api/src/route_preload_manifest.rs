@@ -0,0 +1,280 @@
+use anyhow::Result;
+use bincode::{Decode, Encode};
+use serde::Serialize;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{
+    FxIndexMap, NonLocalValue, ResolvedVc, TryFlatJoinIterExt, TryJoinIterExt, Vc,
+    debug::ValueDebugFormat, trace::TraceRawVcs,
+};
+use turbo_tasks_fs::{FileContent, FileSystemPath};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    output::{
+        ExpandOutputAssetsInput, OutputAsset, OutputAssets, OutputAssetsReference,
+        expand_output_assets,
+    },
+};
+
+use crate::{project::Project, route::EndpointGroupKey};
+
+/// A client asset that is loaded for a route.
+#[derive(
+    TraceRawVcs,
+    PartialEq,
+    Eq,
+    ValueDebugFormat,
+    Clone,
+    Debug,
+    NonLocalValue,
+    Encode,
+    Decode,
+    Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PreloadAsset {
+    /// The path relative to `/_next/`.
+    pub path: RcStr,
+    /// The `as` attribute of a `<link rel="preload">` for this asset.
+    #[serde(rename = "as")]
+    pub preload_as: RcStr,
+    pub content_type: RcStr,
+    pub size: u64,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct PreloadAssets(Vec<PreloadAsset>);
+
+/// Returns the client assets (chunks, styles, fonts and other static assets) of the output
+/// assets of a route, in the order they are referenced.
+#[turbo_tasks::function]
+pub async fn route_preload_assets(
+    output_assets: Vc<OutputAssets>,
+    client_relative_path: FileSystemPath,
+) -> Result<Vc<PreloadAssets>> {
+    let output_assets = expand_output_assets(
+        output_assets
+            .await?
+            .into_iter()
+            .map(|asset| ExpandOutputAssetsInput::Asset(*asset)),
+        true,
+    )
+    .await?;
+    let assets = output_assets
+        .iter()
+        .map(async |asset| {
+            let path = asset.path().await?;
+            let Some(relative_path) = client_relative_path.get_path_to(&path) else {
+                return Ok(None);
+            };
+            let extension = path.extension();
+            let Some(preload_as) = preload_as(extension) else {
+                return Ok(None);
+            };
+            let AssetContent::File(content) = &*asset.content().await? else {
+                return Ok(None);
+            };
+            let FileContent::Content(file) = &*content.await? else {
+                return Ok(None);
+            };
+            Ok(Some(PreloadAsset {
+                path: relative_path.into(),
+                preload_as: preload_as.into(),
+                content_type: mime_guess::from_ext(extension)
+                    .first_or_octet_stream()
+                    .essence_str()
+                    .into(),
+                size: file.content().len() as u64,
+            }))
+        })
+        .try_flat_join()
+        .await?;
+    Ok(Vc::cell(assets))
+}
+
+/// The `as` attribute of a `<link rel="preload">` for a file with the given extension, or `None`
+/// for files that are never loaded by the browser, e.g. source maps.
+fn preload_as(extension: &str) -> Option<&'static str> {
+    Some(match extension {
+        "map" => return None,
+        "js" | "mjs" => "script",
+        "css" => "style",
+        "woff" | "woff2" | "ttf" | "otf" | "eot" => "font",
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "avif" | "svg" | "ico" | "bmp" => "image",
+        _ => "fetch",
+    })
+}
+
+#[derive(Serialize)]
+struct RoutePreloadManifest<'l> {
+    routes: FxIndexMap<&'l str, &'l [PreloadAsset]>,
+}
+
+/// A manifest with the client assets of every route, so the client can start loading them
+/// before navigating to a route.
+#[turbo_tasks::value]
+pub struct RoutePreloadManifestAsset {
+    path: FileSystemPath,
+    project: ResolvedVc<Project>,
+}
+
+#[turbo_tasks::value_impl]
+impl RoutePreloadManifestAsset {
+    #[turbo_tasks::function]
+    pub fn new(path: FileSystemPath, project: ResolvedVc<Project>) -> Vc<Self> {
+        RoutePreloadManifestAsset { path, project }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for RoutePreloadManifestAsset {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let client_relative_path = self.project.client_relative_path().owned().await?;
+        let endpoint_groups = self.project.get_all_endpoint_groups(false).await?;
+
+        let routes = endpoint_groups
+            .iter()
+            .filter_map(|(key, group)| match key {
+                EndpointGroupKey::Route(route) => Some((route, group)),
+                _ => None,
+            })
+            .map(async |(route, group)| {
+                let assets =
+                    route_preload_assets(group.output_assets(), client_relative_path.clone())
+                        .await?;
+                Ok((route, assets))
+            })
+            .try_join()
+            .await?;
+
+        let manifest = serde_json::to_string_pretty(&RoutePreloadManifest {
+            routes: routes
+                .iter()
+                .map(|(route, assets)| (route.as_str(), &***assets))
+                .collect(),
+        })?;
+        Ok(AssetContent::File(FileContent::Content(manifest.into()).resolved_cell()).cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAssetsReference for RoutePreloadManifestAsset {}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for RoutePreloadManifestAsset {
+    #[turbo_tasks::function]
+    fn path(&self) -> Vc<FileSystemPath> {
+        self.path.clone().cell()
+    }
+}
+
+#[turbo_tasks::function]
+pub async fn route_preload_manifest_asset_if_enabled(
+    project: ResolvedVc<Project>,
+) -> Result<Vc<OutputAssets>> {
+    let enabled = *project
+        .next_config()
+        .turbopack_route_preload_manifest()
+        .await?;
+    let assets = if enabled {
+        // Next.js serves `/_next/static` with an immutable cache policy, so the manifest is
+        // emitted next to `_buildManifest.js` in the directory of the build.
+        let build_id = project.build_id().await?;
+        let path = project
+            .client_relative_path()
+            .await?
+            .join(&format!("static/{build_id}/route-preload-manifest.json"))?;
+        let asset = RoutePreloadManifestAsset::new(path, *project)
+            .to_resolved()
+            .await?;
+        vec![ResolvedVc::upcast(asset)]
+    } else {
+        vec![]
+    };
+    Ok(Vc::cell(assets))
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_tasks::ResolvedVc;
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+    use turbo_tasks_fs::{File, FileSystem, NullFileSystem};
+    use turbopack_core::virtual_output::VirtualOutputAsset;
+
+    use super::*;
+
+    async fn output_asset(
+        path: FileSystemPath,
+        content: &str,
+        references: Vec<ResolvedVc<Box<dyn OutputAsset>>>,
+    ) -> Result<ResolvedVc<Box<dyn OutputAsset>>> {
+        let content = AssetContent::file(FileContent::Content(File::from(content)).cell())
+            .to_resolved()
+            .await?;
+        Ok(ResolvedVc::upcast(
+            VirtualOutputAsset::new_with_references(path, content, ResolvedVc::cell(references))
+                .to_resolved()
+                .await?,
+        ))
+    }
+
+    /// The output assets of a route: a server entry, and a client chunk referencing its source
+    /// map and an image.
+    #[turbo_tasks::function(operation)]
+    async fn route_preload_assets_operation() -> Result<Vc<PreloadAssets>> {
+        let root = NullFileSystem {}.cell().root().owned().await?;
+        let client_root = root.join(".next")?;
+        let image = output_asset(client_root.join("static/media/hero.png")?, "png", vec![]).await?;
+        let source_map =
+            output_asset(client_root.join("static/chunks/page.js.map")?, "{}", vec![]).await?;
+        let chunk = output_asset(
+            client_root.join("static/chunks/page.js")?,
+            "console.log(1)",
+            vec![source_map, image],
+        )
+        .await?;
+        let server = output_asset(client_root.join("server/app/page.js")?, "", vec![]).await?;
+        Ok(route_preload_assets(
+            Vc::cell(vec![server, chunk]),
+            client_root,
+        ))
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_route_preload_assets() {
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            let assets = route_preload_assets_operation()
+                .read_strongly_consistent()
+                .await?;
+            let mut assets = assets.iter().collect::<Vec<_>>();
+            assets.sort_by(|a, b| a.path.cmp(&b.path));
+
+            // Server assets and source maps are never loaded by the browser
+            assert_eq!(assets.len(), 2);
+            assert_eq!(assets[0].path, "static/chunks/page.js");
+            assert_eq!(assets[0].preload_as, "script");
+            assert_eq!(assets[0].size, 14);
+            assert_eq!(assets[1].path, "static/media/hero.png");
+            assert_eq!(assets[1].preload_as, "image");
+            assert_eq!(assets[1].content_type, "image/png");
+            assert_eq!(assets[1].size, 3);
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_preload_as() {
+        assert_eq!(preload_as("js"), Some("script"));
+        assert_eq!(preload_as("css"), Some("style"));
+        assert_eq!(preload_as("woff2"), Some("font"));
+        assert_eq!(preload_as("avif"), Some("image"));
+        assert_eq!(preload_as("glb"), Some("fetch"));
+        assert_eq!(preload_as("map"), None);
+    }
+}
@@ -0,0 +1,484 @@
+use anyhow::Result;
+use next_core::{
+    app_structure::{AppDirModules, AppPageLoaderTree, Entrypoint, Entrypoints, MetadataItem},
+    pages_structure::{PagesDirectoryStructure, PagesStructure},
+    parse_segment_config_from_loader_tree, parse_segment_config_from_source,
+    segment_config::{
+        NextRevalidate, NextSegmentConfig, NextSegmentDynamic, NextSegmentFetchCache,
+        ParseSegmentMode,
+    },
+    util::NextRuntime,
+};
+use serde::Serialize;
+use serde_json::{Value as JsonValue, json};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, ResolvedVc, TryJoinIterExt, Vc};
+use turbo_tasks_fs::{FileContent, FileSystemPath};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    file_source::FileSource,
+    output::{OutputAsset, OutputAssets, OutputAssetsReference},
+};
+
+use crate::project::Project;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RouteTree {
+    app: Vec<AppRouteNode>,
+    pages: Vec<PagesRouteNode>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppRouteNode {
+    pathname: String,
+    /// `page`, `route` or `metadata`.
+    #[serde(rename = "type")]
+    ty: &'static str,
+    /// The app directory paths of the route, including route groups and parallel slots, e.g.
+    /// `/(shop)/product/@modal/(.)[id]/page`.
+    pages: Vec<String>,
+    intercepting: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<RcStr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tree: Option<SegmentNode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<SegmentConfig>,
+}
+
+/// A segment of a loader tree with its special files and parallel routes.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SegmentNode {
+    segment: RcStr,
+    files: FxIndexMap<&'static str, RcStr>,
+    parallel_routes: FxIndexMap<RcStr, SegmentNode>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SegmentConfig {
+    runtime: &'static str,
+    dynamic: Option<&'static str>,
+    dynamic_params: Option<bool>,
+    revalidate: Option<JsonValue>,
+    fetch_cache: Option<&'static str>,
+    preferred_region: Option<Vec<RcStr>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PagesRouteNode {
+    pathname: RcStr,
+    /// `page` or `api`.
+    #[serde(rename = "type")]
+    ty: &'static str,
+    file: RcStr,
+    config: SegmentConfig,
+}
+
+impl SegmentConfig {
+    fn new(config: &NextSegmentConfig) -> Self {
+        SegmentConfig {
+            runtime: match config.runtime.unwrap_or_default() {
+                NextRuntime::NodeJs => "nodejs",
+                NextRuntime::Edge => "edge",
+            },
+            dynamic: config.dynamic.map(|dynamic| match dynamic {
+                NextSegmentDynamic::Auto => "auto",
+                NextSegmentDynamic::ForceDynamic => "force-dynamic",
+                NextSegmentDynamic::Error => "error",
+                NextSegmentDynamic::ForceStatic => "force-static",
+            }),
+            dynamic_params: config.dynamic_params,
+            revalidate: config.revalidate.map(|revalidate| match revalidate {
+                NextRevalidate::Never => json!(false),
+                NextRevalidate::ForceCache => json!("force-cache"),
+                NextRevalidate::Frequency { seconds } => json!(seconds),
+            }),
+            fetch_cache: config.fetch_cache.map(|fetch_cache| match fetch_cache {
+                NextSegmentFetchCache::Auto => "auto",
+                NextSegmentFetchCache::DefaultCache => "default-cache",
+                NextSegmentFetchCache::OnlyCache => "only-cache",
+                NextSegmentFetchCache::ForceCache => "force-cache",
+                NextSegmentFetchCache::DefaultNoStore => "default-no-store",
+                NextSegmentFetchCache::OnlyNoStore => "only-no-store",
+                NextSegmentFetchCache::ForceNoStore => "force-no-store",
+            }),
+            preferred_region: config.preferred_region.clone(),
+        }
+    }
+}
+
+/// Returns the path of `path` relative to the project, as it appears in the route tree.
+fn project_relative(project_path: &FileSystemPath, path: &FileSystemPath) -> RcStr {
+    project_path
+        .get_path_to(path)
+        .map(RcStr::from)
+        .unwrap_or_else(|| path.path.clone())
+}
+
+fn segment_node(project_path: &FileSystemPath, tree: &AppPageLoaderTree) -> SegmentNode {
+    let AppDirModules {
+        page,
+        layout,
+        error,
+        global_error,
+        global_not_found,
+        loading,
+        template,
+        forbidden,
+        unauthorized,
+        not_found,
+        default,
+        route,
+        metadata: _,
+    } = &tree.modules;
+    let files = [
+        ("page", page),
+        ("layout", layout),
+        ("template", template),
+        ("loading", loading),
+        ("error", error),
+        ("globalError", global_error),
+        ("notFound", not_found),
+        ("globalNotFound", global_not_found),
+        ("forbidden", forbidden),
+        ("unauthorized", unauthorized),
+        ("default", default),
+        ("route", route),
+    ]
+    .into_iter()
+    .filter_map(|(name, path)| Some((name, project_relative(project_path, path.as_ref()?))))
+    .collect();
+    SegmentNode {
+        segment: tree.segment.clone(),
+        files,
+        parallel_routes: tree
+            .parallel_routes
+            .iter()
+            .map(|(slot, tree)| (slot.clone(), segment_node(project_path, tree)))
+            .collect(),
+    }
+}
+
+async fn app_route_node(
+    project_path: &FileSystemPath,
+    pathname: String,
+    intercepting: bool,
+    entrypoint: &Entrypoint,
+) -> Result<AppRouteNode> {
+    Ok(match entrypoint {
+        Entrypoint::AppPage {
+            pages, loader_tree, ..
+        } => AppRouteNode {
+            pathname,
+            ty: "page",
+            pages: pages.iter().map(|page| page.to_string()).collect(),
+            intercepting,
+            file: None,
+            tree: Some(segment_node(project_path, &*loader_tree.await?)),
+            config: Some(SegmentConfig::new(
+                &*parse_segment_config_from_loader_tree(**loader_tree).await?,
+            )),
+        },
+        Entrypoint::AppRoute {
+            page,
+            path,
+            root_layouts,
+            ..
+        } => {
+            let mut config = parse_segment_config_from_source(
+                Vc::upcast(FileSource::new(path.clone())),
+                ParseSegmentMode::App,
+            )
+            .owned()
+            .await?;
+            for layout in root_layouts.await?.iter().rev() {
+                let source = Vc::upcast(FileSource::new(layout.clone()));
+                config.apply_parent_config(
+                    &*parse_segment_config_from_source(source, ParseSegmentMode::App).await?,
+                );
+            }
+            AppRouteNode {
+                pathname,
+                ty: "route",
+                pages: vec![page.to_string()],
+                intercepting,
+                file: Some(project_relative(project_path, path)),
+                tree: None,
+                config: Some(SegmentConfig::new(&config)),
+            }
+        }
+        Entrypoint::AppMetadata { page, metadata, .. } => {
+            let (MetadataItem::Static { path } | MetadataItem::Dynamic { path }) = metadata;
+            AppRouteNode {
+                pathname,
+                ty: "metadata",
+                pages: vec![page.to_string()],
+                intercepting,
+                file: Some(project_relative(project_path, path)),
+                tree: None,
+                config: None,
+            }
+        }
+    })
+}
+
+async fn app_route_nodes(
+    project_path: &FileSystemPath,
+    entrypoints: Vc<Entrypoints>,
+) -> Result<Vec<AppRouteNode>> {
+    entrypoints
+        .await?
+        .iter()
+        .map(|(path, entrypoint)| {
+            app_route_node(
+                project_path,
+                path.to_string(),
+                path.contains_interception(),
+                entrypoint,
+            )
+        })
+        .try_join()
+        .await
+}
+
+/// A JSON description of the resolved route tree of the app and pages directories, including
+/// the special files of every segment, parallel and intercepting routes, the segment config of
+/// every route and metadata routes.
+#[turbo_tasks::value]
+pub struct RouteTreeAsset {
+    path: FileSystemPath,
+    project: ResolvedVc<Project>,
+}
+
+#[turbo_tasks::value_impl]
+impl RouteTreeAsset {
+    #[turbo_tasks::function]
+    pub fn new(path: FileSystemPath, project: ResolvedVc<Project>) -> Vc<Self> {
+        RouteTreeAsset { path, project }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for RouteTreeAsset {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let project_path = self.project.project_path().owned().await?;
+
+        let app = if let Some(app_project) = *self.project.app_project().await? {
+            app_route_nodes(&project_path, app_project.app_entrypoints()).await?
+        } else {
+            vec![]
+        };
+
+        let PagesStructure {
+            api,
+            pages,
+            should_create_pages_entries,
+            ..
+        } = &*self.project.pages_project().pages_structure().await?;
+        let mut items = Vec::new();
+        if *should_create_pages_entries {
+            for (dir, is_api) in [(*api, true), (*pages, false)] {
+                let mut queue = dir.into_iter().collect::<Vec<_>>();
+                while let Some(dir) = queue.pop() {
+                    let PagesDirectoryStructure {
+                        items: dir_items,
+                        children,
+                        ..
+                    } = &*dir.await?;
+                    items.extend(dir_items.iter().map(|&item| (item, is_api)));
+                    queue.extend(children.iter().copied());
+                }
+            }
+        }
+        let pages = items
+            .into_iter()
+            .map(async |(item, is_api)| {
+                let file = item.file_path().owned().await?;
+                let config = parse_segment_config_from_source(
+                    Vc::upcast(FileSource::new(file.clone())),
+                    ParseSegmentMode::Base,
+                )
+                .await?;
+                Ok(PagesRouteNode {
+                    pathname: format!("/{}", item.await?.next_router_path.path).into(),
+                    ty: if is_api { "api" } else { "page" },
+                    file: project_relative(&project_path, &file),
+                    config: SegmentConfig::new(&config),
+                })
+            })
+            .try_join()
+            .await?;
+
+        let tree = serde_json::to_string_pretty(&RouteTree { app, pages })?;
+        Ok(AssetContent::File(FileContent::Content(tree.into()).resolved_cell()).cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAssetsReference for RouteTreeAsset {}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for RouteTreeAsset {
+    #[turbo_tasks::function]
+    fn path(&self) -> Vc<FileSystemPath> {
+        self.path.clone().cell()
+    }
+}
+
+#[turbo_tasks::function]
+pub async fn route_tree_asset_if_enabled(project: ResolvedVc<Project>) -> Result<Vc<OutputAssets>> {
+    let enabled = *project.next_config().turbopack_route_tree().await?;
+    let assets = if enabled {
+        let path = project.node_root().await?.join("route-tree.json")?;
+        let asset = RouteTreeAsset::new(path, *project).to_resolved().await?;
+        vec![ResolvedVc::upcast(asset)]
+    } else {
+        vec![]
+    };
+    Ok(Vc::cell(assets))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use next_core::{app_structure::get_entrypoints, mode::NextMode};
+    use serde_json::Value;
+    use turbo_rcstr::rcstr;
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+    use turbo_tasks_fs::{DiskFileSystem, FileSystem};
+
+    use super::*;
+
+    #[turbo_tasks::function(operation)]
+    async fn app_route_tree_operation(root: RcStr) -> Result<Vc<RcStr>> {
+        let project_path = DiskFileSystem::new(rcstr!("project"), root)
+            .root()
+            .owned()
+            .await?;
+        let entrypoints = get_entrypoints(
+            project_path.join("app")?,
+            Vc::cell(vec![rcstr!("tsx")]),
+            Vc::cell(false),
+            NextMode::Development.cell(),
+        );
+        let nodes = app_route_nodes(&project_path, entrypoints).await?;
+        Ok(Vc::cell(serde_json::to_string(&nodes)?.into()))
+    }
+
+    /// Returns the segments on the path to the first page below `tree`, following `slot` at the
+    /// top and `children` below.
+    fn page_path<'a>(tree: &'a Value, slot: &str) -> (Vec<&'a str>, &'a str) {
+        let mut segments = Vec::new();
+        let mut node = &tree["parallelRoutes"][slot];
+        loop {
+            segments.push(node["segment"].as_str().unwrap());
+            if let Some(page) = node["files"]["page"].as_str() {
+                return (segments, page);
+            }
+            node = &node["parallelRoutes"]["children"];
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_app_route_tree() {
+        let project_root = tempfile::tempdir().unwrap();
+        for file in [
+            "app/layout.tsx",
+            "app/page.tsx",
+            "app/blog/[slug]/page.tsx",
+            "app/docs/[...path]/page.tsx",
+            "app/dashboard/layout.tsx",
+            "app/dashboard/page.tsx",
+            "app/dashboard/@analytics/page.tsx",
+            "app/dashboard/@analytics/default.tsx",
+        ] {
+            let path = project_root.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "export default function Page() {}").unwrap();
+        }
+        // The built-in not-found page is resolved from the `next` package
+        let next_package = project_root.path().join("node_modules/next");
+        fs::create_dir_all(&next_package).unwrap();
+        fs::write(next_package.join("package.json"), r#"{ "name": "next" }"#).unwrap();
+        let root = RcStr::from(project_root.path().to_str().unwrap());
+
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async move {
+            let tree = app_route_tree_operation(root)
+                .read_strongly_consistent()
+                .await?;
+            let routes: Vec<Value> = serde_json::from_str(&tree)?;
+            let route = |pathname: &str| {
+                routes
+                    .iter()
+                    .find(|route| route["pathname"] == pathname)
+                    .unwrap_or_else(|| panic!("missing route {pathname}"))
+            };
+
+            let mut pathnames = routes
+                .iter()
+                .map(|route| route["pathname"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            pathnames.sort();
+            assert_eq!(
+                pathnames,
+                [
+                    "/",
+                    "/_not-found",
+                    "/blog/[slug]",
+                    "/dashboard",
+                    "/docs/[...path]"
+                ]
+            );
+            assert!(routes.iter().all(|route| route["type"] == "page"));
+            assert!(routes.iter().all(|route| route["intercepting"] == false));
+
+            let blog = route("/blog/[slug]");
+            assert_eq!(blog["pages"], serde_json::json!(["/blog/[slug]/page"]));
+            assert_eq!(blog["tree"]["files"]["layout"], "app/layout.tsx");
+            assert_eq!(
+                page_path(&blog["tree"], "children"),
+                (
+                    vec!["blog", "[slug]", "__PAGE__"],
+                    "app/blog/[slug]/page.tsx"
+                )
+            );
+            assert_eq!(
+                page_path(&route("/docs/[...path]")["tree"], "children"),
+                (
+                    vec!["docs", "[...path]", "__PAGE__"],
+                    "app/docs/[...path]/page.tsx"
+                )
+            );
+
+            // The parallel slot is a sibling of the `children` of the dashboard layout
+            let dashboard = &route("/dashboard")["tree"]["parallelRoutes"]["children"];
+            assert_eq!(dashboard["segment"], "dashboard");
+            assert_eq!(dashboard["files"]["layout"], "app/dashboard/layout.tsx");
+            assert_eq!(
+                page_path(dashboard, "children"),
+                (vec!["__PAGE__"], "app/dashboard/page.tsx")
+            );
+            assert_eq!(
+                page_path(dashboard, "analytics"),
+                (
+                    vec!["(slot)", "__PAGE__"],
+                    "app/dashboard/@analytics/page.tsx"
+                )
+            );
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
@@ -8,6 +8,7 @@ use next_core::{
     },
     util::NextRuntime,
 };
+use rustc_hash::{FxHashMap, FxHashSet};
 use swc_core::{
     atoms::{Atom, atom},
     common::comments::Comments,
@@ -32,8 +33,12 @@ use turbopack_core::{
     context::AssetContext,
     file_source::FileSource,
     ident::AssetIdent,
+    issue::{Issue, IssueSeverity, IssueStage, OptionStyledString, StyledString},
     module::Module,
-    module_graph::{ModuleGraph, ModuleGraphLayer, async_module_info::AsyncModulesInfo},
+    module_graph::{
+        ModuleGraph, ModuleGraphLayer, async_module_info::AsyncModulesInfo,
+        binding_usage_info::ModuleExportUsageInfo,
+    },
     output::OutputAsset,
     reference_type::{EcmaScriptModulesReferenceSubType, ReferenceType},
     resolve::ModulePart,
@@ -45,8 +50,8 @@ use turbopack_ecmascript::{
     tree_shake::asset::EcmascriptModulePartAsset,
 };
 
-/// Metadata for a server action: (layer, exported_name, filename)
-type ActionMetadata = (ActionLayer, String, String);
+/// Metadata for a server action: (layer, exported_name, filename, args)
+type ActionMetadata<'a> = (ActionLayer, String, String, Option<&'a serde_json::Value>);
 
 #[turbo_tasks::value]
 pub(crate) struct ServerActionsManifest {
@@ -59,11 +64,15 @@ pub(crate) struct ServerActionsManifest {
 /// output), and constructs a evaluatable "action loader" entry point and
 /// manifest describing the found actions.
 ///
+/// The `unreferenced_actions` are left out of the manifest, so they can't be
+/// called.
+///
 /// If Server Actions are not enabled, this returns an empty manifest and a None
 /// loader.
 #[turbo_tasks::function]
 pub(crate) async fn create_server_actions_manifest(
     actions: Vc<AllActions>,
+    unreferenced_actions: Vc<AllActions>,
     project_path: FileSystemPath,
     node_root: FileSystemPath,
     page_name: RcStr,
@@ -84,6 +93,7 @@ pub(crate) async fn create_server_actions_manifest(
         page_name,
         runtime,
         actions,
+        unreferenced_actions,
         chunk_item,
         module_graph.async_module_info(),
     )
@@ -158,6 +168,7 @@ async fn build_manifest(
     page_name: RcStr,
     runtime: NextRuntime,
     actions: Vc<AllActions>,
+    unreferenced_actions: Vc<AllActions>,
     chunk_item: Vc<Box<dyn ChunkItem>>,
     async_module_info: Vc<AsyncModulesInfo>,
 ) -> Result<ResolvedVc<Box<dyn OutputAsset>>> {
@@ -172,6 +183,11 @@ async fn build_manifest(
     let key = format!("app{page_name}");
 
     let actions_value = actions.await?;
+    let unreferenced_actions = unreferenced_actions.await?;
+    let unreferenced_ids = unreferenced_actions
+        .iter()
+        .map(|(hash_id, _)| hash_id.as_str())
+        .collect::<FxHashSet<_>>();
     let loader_id = chunk_item.id().await?;
     let loader_id = match &loader_id {
         ModuleId::Number(id) => ActionManifestModuleId::Number(*id),
@@ -183,8 +199,11 @@ async fn build_manifest(
     };
 
     // Collect all the action metadata including filenames and location
-    let mut action_metadata: Vec<(String, ActionMetadata)> = Vec::new();
+    let mut action_metadata: Vec<(String, ActionMetadata<'_>)> = Vec::new();
     for (hash_id, (layer, meta, module)) in actions_value.iter() {
+        if unreferenced_ids.contains(hash_id.as_str()) {
+            continue;
+        }
         // Use source_path from the action comment if available (contains original .ts/.tsx path),
         // otherwise fall back to module.ident().path() (may be compiled .js path)
         let filename = if !meta.source_path.is_empty() {
@@ -194,11 +213,14 @@ async fn build_manifest(
             module_path.to_string()
         };
 
-        action_metadata.push((hash_id.clone(), (*layer, meta.name.clone(), filename)));
+        action_metadata.push((
+            hash_id.clone(),
+            (*layer, meta.name.clone(), filename, meta.args.as_ref()),
+        ));
     }
 
     // Now create the manifest entries
-    for (hash_id, (layer, name, filename)) in &action_metadata {
+    for (hash_id, (layer, name, filename, args)) in &action_metadata {
         let entry = mapping.entry(hash_id.as_str()).or_default();
         entry.workers.insert(
             &key,
@@ -216,6 +238,7 @@ async fn build_manifest(
         // Hoist the filename and exported_name to the entry level
         entry.exported_name = name.as_str();
         entry.filename = filename.as_str();
+        entry.args = *args;
     }
 
     Ok(ResolvedVc::upcast(
@@ -268,15 +291,19 @@ pub async fn to_rsc_context(
 enum ServerActionInfoRaw {
     /// Old format: just the export name as a string
     Name(String),
-    /// New format: object with name
-    WithName { name: String },
+    /// New format: object with name, and the parameters if the argument schema is extracted
+    WithName {
+        name: String,
+        #[serde(default)]
+        args: Option<serde_json::Value>,
+    },
 }
 
 impl ServerActionInfoRaw {
     fn into_action_entry(self) -> ActionEntry {
         match self {
-            ServerActionInfoRaw::Name(name) => ActionEntry { name },
-            ServerActionInfoRaw::WithName { name } => ActionEntry { name },
+            ServerActionInfoRaw::Name(name) => ActionEntry { name, args: None },
+            ServerActionInfoRaw::WithName { name, args } => ActionEntry { name, args },
         }
     }
 }
@@ -285,6 +312,9 @@ impl ServerActionInfoRaw {
 #[derive(Clone, Debug, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
 pub struct ActionEntry {
     pub name: String,
+    /// The parameters of the action, as listed in the server reference manifest.
+    #[bincode(with = "turbo_bincode::serde_self_describing")]
+    pub args: Option<serde_json::Value>,
 }
 
 /// Parses the Server Actions comment for all exported action function names.
@@ -480,6 +510,8 @@ pub struct ActionMeta {
     pub name: String,
     /// The original source file path (from entry_path in the action comment)
     pub source_path: String,
+    #[bincode(with = "turbo_bincode::serde_self_describing")]
+    pub args: Option<serde_json::Value>,
 }
 
 type HashToLayerNameModule = Vec<(
@@ -549,3 +581,147 @@ pub async fn map_server_actions(
         .await?;
     Ok(Vc::cell(actions.into_iter().collect()))
 }
+
+/// The hashed ids of Server Actions which are imported by another module.
+#[turbo_tasks::value(transparent)]
+pub struct ReferencedServerActions(FxHashSet<String>);
+
+/// Returns the ids of the Server Actions in the graph which are imported by a
+/// Client Component (in the browser or during SSR). Imports from other server
+/// modules don't make an action callable from the client.
+#[turbo_tasks::function]
+pub async fn map_referenced_server_actions(
+    graph: ResolvedVc<ModuleGraphLayer>,
+    actions: Vc<AllModuleActions>,
+) -> Result<Vc<ReferencedServerActions>> {
+    let actions = actions.await?;
+
+    let mut edges = Vec::new();
+    graph.await?.traverse_edges_unordered(|parent, target| {
+        if let Some((parent, ref_data)) = parent
+            && actions.contains_key(&target)
+        {
+            edges.push((parent, target, ref_data.binding_usage.export.clone()));
+        }
+        Ok(())
+    })?;
+    let client_edges = edges
+        .into_iter()
+        .map(async |(parent, target, export)| {
+            let is_client = parent.ident().await?.layer.as_ref().is_some_and(|layer| {
+                matches!(
+                    layer.name().as_str(),
+                    "app-client" | "app-ssr" | "app-edge-ssr"
+                )
+            });
+            Ok(is_client.then_some((target, export)))
+        })
+        .try_flat_join()
+        .await?;
+
+    let mut usages = FxHashMap::<_, ModuleExportUsageInfo>::default();
+    for (target, export) in client_edges {
+        usages.entry(target).or_default().add(&export);
+    }
+
+    let referenced = actions
+        .iter()
+        .map(async |(module, (_, action_map))| {
+            let usage = usages.get(module);
+            Ok(action_map
+                .await?
+                .actions
+                .iter()
+                .filter(|(_, entry)| is_action_referenced(&entry.name, usage))
+                .map(|(hash_id, _)| hash_id.clone())
+                .collect::<Vec<_>>())
+        })
+        .try_flat_join()
+        .await?;
+    Ok(Vc::cell(referenced.into_iter().collect()))
+}
+
+/// Inline actions (and inline `"use cache"` functions) are defined inside of a
+/// Server Component and passed to the client from there, so they are referenced
+/// even though no other module imports them.
+fn is_action_referenced(name: &str, usage: Option<&ModuleExportUsageInfo>) -> bool {
+    name.starts_with("$$RSC_SERVER_")
+        || usage.is_some_and(|usage| usage.is_export_used(&name.into()))
+}
+
+#[turbo_tasks::value(shared)]
+pub(crate) struct UnusedServerActionIssue {
+    pub module: ResolvedVc<Box<dyn Module>>,
+    pub name: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for UnusedServerActionIssue {
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Text(rcstr!("The Server Action ")),
+            StyledString::Code(self.name.clone()),
+            StyledString::Text(rcstr!(" is never used")),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Line(vec![
+                StyledString::Text(rcstr!(
+                    "It isn't imported by a Client Component or passed to the client by a Server \
+                     Component, so it can never be called. Remove the export, or enable "
+                )),
+                StyledString::Code(rcstr!("turbopack.pruneUnusedServerActions")),
+                StyledString::Text(rcstr!(" to leave it out of the server reference manifest.")),
+            ])
+            .resolved_cell(),
+        ))
+    }
+
+    fn severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.module.ident().path()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Analysis.cell()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::rcstr;
+    use turbopack_core::{
+        module_graph::binding_usage_info::ModuleExportUsageInfo, resolve::ExportUsage,
+    };
+
+    use super::is_action_referenced;
+
+    #[test]
+    fn test_is_action_referenced() {
+        let mut named = ModuleExportUsageInfo::default();
+        named.add(&ExportUsage::Named(rcstr!("create")));
+        assert!(is_action_referenced("create", Some(&named)));
+        assert!(!is_action_referenced("remove", Some(&named)));
+        assert!(is_action_referenced(
+            "remove",
+            Some(&ModuleExportUsageInfo::All)
+        ));
+        assert!(!is_action_referenced(
+            "create",
+            Some(&ModuleExportUsageInfo::Evaluation)
+        ));
+        assert!(!is_action_referenced("create", None));
+        assert!(is_action_referenced("$$RSC_SERVER_ACTION_0", None));
+        assert!(is_action_referenced("$$RSC_SERVER_CACHE_0", None));
+    }
+}
@@ -0,0 +1,383 @@
+use std::{
+    collections::hash_map::Entry,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use bincode::{Decode, Encode};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{NonLocalValue, TryFlatJoinIterExt, Vc, trace::TraceRawVcs};
+use turbo_tasks_fs::FileJsonContent;
+use turbo_tasks_hash::hash_xxh3_hash64;
+use turbopack_core::{
+    asset::Asset,
+    output::{OutputAsset, OutputAssets},
+    reference::all_assets_from_entries,
+};
+
+use crate::project::Project;
+
+/// The files traced for a single server entry.
+#[derive(Clone, Debug, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+pub struct StandaloneEntry {
+    /// The path of the traced chunk relative to the node root, e.g. `server/app/page.js`.
+    pub name: RcStr,
+    /// The traced files relative to the project root, which is the `outputFileTracingRoot`.
+    pub files: Vec<RcStr>,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct StandaloneEntries(Vec<StandaloneEntry>);
+
+#[derive(Deserialize)]
+struct NftJson {
+    files: Vec<String>,
+}
+
+/// Collects the files listed in the `.nft.json` traces among `output_assets` and the assets they
+/// reference. The minimal server trace is skipped, as the standalone output starts the full
+/// `next-server` instead.
+#[turbo_tasks::function]
+pub async fn standalone_entries(
+    project: Vc<Project>,
+    output_assets: Vc<OutputAssets>,
+) -> Result<Vc<StandaloneEntries>> {
+    let node_root = project.node_root().owned().await?;
+    let project_root_path = project.project_root_path().owned().await?;
+
+    let mut entries = all_assets_from_entries(output_assets)
+        .await?
+        .iter()
+        .map(async |asset| {
+            let path = asset.path().await?;
+            let Some(name) = node_root
+                .get_path_to(&path)
+                .and_then(|name| name.strip_suffix(".nft.json"))
+            else {
+                return Ok(None);
+            };
+            if name == "next-minimal-server.js" {
+                return Ok(None);
+            }
+
+            let FileJsonContent::Content(json) = &*asset.content().parse_json().await? else {
+                bail!("Expected {} to contain a valid JSON trace", path.path);
+            };
+            let NftJson { files } = serde_json::from_value(json.clone())
+                .with_context(|| format!("Invalid trace file {}", path.path))?;
+
+            // The traced files are relative to the trace file. The output and the project file
+            // systems share the same root.
+            let trace_dir = project_root_path.join(&path.parent().path)?;
+            let mut files = files
+                .iter()
+                .map(|file| Ok(trace_dir.join(file)?.path))
+                .collect::<Result<Vec<_>>>()?;
+            files.sort();
+            files.dedup();
+
+            Ok(Some(StandaloneEntry {
+                name: name.into(),
+                files,
+            }))
+        })
+        .try_flat_join()
+        .await?;
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(Vc::cell(entries))
+}
+
+/// The total size of the files traced for a [StandaloneEntry].
+#[derive(Debug, PartialEq, Eq)]
+pub struct StandaloneEntrySize {
+    pub name: RcStr,
+    pub files: usize,
+    /// The size in bytes, including files that are shared with other entries.
+    pub size: u64,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StandaloneReport {
+    pub entries: Vec<StandaloneEntrySize>,
+    /// The number of bytes copied to the standalone directory.
+    pub copied_size: u64,
+    /// The number of bytes that were hardlinked instead of copied, because an identical file was
+    /// already copied to a different `node_modules` directory.
+    pub deduplicated_size: u64,
+}
+
+/// Writes the traced files of `entries` from `project_root` to `output_dir`, keeping their paths
+/// relative to the project root. The caller selects the entries, as static pages and edge
+/// functions don't need their traced files, and cleans up `output_dir`.
+///
+/// Package managers frequently install identical copies of a package in multiple `node_modules`
+/// directories. Files in `node_modules` are therefore compared by their content hash, and
+/// duplicates are hardlinked to the first copy instead of being copied again.
+pub fn write_standalone_directory(
+    project_root: &Path,
+    output_dir: &Path,
+    entries: &[StandaloneEntry],
+) -> Result<StandaloneReport> {
+    let mut writer = StandaloneWriter {
+        project_root,
+        output_dir,
+        written: FxHashMap::default(),
+        node_modules_copies: FxHashMap::default(),
+        report: StandaloneReport::default(),
+    };
+    for entry in entries {
+        let mut size = 0;
+        for file in &entry.files {
+            size += writer.write_file(file).with_context(|| {
+                format!(
+                    "Failed to copy traced file {file} of {} to the standalone directory",
+                    entry.name
+                )
+            })?;
+        }
+        writer.report.entries.push(StandaloneEntrySize {
+            name: entry.name.clone(),
+            files: entry.files.len(),
+            size,
+        });
+    }
+
+    Ok(writer.report)
+}
+
+struct StandaloneWriter<'a> {
+    project_root: &'a Path,
+    output_dir: &'a Path,
+    /// The size of every file that was already written, as traces of different entries overlap.
+    written: FxHashMap<RcStr, u64>,
+    /// The first copy of every `node_modules` file, keyed by its size and content hash.
+    node_modules_copies: FxHashMap<(u64, u64), PathBuf>,
+    report: StandaloneReport,
+}
+
+impl StandaloneWriter<'_> {
+    /// Writes a single traced file and returns its size.
+    fn write_file(&mut self, file: &RcStr) -> Result<u64> {
+        if let Some(size) = self.written.get(file) {
+            return Ok(*size);
+        }
+
+        let source = self.project_root.join(&**file);
+        let target = self.output_dir.join(&**file);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let metadata = fs::symlink_metadata(&source)?;
+        let size = if metadata.is_symlink() {
+            symlink(&fs::read_link(&source)?, &target, &source)?;
+            0
+        } else if metadata.is_dir() {
+            0
+        } else if is_in_node_modules(file) {
+            self.copy_node_modules_file(&source, target, metadata.len())?;
+            metadata.len()
+        } else {
+            fs::copy(&source, &target)?;
+            self.report.copied_size += metadata.len();
+            metadata.len()
+        };
+
+        self.written.insert(file.clone(), size);
+        Ok(size)
+    }
+
+    fn copy_node_modules_file(&mut self, source: &Path, target: PathBuf, size: u64) -> Result<()> {
+        let content = fs::read(source)?;
+        match self
+            .node_modules_copies
+            .entry((size, hash_xxh3_hash64(content.as_slice())))
+        {
+            Entry::Occupied(first_copy) => {
+                // Compare the content as well, a hash collision must not replace a file.
+                if fs::read(first_copy.get())? == content
+                    && fs::hard_link(first_copy.get(), &target).is_ok()
+                {
+                    self.report.deduplicated_size += size;
+                    return Ok(());
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(target.clone());
+            }
+        }
+        // `fs::copy` keeps the permissions of the source file, unlike writing `content`.
+        fs::copy(source, &target)?;
+        self.report.copied_size += size;
+        Ok(())
+    }
+}
+
+fn is_in_node_modules(file: &str) -> bool {
+    file.starts_with("node_modules/") || file.contains("/node_modules/")
+}
+
+#[cfg(unix)]
+fn symlink(original: &Path, link: &Path, _source: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(original, link)
+}
+
+#[cfg(windows)]
+fn symlink(original: &Path, link: &Path, source: &Path) -> io::Result<()> {
+    /// `ERROR_PRIVILEGE_NOT_HELD`, returned when symlinks can't be created without elevated
+    /// privileges, i.e. unless "Developer Mode" is turned on.
+    const ERROR_PRIVILEGE_NOT_HELD: i32 = 1314;
+
+    let is_dir = fs::metadata(source)?.is_dir();
+    let result = if is_dir {
+        std::os::windows::fs::symlink_dir(original, link)
+    } else {
+        std::os::windows::fs::symlink_file(original, link)
+    };
+    match result {
+        Err(err)
+            if err.raw_os_error() == Some(ERROR_PRIVILEGE_NOT_HELD)
+                || err.kind() == io::ErrorKind::PermissionDenied =>
+        {
+            // Junction points don't need privileges, but they only support absolute directory
+            // targets. Files are copied instead.
+            if is_dir {
+                let target = match link.parent() {
+                    Some(parent) if original.is_relative() => parent.join(original),
+                    _ => original.to_path_buf(),
+                };
+                junction(&target, link)
+            } else {
+                fs::copy(source, link).map(|_| ())
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+        result => result,
+    }
+}
+
+#[cfg(windows)]
+fn junction(target: &Path, link: &Path) -> io::Result<()> {
+    let output = std::process::Command::new("cmd")
+        .arg("/C")
+        .arg("mklink")
+        .arg("/J")
+        .arg(link)
+        .arg(target)
+        .output()?;
+    if output.status.success() || link.exists() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "Failed to create junction {}: {}",
+            link.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn symlink(_original: &Path, link: &Path, _source: &Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("Cannot create symlink {}", link.display()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, files: &[&str]) -> StandaloneEntry {
+        StandaloneEntry {
+            name: name.into(),
+            files: files.iter().map(|&file| file.into()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_write_standalone_directory() {
+        let project_root = tempfile::tempdir().unwrap();
+        let output_dir = project_root.path().join(".next/standalone");
+        for (file, content) in [
+            ("app/.next/server/app/page.js", "page"),
+            ("app/.next/server/app/about/page.js", "about page"),
+            ("node_modules/a/index.js", "module.exports = 1"),
+            (
+                "node_modules/b/node_modules/a/index.js",
+                "module.exports = 1",
+            ),
+            ("node_modules/b/index.js", "module.exports = 2"),
+        ] {
+            let path = project_root.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        let report = write_standalone_directory(
+            project_root.path(),
+            &output_dir,
+            &[
+                entry(
+                    "server/app/about/page.js",
+                    &[
+                        "app/.next/server/app/about/page.js",
+                        "node_modules/b/index.js",
+                        "node_modules/b/node_modules/a/index.js",
+                    ],
+                ),
+                entry(
+                    "server/app/page.js",
+                    &[
+                        "app/.next/server/app/page.js",
+                        "node_modules/a/index.js",
+                        "node_modules/b/index.js",
+                    ],
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            report,
+            StandaloneReport {
+                entries: vec![
+                    StandaloneEntrySize {
+                        name: "server/app/about/page.js".into(),
+                        files: 3,
+                        size: 46,
+                    },
+                    StandaloneEntrySize {
+                        name: "server/app/page.js".into(),
+                        files: 3,
+                        size: 40,
+                    },
+                ],
+                copied_size: 50,
+                deduplicated_size: 18,
+            }
+        );
+        assert_eq!(
+            fs::read_to_string(output_dir.join("node_modules/a/index.js")).unwrap(),
+            "module.exports = 1"
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            let inode = |file: &str| fs::metadata(output_dir.join(file)).unwrap().ino();
+            assert_eq!(
+                inode("node_modules/a/index.js"),
+                inode("node_modules/b/node_modules/a/index.js")
+            );
+            assert_ne!(
+                inode("node_modules/a/index.js"),
+                inode("node_modules/b/index.js")
+            );
+        }
+    }
+}
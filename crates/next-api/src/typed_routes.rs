@@ -0,0 +1,86 @@
+use anyhow::Result;
+use next_core::typed_routes::typed_routes_declaration;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbopack::emit_asset;
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    output::{OutputAsset, OutputAssets, OutputAssetsReference},
+};
+
+use crate::project::Project;
+
+/// The `href` types of `next/link`, `next/form` and the router, generated from the routes of the
+/// app and pages directories.
+#[turbo_tasks::value]
+pub struct TypedRoutesAsset {
+    path: FileSystemPath,
+    project: ResolvedVc<Project>,
+}
+
+#[turbo_tasks::value_impl]
+impl TypedRoutesAsset {
+    #[turbo_tasks::function]
+    pub fn new(path: FileSystemPath, project: ResolvedVc<Project>) -> Vc<Self> {
+        TypedRoutesAsset { path, project }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for TypedRoutesAsset {
+    #[turbo_tasks::function]
+    fn content(&self) -> Vc<AssetContent> {
+        AssetContent::file(typed_routes_declaration(route_pathnames(*self.project)))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAssetsReference for TypedRoutesAsset {}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for TypedRoutesAsset {
+    #[turbo_tasks::function]
+    fn path(&self) -> Vc<FileSystemPath> {
+        self.path.clone().cell()
+    }
+}
+
+/// The pathnames of all app and pages routes, e.g. `/blog/[slug]`.
+#[turbo_tasks::function]
+async fn route_pathnames(project: Vc<Project>) -> Result<Vc<Vec<RcStr>>> {
+    Ok(Vc::cell(
+        project
+            .entrypoints()
+            .await?
+            .routes
+            .keys()
+            .cloned()
+            .collect(),
+    ))
+}
+
+#[turbo_tasks::function]
+pub async fn typed_routes_asset_if_enabled(
+    project: ResolvedVc<Project>,
+) -> Result<Vc<OutputAssets>> {
+    let assets = if *project.next_config().turbopack_typed_routes().await? {
+        // Replaces the `link.d.ts` that is otherwise generated with `typedRoutes`
+        let path = project.node_root().await?.join("types/link.d.ts")?;
+        let asset = TypedRoutesAsset::new(path, *project).to_resolved().await?;
+        vec![ResolvedVc::upcast(asset)]
+    } else {
+        vec![]
+    };
+    Ok(Vc::cell(assets))
+}
+
+/// Writes the typed routes to disk. During development output assets are only served from
+/// memory, while the type checker and the editor need the file on disk.
+#[turbo_tasks::function]
+pub async fn emit_typed_routes_if_enabled(project: ResolvedVc<Project>) -> Result<()> {
+    for asset in typed_routes_asset_if_enabled(*project).await? {
+        emit_asset(**asset).as_side_effect().await?;
+    }
+    Ok(())
+}
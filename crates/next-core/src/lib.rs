@@ -38,6 +38,7 @@ pub mod raw_ecmascript_module;
 pub mod segment_config;
 pub mod tracing_presets;
 mod transform_options;
+pub mod typed_routes;
 pub mod url_node;
 pub mod util;
 
@@ -18,6 +18,7 @@ use turbopack_core::{
     chunk::{
         AssetSuffix, ChunkingConfig, ChunkingContext, MangleType, MinifyType, SourceMapSourceType,
         SourceMapsType, UnusedReferences, UrlBehavior, chunk_id_strategy::ModuleIdStrategy,
+        property_name_cache::OptionPropertyNameCache,
     },
     compile_time_info::{CompileTimeDefines, CompileTimeInfo, FreeVarReference, FreeVarReferences},
     environment::{BrowserEnvironment, Environment, ExecutionEnvironment},
@@ -43,7 +44,7 @@ use crate::{
         runtime_entry::{RuntimeEntries, RuntimeEntry},
         transforms::get_next_client_transforms_rules,
     },
-    next_config::NextConfig,
+    next_config::{NextConfig, TurbopackMinifyOptions},
     next_font::local::NextFontLocalResolvePlugin,
     next_import_map::{
         get_next_client_fallback_import_map, get_next_client_import_map,
@@ -171,6 +172,10 @@ pub async fn get_client_resolve_options_context(
 
     let resolve_options_context = ResolveOptionsContext {
         enable_node_modules: Some(project_path.root().owned().await?),
+        enable_pnp: Some(project_path.root().owned().await?),
+        http_imports: *next_config
+            .turbopack_http_imports(project_path.clone())
+            .await?,
         custom_conditions,
         import_map: Some(next_client_import_map),
         fallback_import_map: Some(next_client_fallback_import_map),
@@ -341,6 +346,10 @@ pub async fn get_client_module_options_context(
         css: CssOptionsContext {
             source_maps,
             module_css_condition: Some(module_styles_rule_condition()),
+            sass_load_paths: next_config
+                .sass_load_paths(project_path.clone())
+                .owned()
+                .await?,
             ..Default::default()
         },
         static_url_tag: Some(rcstr!("client")),
@@ -435,13 +444,17 @@ pub struct ClientChunkingContextOptions {
     pub export_usage: Vc<OptionBindingUsageInfo>,
     pub unused_references: Vc<UnusedReferences>,
     pub minify: Vc<bool>,
+    pub minify_options: Vc<TurbopackMinifyOptions>,
+    pub property_name_cache: Vc<OptionPropertyNameCache>,
     pub source_maps: Vc<SourceMapsType>,
     pub no_mangling: Vc<bool>,
     pub scope_hoisting: Vc<bool>,
     pub nested_async_chunking: Vc<bool>,
+    pub profile_module_evaluation: Vc<bool>,
     pub debug_ids: Vc<bool>,
     pub should_use_absolute_url_references: Vc<bool>,
     pub css_url_suffix: Vc<Option<RcStr>>,
+    pub service_worker_url: Vc<RcStr>,
 }
 
 #[turbo_tasks::function]
@@ -459,13 +472,17 @@ pub async fn get_client_chunking_context(
         export_usage,
         unused_references,
         minify,
+        minify_options,
+        property_name_cache,
         source_maps,
         no_mangling,
         scope_hoisting,
         nested_async_chunking,
+        profile_module_evaluation,
         debug_ids,
         should_use_absolute_url_references,
         css_url_suffix,
+        service_worker_url,
     } = options;
 
     let next_mode = mode.await?;
@@ -483,9 +500,11 @@ pub async fn get_client_chunking_context(
     .chunk_base_path(Some(asset_prefix.clone()))
     .asset_suffix(AssetSuffix::Inferred.resolved_cell())
     .minify_type(if *minify.await? {
-        MinifyType::Minify {
-            mangle: (!*no_mangling.await?).then_some(MangleType::OptimalSize),
-        }
+        MinifyType::Minify(
+            minify_options
+                .await?
+                .to_minify_options((!*no_mangling.await?).then_some(MangleType::OptimalSize)),
+        )
     } else {
         MinifyType::NoMinify
     })
@@ -493,12 +512,20 @@ pub async fn get_client_chunking_context(
     .asset_base_path(Some(asset_prefix))
     .current_chunk_method(CurrentChunkMethod::DocumentCurrentScript)
     .export_usage(*export_usage.await?)
+    .property_name_cache(*property_name_cache.await?)
     .unused_references(unused_references.to_resolved().await?)
     .module_id_strategy(module_id_strategy.to_resolved().await?)
     .debug_ids(*debug_ids.await?)
     .should_use_absolute_url_references(*should_use_absolute_url_references.await?)
     .nested_async_availability(*nested_async_chunking.await?)
+    .profile_module_evaluation(*profile_module_evaluation.await?)
     .worker_forwarded_globals(worker_forwarded_globals())
+    // Service workers are served from `.next/service-worker` at the configured URL, as their
+    // scope is limited to the path they are served at.
+    .service_worker_path(
+        client_root.join("service-worker/sw.js")?,
+        service_worker_url.owned().await?,
+    )
     .default_url_behavior(UrlBehavior {
         suffix: AssetSuffix::Inferred,
         static_suffix: css_url_suffix.to_resolved().await?,
@@ -9,10 +9,11 @@ use crate::{
     next_client::context::ClientContextType,
     next_config::NextConfig,
     next_shared::transforms::{
-        debug_fn_name::get_debug_fn_name_rule, get_next_dynamic_transform_rule,
-        get_next_font_transform_rule, get_next_image_rule, get_next_lint_transform_rule,
-        get_next_modularize_imports_rule, get_next_pages_transforms_rule,
-        get_server_actions_transform_rule, next_cjs_optimizer::get_next_cjs_optimizer_rule,
+        debug_fn_name::get_debug_fn_name_rule, get_next_define_transitions_rule,
+        get_next_dynamic_transform_rule, get_next_font_transform_rule, get_next_image_rule,
+        get_next_lint_transform_rule, get_next_modularize_imports_rule,
+        get_next_pages_transforms_rule, get_server_actions_transform_rule,
+        next_cjs_optimizer::get_next_cjs_optimizer_rule,
         next_disallow_re_export_all_in_page::get_next_disallow_export_all_in_page_rule,
         next_pure::get_next_pure_rule, server_actions::ActionsTransform,
     },
@@ -67,6 +68,7 @@ pub async fn get_next_client_transforms_rules(
 
     let use_cache_enabled = *next_config.enable_use_cache().await?;
     let cache_kinds = next_config.cache_kinds().to_resolved().await?;
+    let extract_argument_schema = *next_config.enable_server_action_argument_schema().await?;
     let mut is_app_dir = false;
 
     match &context_ty {
@@ -84,8 +86,19 @@ pub async fn get_next_client_transforms_rules(
                 ));
             }
         }
-        ClientContextType::App { .. } => {
+        ClientContextType::App { app_dir } => {
             is_app_dir = true;
+            if !foreign_code {
+                rules.push(
+                    get_next_define_transitions_rule(
+                        next_config,
+                        app_dir.clone(),
+                        mode,
+                        enable_mdx_rs,
+                    )
+                    .await?,
+                );
+            }
             rules.push(
                 get_server_actions_transform_rule(
                     mode,
@@ -94,6 +107,7 @@ pub async fn get_next_client_transforms_rules(
                     enable_mdx_rs,
                     use_cache_enabled,
                     cache_kinds,
+                    extract_argument_schema,
                 )
                 .await?,
             );
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use anyhow::{Context, Result, bail};
 use bincode::{Decode, Encode};
 use either::Either;
@@ -15,13 +17,14 @@ use turbo_tasks_fetch::FetchClientConfig;
 use turbo_tasks_fs::{
     FileSystemPath,
     glob::{Glob, GlobOptions},
+    to_sys_path,
 };
 use turbopack::module_options::{
     ConditionContentType, ConditionItem, ConditionPath, ConditionQuery, LoaderRuleItem,
     WebpackRules, module_options_context::MdxTransformOptions,
 };
 use turbopack_core::{
-    chunk::SourceMapsType,
+    chunk::{ManglePropertiesOptions, MangleType, MinifyOptions, SourceMapsType},
     issue::{
         IgnoreIssue, IgnoreIssuePattern, Issue, IssueExt, IssueSeverity, IssueStage,
         OptionStyledString, StyledString,
@@ -34,6 +37,7 @@ use turbopack_ecmascript_plugins::transform::{
     styled_components::StyledComponentsTransformConfig,
 };
 use turbopack_node::transforms::webpack::{WebpackLoaderItem, WebpackLoaderItems};
+use turbopack_resolve::http_imports::{HttpImportsOptions, OptionHttpImportsOptions};
 
 use crate::{
     app_structure::FileSystemPathVec,
@@ -614,6 +618,32 @@ pub struct TurbopackConfig {
     /// Issue patterns to ignore (suppress) from Turbopack output.
     #[serde(default)]
     pub ignore_issue: Option<Vec<TurbopackIgnoreIssueRule>>,
+    /// Escalates issues that are not part of a checked-in baseline to errors in `next build`.
+    #[serde(default)]
+    pub issue_baseline: Option<TurbopackIssueBaselineConfig>,
+    /// Writes the issues of `next build` to SARIF and JSON Lines files.
+    #[serde(default)]
+    pub issue_reports: Option<TurbopackIssueReportsConfig>,
+    /// Package names that may be bundled more than once without a warning.
+    #[serde(default)]
+    pub allow_duplicate_packages: Option<Vec<RcStr>>,
+    /// Allows importing modules from HTTP(S) URLs.
+    #[serde(default)]
+    pub http_imports: Option<TurbopackHttpImportsConfig>,
+    /// Emits a manifest with the client assets of every route, so they can be preloaded before
+    /// navigating to the route.
+    pub route_preload_manifest: Option<bool>,
+    /// Generates the `href` types of `next/link` and the router from the app and pages routes.
+    pub typed_routes: Option<bool>,
+    /// Emits a JSON description of the resolved app and pages route tree.
+    pub route_tree: Option<bool>,
+    /// Leaves Server Actions which are never imported by another module out of the server
+    /// reference manifest.
+    pub prune_unused_server_actions: Option<bool>,
+    /// Includes a profiler in the runtime that records how long each module takes to evaluate.
+    pub profile_module_evaluation: Option<bool>,
+    /// The path (without the basePath) service workers are served at.
+    pub service_worker_path: Option<RcStr>,
 }
 
 #[derive(
@@ -920,6 +950,38 @@ pub enum ModuleIds {
 #[turbo_tasks::value(transparent)]
 pub struct OptionModuleIds(pub Option<ModuleIds>);
 
+/// Options for the production minifier. Mangling isn't configurable here, it's enabled unless the
+/// build is run with `--no-mangling`.
+#[turbo_tasks::value(shared, operation)]
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TurbopackMinifyOptions {
+    pub drop_console: bool,
+    pub pure_funcs: Vec<RcStr>,
+    pub keep_classnames: bool,
+    pub keep_fnames: bool,
+    pub passes: Option<usize>,
+    pub ecma: Option<u16>,
+    /// Mangles the matching property names consistently across all chunks of the build. The
+    /// mangled names are persisted in the `cache` directory, so they stay stable between builds.
+    pub mangle_properties: Option<ManglePropertiesOptions>,
+}
+
+impl TurbopackMinifyOptions {
+    pub fn to_minify_options(&self, mangle: Option<MangleType>) -> MinifyOptions {
+        MinifyOptions {
+            mangle,
+            drop_console: self.drop_console,
+            pure_funcs: self.pure_funcs.clone(),
+            keep_classnames: self.keep_classnames,
+            keep_fnames: self.keep_fnames,
+            passes: self.passes,
+            ecma: self.ecma,
+            mangle_properties: self.mangle_properties.clone(),
+        }
+    }
+}
+
 #[derive(
     Clone, Debug, PartialEq, Deserialize, TraceRawVcs, NonLocalValue, OperationValue, Encode, Decode,
 )]
@@ -1028,6 +1090,73 @@ impl TurbopackIgnoreIssueTextPattern {
     }
 }
 
+/// The `turbopack.issueBaseline` option.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+    Encode,
+    Decode,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TurbopackIssueBaselineConfig {
+    /// The baseline file, relative to the project directory. Defaults to
+    /// `turbopack-issue-baseline.json`.
+    #[serde(default)]
+    pub path: Option<RcStr>,
+    /// Rewrites the baseline with the current issues instead of checking against it.
+    #[serde(default)]
+    pub update: Option<bool>,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionTurbopackIssueBaselineConfig(Option<TurbopackIssueBaselineConfig>);
+
+/// The `turbopack.issueReports` option.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+    Encode,
+    Decode,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TurbopackIssueReportsConfig {
+    /// The SARIF 2.1.0 file, relative to the project directory.
+    #[serde(default)]
+    pub sarif: Option<RcStr>,
+    /// The JSON Lines file, relative to the project directory.
+    #[serde(default)]
+    pub json_lines: Option<RcStr>,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionTurbopackIssueReportsConfig(Option<TurbopackIssueReportsConfig>);
+
+/// The `turbopack.httpImports` option.
+#[derive(
+    Clone, Debug, PartialEq, Deserialize, TraceRawVcs, NonLocalValue, OperationValue, Encode, Decode,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TurbopackHttpImportsConfig {
+    pub allowed_uris: Vec<RcStr>,
+    /// The lock directory, relative to the project directory.
+    #[serde(default)]
+    pub lockfile_location: Option<RcStr>,
+    #[serde(default)]
+    pub frozen: Option<bool>,
+}
+
 /// A single rule in `turbopack.ignoreIssue`.
 #[derive(
     Clone, Debug, PartialEq, Deserialize, TraceRawVcs, NonLocalValue, OperationValue, Encode, Decode,
@@ -1148,6 +1277,8 @@ pub struct ExperimentalConfig {
     worker_threads: Option<bool>,
 
     turbopack_minify: Option<bool>,
+    /// Options for the production minifier, e.g. to drop `console.*` calls.
+    turbopack_minify_options: Option<TurbopackMinifyOptions>,
     turbopack_module_ids: Option<ModuleIds>,
     turbopack_source_maps: Option<bool>,
     turbopack_input_source_maps: Option<bool>,
@@ -1160,6 +1291,10 @@ pub struct ExperimentalConfig {
     /// Disable automatic configuration of the sass loader.
     #[serde(default)]
     turbopack_use_builtin_sass: Option<bool>,
+    /// Compile Sass stylesheets with the built-in native compiler instead of `sass-loader`.
+    /// Stylesheets using features that aren't supported natively still use `sass-loader`.
+    #[serde(default)]
+    turbopack_native_sass: Option<bool>,
     /// Disable automatic configuration of the babel loader when a babel configuration file is
     /// present.
     #[serde(default)]
@@ -1195,7 +1330,38 @@ pub struct ExperimentalConfig {
 )]
 #[serde(rename_all = "camelCase")]
 pub struct SubResourceIntegrity {
-    pub algorithm: Option<RcStr>,
+    pub algorithm: Option<SubResourceIntegrityAlgorithms>,
+}
+
+impl SubResourceIntegrity {
+    /// The configured hash algorithms, in the order they were specified.
+    pub fn algorithms(&self) -> &[RcStr] {
+        match &self.algorithm {
+            Some(SubResourceIntegrityAlgorithms::Single(algorithm)) => {
+                std::slice::from_ref(algorithm)
+            }
+            Some(SubResourceIntegrityAlgorithms::Multiple(algorithms)) => algorithms,
+            None => &[],
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+    Encode,
+    Decode,
+)]
+#[serde(untagged)]
+pub enum SubResourceIntegrityAlgorithms {
+    Single(RcStr),
+    Multiple(Vec<RcStr>),
 }
 
 #[derive(
@@ -1264,6 +1430,8 @@ fn test_esm_externals_deserialization() {
 pub struct ServerActions {
     /// Allows adjusting body parser size limit for server actions.
     pub body_size_limit: Option<SizeLimit>,
+    /// Lists the parameters of every server action in the server reference manifest.
+    pub argument_schema: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, TraceRawVcs, NonLocalValue, OperationValue, Encode, Decode)]
@@ -1731,6 +1899,84 @@ impl NextConfig {
         Ok(alias_map.cell())
     }
 
+    #[turbo_tasks::function]
+    pub fn turbopack_allow_duplicate_packages(&self) -> Vc<Vec<RcStr>> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|t| t.allow_duplicate_packages.clone())
+                .unwrap_or_default(),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn turbopack_route_preload_manifest(&self) -> Vc<bool> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|t| t.route_preload_manifest)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn turbopack_typed_routes(&self) -> Vc<bool> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|t| t.typed_routes)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn turbopack_route_tree(&self) -> Vc<bool> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|t| t.route_tree)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn turbopack_prune_unused_server_actions(&self) -> Vc<bool> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|t| t.prune_unused_server_actions)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn turbopack_http_imports(
+        &self,
+        project_path: FileSystemPath,
+    ) -> Result<Vc<OptionHttpImportsOptions>> {
+        let Some(config) = self
+            .turbopack
+            .as_ref()
+            .and_then(|t| t.http_imports.as_ref())
+        else {
+            return Ok(Vc::cell(None));
+        };
+        Ok(Vc::cell(Some(
+            HttpImportsOptions {
+                allowed_uris: config.allowed_uris.clone(),
+                lock_dir: project_path.join(
+                    config
+                        .lockfile_location
+                        .as_deref()
+                        .unwrap_or("http-imports.lock"),
+                )?,
+                frozen: config.frozen.unwrap_or(false),
+                fetch_client: FetchClientConfig::default().resolved_cell(),
+            }
+            .resolved_cell(),
+        )))
+    }
+
     #[turbo_tasks::function]
     pub fn resolve_extension(&self) -> Vc<ResolveExtensions> {
         let Some(resolve_extensions) = self
@@ -1835,6 +2081,11 @@ impl NextConfig {
         Vc::cell(self.experimental.turbopack_use_builtin_sass)
     }
 
+    #[turbo_tasks::function]
+    pub fn experimental_turbopack_native_sass(&self) -> Vc<Option<bool>> {
+        Vc::cell(self.experimental.turbopack_native_sass)
+    }
+
     #[turbo_tasks::function]
     pub fn react_compiler_options(&self) -> Vc<OptionalReactCompilerOptions> {
         let options = &self.react_compiler;
@@ -1857,6 +2108,55 @@ impl NextConfig {
         Vc::cell(self.sass_options.clone().unwrap_or_default())
     }
 
+    /// The directories of `sassOptions.loadPaths` (and the legacy `sassOptions.includePaths`) for
+    /// the native Sass compiler. Relative directories are relative to the project, directories
+    /// outside of the project file system are skipped.
+    #[turbo_tasks::function]
+    pub async fn sass_load_paths(
+        &self,
+        project_path: FileSystemPath,
+    ) -> Result<Vc<FileSystemPathVec>> {
+        let Some(sass_options) = self.sass_options.as_ref().and_then(|o| o.as_object()) else {
+            return Ok(Vc::cell(vec![]));
+        };
+        let root = project_path.root().owned().await?;
+        let root_sys_path = to_sys_path(root.clone()).await?;
+
+        let mut load_paths = Vec::new();
+        for key in ["loadPaths", "includePaths"] {
+            let Some(JsonValue::Array(dirs)) = sass_options.get(key) else {
+                continue;
+            };
+            for dir in dirs.iter().filter_map(|dir| dir.as_str()) {
+                let dir = Path::new(dir);
+                let (base, relative) = if dir.is_absolute() {
+                    let Some(relative) = root_sys_path
+                        .as_deref()
+                        .and_then(|root| dir.strip_prefix(root).ok())
+                    else {
+                        continue;
+                    };
+                    (&root, relative)
+                } else {
+                    (&project_path, dir)
+                };
+                let Some(relative) = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_str())
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                if let Some(path) = base.try_join(&relative.join("/"))
+                    && !load_paths.contains(&path)
+                {
+                    load_paths.push(path);
+                }
+            }
+        }
+        Ok(Vc::cell(load_paths))
+    }
+
     #[turbo_tasks::function]
     pub fn skip_proxy_url_normalize(&self) -> Vc<bool> {
         Vc::cell(self.skip_proxy_url_normalize.unwrap_or(false))
@@ -1935,6 +2235,19 @@ impl NextConfig {
         )
     }
 
+    #[turbo_tasks::function]
+    pub fn enable_server_action_argument_schema(&self) -> Vc<bool> {
+        Vc::cell(matches!(
+            self.experimental.server_actions,
+            Some(ServerActionsOrLegacyBool::ServerActionsConfig(
+                ServerActions {
+                    argument_schema: Some(true),
+                    ..
+                }
+            ))
+        ))
+    }
+
     #[turbo_tasks::function]
     pub fn enable_root_params(&self) -> Vc<bool> {
         Vc::cell(
@@ -2058,6 +2371,15 @@ impl NextConfig {
         ))
     }
 
+    #[turbo_tasks::function]
+    pub fn turbo_minify_options(&self) -> Vc<TurbopackMinifyOptions> {
+        self.experimental
+            .turbopack_minify_options
+            .clone()
+            .unwrap_or_default()
+            .cell()
+    }
+
     #[turbo_tasks::function]
     pub async fn turbo_scope_hoisting(&self, mode: Vc<NextMode>) -> Result<Vc<bool>> {
         Ok(Vc::cell(match *mode.await? {
@@ -2158,6 +2480,34 @@ impl NextConfig {
         )
     }
 
+    #[turbo_tasks::function]
+    pub fn turbopack_profile_module_evaluation(&self) -> Vc<bool> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|turbopack| turbopack.profile_module_evaluation)
+                .unwrap_or(false),
+        )
+    }
+
+    /// Returns the URL service workers are served at, including the basePath. Defaults to
+    /// `/sw.js`.
+    #[turbo_tasks::function]
+    pub fn turbopack_service_worker_url(&self) -> Vc<RcStr> {
+        let path = self
+            .turbopack
+            .as_ref()
+            .and_then(|turbopack| turbopack.service_worker_path.as_deref())
+            .unwrap_or("/sw.js");
+        Vc::cell(
+            format!(
+                "{}{path}",
+                self.base_path.as_ref().map_or("", |b| b.as_str())
+            )
+            .into(),
+        )
+    }
+
     #[turbo_tasks::function]
     pub fn typescript_tsconfig_path(&self) -> Result<Vc<Option<RcStr>>> {
         Ok(Vc::cell(
@@ -2238,6 +2588,24 @@ impl NextConfig {
             .collect::<Result<Vec<_>>>()?;
         Ok(Vc::cell(rules))
     }
+
+    #[turbo_tasks::function]
+    pub fn turbopack_issue_baseline(&self) -> Vc<OptionTurbopackIssueBaselineConfig> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|tp| tp.issue_baseline.clone()),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn turbopack_issue_reports(&self) -> Vc<OptionTurbopackIssueReportsConfig> {
+        Vc::cell(
+            self.turbopack
+                .as_ref()
+                .and_then(|tp| tp.issue_reports.clone()),
+        )
+    }
 }
 
 /// A subset of ts/jsconfig that next.js implicitly
@@ -2341,4 +2709,19 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_serde_sri_algorithms() {
+        let single: SubResourceIntegrity =
+            serde_json::from_value(serde_json::json!({ "algorithm": "sha256" })).unwrap();
+        assert_eq!(single.algorithms(), &[rcstr!("sha256")]);
+
+        let multiple: SubResourceIntegrity =
+            serde_json::from_value(serde_json::json!({ "algorithm": ["sha384", "sha512"] }))
+                .unwrap();
+        assert_eq!(multiple.algorithms(), &[rcstr!("sha384"), rcstr!("sha512")]);
+
+        let none: SubResourceIntegrity = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(none.algorithms().is_empty());
+    }
 }
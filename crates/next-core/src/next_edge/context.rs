@@ -8,6 +8,7 @@ use turbopack_core::{
     chunk::{
         AssetSuffix, ChunkingConfig, ChunkingContext, MangleType, MinifyType, SourceMapsType,
         UnusedReferences, UrlBehavior, chunk_id_strategy::ModuleIdStrategy,
+        property_name_cache::OptionPropertyNameCache,
     },
     compile_time_info::{CompileTimeDefines, CompileTimeInfo, FreeVarReference, FreeVarReferences},
     environment::{EdgeWorkerEnvironment, Environment, ExecutionEnvironment, NodeJsVersion},
@@ -23,7 +24,7 @@ use turbopack_resolve::resolve_options_context::{ResolveOptionsContext, TsConfig
 use crate::{
     app_structure::CollectedRootParams,
     mode::NextMode,
-    next_config::NextConfig,
+    next_config::{NextConfig, TurbopackMinifyOptions},
     next_font::local::NextFontLocalResolvePlugin,
     next_import_map::{get_next_edge_and_server_fallback_import_map, get_next_edge_import_map},
     next_server::context::ServerContextType,
@@ -147,6 +148,10 @@ pub async fn get_edge_resolve_options_context(
 
     let resolve_options_context = ResolveOptionsContext {
         enable_node_modules: Some(project_path.root().owned().await?),
+        enable_pnp: Some(project_path.root().owned().await?),
+        http_imports: *next_config
+            .turbopack_http_imports(project_path.clone())
+            .await?,
         enable_edge_node_externals: true,
         custom_conditions,
         import_map: Some(next_edge_import_map),
@@ -195,10 +200,13 @@ pub struct EdgeChunkingContextOptions {
     pub export_usage: Vc<OptionBindingUsageInfo>,
     pub unused_references: Vc<UnusedReferences>,
     pub turbo_minify: Vc<bool>,
+    pub turbo_minify_options: Vc<TurbopackMinifyOptions>,
+    pub property_name_cache: Vc<OptionPropertyNameCache>,
     pub turbo_source_maps: Vc<SourceMapsType>,
     pub no_mangling: Vc<bool>,
     pub scope_hoisting: Vc<bool>,
     pub nested_async_chunking: Vc<bool>,
+    pub profile_module_evaluation: Vc<bool>,
     pub client_root: FileSystemPath,
     pub asset_prefix: RcStr,
     pub css_url_suffix: Vc<Option<RcStr>>,
@@ -219,10 +227,13 @@ pub async fn get_edge_chunking_context_with_client_assets(
         export_usage,
         unused_references,
         turbo_minify,
+        turbo_minify_options,
+        property_name_cache,
         turbo_source_maps,
         no_mangling,
         scope_hoisting,
         nested_async_chunking,
+        profile_module_evaluation,
         client_root,
         asset_prefix,
         css_url_suffix,
@@ -245,18 +256,20 @@ pub async fn get_edge_chunking_context_with_client_assets(
         static_suffix: css_url_suffix.to_resolved().await?,
     })
     .minify_type(if *turbo_minify.await? {
-        MinifyType::Minify {
+        MinifyType::Minify(turbo_minify_options.await?.to_minify_options(
             // React needs deterministic function names to work correctly.
-            mangle: (!*no_mangling.await?).then_some(MangleType::Deterministic),
-        }
+            (!*no_mangling.await?).then_some(MangleType::Deterministic),
+        ))
     } else {
         MinifyType::NoMinify
     })
     .source_maps(*turbo_source_maps.await?)
     .module_id_strategy(module_id_strategy.to_resolved().await?)
     .export_usage(*export_usage.await?)
+    .property_name_cache(*property_name_cache.await?)
     .unused_references(unused_references.to_resolved().await?)
     .nested_async_availability(*nested_async_chunking.await?)
+    .profile_module_evaluation(*profile_module_evaluation.await?)
     .worker_forwarded_globals(worker_forwarded_globals());
 
     if !next_mode.is_development() {
@@ -296,10 +309,13 @@ pub async fn get_edge_chunking_context(
         export_usage,
         unused_references,
         turbo_minify,
+        turbo_minify_options,
+        property_name_cache,
         turbo_source_maps,
         no_mangling,
         scope_hoisting,
         nested_async_chunking,
+        profile_module_evaluation,
         client_root,
         asset_prefix,
         css_url_suffix,
@@ -337,17 +353,21 @@ pub async fn get_edge_chunking_context(
     // asset from the output directory.
     .asset_base_path(Some(rcstr!("blob:server/edge/")))
     .minify_type(if *turbo_minify.await? {
-        MinifyType::Minify {
-            mangle: (!*no_mangling.await?).then_some(MangleType::OptimalSize),
-        }
+        MinifyType::Minify(
+            turbo_minify_options
+                .await?
+                .to_minify_options((!*no_mangling.await?).then_some(MangleType::OptimalSize)),
+        )
     } else {
         MinifyType::NoMinify
     })
     .source_maps(*turbo_source_maps.await?)
     .module_id_strategy(module_id_strategy.to_resolved().await?)
     .export_usage(*export_usage.await?)
+    .property_name_cache(*property_name_cache.await?)
     .unused_references(unused_references.to_resolved().await?)
     .nested_async_availability(*nested_async_chunking.await?)
+    .profile_module_evaluation(*profile_module_evaluation.await?)
     .worker_forwarded_globals(worker_forwarded_globals());
 
     if !next_mode.is_development() {
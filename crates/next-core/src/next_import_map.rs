@@ -1,16 +1,21 @@
 use std::{collections::BTreeMap, sync::LazyLock};
 
 use anyhow::{Context, Result};
+use bincode::{Decode, Encode};
 use either::Either;
 use rustc_hash::FxHashMap;
 use turbo_rcstr::{RcStr, rcstr};
-use turbo_tasks::{FxIndexMap, ResolvedVc, Vc, fxindexmap};
+use turbo_tasks::{FxIndexMap, NonLocalValue, ResolvedVc, Vc, fxindexmap, trace::TraceRawVcs};
 use turbo_tasks_fs::{FileSystem, FileSystemPath, to_sys_path};
 use turbopack_core::{
-    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    issue::{
+        Issue, IssueExt, IssueFix, IssueFixes, IssueSeverity, IssueSource, IssueStage,
+        OptionStyledString, StyledString, TextEdit,
+    },
     reference_type::{CommonJsReferenceSubType, ReferenceType},
     resolve::{
         AliasPattern, ExternalTraced, ExternalType, ResolveAliasMap, SubpathValue,
+        error::ImportErrorFixes,
         node::node_cjs_resolve_options,
         options::{ConditionValue, ImportMap, ImportMapping, ResolvedMap},
         parse::Request,
@@ -1489,6 +1494,7 @@ fn insert_client_only_error_alias(import_map: &mut ImportMap) {
                     )])
                     .resolved_cell(),
                 )),
+                fix: Some(InvalidImportFix::AddUseClient),
             }
             .resolved_cell(),
         ))
@@ -1512,6 +1518,7 @@ fn insert_client_only_error_alias(import_map: &mut ImportMap) {
                 )])
                 .resolved_cell(),
             )),
+            fix: Some(InvalidImportFix::AddUseClient),
         }
         .resolved_cell(),
     ))
@@ -1538,6 +1545,7 @@ fn insert_server_only_error_alias(import_map: &mut ImportMap) {
                     )])
                     .resolved_cell(),
                 )),
+                fix: Some(InvalidImportFix::RemoveImport),
             }
             .resolved_cell(),
         ))
@@ -1545,10 +1553,20 @@ fn insert_server_only_error_alias(import_map: &mut ImportMap) {
     );
 }
 
+/// How an [InvalidImportIssue] can be fixed in the importing module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+enum InvalidImportFix {
+    /// Marks the importing module as a Client Component.
+    AddUseClient,
+    /// Removes the import statement.
+    RemoveImport,
+}
+
 #[turbo_tasks::value(shared)]
 struct InvalidImportIssue {
     title: ResolvedVc<StyledString>,
     description: ResolvedVc<OptionStyledString>,
+    fix: Option<InvalidImportFix>,
 }
 
 #[turbo_tasks::value_impl]
@@ -1578,6 +1596,38 @@ impl Issue for InvalidImportIssue {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl ImportErrorFixes for InvalidImportIssue {
+    #[turbo_tasks::function]
+    async fn import_fixes(&self, source: IssueSource) -> Result<Vc<IssueFixes>> {
+        let fix = match self.fix {
+            Some(InvalidImportFix::AddUseClient) => IssueFix {
+                title: rcstr!("Add \"use client\""),
+                edits: vec![TextEdit {
+                    source: IssueSource::start_of(source.source()),
+                    new_text: rcstr!("\"use client\";\n\n"),
+                }],
+            },
+            // Only side effect imports can be removed without breaking the module
+            Some(InvalidImportFix::RemoveImport)
+                if source.text().await?.is_some_and(|text| {
+                    text.starts_with("import ") && !text.contains(" from ")
+                }) =>
+            {
+                IssueFix {
+                    title: rcstr!("Remove the import"),
+                    edits: vec![TextEdit {
+                        source,
+                        new_text: rcstr!(""),
+                    }],
+                }
+            }
+            _ => return Ok(Vc::cell(vec![])),
+        };
+        Ok(Vc::cell(vec![fix]))
+    }
+}
+
 // To alias e.g. both `import "next/link"` and `import "next/link.js"`
 fn insert_exact_alias_or_js(
     import_map: &mut ImportMap,
@@ -1626,3 +1676,113 @@ fn external_request_to_esm_import_mapping(
     }
     .resolved_cell()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use turbo_rcstr::rcstr;
+    use turbo_tasks::{ResolvedVc, Vc};
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+    use turbo_tasks_fs::{DiskFileSystem, FileSystem};
+    use turbopack_core::{
+        file_source::FileSource,
+        issue::{IssueFix, IssueSource, StyledString, TextEdit},
+        resolve::error::ImportErrorFixes,
+        source::Source,
+        source_pos::SourcePos,
+    };
+
+    use super::{InvalidImportFix, InvalidImportIssue};
+
+    fn invalid_import_issue(fix: InvalidImportFix) -> Vc<InvalidImportIssue> {
+        InvalidImportIssue {
+            title: StyledString::Text(rcstr!("invalid import")).resolved_cell(),
+            description: ResolvedVc::cell(None),
+            fix: Some(fix),
+        }
+        .cell()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_invalid_import_fixes() {
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            let root = DiskFileSystem::new(
+                rcstr!("test"),
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests/invalid_import")
+                    .to_str()
+                    .unwrap()
+                    .into(),
+            )
+            .root()
+            .owned()
+            .await?;
+            let side_effect_source: ResolvedVc<Box<dyn Source>> = ResolvedVc::upcast(
+                FileSource::new(root.join("side-effect.js")?)
+                    .to_resolved()
+                    .await?,
+            );
+            let named_source: ResolvedVc<Box<dyn Source>> = ResolvedVc::upcast(
+                FileSource::new(root.join("named.js")?)
+                    .to_resolved()
+                    .await?,
+            );
+            let import_statement = |source: ResolvedVc<Box<dyn Source>>, end: u32| {
+                IssueSource::from_line_col(
+                    source,
+                    SourcePos::new(0),
+                    SourcePos {
+                        line: 0,
+                        column: end,
+                    },
+                )
+            };
+
+            // "use client" is inserted at the start of the importing module
+            let side_effect_import = import_statement(side_effect_source, 21);
+            assert_eq!(
+                *invalid_import_issue(InvalidImportFix::AddUseClient)
+                    .import_fixes(side_effect_import)
+                    .await?,
+                vec![IssueFix {
+                    title: rcstr!("Add \"use client\""),
+                    edits: vec![TextEdit {
+                        source: IssueSource::start_of(side_effect_source),
+                        new_text: rcstr!("\"use client\";\n\n"),
+                    }],
+                }]
+            );
+
+            // side effect imports are removed
+            assert_eq!(
+                *invalid_import_issue(InvalidImportFix::RemoveImport)
+                    .import_fixes(side_effect_import)
+                    .await?,
+                vec![IssueFix {
+                    title: rcstr!("Remove the import"),
+                    edits: vec![TextEdit {
+                        source: side_effect_import,
+                        new_text: rcstr!(""),
+                    }],
+                }]
+            );
+
+            // removing an import with bindings would break the module
+            assert_eq!(
+                *invalid_import_issue(InvalidImportFix::RemoveImport)
+                    .import_fixes(import_statement(named_source, 36))
+                    .await?,
+                vec![]
+            );
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
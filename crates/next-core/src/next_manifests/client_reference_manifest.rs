@@ -2,13 +2,15 @@ use anyhow::Result;
 use either::Either;
 use indoc::formatdoc;
 use itertools::Itertools;
+pub use next_taskless::client_reference_manifest::{
+    CssResource, ManifestNode, ManifestNodeEntry, ModuleId as ManifestModuleId, ModuleLoading,
+    SerializedClientReferenceManifest, get_client_reference_module_key,
+};
+use next_taskless::encode_uri_component;
 use rustc_hash::FxHashMap;
-use serde::Serialize;
 use tracing::Instrument;
 use turbo_rcstr::{RcStr, rcstr};
-use turbo_tasks::{
-    FxIndexMap, FxIndexSet, ResolvedVc, TryFlatJoinIterExt, TryJoinIterExt, ValueToString, Vc,
-};
+use turbo_tasks::{FxIndexSet, ResolvedVc, TryFlatJoinIterExt, TryJoinIterExt, ValueToString, Vc};
 use turbo_tasks_fs::{File, FileContent, FileSystemPath};
 use turbopack_core::{
     asset::{Asset, AssetContent},
@@ -23,73 +25,10 @@ use crate::{
     next_app::ClientReferencesChunks,
     next_client_reference::{ClientReferenceGraphResult, ClientReferenceType},
     next_config::{CrossOriginConfig, NextConfig},
-    next_manifests::{ModuleId, encode_uri_component::encode_uri_component},
+    next_manifests::ModuleId,
     util::NextRuntime,
 };
 
-#[derive(Serialize, Default, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct SerializedClientReferenceManifest {
-    pub module_loading: ModuleLoading,
-    /// Mapping of module path and export name to client module ID and required
-    /// client chunks.
-    pub client_modules: ManifestNode,
-    /// Mapping of client module ID to corresponding SSR module ID and required
-    /// SSR chunks.
-    pub ssr_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
-    /// Same as `ssr_module_mapping`, but for Edge SSR.
-    #[serde(rename = "edgeSSRModuleMapping")]
-    pub edge_ssr_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
-    /// Mapping of client module ID to corresponding RSC module ID and required
-    /// RSC chunks.
-    pub rsc_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
-    /// Same as `rsc_module_mapping`, but for Edge RSC.
-    #[serde(rename = "edgeRscModuleMapping")]
-    pub edge_rsc_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
-    /// Mapping of server component path to required CSS client chunks.
-    #[serde(rename = "entryCSSFiles")]
-    pub entry_css_files: FxIndexMap<RcStr, FxIndexSet<CssResource>>,
-    /// Mapping of server component path to required JS client chunks.
-    #[serde(rename = "entryJSFiles")]
-    pub entry_js_files: FxIndexMap<RcStr, FxIndexSet<RcStr>>,
-}
-
-#[derive(Serialize, Debug, Clone, Eq, Hash, PartialEq)]
-pub struct CssResource {
-    pub path: RcStr,
-    pub inlined: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<RcStr>,
-}
-
-#[derive(Serialize, Default, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct ModuleLoading {
-    pub prefix: RcStr,
-    pub cross_origin: Option<CrossOriginConfig>,
-}
-
-#[derive(Serialize, Default, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct ManifestNode {
-    /// Mapping of export name to manifest node entry.
-    #[serde(flatten)]
-    pub module_exports: FxIndexMap<RcStr, ManifestNodeEntry>,
-}
-
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct ManifestNodeEntry {
-    /// Turbopack module ID.
-    pub id: ModuleId,
-    /// Export name.
-    pub name: RcStr,
-    /// Chunks for the module. JS and CSS.
-    pub chunks: Vec<RcStr>,
-    // TODO(WEB-434)
-    pub r#async: bool,
-}
-
 #[turbo_tasks::value(shared)]
 pub struct ClientReferenceManifest {
     pub node_root: FileSystemPath,
@@ -177,7 +116,12 @@ async fn build_manifest(
             rcstr!("")
         };
 
-        entry_manifest.module_loading.cross_origin = next_config.cross_origin().owned().await?;
+        entry_manifest.module_loading.cross_origin = (*next_config.cross_origin().await?)
+            .as_ref()
+            .map(|cross_origin| match cross_origin {
+                CrossOriginConfig::Anonymous => "anonymous".to_string(),
+                CrossOriginConfig::UseCredentials => "use-credentials".to_string(),
+            });
         let ClientReferencesChunks {
             client_component_client_chunks,
             layout_segment_client_chunks,
@@ -289,7 +233,6 @@ async fn build_manifest(
                             suffix_path
                         )
                     })
-                    .map(RcStr::from)
                     .collect::<Vec<_>>();
 
                 let is_async = async_modules.contains(&ResolvedVc::upcast(client_module));
@@ -330,7 +273,6 @@ async fn build_manifest(
                                 .get_path_to(&chunk_path)
                                 .map(ToString::to_string)
                         })
-                        .map(RcStr::from)
                         .collect::<Vec<_>>();
 
                     let is_async = async_modules.contains(&ResolvedVc::upcast(ssr_module));
@@ -349,8 +291,8 @@ async fn build_manifest(
                 entry_manifest.client_modules.module_exports.insert(
                     get_client_reference_module_key(&server_path, "*"),
                     ManifestNodeEntry {
-                        name: rcstr!("*"),
-                        id: (&client_chunk_item_id).into(),
+                        name: "*".to_string(),
+                        id: manifest_module_id(&client_chunk_item_id),
                         chunks: client_chunks_paths,
                         // This should of course be client_is_async, but SSR can become
                         // async due to ESM externals, and
@@ -362,10 +304,10 @@ async fn build_manifest(
 
                 let mut ssr_manifest_node = ManifestNode::default();
                 ssr_manifest_node.module_exports.insert(
-                    rcstr!("*"),
+                    "*".to_string(),
                     ManifestNodeEntry {
-                        name: rcstr!("*"),
-                        id: (&ssr_chunk_item_id).into(),
+                        name: "*".to_string(),
+                        id: manifest_module_id(&ssr_chunk_item_id),
                         chunks: ssr_chunks_paths,
                         // See above
                         r#async: client_is_async || ssr_is_async,
@@ -374,10 +316,10 @@ async fn build_manifest(
 
                 let mut rsc_manifest_node = ManifestNode::default();
                 rsc_manifest_node.module_exports.insert(
-                    rcstr!("*"),
+                    "*".to_string(),
                     ManifestNodeEntry {
-                        name: rcstr!("*"),
-                        id: (&rsc_chunk_item_id).into(),
+                        name: "*".to_string(),
+                        id: manifest_module_id(&rsc_chunk_item_id),
                         chunks: vec![],
                         r#async: rsc_is_async,
                     },
@@ -387,18 +329,18 @@ async fn build_manifest(
                     NextRuntime::NodeJs => {
                         entry_manifest
                             .ssr_module_mapping
-                            .insert((&client_chunk_item_id).into(), ssr_manifest_node);
+                            .insert(manifest_module_id(&client_chunk_item_id), ssr_manifest_node);
                         entry_manifest
                             .rsc_module_mapping
-                            .insert((&client_chunk_item_id).into(), rsc_manifest_node);
+                            .insert(manifest_module_id(&client_chunk_item_id), rsc_manifest_node);
                     }
                     NextRuntime::Edge => {
                         entry_manifest
                             .edge_ssr_module_mapping
-                            .insert((&client_chunk_item_id).into(), ssr_manifest_node);
+                            .insert(manifest_module_id(&client_chunk_item_id), ssr_manifest_node);
                         entry_manifest
                             .edge_rsc_module_mapping
-                            .insert((&client_chunk_item_id).into(), rsc_manifest_node);
+                            .insert(manifest_module_id(&client_chunk_item_id), rsc_manifest_node);
                     }
                 }
             }
@@ -419,11 +361,11 @@ async fn build_manifest(
                 .await?;
             let entry_js_files = entry_manifest
                 .entry_js_files
-                .entry(server_component_name.clone())
+                .entry(server_component_name.to_string())
                 .or_default();
             let entry_css_files = entry_manifest
                 .entry_css_files
-                .entry(server_component_name)
+                .entry(server_component_name.to_string())
                 .or_default();
 
             let client_chunks = client_assets.primary_assets().await?;
@@ -446,7 +388,7 @@ async fn build_manifest(
                                 {
                                     content_file.content().to_str()?.into()
                                 } else {
-                                    RcStr::default()
+                                    String::new()
                                 },
                             )
                         } else {
@@ -464,7 +406,7 @@ async fn build_manifest(
             }
         }
 
-        let client_reference_manifest_json = serde_json::to_string(&entry_manifest).unwrap();
+        let client_reference_manifest_json = serde_json::to_string(&entry_manifest.turbopack_format()).unwrap();
 
         // We put normalized path for the each entry key and the manifest output path,
         // to conform next.js's load client reference manifest behavior:
@@ -516,11 +458,9 @@ impl From<&TurbopackModuleId> for ModuleId {
     }
 }
 
-/// See next.js/packages/next/src/lib/client-reference.ts
-pub fn get_client_reference_module_key(server_path: &str, export_name: &str) -> RcStr {
-    if export_name == "*" {
-        server_path.into()
-    } else {
-        format!("{server_path}#{export_name}").into()
+fn manifest_module_id(module_id: &TurbopackModuleId) -> ManifestModuleId {
+    match module_id {
+        TurbopackModuleId::String(string) => ManifestModuleId::String(string.to_string()),
+        TurbopackModuleId::Number(number) => ManifestModuleId::Number(*number as _),
     }
 }
@@ -1,7 +1,6 @@
 //! Type definitions for the Next.js manifest formats.
 
 pub mod client_reference_manifest;
-mod encode_uri_component;
 
 use anyhow::{Context, Result};
 use bincode::{Decode, Encode};
@@ -398,6 +397,10 @@ pub struct ActionManifestEntry<'a> {
     /// Source location column number (1-indexed), if available
     #[serde(skip_serializing_if = "Option::is_none")]
     pub col: Option<u32>,
+
+    /// The parameters of the action, if `experimental.serverActions.argumentSchema` is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<&'a serde_json::Value>,
 }
 
 #[derive(Serialize, Debug)]
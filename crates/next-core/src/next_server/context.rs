@@ -17,6 +17,7 @@ use turbopack_core::{
     chunk::{
         AssetSuffix, ChunkingConfig, MangleType, MinifyType, SourceMapSourceType, SourceMapsType,
         UnusedReferences, UrlBehavior, chunk_id_strategy::ModuleIdStrategy,
+        property_name_cache::OptionPropertyNameCache,
     },
     compile_time_defines,
     compile_time_info::{CompileTimeDefines, CompileTimeInfo, FreeVarReferences},
@@ -43,7 +44,7 @@ use crate::{
     app_structure::CollectedRootParams,
     mode::NextMode,
     next_build::get_postcss_package_mapping,
-    next_config::NextConfig,
+    next_config::{NextConfig, TurbopackMinifyOptions},
     next_font::local::NextFontLocalResolvePlugin,
     next_import_map::{get_next_edge_and_server_fallback_import_map, get_next_server_import_map},
     next_server::{
@@ -281,6 +282,10 @@ pub async fn get_server_resolve_options_context(
 
     let resolve_options_context = ResolveOptionsContext {
         enable_node_modules: Some(root_dir.clone()),
+        enable_pnp: Some(root_dir.clone()),
+        http_imports: *next_config
+            .turbopack_http_imports(project_path.clone())
+            .await?,
         enable_node_externals: true,
         enable_node_native_modules: true,
         module: true,
@@ -569,6 +574,10 @@ pub async fn get_server_module_options_context(
         css: CssOptionsContext {
             source_maps,
             module_css_condition: Some(module_styles_rule_condition()),
+            sass_load_paths: next_config
+                .sass_load_paths(project_path.clone())
+                .owned()
+                .await?,
             ..Default::default()
         },
         tree_shaking_mode: tree_shaking_mode_for_user_code,
@@ -993,10 +1002,13 @@ pub struct ServerChunkingContextOptions {
     pub export_usage: Vc<OptionBindingUsageInfo>,
     pub unused_references: Vc<UnusedReferences>,
     pub minify: Vc<bool>,
+    pub minify_options: Vc<TurbopackMinifyOptions>,
+    pub property_name_cache: Vc<OptionPropertyNameCache>,
     pub source_maps: Vc<SourceMapsType>,
     pub no_mangling: Vc<bool>,
     pub scope_hoisting: Vc<bool>,
     pub nested_async_chunking: Vc<bool>,
+    pub profile_module_evaluation: Vc<bool>,
     pub debug_ids: Vc<bool>,
     pub client_root: FileSystemPath,
     pub asset_prefix: RcStr,
@@ -1018,10 +1030,13 @@ pub async fn get_server_chunking_context_with_client_assets(
         export_usage,
         unused_references,
         minify,
+        minify_options,
+        property_name_cache,
         source_maps,
         no_mangling,
         scope_hoisting,
         nested_async_chunking,
+        profile_module_evaluation,
         debug_ids,
         client_root,
         asset_prefix,
@@ -1056,20 +1071,22 @@ pub async fn get_server_chunking_context_with_client_assets(
         static_suffix: ResolvedVc::cell(None),
     })
     .minify_type(if *minify.await? {
-        MinifyType::Minify {
+        MinifyType::Minify(minify_options.await?.to_minify_options(
             // React needs deterministic function names to work correctly.
-            mangle: (!*no_mangling.await?).then_some(MangleType::Deterministic),
-        }
+            (!*no_mangling.await?).then_some(MangleType::Deterministic),
+        ))
     } else {
         MinifyType::NoMinify
     })
     .source_maps(*source_maps.await?)
     .module_id_strategy(module_id_strategy.to_resolved().await?)
     .export_usage(*export_usage.await?)
+    .property_name_cache(*property_name_cache.await?)
     .unused_references(unused_references.to_resolved().await?)
     .file_tracing(next_mode.is_production())
     .debug_ids(*debug_ids.await?)
     .nested_async_availability(*nested_async_chunking.await?)
+    .profile_module_evaluation(*profile_module_evaluation.await?)
     .worker_forwarded_globals(worker_forwarded_globals());
 
     builder = builder.source_map_source_type(if next_mode.is_development() {
@@ -1116,10 +1133,13 @@ pub async fn get_server_chunking_context(
         export_usage,
         unused_references,
         minify,
+        minify_options,
+        property_name_cache,
         source_maps,
         no_mangling,
         scope_hoisting,
         nested_async_chunking,
+        profile_module_evaluation,
         debug_ids,
         client_root,
         asset_prefix,
@@ -1155,19 +1175,23 @@ pub async fn get_server_chunking_context(
         static_suffix: ResolvedVc::cell(None),
     })
     .minify_type(if *minify.await? {
-        MinifyType::Minify {
-            mangle: (!*no_mangling.await?).then_some(MangleType::OptimalSize),
-        }
+        MinifyType::Minify(
+            minify_options
+                .await?
+                .to_minify_options((!*no_mangling.await?).then_some(MangleType::OptimalSize)),
+        )
     } else {
         MinifyType::NoMinify
     })
     .source_maps(*source_maps.await?)
     .module_id_strategy(module_id_strategy.to_resolved().await?)
     .export_usage(*export_usage.await?)
+    .property_name_cache(*property_name_cache.await?)
     .unused_references(unused_references.to_resolved().await?)
     .file_tracing(next_mode.is_production())
     .debug_ids(*debug_ids.await?)
     .nested_async_availability(*nested_async_chunking.await?)
+    .profile_module_evaluation(*profile_module_evaluation.await?)
     .worker_forwarded_globals(worker_forwarded_globals());
 
     if next_mode.is_development() {
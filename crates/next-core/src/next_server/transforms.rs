@@ -71,6 +71,7 @@ pub async fn get_next_server_transforms_rules(
 
     let use_cache_enabled = *next_config.enable_use_cache().await?;
     let cache_kinds = next_config.cache_kinds().to_resolved().await?;
+    let extract_argument_schema = *next_config.enable_server_action_argument_schema().await?;
     let mut is_app_dir = false;
 
     let is_server_components = match &context_ty {
@@ -103,6 +104,7 @@ pub async fn get_next_server_transforms_rules(
                     mdx_rs,
                     use_cache_enabled,
                     cache_kinds,
+                    extract_argument_schema,
                 )
                 .await?,
             );
@@ -120,6 +122,7 @@ pub async fn get_next_server_transforms_rules(
                     mdx_rs,
                     use_cache_enabled,
                     cache_kinds,
+                    extract_argument_schema,
                 )
                 .await?,
             );
@@ -137,6 +140,7 @@ pub async fn get_next_server_transforms_rules(
                     mdx_rs,
                     use_cache_enabled,
                     cache_kinds,
+                    extract_argument_schema,
                 )
                 .await?,
             );
@@ -0,0 +1,83 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use next_custom_transforms::transforms::define_transitions::{
+    collect_transition_patterns, validate_transition_patterns,
+};
+use swc_core::ecma::ast::Program;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbopack::module_options::ModuleRule;
+use turbopack_ecmascript::{CustomTransformer, TransformContext};
+
+use super::get_ecma_transform_rule;
+use crate::{
+    app_structure::{Entrypoint, get_entrypoints},
+    mode::NextMode,
+    next_config::NextConfig,
+    next_shared::transforms::EcmascriptTransformStage,
+};
+
+/// Returns a rule which warns about `defineTransitions` rules with patterns that don't match any
+/// page in `app_dir`.
+pub async fn get_next_define_transitions_rule(
+    next_config: Vc<NextConfig>,
+    app_dir: FileSystemPath,
+    mode: Vc<NextMode>,
+    enable_mdx_rs: bool,
+) -> Result<ModuleRule> {
+    let routes = app_page_routes(
+        app_dir,
+        next_config.page_extensions(),
+        next_config.is_global_not_found_enabled(),
+        mode,
+    )
+    .to_resolved()
+    .await?;
+    Ok(get_ecma_transform_rule(
+        Box::new(NextDefineTransitions { routes }),
+        enable_mdx_rs,
+        EcmascriptTransformStage::Preprocess,
+    ))
+}
+
+/// The routes of all pages in `app_dir`, e.g. `/blog/[slug]`.
+#[turbo_tasks::function]
+async fn app_page_routes(
+    app_dir: FileSystemPath,
+    page_extensions: Vc<Vec<RcStr>>,
+    is_global_not_found_enabled: Vc<bool>,
+    mode: Vc<NextMode>,
+) -> Result<Vc<Vec<RcStr>>> {
+    let entrypoints =
+        get_entrypoints(app_dir, page_extensions, is_global_not_found_enabled, mode).await?;
+    Ok(Vc::cell(
+        entrypoints
+            .iter()
+            .filter(|(_, entrypoint)| matches!(entrypoint, Entrypoint::AppPage { .. }))
+            .map(|(path, _)| path.to_string().into())
+            .collect(),
+    ))
+}
+
+#[derive(Debug)]
+struct NextDefineTransitions {
+    routes: ResolvedVc<Vec<RcStr>>,
+}
+
+#[async_trait]
+impl CustomTransformer for NextDefineTransitions {
+    #[tracing::instrument(level = tracing::Level::TRACE, name = "next_define_transitions", skip_all)]
+    async fn transform(&self, program: &mut Program, _ctx: &TransformContext<'_>) -> Result<()> {
+        let Program::Module(module) = program else {
+            return Ok(());
+        };
+        let patterns = collect_transition_patterns(module);
+        // Only depend on the routes when there is something to validate, so that adding or
+        // removing a page doesn't invalidate every module.
+        if !patterns.is_empty() {
+            validate_transition_patterns(&patterns, &self.routes.await?[..]);
+        }
+        Ok(())
+    }
+}
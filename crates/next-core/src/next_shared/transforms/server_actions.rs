@@ -28,6 +28,7 @@ pub async fn get_server_actions_transform_rule(
     enable_mdx_rs: bool,
     use_cache_enabled: bool,
     cache_kinds: ResolvedVc<CacheKinds>,
+    extract_argument_schema: bool,
 ) -> Result<ModuleRule> {
     let transformer =
         EcmascriptInputTransform::Plugin(ResolvedVc::cell(Box::new(NextServerActions {
@@ -36,6 +37,7 @@ pub async fn get_server_actions_transform_rule(
             encryption_key,
             use_cache_enabled,
             cache_kinds,
+            extract_argument_schema,
         }) as _));
     Ok(ModuleRule::new(
         module_rule_match_js_no_url(enable_mdx_rs),
@@ -53,6 +55,7 @@ struct NextServerActions {
     encryption_key: ResolvedVc<RcStr>,
     use_cache_enabled: bool,
     cache_kinds: ResolvedVc<CacheKinds>,
+    extract_argument_schema: bool,
     mode: NextMode,
 }
 
@@ -69,6 +72,7 @@ impl CustomTransformer for NextServerActions {
                 use_cache_enabled: self.use_cache_enabled,
                 hash_salt: self.encryption_key.await?.to_string(),
                 cache_kinds: self.cache_kinds.owned().await?,
+                extract_argument_schema: self.extract_argument_schema,
             },
             ctx.comments.clone(),
             ctx.unresolved_mark,
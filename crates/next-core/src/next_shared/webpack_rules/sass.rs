@@ -107,6 +107,14 @@ pub async fn get_sass_loader_rules(
 
     let loaders = ResolvedVc::cell(vec![resolve_url_loader, sass_loader]);
 
+    // The native compiler doesn't prepend `additionalData` or call a custom implementation, so
+    // those configurations always go through `sass-loader`. Stylesheets the native compiler
+    // can't handle fall back to the loaders.
+    let module_type = (*next_config.experimental_turbopack_native_sass().await? == Some(true)
+        && additional_data.is_none()
+        && sass_options.get("implementation").is_none())
+    .then(|| rcstr!("sass"));
+
     let mut rules = Vec::new();
 
     for (pattern, rename) in [
@@ -119,7 +127,7 @@ pub async fn get_sass_loader_rules(
                 loaders,
                 rename_as: Some(rename),
                 condition: None,
-                module_type: None,
+                module_type: module_type.clone(),
             },
         ));
     }
@@ -0,0 +1,370 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::Vc;
+use turbo_tasks_fs::{File, FileContent};
+
+use crate::next_app::{AppPath, PathSegment};
+
+/// Returns a `.d.ts` file which restricts the `href` of `next/link`, `next/form` and the
+/// `next/navigation` router to the given route pathnames, e.g. `/blog/[slug]`, and declares the
+/// params of every route. The pathnames are the keys of the project entrypoints, so this covers
+/// app pages and route handlers as well as Pages Router pages and API routes.
+///
+/// Intercepting routes are left out, as they are only reachable through the route they
+/// intercept, and so are internal routes like `/_not-found` or `/_document`.
+#[turbo_tasks::function]
+pub async fn typed_routes_declaration(pathnames: Vc<Vec<RcStr>>) -> Result<Vc<FileContent>> {
+    let routes = linkable_routes(&pathnames.await?);
+    let routes = routes.iter().collect::<Vec<_>>();
+    Ok(FileContent::Content(File::from(typed_routes_source(&routes))).cell())
+}
+
+/// Parses the pathnames of the routes that can be linked to.
+fn linkable_routes(pathnames: &[RcStr]) -> Vec<AppPath> {
+    pathnames
+        .iter()
+        .filter(|pathname| !pathname.trim_start_matches('/').starts_with('_'))
+        .map(|pathname| parse_pathname(pathname))
+        .filter(|route| !route.contains_interception())
+        .collect()
+}
+
+/// Parses a router pathname with bracket placeholders, as used by both the app and the pages
+/// directory.
+fn parse_pathname(pathname: &str) -> AppPath {
+    AppPath(
+        pathname
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                if let Some(name) = segment
+                    .strip_prefix("[[...")
+                    .and_then(|s| s.strip_suffix("]]"))
+                {
+                    PathSegment::OptionalCatchAll(name.into())
+                } else if let Some(name) = segment
+                    .strip_prefix("[...")
+                    .and_then(|s| s.strip_suffix(']'))
+                {
+                    PathSegment::CatchAll(name.into())
+                } else if let Some(name) =
+                    segment.strip_prefix('[').and_then(|s| s.strip_suffix(']'))
+                {
+                    PathSegment::Dynamic(name.into())
+                } else {
+                    PathSegment::Static(segment.into())
+                }
+            })
+            .collect(),
+    )
+}
+
+fn typed_routes_source(routes: &[&AppPath]) -> String {
+    let mut static_routes = Vec::new();
+    let mut dynamic_routes = Vec::new();
+    let mut route_params = Vec::new();
+    for route in routes {
+        if route.is_dynamic() {
+            dynamic_routes.push(route_type(route));
+        } else {
+            static_routes.push(route.to_string());
+        }
+        route_params.push((route.to_string(), params_type(route)));
+    }
+    // The entrypoints are ordered by discovery, sort them to keep the output deterministic.
+    static_routes.sort();
+    dynamic_routes.sort();
+    route_params.sort();
+
+    let serialize = |routes: &[String]| {
+        if routes.is_empty() {
+            return "never".to_string();
+        }
+        routes.iter().fold(String::new(), |mut out, route| {
+            write!(out, "\n    | `{route}`").unwrap();
+            out
+        })
+    };
+    let route_impl = if routes.is_empty() {
+        "string & {}"
+    } else {
+        "
+    | StaticRoutes
+    | SearchOrHash
+    | WithProtocol
+    | `${StaticRoutes}${SearchOrHash}`
+    | (T extends `${DynamicRoutes<infer _>}${Suffix}` ? T : never)
+    "
+    };
+    let route_params = route_params
+        .iter()
+        .fold(String::new(), |mut out, (route, params)| {
+            writeln!(out, "    {}: {params}", quote(route)).unwrap();
+            out
+        });
+
+    format!(
+        r#"// This file is generated automatically by Next.js
+// Do not edit this file manually
+
+// Type definitions for Next.js routes
+
+/**
+ * Internal types used by the Next.js router and Link component.
+ * These types are not meant to be used directly.
+ * @internal
+ */
+declare namespace __next_route_internal_types__ {{
+  type SearchOrHash = `?${{string}}` | `#${{string}}`
+  type WithProtocol = `${{string}}:${{string}}`
+
+  type Suffix = '' | SearchOrHash
+
+  type SafeSlug<S extends string> = S extends `${{string}}/${{string}}`
+    ? never
+    : S extends `${{string}}${{SearchOrHash}}`
+    ? never
+    : S extends ''
+    ? never
+    : S
+
+  type CatchAllSlug<S extends string> = S extends `${{string}}${{SearchOrHash}}`
+    ? never
+    : S extends ''
+    ? never
+    : S
+
+  type OptionalCatchAllSlug<S extends string> =
+    S extends `${{string}}${{SearchOrHash}}` ? never : S
+
+  type StaticRoutes = {static_routes}
+  type DynamicRoutes<T extends string = string> = {dynamic_routes}
+
+  type RouteImpl<T> = {route_impl}
+
+  interface RouteParams {{
+{route_params}  }}
+}}
+
+declare module 'next' {{
+  export {{ default }} from 'next/types.js'
+  export * from 'next/types.js'
+
+  export type Route<T extends string = string> =
+    __next_route_internal_types__.RouteImpl<T>
+
+  export type RouteParams<T extends keyof __next_route_internal_types__.RouteParams> =
+    __next_route_internal_types__.RouteParams[T]
+}}
+
+declare module 'next/link' {{
+  export {{ useLinkStatus }} from 'next/dist/client/link.js'
+
+  import type {{ LinkProps as OriginalLinkProps }} from 'next/dist/client/link.js'
+  import type {{ AnchorHTMLAttributes, DetailedHTMLProps }} from 'react'
+  import type {{ UrlObject }} from 'url'
+
+  type LinkRestProps = Omit<
+    Omit<
+      DetailedHTMLProps<
+        AnchorHTMLAttributes<HTMLAnchorElement>,
+        HTMLAnchorElement
+      >,
+      keyof OriginalLinkProps
+    > &
+      OriginalLinkProps,
+    'href'
+  >
+
+  export type LinkProps<RouteInferType> = LinkRestProps & {{
+    /**
+     * The path or URL to navigate to. This is the only required prop. It can also be an object.
+     * @see https://nextjs.org/docs/api-reference/next/link
+     */
+    href: __next_route_internal_types__.RouteImpl<RouteInferType> | UrlObject
+  }}
+
+  export default function Link<RouteType>(props: LinkProps<RouteType>): JSX.Element
+}}
+
+declare module 'next/navigation' {{
+  export * from 'next/dist/client/components/navigation.js'
+
+  import type {{ NavigateOptions, AppRouterInstance as OriginalAppRouterInstance }} from 'next/dist/shared/lib/app-router-context.shared-runtime.js'
+  import type {{ RedirectType }} from 'next/dist/client/components/redirect-error.js'
+
+  interface AppRouterInstance extends OriginalAppRouterInstance {{
+    /**
+     * Navigate to the provided href.
+     * Pushes a new history entry.
+     */
+    push<RouteType>(href: __next_route_internal_types__.RouteImpl<RouteType>, options?: NavigateOptions): void
+    /**
+     * Navigate to the provided href.
+     * Replaces the current history entry.
+     */
+    replace<RouteType>(href: __next_route_internal_types__.RouteImpl<RouteType>, options?: NavigateOptions): void
+    /**
+     * Prefetch the provided href.
+     */
+    prefetch<RouteType>(href: __next_route_internal_types__.RouteImpl<RouteType>): void
+  }}
+
+  export function useRouter(): AppRouterInstance;
+
+  export function redirect<RouteType>(
+    /** The URL to redirect to */
+    url: __next_route_internal_types__.RouteImpl<RouteType>,
+    type?: RedirectType
+  ): never;
+
+  export function permanentRedirect<RouteType>(
+    /** The URL to redirect to */
+    url: __next_route_internal_types__.RouteImpl<RouteType>,
+    type?: RedirectType
+  ): never;
+}}
+
+declare module 'next/form' {{
+  import type {{ FormProps as OriginalFormProps }} from 'next/dist/client/form.js'
+
+  type FormRestProps = Omit<OriginalFormProps, 'action'>
+
+  export type FormProps<RouteInferType> = {{
+    /**
+     * `action` can be either a `string` or a function.
+     * - If `action` is a string, it will be interpreted as a path or URL to navigate to when the form is submitted.
+     *   The path will be prefetched when the form becomes visible.
+     * - If `action` is a function, it will be called when the form is submitted. See the [React docs](https://react.dev/reference/react-dom/components/form#props) for more.
+     */
+    action: __next_route_internal_types__.RouteImpl<RouteInferType> | ((formData: FormData) => void)
+  }} & FormRestProps
+
+  export default function Form<RouteType>(props: FormProps<RouteType>): JSX.Element
+}}
+"#,
+        static_routes = serialize(&static_routes),
+        dynamic_routes = serialize(&dynamic_routes),
+    )
+}
+
+/// The template literal type of the hrefs of a dynamic route, e.g. `/blog/${SafeSlug<T>}`.
+fn route_type(route: &AppPath) -> String {
+    route.iter().fold(String::new(), |mut out, segment| {
+        out.push('/');
+        match segment {
+            PathSegment::Static(s) => out.push_str(s),
+            PathSegment::Dynamic(_) => out.push_str("${SafeSlug<T>}"),
+            PathSegment::CatchAll(_) => out.push_str("${CatchAllSlug<T>}"),
+            PathSegment::OptionalCatchAll(_) => out.push_str("${OptionalCatchAllSlug<T>}"),
+        }
+        out
+    })
+}
+
+/// The object type of the params of a route, e.g. `{ "slug": string }`.
+fn params_type(route: &AppPath) -> String {
+    let params = route
+        .iter()
+        .filter_map(|segment| match segment {
+            PathSegment::Static(_) => None,
+            PathSegment::Dynamic(name) => Some(format!("{}: string", quote(name))),
+            PathSegment::CatchAll(name) => Some(format!("{}: string[]", quote(name))),
+            PathSegment::OptionalCatchAll(name) => Some(format!("{}?: string[]", quote(name))),
+        })
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", params.join("; "))
+    }
+}
+
+fn quote(s: &str) -> String {
+    serde_json::to_string(s).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::rcstr;
+
+    use super::{linkable_routes, params_type, parse_pathname, route_type, typed_routes_source};
+    use crate::next_app::{AppPath, PathSegment};
+
+    #[test]
+    fn test_route_types() {
+        let root = AppPath(vec![]);
+        let post = AppPath(vec![
+            PathSegment::Static(rcstr!("blog")),
+            PathSegment::Dynamic(rcstr!("slug")),
+        ]);
+        let docs = AppPath(vec![
+            PathSegment::Static(rcstr!("docs")),
+            PathSegment::OptionalCatchAll(rcstr!("path")),
+        ]);
+
+        assert_eq!(route_type(&post), "/blog/${SafeSlug<T>}");
+        assert_eq!(route_type(&docs), "/docs/${OptionalCatchAllSlug<T>}");
+        assert_eq!(params_type(&root), "{}");
+        assert_eq!(params_type(&post), r#"{ "slug": string }"#);
+        assert_eq!(params_type(&docs), r#"{ "path"?: string[] }"#);
+
+        let source = typed_routes_source(&[&post, &root, &docs]);
+        assert!(source.contains("type StaticRoutes = \n    | `/`\n"));
+        assert!(source.contains(
+            "type DynamicRoutes<T extends string = string> = \n    | `/blog/${SafeSlug<T>}`\n    \
+             | `/docs/${OptionalCatchAllSlug<T>}`\n"
+        ));
+        assert!(source.contains("    \"/blog/[slug]\": { \"slug\": string }\n"));
+    }
+
+    #[test]
+    fn test_parse_pathname() {
+        assert_eq!(parse_pathname("/"), AppPath(vec![]));
+        assert_eq!(
+            parse_pathname("/api/users/[id]"),
+            AppPath(vec![
+                PathSegment::Static(rcstr!("api")),
+                PathSegment::Static(rcstr!("users")),
+                PathSegment::Dynamic(rcstr!("id")),
+            ])
+        );
+        assert_eq!(
+            parse_pathname("/shop/[...slug]/[[...rest]]"),
+            AppPath(vec![
+                PathSegment::Static(rcstr!("shop")),
+                PathSegment::CatchAll(rcstr!("slug")),
+                PathSegment::OptionalCatchAll(rcstr!("rest")),
+            ])
+        );
+        assert!(parse_pathname("/feed/(..)photo/[id]").contains_interception());
+    }
+
+    #[test]
+    fn test_linkable_routes() {
+        assert_eq!(
+            linkable_routes(&[
+                rcstr!("/"),
+                rcstr!("/_not-found"),
+                rcstr!("/_app"),
+                rcstr!("/_document"),
+                rcstr!("/_error"),
+                rcstr!("/feed/(..)photo/[id]"),
+                rcstr!("/about"),
+            ]),
+            vec![
+                AppPath(vec![]),
+                AppPath(vec![PathSegment::Static(rcstr!("about"))]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_route_types_without_routes() {
+        let source = typed_routes_source(&[]);
+        assert!(source.contains("type StaticRoutes = never\n"));
+        assert!(source.contains("type RouteImpl<T> = string & {}\n"));
+    }
+}
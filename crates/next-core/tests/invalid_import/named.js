@@ -0,0 +1,3 @@
+import { cache } from "server-only";
+
+export function Component() {}
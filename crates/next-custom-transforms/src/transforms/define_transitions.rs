@@ -0,0 +1,398 @@
+//! Validates the string patterns of `defineTransitions` rules from `next/navigation` against the
+//! routes of the app.
+//!
+//! Rules are matched against pathnames at runtime, so a pattern that doesn't match any route
+//! (e.g. because of a typo) is never used. Only string literals are checked, other patterns
+//! (regular expressions, functions or computed values) are ignored.
+
+use std::borrow::Borrow;
+
+use rustc_hash::FxHashSet;
+use swc_core::{
+    common::{Span, errors::HANDLER},
+    ecma::{
+        ast::{
+            ArrayLit, CallExpr, Callee, Expr, Id, ImportDecl, ImportSpecifier, Lit, MemberProp,
+            Module, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Prop, PropName,
+            PropOrSpread, Tpl,
+        },
+        visit::{Visit, VisitWith},
+    },
+};
+
+/// A string pattern in the `from` or `to` field of a transition rule.
+#[derive(Debug, Clone)]
+pub struct TransitionPattern {
+    pub field: &'static str,
+    pub pattern: String,
+    pub span: Span,
+}
+
+/// Returns the string patterns of all `defineTransitions({ rules })` calls in `module`.
+pub fn collect_transition_patterns(module: &Module) -> Vec<TransitionPattern> {
+    let mut collector = TransitionPatternCollector::default();
+    module.visit_with(&mut collector);
+    collector.patterns
+}
+
+/// Emits a warning for every pattern that doesn't match any of the app `routes`, e.g.
+/// `/blog/[slug]`.
+pub fn validate_transition_patterns(patterns: &[TransitionPattern], routes: &[impl Borrow<str>]) {
+    for pattern in patterns {
+        if routes
+            .iter()
+            .any(|route| pattern_matches_route(&pattern.pattern, route.borrow()))
+        {
+            continue;
+        }
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_warn(
+                    pattern.span,
+                    &format!(
+                        "The transition rule pattern \"{}\" in `{}` does not match any route, so \
+                         the rule will never be used.",
+                        pattern.pattern, pattern.field
+                    ),
+                )
+                .emit()
+        });
+    }
+}
+
+/// Returns a visitor that validates the patterns of `defineTransitions` rules against the app
+/// `routes`.
+pub fn define_transitions_validator(routes: Vec<String>) -> impl Visit {
+    DefineTransitionsValidator { routes }
+}
+
+struct DefineTransitionsValidator {
+    routes: Vec<String>,
+}
+
+impl Visit for DefineTransitionsValidator {
+    fn visit_module(&mut self, module: &Module) {
+        validate_transition_patterns(&collect_transition_patterns(module), &self.routes);
+    }
+}
+
+#[derive(Default)]
+struct TransitionPatternCollector {
+    /// Local bindings of `defineTransitions`.
+    define_transitions: FxHashSet<Id>,
+    /// Local bindings of `import * as navigation from 'next/navigation'`.
+    namespaces: FxHashSet<Id>,
+    patterns: Vec<TransitionPattern>,
+}
+
+impl TransitionPatternCollector {
+    fn collect_import(&mut self, decl: &ImportDecl) {
+        if decl.src.value.as_str() != Some("next/navigation") {
+            return;
+        }
+        for specifier in &decl.specifiers {
+            match specifier {
+                ImportSpecifier::Named(named) => {
+                    let imported = match &named.imported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.as_str(),
+                        Some(ModuleExportName::Str(s)) => s.value.as_str().unwrap_or_default(),
+                        None => named.local.sym.as_str(),
+                    };
+                    if imported == "defineTransitions" {
+                        self.define_transitions.insert(named.local.to_id());
+                    }
+                }
+                ImportSpecifier::Namespace(namespace) => {
+                    self.namespaces.insert(namespace.local.to_id());
+                }
+                ImportSpecifier::Default(_) => {}
+            }
+        }
+    }
+
+    fn is_define_transitions(&self, callee: &Callee) -> bool {
+        let Callee::Expr(callee) = callee else {
+            return false;
+        };
+        match &**callee {
+            Expr::Ident(ident) => self.define_transitions.contains(&ident.to_id()),
+            Expr::Member(member) => {
+                matches!(&*member.obj, Expr::Ident(obj) if self.namespaces.contains(&obj.to_id()))
+                    && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"defineTransitions")
+            }
+            _ => false,
+        }
+    }
+
+    fn collect_rules(&mut self, rules: &ArrayLit) {
+        for rule in rules.elems.iter().flatten() {
+            if rule.spread.is_some() {
+                continue;
+            }
+            let Expr::Object(rule) = &*rule.expr else {
+                continue;
+            };
+            for field in ["from", "to"] {
+                let Some(value) = object_property(rule, field) else {
+                    continue;
+                };
+                match value {
+                    Expr::Array(values) => {
+                        for value in values.elems.iter().flatten() {
+                            if value.spread.is_none() {
+                                self.collect_pattern(field, &value.expr);
+                            }
+                        }
+                    }
+                    value => self.collect_pattern(field, value),
+                }
+            }
+        }
+    }
+
+    fn collect_pattern(&mut self, field: &'static str, value: &Expr) {
+        let (pattern, span) = match value {
+            Expr::Lit(Lit::Str(s)) => (s.value.to_string_lossy().into_owned(), s.span),
+            Expr::Tpl(Tpl {
+                exprs,
+                quasis,
+                span,
+            }) if exprs.is_empty() => {
+                let Some(cooked) = quasis.first().and_then(|quasi| quasi.cooked.as_ref()) else {
+                    return;
+                };
+                (cooked.to_string_lossy().into_owned(), *span)
+            }
+            _ => return,
+        };
+        self.patterns.push(TransitionPattern {
+            field,
+            pattern,
+            span,
+        });
+    }
+}
+
+impl Visit for TransitionPatternCollector {
+    fn visit_module(&mut self, module: &Module) {
+        // Imports are hoisted, so they are collected before looking at any calls
+        for item in &module.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = item {
+                self.collect_import(decl);
+            }
+        }
+        if self.define_transitions.is_empty() && self.namespaces.is_empty() {
+            return;
+        }
+        module.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        call.visit_children_with(self);
+
+        if !self.is_define_transitions(&call.callee) {
+            return;
+        }
+        let Some(config) = call.args.first() else {
+            return;
+        };
+        if config.spread.is_some() {
+            return;
+        }
+        if let Expr::Object(config) = &*config.expr
+            && let Some(Expr::Array(rules)) = object_property(config, "rules")
+        {
+            self.collect_rules(rules);
+        }
+    }
+}
+
+/// Returns the value of the `name` property of an object literal, if it's statically known.
+fn object_property<'a>(object: &'a ObjectLit, name: &str) -> Option<&'a Expr> {
+    object.props.iter().rev().find_map(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        let Prop::KeyValue(key_value) = &**prop else {
+            return None;
+        };
+        let key = match &key_value.key {
+            PropName::Ident(ident) => ident.sym.as_str(),
+            PropName::Str(s) => s.value.as_str()?,
+            _ => return None,
+        };
+        (key == name).then_some(&*key_value.value)
+    })
+}
+
+/// A character class of an edge in a [PathAutomaton].
+#[derive(Clone, Copy)]
+enum CharClass {
+    Char(char),
+    /// Any character except `/`.
+    NotSlash,
+    Any,
+}
+
+impl CharClass {
+    fn intersects(self, other: CharClass) -> bool {
+        match (self, other) {
+            (CharClass::Char(a), CharClass::Char(b)) => a == b,
+            (CharClass::Char(c), CharClass::NotSlash)
+            | (CharClass::NotSlash, CharClass::Char(c)) => c != '/',
+            _ => true,
+        }
+    }
+}
+
+/// A nondeterministic finite automaton over pathname characters. Edges without a character class
+/// are epsilon edges.
+#[derive(Default)]
+struct PathAutomaton {
+    edges: Vec<Vec<(Option<CharClass>, usize)>>,
+    accepting: Vec<bool>,
+}
+
+impl PathAutomaton {
+    fn add_state(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.accepting.push(false);
+        self.edges.len() - 1
+    }
+
+    fn add_edge(&mut self, from: usize, class: Option<CharClass>, to: usize) {
+        self.edges[from].push((class, to));
+    }
+
+    /// Adds edges for the characters of `literal` starting at `from`, and returns the last state.
+    fn add_literal(&mut self, mut from: usize, literal: &str) -> usize {
+        for c in literal.chars() {
+            let to = self.add_state();
+            self.add_edge(from, Some(CharClass::Char(c)), to);
+            from = to;
+        }
+        from
+    }
+
+    /// Adds a `/` followed by one or more characters of `class`, and returns the last state.
+    fn add_dynamic_segment(&mut self, from: usize, class: CharClass) -> usize {
+        let slash = self.add_literal(from, "/");
+        let end = self.add_state();
+        self.add_edge(slash, Some(class), end);
+        self.add_edge(end, Some(class), end);
+        end
+    }
+
+    /// A transition pattern, where `*` matches any sequence of characters.
+    fn from_pattern(pattern: &str) -> Self {
+        let mut automaton = PathAutomaton::default();
+        let mut state = automaton.add_state();
+        for c in pattern.chars() {
+            if c == '*' {
+                automaton.add_edge(state, Some(CharClass::Any), state);
+            } else {
+                state = automaton.add_literal(state, c.encode_utf8(&mut [0; 4]));
+            }
+        }
+        automaton.accepting[state] = true;
+        automaton
+    }
+
+    /// The pathnames of an app route, e.g. `/blog/[slug]` or `/docs/[[...path]]`. Pathnames may
+    /// have a trailing slash.
+    fn from_route(route: &str) -> Self {
+        let mut automaton = PathAutomaton::default();
+        let mut state = automaton.add_state();
+        for segment in route.split('/').filter(|segment| !segment.is_empty()) {
+            if segment.starts_with("[[...") && segment.ends_with("]]") {
+                let end = automaton.add_dynamic_segment(state, CharClass::Any);
+                automaton.add_edge(state, None, end);
+                state = end;
+            } else if segment.starts_with("[...") && segment.ends_with(']') {
+                state = automaton.add_dynamic_segment(state, CharClass::Any);
+            } else if segment.starts_with('[') && segment.ends_with(']') {
+                state = automaton.add_dynamic_segment(state, CharClass::NotSlash);
+            } else {
+                state = automaton.add_literal(state, "/");
+                state = automaton.add_literal(state, segment);
+            }
+        }
+        // Without any required segments this also accepts the empty string, which is never a
+        // pathname and can only be matched by an empty pattern.
+        automaton.accepting[state] = true;
+        let trailing_slash = automaton.add_literal(state, "/");
+        automaton.accepting[trailing_slash] = true;
+        automaton
+    }
+
+    /// Returns true if there is a string that is accepted by both automatons.
+    fn intersects(&self, other: &PathAutomaton) -> bool {
+        let mut visited = vec![false; self.edges.len() * other.edges.len()];
+        let mut queue = vec![(0, 0)];
+        while let Some((a, b)) = queue.pop() {
+            let index = a * other.edges.len() + b;
+            if visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            if self.accepting[a] && other.accepting[b] {
+                return true;
+            }
+            for &(class_a, to_a) in &self.edges[a] {
+                match class_a {
+                    None => queue.push((to_a, b)),
+                    Some(class_a) => {
+                        for &(class_b, to_b) in &other.edges[b] {
+                            if class_b.is_some_and(|class_b| class_a.intersects(class_b)) {
+                                queue.push((to_a, to_b));
+                            }
+                        }
+                    }
+                }
+            }
+            for &(class_b, to_b) in &other.edges[b] {
+                if class_b.is_none() {
+                    queue.push((a, to_b));
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Returns true if a pathname exists that is matched by the transition `pattern` and belongs to
+/// the app `route`.
+pub fn pattern_matches_route(pattern: &str, route: &str) -> bool {
+    !pattern.is_empty()
+        && PathAutomaton::from_pattern(pattern).intersects(&PathAutomaton::from_route(route))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pattern_matches_route;
+
+    #[test]
+    fn test_pattern_matches_route() {
+        assert!(pattern_matches_route("/", "/"));
+        assert!(pattern_matches_route("*", "/"));
+        assert!(!pattern_matches_route("/", "/blog"));
+        assert!(pattern_matches_route("/blog", "/blog"));
+        assert!(pattern_matches_route("/blog/", "/blog"));
+        assert!(!pattern_matches_route("/blgo", "/blog"));
+        assert!(pattern_matches_route("/blog/*", "/blog/[slug]"));
+        assert!(pattern_matches_route("/blog/hello", "/blog/[slug]"));
+        assert!(!pattern_matches_route("/blog/a/b", "/blog/[slug]"));
+        assert!(!pattern_matches_route("/blog/", "/blog/[slug]"));
+        assert!(pattern_matches_route("/docs/a/b", "/docs/[...path]"));
+        assert!(!pattern_matches_route("/docs", "/docs/[...path]"));
+        assert!(pattern_matches_route("/shop", "/shop/[[...path]]"));
+        assert!(pattern_matches_route("/shop/a/b", "/shop/[[...path]]"));
+        assert!(pattern_matches_route("/", "/[[...path]]"));
+        assert!(pattern_matches_route("/a", "/[[...path]]"));
+        assert!(pattern_matches_route("*/edit", "/posts/[id]/edit"));
+        assert!(pattern_matches_route("/posts/*", "/posts/[id]/edit"));
+        assert!(!pattern_matches_route("/post/*", "/posts/[id]/edit"));
+        assert!(!pattern_matches_route("/posts/*/view", "/posts/[id]/edit"));
+        assert!(!pattern_matches_route("", "/[[...path]]"));
+    }
+}
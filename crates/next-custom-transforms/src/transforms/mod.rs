@@ -2,6 +2,7 @@ pub mod cjs_finder;
 pub mod cjs_optimizer;
 pub mod debug_fn_name;
 pub mod debug_instant_stack;
+pub mod define_transitions;
 pub mod disallow_re_export_all_in_page;
 pub mod dynamic;
 pub mod fonts;
@@ -50,6 +50,10 @@ pub struct Config {
     pub use_cache_enabled: bool,
     pub hash_salt: String,
     pub cache_kinds: FxHashSet<RcStr>,
+    /// Adds the names, arity and simple TypeScript types of the parameters of every Server
+    /// Reference to the magic comment, so they can be listed in the server reference manifest.
+    #[serde(default)]
+    pub extract_argument_schema: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -82,6 +86,184 @@ struct ServerReferenceExport {
 #[derive(Clone, Debug, serde::Serialize)]
 struct ServerReferenceExportInfo {
     name: Atom,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<ArgumentSchema>,
+}
+
+/// The arguments a Server Reference accepts, as declared by its parameters.
+#[derive(Clone, Debug, serde::Serialize)]
+struct ArgumentSchema {
+    /// The number of declared parameters, not including a rest parameter.
+    arity: usize,
+    params: Vec<ParamSchema>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rest: Option<ParamSchema>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct ParamSchema {
+    /// `None` for destructured parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<Atom>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    optional: bool,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    ty: Option<ArgumentType>,
+}
+
+/// A TypeScript type annotation which can be checked without resolving any types: keywords,
+/// literals, and arrays, object literals and unions of those.
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum ArgumentType {
+    String,
+    Number,
+    Boolean,
+    Bigint,
+    Null,
+    Undefined,
+    Literal { value: serde_json::Value },
+    Array { items: Box<ArgumentType> },
+    Object { properties: Vec<PropertySchema> },
+    Union { types: Vec<ArgumentType> },
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct PropertySchema {
+    name: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    optional: bool,
+    #[serde(rename = "type")]
+    ty: ArgumentType,
+}
+
+impl ArgumentSchema {
+    fn new(params: &[Param]) -> Self {
+        let mut schema = ArgumentSchema {
+            arity: 0,
+            params: vec![],
+            rest: None,
+        };
+        for param in params {
+            if let Pat::Rest(RestPat { arg, type_ann, .. }) = &param.pat {
+                let mut rest = ParamSchema::new(arg);
+                if let Some(type_ann) = type_ann {
+                    rest.ty = ArgumentType::new(&type_ann.type_ann);
+                }
+                schema.rest = Some(rest);
+                break;
+            }
+            schema.params.push(ParamSchema::new(&param.pat));
+        }
+        schema.arity = schema.params.len();
+        schema
+    }
+}
+
+impl ParamSchema {
+    fn new(pat: &Pat) -> Self {
+        let type_of = |type_ann: &Option<Box<TsTypeAnn>>| {
+            type_ann
+                .as_deref()
+                .and_then(|type_ann| ArgumentType::new(&type_ann.type_ann))
+        };
+        match pat {
+            Pat::Ident(BindingIdent { id, type_ann }) => ParamSchema {
+                name: Some(id.sym.clone()),
+                optional: id.optional,
+                ty: type_of(type_ann),
+            },
+            Pat::Assign(AssignPat { left, .. }) => ParamSchema {
+                optional: true,
+                ..ParamSchema::new(left)
+            },
+            Pat::Object(ObjectPat {
+                optional, type_ann, ..
+            })
+            | Pat::Array(ArrayPat {
+                optional, type_ann, ..
+            }) => ParamSchema {
+                name: None,
+                optional: *optional,
+                ty: type_of(type_ann),
+            },
+            _ => ParamSchema {
+                name: None,
+                optional: false,
+                ty: None,
+            },
+        }
+    }
+}
+
+impl ArgumentType {
+    fn new(ty: &TsType) -> Option<Self> {
+        Some(match ty {
+            TsType::TsKeywordType(TsKeywordType { kind, .. }) => match kind {
+                TsKeywordTypeKind::TsStringKeyword => ArgumentType::String,
+                TsKeywordTypeKind::TsNumberKeyword => ArgumentType::Number,
+                TsKeywordTypeKind::TsBooleanKeyword => ArgumentType::Boolean,
+                TsKeywordTypeKind::TsBigIntKeyword => ArgumentType::Bigint,
+                TsKeywordTypeKind::TsNullKeyword => ArgumentType::Null,
+                TsKeywordTypeKind::TsUndefinedKeyword => ArgumentType::Undefined,
+                _ => return None,
+            },
+            TsType::TsLitType(TsLitType { lit, .. }) => ArgumentType::Literal {
+                value: match lit {
+                    TsLit::Str(s) => s.value.to_string_lossy().into_owned().into(),
+                    TsLit::Bool(b) => b.value.into(),
+                    // Keep integers as such instead of writing `1.0`
+                    TsLit::Number(n) if n.value.fract() == 0.0 && n.value.abs() < 2f64.powi(53) => {
+                        (n.value as i64).into()
+                    }
+                    TsLit::Number(n) => n.value.into(),
+                    _ => return None,
+                },
+            },
+            TsType::TsArrayType(TsArrayType { elem_type, .. }) => ArgumentType::Array {
+                items: Box::new(ArgumentType::new(elem_type)?),
+            },
+            TsType::TsTypeLit(TsTypeLit { members, .. }) => ArgumentType::Object {
+                properties: members
+                    .iter()
+                    .map(|member| {
+                        let TsTypeElement::TsPropertySignature(TsPropertySignature {
+                            key,
+                            computed: false,
+                            optional,
+                            type_ann: Some(type_ann),
+                            ..
+                        }) = member
+                        else {
+                            return None;
+                        };
+                        let name = match &**key {
+                            Expr::Ident(ident) => ident.sym.to_string(),
+                            Expr::Lit(Lit::Str(s)) => s.value.to_string_lossy().into_owned(),
+                            _ => return None,
+                        };
+                        Some(PropertySchema {
+                            name,
+                            optional: *optional,
+                            ty: ArgumentType::new(&type_ann.type_ann)?,
+                        })
+                    })
+                    .collect::<Option<_>>()?,
+            },
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                TsUnionType { types, .. },
+            )) => ArgumentType::Union {
+                types: types
+                    .iter()
+                    .map(|ty| ArgumentType::new(ty))
+                    .collect::<Option<_>>()?,
+            },
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                ArgumentType::new(type_ann)?
+            }
+            _ => return None,
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -190,6 +372,7 @@ pub fn server_actions<C: Comments>(
         hoisted_extra_items: Default::default(),
         reference_ids_by_export_name: Default::default(),
         server_reference_exports: Default::default(),
+        argument_schemas: Default::default(),
 
         private_ctxt: SyntaxContext::empty().apply_mark(Mark::new()),
         unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
@@ -264,6 +447,10 @@ struct ServerActions<C: Comments> {
     /// A list of server references for originally exported server functions only.
     server_reference_exports: Vec<ServerReferenceExport>,
 
+    /// The argument schemas of server references, when `extract_argument_schema` is enabled:
+    /// reference_id -> schema
+    argument_schemas: FxHashMap<Atom, ArgumentSchema>,
+
     private_ctxt: SyntaxContext,
     unresolved_ctxt: SyntaxContext,
 
@@ -371,6 +558,16 @@ impl<C: Comments> ServerActions<C> {
         Atom::from(hex_encode(result))
     }
 
+    /// Records the argument schema of a server reference, if enabled.
+    fn register_argument_schema(&mut self, reference_id: &Atom, params: Option<&Vec<Param>>) {
+        if self.config.extract_argument_schema
+            && let Some(params) = params
+        {
+            self.argument_schemas
+                .insert(reference_id.clone(), ArgumentSchema::new(params));
+        }
+    }
+
     fn is_default_export(&self) -> bool {
         matches!(
             self.current_export_name,
@@ -501,6 +698,9 @@ impl<C: Comments> ServerActions<C> {
             false,
             Some(&new_params),
         );
+        // The encrypted closure variables are bound on the server, the client doesn't pass them
+        let client_params = new_params[usize::from(!ids_from_closure.is_empty())..].to_vec();
+        self.register_argument_schema(&action_id, Some(&client_params));
 
         self.has_action = true;
         self.reference_ids_by_export_name.insert(
@@ -668,6 +868,9 @@ impl<C: Comments> ServerActions<C> {
             false,
             Some(&new_params),
         );
+        // The encrypted closure variables are bound on the server, the client doesn't pass them
+        let client_params = new_params[usize::from(!ids_from_closure.is_empty())..].to_vec();
+        self.register_argument_schema(&action_id, Some(&client_params));
 
         self.has_action = true;
         self.reference_ids_by_export_name.insert(
@@ -812,6 +1015,9 @@ impl<C: Comments> ServerActions<C> {
             true,
             Some(&new_params),
         );
+        // The closure variables are bound by the cache wrapper, the client doesn't pass them
+        let client_params = new_params[usize::from(!ids_from_closure.is_empty())..].to_vec();
+        self.register_argument_schema(&reference_id, Some(&client_params));
 
         self.has_cache = true;
         self.reference_ids_by_export_name.insert(
@@ -917,6 +1123,9 @@ impl<C: Comments> ServerActions<C> {
             true,
             Some(&new_params),
         );
+        // The closure variables are bound by the cache wrapper, the client doesn't pass them
+        let client_params = new_params[usize::from(!ids_from_closure.is_empty())..].to_vec();
+        self.register_argument_schema(&reference_id, Some(&client_params));
 
         self.has_cache = true;
         self.reference_ids_by_export_name.insert(
@@ -1018,6 +1227,7 @@ impl<C: Comments> ServerActions<C> {
     ) {
         if let Some(fn_name) = fn_name {
             let reference_id = self.generate_server_reference_id(export_name, false, params);
+            self.register_argument_schema(&reference_id, params);
 
             self.has_action = true;
             self.reference_ids_by_export_name
@@ -1032,6 +1242,7 @@ impl<C: Comments> ServerActions<C> {
         } else if self.is_default_export() {
             let action_ident = Ident::new(self.gen_action_ident(), span, self.private_ctxt);
             let reference_id = self.generate_server_reference_id(export_name, false, params);
+            self.register_argument_schema(&reference_id, params);
 
             self.has_action = true;
             self.reference_ids_by_export_name
@@ -1081,6 +1292,7 @@ impl<C: Comments> ServerActions<C> {
     ) {
         if let Some(fn_name) = fn_name {
             let reference_id = self.generate_server_reference_id(export_name, true, params);
+            self.register_argument_schema(&reference_id, params);
 
             self.has_cache = true;
             self.reference_ids_by_export_name
@@ -1095,6 +1307,7 @@ impl<C: Comments> ServerActions<C> {
         } else if self.is_default_export() {
             let cache_ident = Ident::new(self.gen_cache_ident(), span, self.private_ctxt);
             let reference_id = self.generate_server_reference_id(export_name, true, params);
+            self.register_argument_schema(&reference_id, params);
 
             self.has_cache = true;
             self.reference_ids_by_export_name
@@ -2693,7 +2906,13 @@ impl<C: Comments> VisitMut for ServerActions<C> {
                 .iter()
                 .map(|(export_name, reference_id)| {
                     let name_atom = export_name.atom().into_owned();
-                    (reference_id, ServerReferenceExportInfo { name: name_atom })
+                    (
+                        reference_id,
+                        ServerReferenceExportInfo {
+                            name: name_atom,
+                            args: self.argument_schemas.get(reference_id).cloned(),
+                        },
+                    )
                 })
                 .collect::<BTreeMap<_, _>>();
 
@@ -2765,7 +2984,10 @@ impl<C: Comments> VisitMut for ServerActions<C> {
                             let stripped_export_name = strip_export_name_span(&export_name);
 
                             let name_atom = export_name.atom().into_owned();
-                            let export_info = ServerReferenceExportInfo { name: name_atom };
+                            let export_info = ServerReferenceExportInfo {
+                                name: name_atom,
+                                args: self.argument_schemas.get(&ref_id).cloned(),
+                            };
 
                             new.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
@@ -187,6 +187,7 @@ fn react_server_actions_errors(input: PathBuf) {
                         use_cache_enabled: true,
                         hash_salt: "".into(),
                         cache_kinds: FxHashSet::default(),
+                        extract_argument_schema: false,
                     },
                     tr.comments.as_ref().clone(),
                     unresolved_mark,
@@ -256,6 +257,7 @@ fn use_cache_not_allowed(input: PathBuf) {
                         use_cache_enabled: false,
                         hash_salt: "".into(),
                         cache_kinds: FxHashSet::from_iter([rcstr!("x")]),
+                        extract_argument_schema: false,
                     },
                     tr.comments.as_ref().clone(),
                     unresolved_mark,
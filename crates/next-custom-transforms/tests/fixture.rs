@@ -9,6 +9,7 @@ use next_custom_transforms::transforms::{
     cjs_optimizer::cjs_optimizer,
     debug_fn_name::debug_fn_name,
     debug_instant_stack::debug_instant_stack,
+    define_transitions::define_transitions_validator,
     dynamic::{NextDynamicMode, next_dynamic},
     fonts::{Config as FontLoaderConfig, next_font_loaders},
     named_import_transform::named_import_transform,
@@ -502,6 +503,7 @@ fn react_server_components_fixture(input: PathBuf) {
                         use_cache_enabled: true,
                         hash_salt: "".into(),
                         cache_kinds: FxHashSet::default(),
+                        extract_argument_schema: false,
                     },
                     tr.comments.as_ref().clone(),
                     unresolved_mark,
@@ -554,6 +556,7 @@ fn server_actions_fixture(input: PathBuf) {
     let output = input.parent().unwrap().join(format!("output.{extension}"));
     let is_react_server_layer = input.iter().any(|s| s.to_str() == Some("server-graph"));
     let is_development = input.iter().any(|s| s.to_str() == Some("development"));
+    let extract_argument_schema = input.iter().any(|s| s.to_str() == Some("argument-schema"));
 
     let mode = if input.iter().any(|s| s.to_str() == Some("turbopack")) {
         ServerActionsMode::Turbopack
@@ -576,6 +579,7 @@ fn server_actions_fixture(input: PathBuf) {
                         use_cache_enabled: true,
                         hash_salt: "".into(),
                         cache_kinds: FxHashSet::from_iter(["x".into()]),
+                        extract_argument_schema,
                     },
                     tr.comments.as_ref().clone(),
                     unresolved_mark,
@@ -616,6 +620,7 @@ fn next_font_with_directive_fixture(input: PathBuf) {
                         use_cache_enabled: true,
                         hash_salt: "".into(),
                         cache_kinds: FxHashSet::default(),
+                        extract_argument_schema: false,
                     },
                     tr.comments.as_ref().clone(),
                     unresolved_mark,
@@ -921,6 +926,34 @@ fn test_edge_assert(input: PathBuf) {
     );
 }
 
+#[fixture("tests/fixture/define-transitions/**/input.js")]
+fn test_define_transitions(input: PathBuf) {
+    let output = input.parent().unwrap().join("output.js");
+    let routes = ["/", "/blog", "/blog/[slug]", "/docs/[...path]"];
+
+    test_fixture(
+        syntax(),
+        &|_| {
+            let top_level_mark = Mark::fresh(Mark::root());
+            let unresolved_mark = Mark::fresh(Mark::root());
+
+            (
+                resolver(unresolved_mark, top_level_mark, true),
+                lint_to_fold(define_transitions_validator(
+                    routes.iter().map(|route| route.to_string()).collect(),
+                )),
+            )
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            module: Some(true),
+            ..Default::default()
+        },
+    );
+}
+
 #[fixture("tests/fixture/source-maps/**/input.js")]
 fn test_source_maps(input: PathBuf) {
     let output: PathBuf = input.parent().unwrap().join("output.js");
@@ -947,6 +980,7 @@ fn test_source_maps(input: PathBuf) {
                         use_cache_enabled: true,
                         hash_salt: "".into(),
                         cache_kinds: FxHashSet::from_iter([]),
+                        extract_argument_schema: false,
                     },
                     tr.comments.as_ref().clone(),
                     unresolved_mark,
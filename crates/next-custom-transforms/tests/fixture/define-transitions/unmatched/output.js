@@ -0,0 +1,21 @@
+import { defineTransitions as transitions } from 'next/navigation';
+import * as navigation from 'next/navigation';
+export const blog = transitions({
+    rules: [
+        {
+            from: '/blgo',
+            to: '/blog/*'
+        }
+    ]
+});
+export const docs = navigation.defineTransitions({
+    rules: [
+        {
+            from: '/docs',
+            to: [
+                '/docs/*',
+                `/blog/a/b`
+            ]
+        }
+    ]
+});
@@ -0,0 +1,24 @@
+import { defineTransitions } from 'next/navigation';
+import { slide } from './transitions';
+export const transitions = defineTransitions({
+    rules: [
+        {
+            from: '/blog',
+            to: '/blog/*',
+            transition: slide
+        },
+        {
+            from: [
+                '/',
+                '/docs/getting-started/installation'
+            ],
+            to: '*',
+            transition: 'fade'
+        },
+        {
+            from: /^\/admin/,
+            to: (pathname)=>pathname.startsWith('/admin'),
+            transition: 'none'
+        }
+    ]
+});
@@ -0,0 +1,13 @@
+'use server'
+
+export async function createPost(
+  title: string,
+  visibility: 'public' | 'private' = 'public'
+) {}
+
+export async function tagPost(id: number, ...tags: string[]) {}
+
+export async function movePost(
+  { from, to }: { from: string; to?: string },
+  formData: FormData
+) {}
@@ -0,0 +1,7 @@
+/* __next_internal_action_entry_do_not_use__ {"6064529140e0a5fdb5e756b493e8b9ab36af0e3f70":{"name":"movePost","args":{"arity":2,"params":[{"type":{"kind":"object","properties":[{"name":"from","type":{"kind":"string"}},{"name":"to","optional":true,"type":{"kind":"string"}}]}},{"name":"formData"}]}},"60f3c4984924b0a1d50995516d05e3d4b337218a38":{"name":"createPost","args":{"arity":2,"params":[{"name":"title","type":{"kind":"string"}},{"name":"visibility","optional":true,"type":{"kind":"union","types":[{"kind":"literal","value":"public"},{"kind":"literal","value":"private"}]}}]}},"7ff405a801f3e5cc17e0af062a92995fba76a0ad58":{"name":"tagPost","args":{"arity":1,"params":[{"name":"id","type":{"kind":"number"}}],"rest":{"name":"tags","type":{"kind":"array","items":{"kind":"string"}}}}}} */ import { createServerReference, callServer, findSourceMapURL } from "private-next-rsc-action-client-wrapper";
+const $$RSC_SERVER_ACTION_0 = /*#__PURE__*/ createServerReference("60f3c4984924b0a1d50995516d05e3d4b337218a38", callServer, void 0, findSourceMapURL, "createPost");
+export { $$RSC_SERVER_ACTION_0 as createPost };
+const $$RSC_SERVER_ACTION_1 = /*#__PURE__*/ createServerReference("7ff405a801f3e5cc17e0af062a92995fba76a0ad58", callServer, void 0, findSourceMapURL, "tagPost");
+export { $$RSC_SERVER_ACTION_1 as tagPost };
+const $$RSC_SERVER_ACTION_2 = /*#__PURE__*/ createServerReference("6064529140e0a5fdb5e756b493e8b9ab36af0e3f70", callServer, void 0, findSourceMapURL, "movePost");
+export { $$RSC_SERVER_ACTION_2 as movePost };
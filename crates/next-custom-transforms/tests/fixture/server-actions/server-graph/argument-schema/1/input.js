@@ -0,0 +1,5 @@
+// app/send.ts
+'use server'
+export async function myAction(a, b, c) {
+  console.log('a')
+}
@@ -0,0 +1,143 @@
+//! Converts turbopack issues to LSP diagnostics.
+
+use std::path::{Path, PathBuf};
+
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use rustc_hash::FxHashMap;
+use turbopack_core::issue::{IssueSeverity, PlainIssue};
+
+const PROJECT_PREFIX: &str = "[project]/";
+
+/// Groups the issues by the file they are reported for. Issues of files outside of the project,
+/// e.g. of internal modules, can't be shown in the editor and are skipped.
+pub fn diagnostics_by_file<'a>(
+    root_path: &Path,
+    issues: impl IntoIterator<Item = &'a PlainIssue>,
+) -> FxHashMap<PathBuf, Vec<Diagnostic>> {
+    let mut diagnostics: FxHashMap<_, Vec<_>> = FxHashMap::default();
+    for issue in issues {
+        let Some(path) = issue.file_path.strip_prefix(PROJECT_PREFIX) else {
+            continue;
+        };
+        diagnostics
+            .entry(root_path.join(path))
+            .or_default()
+            .push(issue_to_diagnostic(issue));
+    }
+    diagnostics
+}
+
+pub fn issue_to_diagnostic(issue: &PlainIssue) -> Diagnostic {
+    let mut message = issue.title.to_unstyled_string();
+    if let Some(description) = &issue.description {
+        message.push_str("\n\n");
+        message.push_str(&description.to_unstyled_string());
+    }
+
+    Diagnostic {
+        range: issue_range(issue).unwrap_or_default(),
+        severity: Some(diagnostic_severity(issue.severity)),
+        code: Some(NumberOrString::String(issue.stage.to_string())),
+        source: Some("turbopack".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+fn diagnostic_severity(severity: IssueSeverity) -> DiagnosticSeverity {
+    match severity {
+        IssueSeverity::Bug | IssueSeverity::Fatal | IssueSeverity::Error => {
+            DiagnosticSeverity::ERROR
+        }
+        IssueSeverity::Warning => DiagnosticSeverity::WARNING,
+        IssueSeverity::Suggestion | IssueSeverity::Hint => DiagnosticSeverity::HINT,
+        IssueSeverity::Note | IssueSeverity::Info => DiagnosticSeverity::INFORMATION,
+    }
+}
+
+/// The range of the issue in the file at [PlainIssue::file_path]. The range of the source is
+/// dropped when it was mapped to another file, e.g. through a source map.
+fn issue_range(issue: &PlainIssue) -> Option<Range> {
+    let source = issue.source.as_ref()?;
+    if !source.asset.ident.starts_with(issue.file_path.as_str()) {
+        return None;
+    }
+    let (start, end) = source.range?;
+    Some(Range::new(
+        Position::new(start.line, start.column),
+        Position::new(end.line, end.column),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use lsp_types::{DiagnosticSeverity, Position, Range};
+    use turbo_rcstr::rcstr;
+    use turbo_tasks::ReadRef;
+    use turbo_tasks_fs::FileContent;
+    use turbopack_core::{
+        issue::{
+            IssueSeverity, IssueStage, PlainIssue, PlainIssueSource, PlainSource, StyledString,
+        },
+        source_pos::SourcePos,
+    };
+
+    use super::diagnostics_by_file;
+
+    fn issue(file_path: &str, source_ident: &str) -> PlainIssue {
+        PlainIssue {
+            severity: IssueSeverity::Error,
+            stage: IssueStage::Resolve,
+            title: StyledString::Text(rcstr!("Module not found")),
+            file_path: file_path.into(),
+            description: Some(StyledString::Text(rcstr!("Can't resolve './missing'"))),
+            detail: None,
+            documentation_link: rcstr!(""),
+            source: Some(PlainIssueSource {
+                asset: ReadRef::new_owned(PlainSource {
+                    ident: ReadRef::new_owned(source_ident.into()),
+                    content: ReadRef::new_owned(FileContent::NotFound),
+                }),
+                range: Some((
+                    SourcePos { line: 1, column: 7 },
+                    SourcePos {
+                        line: 1,
+                        column: 18,
+                    },
+                )),
+            }),
+            import_traces: vec![],
+            fixes: vec![],
+        }
+    }
+
+    #[test]
+    fn groups_project_issues_by_file() {
+        let issues = [
+            issue(
+                "[project]/app/page.tsx",
+                "[project]/app/page.tsx [app-rsc] (ecmascript)",
+            ),
+            issue("[project]/app/page.tsx", "[project]/app/other.css"),
+            issue("[next]/internal/entry.js", "[next]/internal/entry.js"),
+        ];
+        let diagnostics = diagnostics_by_file(Path::new("/app"), &issues);
+        assert_eq!(diagnostics.len(), 1);
+
+        let page = &diagnostics[Path::new("/app/app/page.tsx")];
+        assert_eq!(page.len(), 2);
+        assert_eq!(page[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            page[0].range,
+            Range::new(Position::new(1, 7), Position::new(1, 18))
+        );
+        assert_eq!(
+            page[0].message,
+            "Module not found\n\nCan't resolve './missing'"
+        );
+        // The source of the second issue is another file
+        assert_eq!(page[1].range, Range::default());
+    }
+}
@@ -0,0 +1,9 @@
+#![feature(future_join)]
+#![feature(min_specialization)]
+#![feature(arbitrary_self_types)]
+#![feature(arbitrary_self_types_pointers)]
+
+pub mod diagnostics;
+pub mod project;
+pub mod server;
+pub mod specifier;
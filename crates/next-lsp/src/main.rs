@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use lsp_server::Connection;
+use lsp_types::InitializeParams;
+use next_lsp::{
+    project::{LspProject, read_project_options},
+    server::{serve, server_capabilities},
+};
+use serde::Deserialize;
+use turbo_tasks::TurboTasks;
+use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+
+/// Runs a Next.js project in development mode and serves its issues and module graph over the
+/// Language Server Protocol on stdin/stdout.
+#[derive(Parser)]
+struct Args {
+    /// The project options, e.g. as written by `next-build-test generate`. Can also be passed as
+    /// `projectOptions` in the `initializationOptions` of the client.
+    #[arg(long)]
+    project_options: Option<PathBuf>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InitializationOptions {
+    project_options: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    // stdout is used by the protocol
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .init();
+
+    let (connection, io_threads) = Connection::stdio();
+    let params: InitializeParams = serde_json::from_value(
+        connection.initialize(serde_json::to_value(server_capabilities())?)?,
+    )?;
+    let initialization_options: InitializationOptions = params
+        .initialization_options
+        .map(serde_json::from_value)
+        .transpose()?
+        .unwrap_or_default();
+    let project_options_path = args
+        .project_options
+        .or(initialization_options.project_options)
+        .context("The path of the project options is required, pass --project-options")?;
+    let options = read_project_options(&project_options_path)?;
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+    let project = runtime.block_on(async {
+        let turbo_tasks = TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions {
+                storage_mode: None,
+                ..Default::default()
+            },
+            noop_backing_storage(),
+        ));
+        LspProject::new(turbo_tasks, options).await
+    })?;
+
+    serve(&connection, &runtime, project)?;
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,568 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Context, Result};
+use next_api::project::{
+    Project, ProjectContainer, ProjectOptions, whole_app_module_graph_operation,
+};
+use rustc_hash::FxHashMap;
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{
+    FxIndexMap, FxIndexSet, ReadRef, ResolvedVc, TaskId, TryFlatJoinIterExt, TryJoinIterExt,
+    TurboTasks, Vc,
+};
+use turbo_tasks_backend::{NoopBackingStorage, TurboTasksBackend};
+use turbo_tasks_fs::{FileSystemPath, rope::Rope, to_sys_path};
+use turbo_unix_path::sys_to_unix;
+use turbopack_core::{
+    issue::{CollectibleIssuesExt, PlainIssue},
+    module::Module,
+    reference_type::{CommonJsReferenceSubType, EcmaScriptModulesReferenceSubType},
+    resolve::{ResolveErrorMode, origin::ResolveOrigin, parse::Request},
+};
+use turbopack_resolve::ecmascript::{cjs_resolve, esm_resolve};
+
+use crate::specifier::ImportKind;
+
+pub type Backend = TurboTasksBackend<NoopBackingStorage>;
+
+/// A Next.js project running in development mode, with the queries needed by the language server.
+pub struct LspProject {
+    turbo_tasks: Arc<TurboTasks<Backend>>,
+    container: ResolvedVc<ProjectContainer>,
+    root_path: PathBuf,
+}
+
+impl LspProject {
+    pub async fn new(
+        turbo_tasks: Arc<TurboTasks<Backend>>,
+        options: ProjectOptions,
+    ) -> Result<Self> {
+        let root_path = PathBuf::from(options.root_path.as_str());
+        let container = turbo_tasks
+            .run(async move {
+                let container = ProjectContainer::new(rcstr!("next-lsp"), options.dev)
+                    .to_resolved()
+                    .await?;
+                container.initialize(options).await?;
+                Ok(container)
+            })
+            .await?;
+        Ok(Self {
+            turbo_tasks,
+            container,
+            root_path,
+        })
+    }
+
+    pub fn root_path(&self) -> &Path {
+        &self.root_path
+    }
+
+    /// The path of `file` relative to the root of the project file system, or `None` when it's
+    /// outside of it.
+    fn relative_path(&self, file: &Path) -> Option<RcStr> {
+        let relative = file.strip_prefix(&self.root_path).ok()?;
+        Some(sys_to_unix(relative.to_str()?).into())
+    }
+
+    /// Calls `callback` with the issues of all routes, and again every time they change.
+    pub fn subscribe_issues(
+        &self,
+        callback: impl Fn(Arc<Vec<ReadRef<PlainIssue>>>) + Send + Sync + Clone + 'static,
+    ) -> TaskId {
+        let container = self.container;
+        self.turbo_tasks.spawn_root_task(move || {
+            let callback = callback.clone();
+            async move {
+                let issues = project_issues_operation(container)
+                    .read_strongly_consistent()
+                    .await?;
+                callback(issues.issues.clone());
+                Ok(Vc::<()>::default())
+            }
+        })
+    }
+
+    /// Uses `content` instead of the file on disk, e.g. for an unsaved editor buffer.
+    pub async fn set_content(&self, file: &Path, content: String) -> Result<()> {
+        let Some(path) = self.relative_path(file) else {
+            return Ok(());
+        };
+        let container = self.container;
+        self.turbo_tasks
+            .run(async move {
+                let project = container.project();
+                let path = project.project_root_path().await?.join(&path)?;
+                project
+                    .project_overlay_fs()
+                    .await?
+                    .set_content(&path, Rope::from(content));
+                Ok(())
+            })
+            .await
+    }
+
+    /// Reads `file` from disk again after [LspProject::set_content].
+    pub async fn clear_content(&self, file: &Path) -> Result<()> {
+        let Some(path) = self.relative_path(file) else {
+            return Ok(());
+        };
+        let container = self.container;
+        self.turbo_tasks
+            .run(async move {
+                let project = container.project();
+                let path = project.project_root_path().await?.join(&path)?;
+                project.project_overlay_fs().await?.clear_content(&path);
+                Ok(())
+            })
+            .await
+    }
+
+    /// Resolves `request` from every module of `file` in the module graph, i.e. once per layer
+    /// the file is used in. This applies the aliases and `exports` conditions of that layer.
+    pub async fn resolve_import(
+        &self,
+        file: &Path,
+        request: &str,
+        kind: ImportKind,
+    ) -> Result<Vec<PathBuf>> {
+        let Some(path) = self.relative_path(file) else {
+            return Ok(vec![]);
+        };
+        let container = self.container;
+        let request = RcStr::from(request);
+        let is_require = kind == ImportKind::Require;
+        self.turbo_tasks
+            .run(async move {
+                let paths = resolve_import_operation(container, path, request, is_require)
+                    .read_strongly_consistent()
+                    .await?;
+                sys_paths(&paths).await
+            })
+            .await
+    }
+
+    /// Returns the files importing `file` in the module graph.
+    pub async fn importers(&self, file: &Path) -> Result<Vec<PathBuf>> {
+        let Some(path) = self.relative_path(file) else {
+            return Ok(vec![]);
+        };
+        let container = self.container;
+        self.turbo_tasks
+            .run(async move {
+                let paths = importers_operation(container, path)
+                    .read_strongly_consistent()
+                    .await?;
+                sys_paths(&paths).await
+            })
+            .await
+    }
+}
+
+async fn sys_paths(paths: &[FileSystemPath]) -> Result<Vec<PathBuf>> {
+    paths
+        .iter()
+        .map(async |path| to_sys_path(path.clone()).await)
+        .try_flat_join()
+        .await
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct ProjectIssues {
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+}
+
+#[turbo_tasks::function(operation)]
+async fn project_issues_operation(
+    container: ResolvedVc<ProjectContainer>,
+) -> Result<Vc<ProjectIssues>> {
+    let project = container.project().to_resolved().await?;
+    let module_graphs_op = whole_app_module_graph_operation(project);
+    let result = module_graphs_op.read_strongly_consistent().await;
+    let issues = module_graphs_op
+        .peek_issues()
+        .get_plain_issues(project.issue_filter())
+        .await?;
+    // A failed compilation is reported through its issues
+    if issues.is_empty() {
+        result?;
+    }
+    Ok(ProjectIssues {
+        issues: Arc::new(issues),
+    }
+    .cell())
+}
+
+#[turbo_tasks::value(transparent)]
+struct FileSystemPaths(Vec<FileSystemPath>);
+
+/// The modules of every file in the module graph, and the files importing them.
+#[turbo_tasks::value(serialization = "none")]
+struct ModuleIndex {
+    modules: FxIndexMap<FileSystemPath, Vec<ResolvedVc<Box<dyn Module>>>>,
+    importers: FxIndexMap<FileSystemPath, FxIndexSet<FileSystemPath>>,
+}
+
+#[turbo_tasks::function]
+async fn module_index(project: ResolvedVc<Project>) -> Result<Vc<ModuleIndex>> {
+    let module_graph = project.whole_app_module_graphs().await?.full.await?;
+
+    let module_paths = module_graph
+        .iter_nodes()
+        .map(async |module| Ok((module, module.ident().path().owned().await?)))
+        .try_join()
+        .await?
+        .into_iter()
+        .collect::<FxHashMap<_, _>>();
+
+    let mut modules: FxIndexMap<_, Vec<_>> = FxIndexMap::default();
+    for (module, path) in &module_paths {
+        modules.entry(path.clone()).or_default().push(*module);
+    }
+
+    let mut importers: FxIndexMap<_, FxIndexSet<_>> = FxIndexMap::default();
+    module_graph.traverse_edges_unordered(|parent, current| {
+        if let Some((parent, _)) = parent
+            && let (Some(parent_path), Some(path)) =
+                (module_paths.get(&parent), module_paths.get(&current))
+            && parent_path != path
+        {
+            importers
+                .entry(path.clone())
+                .or_default()
+                .insert(parent_path.clone());
+        }
+        Ok(())
+    })?;
+
+    Ok(ModuleIndex { modules, importers }.cell())
+}
+
+#[turbo_tasks::function(operation)]
+async fn resolve_import_operation(
+    container: ResolvedVc<ProjectContainer>,
+    path: RcStr,
+    request: RcStr,
+    is_require: bool,
+) -> Result<Vc<FileSystemPaths>> {
+    let project = container.project().to_resolved().await?;
+    let file = project.project_root_path().await?.join(&path)?;
+    let index = module_index(*project).await?;
+    let Some(modules) = index.modules.get(&file) else {
+        return Ok(Vc::cell(vec![]));
+    };
+
+    let request = Request::parse_string(request);
+    let results = modules
+        .iter()
+        .filter_map(|module| ResolvedVc::try_sidecast::<Box<dyn ResolveOrigin>>(*module))
+        .map(async |origin| {
+            let result = if is_require {
+                cjs_resolve(
+                    *origin,
+                    request,
+                    CommonJsReferenceSubType::Undefined,
+                    None,
+                    ResolveErrorMode::Ignore,
+                )
+                .await?
+            } else {
+                esm_resolve(
+                    *origin,
+                    request,
+                    EcmaScriptModulesReferenceSubType::Import,
+                    ResolveErrorMode::Ignore,
+                    None,
+                )
+                .await?
+            };
+            result
+                .await?
+                .primary_modules_ref()
+                .await?
+                .into_iter()
+                .map(async |module| module.ident().path().owned().await)
+                .try_join()
+                .await
+        })
+        .try_join()
+        .await?;
+
+    let paths = results.into_iter().flatten().collect::<FxIndexSet<_>>();
+    Ok(Vc::cell(paths.into_iter().collect()))
+}
+
+#[turbo_tasks::function(operation)]
+async fn importers_operation(
+    container: ResolvedVc<ProjectContainer>,
+    path: RcStr,
+) -> Result<Vc<FileSystemPaths>> {
+    let project = container.project().to_resolved().await?;
+    let file = project.project_root_path().await?.join(&path)?;
+    let index = module_index(*project).await?;
+    Ok(Vc::cell(
+        index
+            .importers
+            .get(&file)
+            .map(|importers| importers.iter().cloned().collect())
+            .unwrap_or_default(),
+    ))
+}
+
+/// Returns the project options, e.g. as written by `next-build-test generate`.
+pub fn read_project_options(path: &Path) -> Result<ProjectOptions> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Unable to open the project options at {}", path.display()))?;
+    let mut options: ProjectOptions = serde_json::from_reader(file)
+        .with_context(|| format!("Invalid project options at {}", path.display()))?;
+    options.dev = true;
+    options.watch.enable = true;
+    Ok(options)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        path::{Path, PathBuf},
+        time::Duration,
+    };
+
+    use anyhow::Result;
+    use next_api::project::{DefineEnv, DraftModeOptions, ProjectContainer, ProjectOptions};
+    use turbo_rcstr::{RcStr, rcstr};
+    use turbo_tasks::{ResolvedVc, TurboTasks, Vc};
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+
+    use super::{
+        LspProject, ModuleIndex, importers_operation, module_index, resolve_import_operation,
+    };
+    use crate::specifier::ImportKind;
+
+    /// The fixture project is part of the project file system of the repository, so `next` and
+    /// `react` are resolved from its `node_modules`, which requires the `next` package to be
+    /// built.
+    pub(crate) fn fixture_path(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixture")
+            .join(path)
+            .canonicalize()
+            .unwrap()
+    }
+
+    pub(crate) async fn fixture_project() -> Result<LspProject> {
+        let root_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../..")
+            .canonicalize()?;
+        let turbo_tasks = TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        LspProject::new(
+            turbo_tasks,
+            ProjectOptions {
+                root_path: root_path.to_string_lossy().into(),
+                project_path: fixture_path("").to_string_lossy().into(),
+                next_config: serde_json::json!({
+                    "configFileName": "next.config.js",
+                    "distDir": ".next",
+                    "distDirRoot": ".next",
+                    "pageExtensions": ["js"],
+                })
+                .to_string()
+                .into(),
+                env: vec![],
+                define_env: DefineEnv {
+                    client: vec![],
+                    edge: vec![],
+                    nodejs: vec![],
+                },
+                watch: Default::default(),
+                dev: true,
+                encryption_key: rcstr!("deadbeef"),
+                build_id: rcstr!("development"),
+                preview_props: DraftModeOptions {
+                    preview_mode_id: rcstr!("test"),
+                    preview_mode_encryption_key: rcstr!("deadbeef"),
+                    preview_mode_signing_key: rcstr!("deadbeef"),
+                },
+                browserslist_query: rcstr!("last 1 Chrome versions"),
+                no_mangling: false,
+                write_routes_hashes_manifest: false,
+                current_node_js_version: rcstr!("20.0.0"),
+                debug_build_paths: None,
+                deferred_entries: None,
+                is_persistent_caching_enabled: false,
+            },
+        )
+        .await
+    }
+
+    #[turbo_tasks::function(operation)]
+    fn module_index_operation(container: ResolvedVc<ProjectContainer>) -> Vc<ModuleIndex> {
+        module_index(container.project())
+    }
+
+    /// The path of `file` relative to the project file system, as used by the operations.
+    fn relative(project: &LspProject, file: &str) -> RcStr {
+        project.relative_path(&fixture_path(file)).unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn indexes_the_modules_and_importers_of_each_file() {
+        let project = fixture_project().await.unwrap();
+        let container = project.container;
+        let (index_path, greet_path) = (
+            relative(&project, "pages/index.js"),
+            relative(&project, "lib/greet.js"),
+        );
+        project
+            .turbo_tasks
+            .run(async move {
+                let project_path = container.project().project_root_path().owned().await?;
+                let index = module_index_operation(container)
+                    .read_strongly_consistent()
+                    .await?;
+
+                // Pages are compiled for the client and for SSR
+                let index_modules = &index.modules[&project_path.join(&index_path)?];
+                assert!(index_modules.len() >= 2);
+
+                let greet_importers = index.importers[&project_path.join(&greet_path)?]
+                    .iter()
+                    .map(|path| path.path.clone())
+                    .collect::<Vec<_>>();
+                assert_eq!(greet_importers.len(), 2);
+                assert!(
+                    greet_importers
+                        .iter()
+                        .any(|path| path.ends_with("pages/index.js"))
+                );
+                assert!(
+                    greet_importers
+                        .iter()
+                        .any(|path| path.ends_with("pages/about.js"))
+                );
+                Ok(())
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn resolves_aliases_and_export_conditions() {
+        let project = fixture_project().await.unwrap();
+        let container = project.container;
+        let index_path = relative(&project, "pages/index.js");
+        let greet_path = relative(&project, "lib/greet.js");
+        project
+            .turbo_tasks
+            .run(async move {
+                let resolve =
+                    async |request: RcStr, is_require: bool| -> Result<Vec<RcStr>> {
+                        Ok(resolve_import_operation(
+                            container,
+                            index_path.clone(),
+                            request,
+                            is_require,
+                        )
+                        .read_strongly_consistent()
+                        .await?
+                        .iter()
+                        .map(|path| path.path.clone())
+                        .collect())
+                    };
+                assert_eq!(
+                    resolve(rcstr!("@/lib/greet"), false).await?,
+                    [greet_path.clone()]
+                );
+                let esm = resolve(rcstr!("conditional"), false).await?;
+                assert_eq!(esm.len(), 1);
+                assert!(esm[0].ends_with("node_modules/conditional/esm.js"));
+                let cjs = resolve(rcstr!("conditional"), true).await?;
+                assert_eq!(cjs.len(), 1);
+                assert!(cjs[0].ends_with("node_modules/conditional/cjs.js"));
+                assert!(resolve(rcstr!("./missing"), false).await?.is_empty());
+
+                let importers = importers_operation(container, greet_path)
+                    .read_strongly_consistent()
+                    .await?;
+                assert_eq!(importers.len(), 2);
+                Ok(())
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn uses_the_content_of_open_documents() {
+        let project = fixture_project().await.unwrap();
+        let about = fixture_path("pages/about.js");
+        let greet = fixture_path("lib/greet.js");
+        assert_eq!(project.importers(&greet).await.unwrap().len(), 2);
+        assert_eq!(
+            project
+                .resolve_import(&about, "../lib/greet", ImportKind::Esm)
+                .await
+                .unwrap(),
+            [greet.clone()]
+        );
+
+        project
+            .set_content(
+                &about,
+                "export default function About() {\n  return 'about'\n}\n".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            project.importers(&greet).await.unwrap(),
+            [fixture_path("pages/index.js")]
+        );
+
+        project.clear_content(&about).await.unwrap();
+        assert_eq!(project.importers(&greet).await.unwrap().len(), 2);
+
+        // Files outside of the project file system are ignored
+        assert!(
+            project
+                .importers(Path::new("/outside/of/the/project.js"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn reports_issues_of_open_documents() {
+        let project = fixture_project().await.unwrap();
+        let about = fixture_path("pages/about.js");
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        project.subscribe_issues(move |issues| {
+            let _ = sender.send(issues);
+        });
+        project
+            .set_content(
+                &about,
+                "import './missing'\n\nexport default function About() {}\n".to_string(),
+            )
+            .await
+            .unwrap();
+
+        // The issues are reported again whenever they change
+        loop {
+            let issues = tokio::time::timeout(Duration::from_secs(60), receiver.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            if issues
+                .iter()
+                .any(|issue| issue.file_path.ends_with("pages/about.js"))
+            {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,421 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Result};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    GotoDefinitionParams, GotoDefinitionResponse, Location, LocationLink, OneOf,
+    PublishDiagnosticsParams, Range, ReferenceParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _,
+        PublishDiagnostics,
+    },
+    request::{GotoDefinition, References, Request as _},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::de::DeserializeOwned;
+use tokio::runtime::Runtime;
+
+use crate::{
+    diagnostics::diagnostics_by_file,
+    project::LspProject,
+    specifier::{import_specifier_at, import_specifiers},
+};
+
+pub fn server_capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Handles the messages of the client until it shuts down the server.
+///
+/// Diagnostics are published whenever the issues of the project change. "Go to definition" on
+/// an import specifier navigates to the module turbopack resolves it to, and "find references"
+/// lists the files importing the current file.
+pub fn serve(connection: &Connection, runtime: &Runtime, project: LspProject) -> Result<()> {
+    let mut server = Server {
+        connection,
+        project,
+        documents: FxHashMap::default(),
+    };
+    runtime.block_on(async { server.publish_diagnostics() });
+
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                let id = request.id.clone();
+                let response = match runtime.block_on(server.handle_request(request)) {
+                    Ok(response) => response,
+                    Err(error) => {
+                        Response::new_err(id, ErrorCode::InternalError as i32, format!("{error:#}"))
+                    }
+                };
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                if let Err(error) = runtime.block_on(server.handle_notification(notification)) {
+                    tracing::error!("failed to handle notification: {error:#}");
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+struct Server<'a> {
+    connection: &'a Connection,
+    project: LspProject,
+    /// The content of the documents opened in the editor.
+    documents: FxHashMap<PathBuf, String>,
+}
+
+impl Server<'_> {
+    fn publish_diagnostics(&self) {
+        let sender = self.connection.sender.clone();
+        let root_path = self.project.root_path().to_path_buf();
+        // Files with diagnostics need an empty update once their issues are fixed
+        let published = Arc::new(Mutex::new(FxHashSet::<PathBuf>::default()));
+        self.project.subscribe_issues(move |issues| {
+            let mut diagnostics = diagnostics_by_file(&root_path, issues.iter().map(|i| &**i));
+            let mut published = published.lock().unwrap();
+            for path in published.drain() {
+                diagnostics.entry(path).or_default();
+            }
+            for (path, diagnostics) in diagnostics {
+                let Ok(uri) = Url::from_file_path(&path) else {
+                    continue;
+                };
+                if !diagnostics.is_empty() {
+                    published.insert(path);
+                }
+                let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+                let notification =
+                    Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+                if sender.send(Message::Notification(notification)).is_err() {
+                    // The connection is closed
+                    return;
+                }
+            }
+        });
+    }
+
+    async fn handle_request(&mut self, request: Request) -> Result<Response> {
+        let id = request.id.clone();
+        match request.method.as_str() {
+            GotoDefinition::METHOD => {
+                let params: GotoDefinitionParams = params(request)?;
+                let result = self.goto_definition(params).await?;
+                Ok(Response::new_ok(id, result))
+            }
+            References::METHOD => {
+                let params: ReferenceParams = params(request)?;
+                let result = self.references(params).await?;
+                Ok(Response::new_ok(id, result))
+            }
+            method => Ok(method_not_found(id, method)),
+        }
+    }
+
+    async fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let path = file_path(&params.text_document.uri)?;
+                self.set_document(path, params.text_document.text).await?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let path = file_path(&params.text_document.uri)?;
+                // Full text sync sends a single change with the whole document
+                if let Some(change) = params.content_changes.into_iter().next_back() {
+                    self.set_document(path, change.text).await?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let path = file_path(&params.text_document.uri)?;
+                self.documents.remove(&path);
+                self.project.clear_content(&path).await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn set_document(&mut self, path: PathBuf, text: String) -> Result<()> {
+        self.project.set_content(&path, text.clone()).await?;
+        self.documents.insert(path, text);
+        Ok(())
+    }
+
+    /// The content of `path` in the editor, or on disk if it's not open.
+    async fn document(&self, path: &Path) -> Result<String> {
+        if let Some(text) = self.documents.get(path) {
+            return Ok(text.clone());
+        }
+        tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Unable to read {}", path.display()))
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let position = params.text_document_position_params;
+        let path = file_path(&position.text_document.uri)?;
+        let text = self.document(&path).await?;
+        let Some(specifier) = import_specifier_at(&text, position.position) else {
+            return Ok(None);
+        };
+
+        let links = self
+            .project
+            .resolve_import(&path, &specifier.request, specifier.kind)
+            .await?
+            .into_iter()
+            .filter_map(|target| Url::from_file_path(target).ok())
+            .map(|target_uri| LocationLink {
+                origin_selection_range: Some(specifier.range),
+                target_uri,
+                target_range: Range::default(),
+                target_selection_range: Range::default(),
+            })
+            .collect::<Vec<_>>();
+        Ok((!links.is_empty()).then_some(GotoDefinitionResponse::Link(links)))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let path = file_path(&params.text_document_position.text_document.uri)?;
+        let mut locations = Vec::new();
+        for importer in self.project.importers(&path).await? {
+            let Ok(uri) = Url::from_file_path(&importer) else {
+                continue;
+            };
+            let range = match self.document(&importer).await {
+                Ok(text) => import_range(&text, &path),
+                Err(_) => None,
+            };
+            locations.push(Location::new(uri, range.unwrap_or_default()));
+        }
+        Ok(Some(locations))
+    }
+}
+
+/// Finds the import of `target` in `text` by its file stem, as the specifier can use an alias or
+/// omit the extension.
+fn import_range(text: &str, target: &Path) -> Option<Range> {
+    let stem = target.file_stem()?.to_str()?;
+    import_specifiers(text)
+        .find(|specifier| {
+            let name = specifier.request.rsplit('/').next().unwrap_or_default();
+            name == stem || name.split_once('.').is_some_and(|(name, _)| name == stem)
+        })
+        .map(|specifier| specifier.range)
+}
+
+fn params<T: DeserializeOwned>(request: Request) -> Result<T> {
+    serde_json::from_value(request.params)
+        .with_context(|| format!("Invalid params for {}", request.method))
+}
+
+fn method_not_found(id: RequestId, method: &str) -> Response {
+    Response::new_err(
+        id,
+        ErrorCode::MethodNotFound as i32,
+        format!("Unsupported method {method}"),
+    )
+}
+
+fn file_path(uri: &Url) -> Result<PathBuf> {
+    uri.to_file_path()
+        .ok()
+        .with_context(|| format!("{uri} is not a file"))
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_server::{Connection, Notification, Request, RequestId, Response};
+    use lsp_types::{
+        DidCloseTextDocumentParams, DidOpenTextDocumentParams, GotoDefinitionParams,
+        GotoDefinitionResponse, Location, PartialResultParams, Position, Range, ReferenceContext,
+        ReferenceParams, TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams, Url,
+        WorkDoneProgressParams,
+        notification::{DidCloseTextDocument, DidOpenTextDocument, Notification as _},
+        request::{GotoDefinition, References, Request as _},
+    };
+    use rustc_hash::FxHashMap;
+    use serde::Serialize;
+
+    use super::Server;
+    use crate::project::tests::{fixture_path, fixture_project};
+
+    fn uri(path: &str) -> Url {
+        Url::from_file_path(fixture_path(path)).unwrap()
+    }
+
+    fn position_params(path: &str, position: Position) -> TextDocumentPositionParams {
+        TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri(path) },
+            position,
+        }
+    }
+
+    fn request(method: &str, params: impl Serialize) -> Request {
+        Request::new(RequestId::from(1), method.to_string(), params)
+    }
+
+    fn result<T: serde::de::DeserializeOwned>(response: Response) -> T {
+        assert!(response.error.is_none(), "{:?}", response.error);
+        serde_json::from_value(response.result.unwrap()).unwrap()
+    }
+
+    async fn references(server: &mut Server<'_>, path: &str) -> Vec<Location> {
+        let response = server
+            .handle_request(request(
+                References::METHOD,
+                ReferenceParams {
+                    text_document_position: position_params(path, Position::new(0, 0)),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                    context: ReferenceContext {
+                        include_declaration: false,
+                    },
+                },
+            ))
+            .await
+            .unwrap();
+        let mut locations: Vec<Location> = result(response);
+        locations.sort_by(|a, b| a.uri.as_str().cmp(b.uri.as_str()));
+        locations
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn navigates_the_module_graph() {
+        let (connection, _client) = Connection::memory();
+        let mut server = Server {
+            connection: &connection,
+            project: fixture_project().await.unwrap(),
+            documents: FxHashMap::default(),
+        };
+
+        // `@/lib/greet` in `pages/index.js`
+        let response = server
+            .handle_request(request(
+                GotoDefinition::METHOD,
+                GotoDefinitionParams {
+                    text_document_position_params: position_params(
+                        "pages/index.js",
+                        Position::new(0, 25),
+                    ),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                },
+            ))
+            .await
+            .unwrap();
+        let Some(GotoDefinitionResponse::Link(links)) = result(response) else {
+            panic!("expected a link to the resolved module");
+        };
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].target_uri, uri("lib/greet.js"));
+        assert_eq!(
+            links[0].origin_selection_range,
+            Some(Range::new(Position::new(0, 23), Position::new(0, 34)))
+        );
+
+        assert_eq!(
+            references(&mut server, "lib/greet.js").await,
+            [
+                Location::new(
+                    uri("pages/about.js"),
+                    Range::new(Position::new(0, 23), Position::new(0, 35))
+                ),
+                Location::new(
+                    uri("pages/index.js"),
+                    Range::new(Position::new(0, 23), Position::new(0, 34))
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn uses_the_content_of_open_documents() {
+        let (connection, _client) = Connection::memory();
+        let mut server = Server {
+            connection: &connection,
+            project: fixture_project().await.unwrap(),
+            documents: FxHashMap::default(),
+        };
+
+        server
+            .handle_notification(Notification::new(
+                DidOpenTextDocument::METHOD.to_string(),
+                DidOpenTextDocumentParams {
+                    text_document: TextDocumentItem::new(
+                        uri("pages/about.js"),
+                        "javascript".to_string(),
+                        1,
+                        "export default function About() {}\n".to_string(),
+                    ),
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            references(&mut server, "lib/greet.js")
+                .await
+                .into_iter()
+                .map(|location| location.uri)
+                .collect::<Vec<_>>(),
+            [uri("pages/index.js")]
+        );
+
+        server
+            .handle_notification(Notification::new(
+                DidCloseTextDocument::METHOD.to_string(),
+                DidCloseTextDocumentParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri("pages/about.js"),
+                    },
+                },
+            ))
+            .await
+            .unwrap();
+        assert!(server.documents.is_empty());
+        assert_eq!(references(&mut server, "lib/greet.js").await.len(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn rejects_unsupported_methods() {
+        let (connection, _client) = Connection::memory();
+        let mut server = Server {
+            connection: &connection,
+            project: fixture_project().await.unwrap(),
+            documents: FxHashMap::default(),
+        };
+        let response = server
+            .handle_request(request("textDocument/hover", ()))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.error.unwrap().code,
+            lsp_server::ErrorCode::MethodNotFound as i32
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Finds the import specifier under the cursor without parsing the module, so that it also works
+//! for unsaved buffers with syntax errors.
+
+use lsp_types::{Position, Range};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportKind {
+    /// `import`, `export ... from` and `import()`.
+    Esm,
+    /// `require()` and `require.resolve()`.
+    Require,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportSpecifier {
+    pub request: String,
+    pub kind: ImportKind,
+    /// The range of the specifier without the quotes.
+    pub range: Range,
+}
+
+/// Returns the string literal at `position` if it's the specifier of an import or require.
+pub fn import_specifier_at(text: &str, position: Position) -> Option<ImportSpecifier> {
+    let line = text.lines().nth(position.line as usize)?;
+    let cursor = byte_offset(line, position.character)?;
+    string_literals(line)
+        .find(|(start, end)| (start - 1..=*end).contains(&cursor))
+        .and_then(|literal| import_specifier(position.line, line, literal))
+}
+
+/// Returns the specifiers of all imports and requires in `text`.
+pub fn import_specifiers(text: &str) -> impl Iterator<Item = ImportSpecifier> + '_ {
+    text.lines().enumerate().flat_map(|(index, line)| {
+        string_literals(line)
+            .filter_map(move |literal| import_specifier(index as u32, line, literal))
+    })
+}
+
+fn import_specifier(
+    line_index: u32,
+    line: &str,
+    (start, end): (usize, usize),
+) -> Option<ImportSpecifier> {
+    let kind = import_kind(&line[..start - 1])?;
+    Some(ImportSpecifier {
+        request: line[start..end].to_string(),
+        kind,
+        range: Range::new(
+            Position::new(line_index, utf16_len(&line[..start])),
+            Position::new(line_index, utf16_len(&line[..end])),
+        ),
+    })
+}
+
+/// Returns the byte ranges of the contents of the string literals in `line`.
+fn string_literals(line: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut open: Option<(usize, char)> = None;
+    line.char_indices().filter_map(move |(index, char)| {
+        match open {
+            Some((start, quote)) if char == quote => {
+                open = None;
+                return Some((start + 1, index));
+            }
+            None if matches!(char, '"' | '\'' | '`') => open = Some((index, char)),
+            _ => {}
+        }
+        None
+    })
+}
+
+/// Determines the kind of import from the code in front of the specifier.
+fn import_kind(prefix: &str) -> Option<ImportKind> {
+    let prefix = prefix.trim_end();
+    if prefix.ends_with("require(") || prefix.ends_with("require.resolve(") {
+        return Some(ImportKind::Require);
+    }
+    if prefix.ends_with("import(") || prefix.ends_with("from") || prefix.ends_with("import") {
+        return Some(ImportKind::Esm);
+    }
+    None
+}
+
+fn byte_offset(line: &str, character: u32) -> Option<usize> {
+    let mut utf16 = 0;
+    for (index, char) in line.char_indices() {
+        if utf16 >= character {
+            return Some(index);
+        }
+        utf16 += char.len_utf16() as u32;
+    }
+    (utf16 >= character).then_some(line.len())
+}
+
+fn utf16_len(text: &str) -> u32 {
+    text.chars().map(|char| char.len_utf16() as u32).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range};
+
+    use super::{ImportKind, ImportSpecifier, import_specifier_at, import_specifiers};
+
+    #[test]
+    fn finds_esm_and_require_specifiers() {
+        let text = "import { a } from './a'\nconst b = require(\"b/sub\")\nexport * from `@/c`\n";
+        assert_eq!(
+            import_specifier_at(text, Position::new(0, 20)),
+            Some(ImportSpecifier {
+                request: "./a".into(),
+                kind: ImportKind::Esm,
+                range: Range::new(Position::new(0, 19), Position::new(0, 22)),
+            })
+        );
+        let b = import_specifier_at(text, Position::new(1, 20)).unwrap();
+        assert_eq!((b.request.as_str(), b.kind), ("b/sub", ImportKind::Require));
+        let c = import_specifier_at(text, Position::new(2, 16)).unwrap();
+        assert_eq!((c.request.as_str(), c.kind), ("@/c", ImportKind::Esm));
+    }
+
+    #[test]
+    fn finds_side_effect_and_dynamic_imports() {
+        let text = "import 'server-only'\nconst m = await import('./m')";
+        let side_effect = import_specifier_at(text, Position::new(0, 10)).unwrap();
+        assert_eq!(side_effect.request, "server-only");
+        let dynamic = import_specifier_at(text, Position::new(1, 25)).unwrap();
+        assert_eq!(dynamic.request, "./m");
+    }
+
+    #[test]
+    fn ignores_other_strings() {
+        let text = "const a = 'not an import'\nimport { b } from './b'";
+        assert_eq!(import_specifier_at(text, Position::new(0, 14)), None);
+        assert_eq!(import_specifier_at(text, Position::new(1, 3)), None);
+    }
+
+    #[test]
+    fn lists_all_specifiers() {
+        let text = "import a from './a'\nconst s = 'x'\nconst b = require('./b')";
+        let requests = import_specifiers(text)
+            .map(|specifier| (specifier.range.start.line, specifier.request))
+            .collect::<Vec<_>>();
+        assert_eq!(requests, [(0, "./a".to_string()), (2, "./b".to_string())]);
+    }
+}
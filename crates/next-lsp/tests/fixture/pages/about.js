@@ -0,0 +1,5 @@
+import { greet } from '../lib/greet'
+
+export default function About() {
+  return greet('about')
+}
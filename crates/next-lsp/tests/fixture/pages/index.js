@@ -0,0 +1,6 @@
+import { greet } from '@/lib/greet'
+import { format } from 'conditional'
+
+export default function Home() {
+  return greet(format('world'))
+}
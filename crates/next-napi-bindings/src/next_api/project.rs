@@ -1,4 +1,11 @@
-use std::{borrow::Cow, io::Write, path::PathBuf, sync::Arc, thread, time::Duration};
+use std::{
+    borrow::Cow,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
 
 use anyhow::{Context, Result, anyhow, bail};
 use bincode::{Decode, Encode};
@@ -13,6 +20,7 @@ use napi_derive::napi;
 use next_api::{
     entrypoints::Entrypoints,
     next_server_nft::next_server_nft_assets,
+    nft_json::why_traced,
     operation::{
         EntrypointsOperation, InstrumentationOperation, MiddlewareOperation, OptionEndpoint,
         RouteOperation,
@@ -22,7 +30,11 @@ use next_api::{
         ProjectContainer, ProjectOptions, WatchOptions,
     },
     route::{Endpoint, EndpointGroupKey, Route},
+    route_preload_manifest::route_preload_manifest_asset_if_enabled,
+    route_tree::route_tree_asset_if_enabled,
     routes_hashes_manifest::routes_hashes_manifest_asset_if_enabled,
+    standalone::{StandaloneEntries, StandaloneEntry, StandaloneReport, standalone_entries},
+    typed_routes::{emit_typed_routes_if_enabled, typed_routes_asset_if_enabled},
 };
 use next_core::{
     app_structure::find_app_dir,
@@ -33,6 +45,7 @@ use next_core::{
 };
 use once_cell::sync::Lazy;
 use rand::Rng;
+use rustc_hash::FxHashMap;
 use serde::Serialize;
 use tokio::{io::AsyncWriteExt, runtime::Handle, time::Instant};
 use tracing::Instrument;
@@ -47,19 +60,29 @@ use turbo_tasks::{
 };
 use turbo_tasks_backend::{BackingStorage, db_invalidation::invalidation_reasons};
 use turbo_tasks_fs::{
-    DiskFileSystem, FileContent, FileSystem, FileSystemPath, invalidation,
+    DiskFileSystem, FileContent, FileSystem, FileSystemPath, invalidation, rope::Rope,
     to_sys_path as fs_path_to_sys_path, util::uri_from_file,
 };
 use turbo_unix_path::{get_relative_path_to, sys_to_unix, unix_to_sys};
 use turbopack_core::{
     PROJECT_FILESYSTEM_NAME, SOURCE_URL_PROTOCOL,
+    chunk::ModuleId,
     diagnostics::PlainDiagnostic,
-    issue::{IssueFilter, PlainIssue},
+    issue::{
+        IssueFilter, PlainIssue,
+        baseline::IssueBaseline,
+        export::{IssueJsonLinesWriter, SarifTool, write_sarif},
+    },
     output::{OutputAsset, OutputAssets},
     source_map::{SourceMap, Token},
+    traced_asset::TraceChains,
     version::{PartialUpdate, TotalUpdate, Update, VersionState},
 };
 use turbopack_ecmascript_hmr_protocol::{ClientUpdateInstruction, Issue, ResourceIdentifier};
+use turbopack_ecmascript_runtime::{
+    ModuleEvaluationProfile, ModuleEvaluationReport, ModuleIdPaths, module_evaluation_trace,
+    module_id_paths,
+};
 use turbopack_trace_utils::{
     exit::{ExitHandler, ExitReceiver},
     filter_layer::FilterLayer,
@@ -1343,6 +1366,13 @@ pub async fn project_write_all_entrypoints_to_disk(
         .await?;
     }
 
+    let issue_output_options = tt
+        .run(async move { issue_output_options(container).await })
+        .or_else(|e| ctx.throw_turbopack_internal_result(&e.into()))
+        .await?;
+    let mut issue_output =
+        BuildIssueOutput::new(issue_output_options).map_err(issue_output_error)?;
+
     let first_phase = if has_deferred_entrypoints {
         EntrypointsWritePhase::NonDeferred
     } else {
@@ -1378,6 +1408,9 @@ pub async fn project_write_all_entrypoints_to_disk(
         })
         .or_else(|e| ctx.throw_turbopack_internal_result(&e.into()))
         .await?;
+    issue_output
+        .report(&mut issues)
+        .map_err(issue_output_error)?;
 
     if has_deferred_entrypoints {
         ctx.on_before_deferred_entries().await?;
@@ -1416,7 +1449,7 @@ pub async fn project_write_all_entrypoints_to_disk(
             .await?;
         }
 
-        let (deferred_entrypoints, deferred_issues, deferred_diags) = tt
+        let (deferred_entrypoints, mut deferred_issues, deferred_diags) = tt
             .run(async move {
                 let entrypoints_with_issues_op = get_all_written_entrypoints_with_issues_operation(
                     container,
@@ -1448,11 +1481,14 @@ pub async fn project_write_all_entrypoints_to_disk(
         if deferred_entrypoints.is_some() {
             entrypoints = deferred_entrypoints;
         }
+        issue_output
+            .report(&mut deferred_issues)
+            .map_err(issue_output_error)?;
         issues.extend(deferred_issues);
         diags.extend(deferred_diags);
     }
 
-    let (emit_issues, emit_diags) = tt
+    let (mut emit_issues, emit_diags) = tt
         .run(async move {
             let emit_result_op = emit_all_output_assets_once_with_issues_operation(
                 container,
@@ -1475,9 +1511,41 @@ pub async fn project_write_all_entrypoints_to_disk(
         .or_else(|e| ctx.throw_turbopack_internal_result(&e.into()))
         .await?;
 
+    issue_output
+        .report(&mut emit_issues)
+        .map_err(issue_output_error)?;
     issues.extend(emit_issues);
     diags.extend(emit_diags);
 
+    // The property name cache is written once everything else is emitted, so the mangled names
+    // of this build are never read back while it is running.
+    let (mut cache_issues, cache_diags) = tt
+        .run(async move {
+            let write_result_op = write_property_name_cache_with_issues_operation(container);
+            let OperationResult {
+                issues,
+                diagnostics,
+                effects,
+            } = &*write_result_op.read_strongly_consistent().await?;
+
+            effects.apply().await?;
+
+            Ok((
+                issues.iter().cloned().collect::<Vec<_>>(),
+                diagnostics.iter().cloned().collect::<Vec<_>>(),
+            ))
+        })
+        .or_else(|e| ctx.throw_turbopack_internal_result(&e.into()))
+        .await?;
+
+    issue_output
+        .report(&mut cache_issues)
+        .map_err(issue_output_error)?;
+    issues.extend(cache_issues);
+    diags.extend(cache_diags);
+
+    issue_output.finish(&issues).map_err(issue_output_error)?;
+
     Ok(TurbopackResult {
         result: if let Some(entrypoints) = entrypoints {
             Some(NapiEntrypoints::from_entrypoints_op(
@@ -1492,6 +1560,141 @@ pub async fn project_write_all_entrypoints_to_disk(
     })
 }
 
+const DEFAULT_ISSUE_BASELINE_PATH: &str = "turbopack-issue-baseline.json";
+
+/// The files configured by `turbopack.issueBaseline` and `turbopack.issueReports`.
+#[derive(Default)]
+struct IssueOutputOptions {
+    /// The baseline file and whether it should be rewritten.
+    baseline: Option<(PathBuf, bool)>,
+    /// The SARIF report and the `file://` URI of the project root, which its locations are
+    /// relative to.
+    sarif: Option<(PathBuf, String)>,
+    json_lines: Option<PathBuf>,
+}
+
+async fn issue_output_options(
+    container: ResolvedVc<ProjectContainer>,
+) -> Result<IssueOutputOptions> {
+    let project = container.project();
+    let project_path = project.project_path().owned().await?;
+    let sys_path = async |path: &str| {
+        fs_path_to_sys_path(project_path.join(path)?)
+            .await?
+            .with_context(|| format!("{path} must be on disk"))
+    };
+
+    let mut options = IssueOutputOptions::default();
+    if let Some(config) = &*project.next_config().turbopack_issue_baseline().await? {
+        let path = config
+            .path
+            .as_deref()
+            .unwrap_or(DEFAULT_ISSUE_BASELINE_PATH);
+        options.baseline = Some((sys_path(path).await?, config.update.unwrap_or(false)));
+    }
+    if let Some(config) = &*project.next_config().turbopack_issue_reports().await? {
+        if let Some(path) = &config.sarif {
+            let project_root_uri =
+                uri_from_file(project.project_root_path().owned().await?, None).await? + "/";
+            options.sarif = Some((sys_path(path).await?, project_root_uri));
+        }
+        if let Some(path) = &config.json_lines {
+            options.json_lines = Some(sys_path(path).await?);
+        }
+    }
+    Ok(options)
+}
+
+fn issue_output_error(err: anyhow::Error) -> napi::Error {
+    napi::Error::from_reason(PrettyPrintError(&err).to_string())
+}
+
+/// Applies the issue baseline to the issues of `next build` and writes them to the configured
+/// reports. The JSON Lines report is written while the build is running, after every phase.
+struct BuildIssueOutput {
+    baseline_path: Option<PathBuf>,
+    /// `None` when the baseline should be (re)written with the issues of this build.
+    baseline: Option<IssueBaseline>,
+    sarif: Option<(PathBuf, String)>,
+    json_lines: Option<IssueJsonLinesWriter<std::io::BufWriter<std::fs::File>>>,
+}
+
+impl BuildIssueOutput {
+    fn new(options: IssueOutputOptions) -> Result<Self> {
+        let baseline = match &options.baseline {
+            Some((path, false)) => read_issue_baseline(path)?,
+            _ => None,
+        };
+        let json_lines = options
+            .json_lines
+            .map(|path| {
+                let file = std::fs::File::create(&path)
+                    .with_context(|| format!("Unable to create {}", path.display()))?;
+                anyhow::Ok(IssueJsonLinesWriter::new(std::io::BufWriter::new(file)))
+            })
+            .transpose()?;
+        Ok(Self {
+            baseline_path: options.baseline.map(|(path, _)| path),
+            baseline,
+            sarif: options.sarif,
+            json_lines,
+        })
+    }
+
+    /// Escalates the issues which are not part of the baseline to errors and appends them to the
+    /// JSON Lines report. Issues that were already reported by an earlier phase are skipped by the
+    /// writer.
+    fn report(&mut self, issues: &mut [ReadRef<PlainIssue>]) -> Result<()> {
+        if let Some(baseline) = &self.baseline {
+            baseline.escalate_new_issues(issues);
+        }
+        if let Some(writer) = &mut self.json_lines {
+            for issue in issues.iter() {
+                writer.write_issue(issue)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the baseline, unless it was checked against, and the SARIF report, which can't be
+    /// written incrementally.
+    fn finish(self, issues: &[ReadRef<PlainIssue>]) -> Result<()> {
+        if let (Some(path), None) = (&self.baseline_path, &self.baseline) {
+            let baseline = IssueBaseline::from_issues(issues.iter().map(|issue| &**issue));
+            std::fs::write(path, baseline.to_json()?).with_context(|| {
+                format!("Unable to write the issue baseline to {}", path.display())
+            })?;
+        }
+        if let Some((path, project_root_uri)) = &self.sarif {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Unable to create {}", path.display()))?;
+            write_sarif(
+                std::io::BufWriter::new(file),
+                &SarifTool {
+                    name: "next.js",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: "https://nextjs.org",
+                },
+                project_root_uri,
+                issues.iter().map(|issue| &**issue),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the issue baseline at `path`. Returns `None` when it doesn't exist yet.
+fn read_issue_baseline(path: &Path) -> Result<Option<IssueBaseline>> {
+    match std::fs::read_to_string(path) {
+        Ok(json) => Ok(Some(IssueBaseline::from_json(&json).with_context(
+            || format!("Invalid issue baseline at {}", path.display()),
+        )?)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err)
+            .with_context(|| format!("Unable to read the issue baseline at {}", path.display())),
+    }
+}
+
 #[turbo_tasks::function(operation)]
 async fn get_all_written_entrypoints_with_issues_operation(
     container: ResolvedVc<ProjectContainer>,
@@ -1599,6 +1802,44 @@ async fn emit_all_output_assets_once_with_issues_operation(
     .cell())
 }
 
+#[turbo_tasks::function(operation)]
+fn property_name_cache_assets_operation(
+    container: ResolvedVc<ProjectContainer>,
+) -> Vc<OutputAssets> {
+    container.project().property_name_cache_assets()
+}
+
+#[turbo_tasks::function(operation)]
+async fn write_property_name_cache_operation(
+    container: ResolvedVc<ProjectContainer>,
+) -> Result<()> {
+    container
+        .project()
+        .emit_all_output_assets(property_name_cache_assets_operation(container))
+        .as_side_effect()
+        .await?;
+    Ok(())
+}
+
+#[turbo_tasks::function(operation)]
+async fn write_property_name_cache_with_issues_operation(
+    container: ResolvedVc<ProjectContainer>,
+) -> Result<Vc<OperationResult>> {
+    let filter = issue_filter_from_container(container);
+    let (_, issues, diagnostics, effects) = strongly_consistent_catch_collectables(
+        write_property_name_cache_operation(container),
+        filter,
+    )
+    .await?;
+
+    Ok(OperationResult {
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
 #[turbo_tasks::function(operation)]
 async fn output_assets_operation(
     container: ResolvedVc<ProjectContainer>,
@@ -1644,6 +1885,9 @@ async fn output_assets_operation(
     let whole_app_module_graphs = project.whole_app_module_graphs();
     let nft = next_server_nft_assets(project).await?;
     let routes_hashes_manifest = routes_hashes_manifest_asset_if_enabled(project).await?;
+    let route_preload_manifest = route_preload_manifest_asset_if_enabled(project).await?;
+    let typed_routes = typed_routes_asset_if_enabled(project).await?;
+    let route_tree = route_tree_asset_if_enabled(project).await?;
 
     whole_app_module_graphs.as_side_effect().await?;
 
@@ -1652,6 +1896,9 @@ async fn output_assets_operation(
             .into_iter()
             .chain(nft.iter().copied())
             .chain(routes_hashes_manifest.iter().copied())
+            .chain(route_preload_manifest.iter().copied())
+            .chain(typed_routes.iter().copied())
+            .chain(route_tree.iter().copied())
             .collect(),
     ))
 }
@@ -2365,6 +2612,50 @@ pub async fn project_get_source_for_asset(
         .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e.into()).to_string()))
 }
 
+/// Sets the in-memory content of `file_path` (relative to the root of the project file system),
+/// e.g. an unsaved editor buffer. It's used instead of the file on disk until it's cleared with
+/// [project_clear_overlay_content]. Returns `false` when the content didn't change.
+#[tracing::instrument(level = "info", name = "set overlay content", skip_all)]
+#[napi]
+pub async fn project_set_overlay_content(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    file_path: RcStr,
+    content: String,
+) -> napi::Result<bool> {
+    let container = project.container;
+    let ctx = &project.turbopack_ctx;
+    ctx.turbo_tasks()
+        .run(async move {
+            let project = container.project();
+            let path = project.project_root_path().await?.join(&file_path)?;
+            let overlay_fs = project.project_overlay_fs().await?;
+            Ok(overlay_fs.set_content(&path, Rope::from(content)))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e.into()).to_string()))
+}
+
+/// Removes the in-memory content of `file_path` set with [project_set_overlay_content], so the
+/// file is read from disk again. Returns `false` when there was no in-memory content.
+#[tracing::instrument(level = "info", name = "clear overlay content", skip_all)]
+#[napi]
+pub async fn project_clear_overlay_content(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    file_path: RcStr,
+) -> napi::Result<bool> {
+    let container = project.container;
+    let ctx = &project.turbopack_ctx;
+    ctx.turbo_tasks()
+        .run(async move {
+            let project = container.project();
+            let path = project.project_root_path().await?.join(&file_path)?;
+            let overlay_fs = project.project_overlay_fs().await?;
+            Ok(overlay_fs.clear_content(&path))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e.into()).to_string()))
+}
+
 #[tracing::instrument(level = "info", name = "get SourceMap for asset", skip_all)]
 #[napi]
 pub async fn project_get_source_map(
@@ -2400,6 +2691,245 @@ pub fn project_get_source_map_sync(
     })
 }
 
+#[turbo_tasks::function(operation)]
+async fn module_id_paths_operation(
+    container: ResolvedVc<ProjectContainer>,
+) -> Result<Vc<ModuleIdPaths>> {
+    let project = container.project();
+    Ok(module_id_paths(
+        *project.whole_app_module_graphs().await?.full,
+        project.module_ids(),
+    ))
+}
+
+/// Reads a module evaluation profile recorded by the runtimes and passes it to `f` with the paths
+/// of the profiled modules.
+async fn with_module_evaluation_profile(
+    project: External<ProjectInstance>,
+    profile: String,
+    f: impl FnOnce(&ModuleEvaluationProfile, &FxHashMap<ModuleId, RcStr>) -> Result<String>
+    + Send
+    + 'static,
+) -> napi::Result<String> {
+    let container = project.container;
+    project
+        .turbopack_ctx
+        .turbo_tasks()
+        .run_once(async move {
+            let profile = ModuleEvaluationProfile::from_json(&profile)?;
+            let paths = module_id_paths_operation(container)
+                .read_strongly_consistent()
+                .await?;
+            f(&profile, &paths)
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))
+}
+
+/// Aggregates a module evaluation profile (see `turbopack.profileModuleEvaluation`) by file and
+/// returns it as a table, sorted by the evaluation time of the files themselves.
+#[napi]
+pub async fn project_module_evaluation_report(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    profile: String,
+) -> napi::Result<String> {
+    with_module_evaluation_profile(project, profile, |profile, paths| {
+        Ok(ModuleEvaluationReport::new(profile, paths).to_string())
+    })
+    .await
+}
+
+/// Converts a module evaluation profile (see `turbopack.profileModuleEvaluation`) to a trace that
+/// can be opened with the trace server.
+#[napi]
+pub async fn project_module_evaluation_trace(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    profile: String,
+) -> napi::Result<String> {
+    with_module_evaluation_profile(project, profile, module_evaluation_trace).await
+}
+
+#[napi(object)]
+pub struct NapiStandaloneEntrySize {
+    /// The path of the traced chunk relative to the dist directory, e.g. `server/app/page.js`.
+    pub name: RcStr,
+    pub files: u32,
+    pub size: f64,
+}
+
+#[napi(object)]
+pub struct NapiStandaloneReport {
+    pub entries: Vec<NapiStandaloneEntrySize>,
+    pub copied_size: f64,
+    pub deduplicated_size: f64,
+}
+
+impl From<StandaloneReport> for NapiStandaloneReport {
+    fn from(report: StandaloneReport) -> Self {
+        NapiStandaloneReport {
+            entries: report
+                .entries
+                .into_iter()
+                .map(|entry| NapiStandaloneEntrySize {
+                    name: entry.name,
+                    files: entry.files as u32,
+                    size: entry.size as f64,
+                })
+                .collect(),
+            copied_size: report.copied_size as f64,
+            deduplicated_size: report.deduplicated_size as f64,
+        }
+    }
+}
+
+#[turbo_tasks::function(operation)]
+fn standalone_entries_operation(
+    container: ResolvedVc<ProjectContainer>,
+    app_dir_only: bool,
+) -> Vc<StandaloneEntries> {
+    let output_assets =
+        output_assets_operation(container, app_dir_only, EntrypointsWritePhase::All).connect();
+    standalone_entries(container.project(), output_assets)
+}
+
+#[napi(object)]
+pub struct NapiStandaloneEntry {
+    /// The path of the traced chunk relative to the dist directory, e.g. `server/app/page.js`.
+    pub name: RcStr,
+    /// The traced files relative to the project root.
+    pub files: Vec<RcStr>,
+}
+
+impl From<StandaloneEntry> for NapiStandaloneEntry {
+    fn from(entry: StandaloneEntry) -> Self {
+        NapiStandaloneEntry {
+            name: entry.name,
+            files: entry.files,
+        }
+    }
+}
+
+impl From<NapiStandaloneEntry> for StandaloneEntry {
+    fn from(entry: NapiStandaloneEntry) -> Self {
+        StandaloneEntry {
+            name: entry.name,
+            files: entry.files,
+        }
+    }
+}
+
+/// Returns the files traced for every server entry, to be written to the `standalone` directory
+/// for `output: 'standalone'` by `write_standalone_directory`. This must be called after
+/// `project_write_all_entrypoints_to_disk`.
+#[tracing::instrument(level = "info", name = "get standalone entries", skip_all)]
+#[napi]
+pub async fn project_standalone_entries(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    app_dir_only: bool,
+) -> napi::Result<Vec<NapiStandaloneEntry>> {
+    let ctx = &project.turbopack_ctx;
+    let container = project.container;
+
+    let entries = ctx
+        .turbo_tasks()
+        .run(async move {
+            let entries = standalone_entries_operation(container, app_dir_only)
+                .read_strongly_consistent()
+                .await?;
+            Ok(entries
+                .iter()
+                .cloned()
+                .map(NapiStandaloneEntry::from)
+                .collect())
+        })
+        .or_else(|e| ctx.throw_turbopack_internal_result(&e.into()))
+        .await?;
+
+    Ok(entries)
+}
+
+/// Copies the traced files of `entries` from `project_root`, the `outputFileTracingRoot`, to
+/// `output_dir`. `next build` selects the entries that need their traced files, i.e. no static
+/// pages or edge functions.
+#[tracing::instrument(level = "info", name = "write standalone directory", skip_all)]
+#[napi]
+pub async fn write_standalone_directory(
+    project_root: String,
+    output_dir: String,
+    entries: Vec<NapiStandaloneEntry>,
+) -> napi::Result<NapiStandaloneReport> {
+    let entries = entries
+        .into_iter()
+        .map(StandaloneEntry::from)
+        .collect::<Vec<_>>();
+    let report = tokio::task::spawn_blocking(move || {
+        next_api::standalone::write_standalone_directory(
+            Path::new(&project_root),
+            Path::new(&output_dir),
+            &entries,
+        )
+    })
+    .await
+    .context("panicked while writing the standalone directory")??;
+
+    Ok(report.into())
+}
+
+#[napi(object)]
+pub struct NapiTraceChainStep {
+    /// The kind of the reference from the previous step, e.g. `require` or `native binding`.
+    /// Missing for the traced chunk at the start of the chain.
+    pub kind: Option<String>,
+    pub path: RcStr,
+}
+
+#[turbo_tasks::function(operation)]
+fn why_traced_operation(
+    container: ResolvedVc<ProjectContainer>,
+    app_dir_only: bool,
+    name: RcStr,
+    path: RcStr,
+) -> Vc<TraceChains> {
+    let output_assets =
+        output_assets_operation(container, app_dir_only, EntrypointsWritePhase::All).connect();
+    why_traced(container.project(), output_assets, name, path)
+}
+
+/// Explains why `path`, relative to the `outputFileTracingRoot`, is included in the trace of the
+/// server chunk `name`, e.g. `server/app/page.js`. Returns every shortest reference chain from
+/// the chunk to the file.
+#[napi]
+pub async fn project_why_traced(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    app_dir_only: bool,
+    name: RcStr,
+    path: RcStr,
+) -> napi::Result<Vec<Vec<NapiTraceChainStep>>> {
+    let ctx = &project.turbopack_ctx;
+    let container = project.container;
+
+    ctx.turbo_tasks()
+        .run(async move {
+            let chains = why_traced_operation(container, app_dir_only, name, path)
+                .read_strongly_consistent()
+                .await?;
+            Ok(chains
+                .iter()
+                .map(|chain| {
+                    chain
+                        .iter()
+                        .map(|step| NapiTraceChainStep {
+                            kind: step.kind.map(|kind| kind.to_string()),
+                            path: step.path.clone(),
+                        })
+                        .collect()
+                })
+                .collect())
+        })
+        .or_else(|e| ctx.throw_turbopack_internal_result(&e.into()))
+        .await
+}
+
 #[napi]
 pub async fn project_write_analyze_data(
     #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
@@ -2433,3 +2963,62 @@ pub async fn project_write_analyze_data(
             .collect(),
     })
 }
+
+#[turbo_tasks::function(operation)]
+async fn write_typed_routes_operation(container: ResolvedVc<ProjectContainer>) -> Result<()> {
+    emit_typed_routes_if_enabled(container.project())
+        .as_side_effect()
+        .await?;
+    Ok(())
+}
+
+#[turbo_tasks::function(operation)]
+async fn write_typed_routes_with_issues_operation(
+    container: ResolvedVc<ProjectContainer>,
+) -> Result<Vc<OperationResult>> {
+    let filter = issue_filter_from_container(container);
+    let (_, issues, diagnostics, effects) =
+        strongly_consistent_catch_collectables(write_typed_routes_operation(container), filter)
+            .await?;
+    Ok(OperationResult {
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+/// Writes `types/link.d.ts` when `turbopack.typedRoutes` is enabled. `next build` emits it with
+/// the other output assets, this is used by `next dev` when routes are added or removed.
+#[napi]
+pub async fn project_write_typed_routes(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<()>> {
+    let container = project.container;
+    let (issues, diagnostics) = project
+        .turbopack_ctx
+        .turbo_tasks()
+        .run_once(async move {
+            let OperationResult {
+                issues,
+                diagnostics,
+                effects,
+            } = &*write_typed_routes_with_issues_operation(container)
+                .read_strongly_consistent()
+                .await?;
+
+            effects.apply().await?;
+            Ok((issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: (),
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
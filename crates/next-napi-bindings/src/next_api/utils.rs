@@ -17,8 +17,8 @@ use turbo_tasks_fs::FileContent;
 use turbopack_core::{
     diagnostics::{Diagnostic, DiagnosticContextExt, PlainDiagnostic},
     issue::{
-        CollectibleIssuesExt, IssueFilter, IssueSeverity, PlainIssue, PlainIssueSource,
-        PlainSource, StyledString,
+        CollectibleIssuesExt, IssueFilter, IssueSeverity, PlainIssue, PlainIssueFix,
+        PlainIssueSource, PlainSource, PlainTextEdit, StyledString,
     },
     source_pos::SourcePos,
 };
@@ -135,6 +135,7 @@ pub struct NapiIssue {
     pub source: Option<NapiIssueSource>,
     pub documentation_link: String,
     pub import_traces: serde_json::Value,
+    pub fixes: Vec<NapiIssueFix>,
 }
 
 impl From<&PlainIssue> for NapiIssue {
@@ -155,6 +156,41 @@ impl From<&PlainIssue> for NapiIssue {
             source: issue.source.as_ref().map(|source| source.into()),
             title: serde_json::to_value(StyledStringSerialize::from(&issue.title)).unwrap(),
             import_traces: serde_json::to_value(&issue.import_traces).unwrap(),
+            fixes: issue.fixes.iter().map(|fix| fix.into()).collect(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct NapiIssueFix {
+    pub title: String,
+    pub edits: Vec<NapiTextEdit>,
+}
+
+impl From<&PlainIssueFix> for NapiIssueFix {
+    fn from(fix: &PlainIssueFix) -> Self {
+        Self {
+            title: fix.title.to_string(),
+            edits: fix.edits.iter().map(|edit| edit.into()).collect(),
+        }
+    }
+}
+
+/// A replacement of `range` in the file identified by `ident` with `new_text`. An empty range
+/// inserts the text.
+#[napi(object)]
+pub struct NapiTextEdit {
+    pub ident: String,
+    pub range: NapiIssueSourceRange,
+    pub new_text: String,
+}
+
+impl From<&PlainTextEdit> for NapiTextEdit {
+    fn from(edit: &PlainTextEdit) -> Self {
+        Self {
+            ident: edit.asset.ident.to_string(),
+            range: (&edit.range).into(),
+            new_text: edit.new_text.to_string(),
         }
     }
 }
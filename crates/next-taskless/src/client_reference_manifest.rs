@@ -0,0 +1,341 @@
+//! The serialized format of the client reference manifest
+//! (`server/app/**/page_client-reference-manifest.js`), shared by Turbopack and the Rspack binding.
+//!
+//! See `packages/next/src/build/webpack/plugins/flight-manifest-plugin.ts` for the consumer-facing
+//! TypeScript definitions.
+
+use std::{hash::BuildHasherDefault, sync::LazyLock};
+
+use indexmap::{IndexMap, IndexSet};
+use regex::Regex;
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+
+type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
+type FxIndexSet<T> = IndexSet<T, BuildHasherDefault<FxHasher>>;
+
+/// The fields are declared in the same order as the webpack plugin creates them, so the serialized
+/// manifests are identical.
+#[derive(Serialize, Deserialize, Default, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SerializedClientReferenceManifest {
+    pub module_loading: ModuleLoading,
+    /// Mapping of client module ID to corresponding SSR module ID and required
+    /// SSR chunks.
+    pub ssr_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
+    /// Same as `ssr_module_mapping`, but for Edge SSR.
+    #[serde(rename = "edgeSSRModuleMapping")]
+    pub edge_ssr_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
+    /// Mapping of module path and export name to client module ID and required
+    /// client chunks.
+    pub client_modules: ManifestNode,
+    /// Mapping of server component path to required CSS client chunks.
+    #[serde(rename = "entryCSSFiles")]
+    pub entry_css_files: FxIndexMap<String, FxIndexSet<CssResource>>,
+    /// Mapping of client module ID to corresponding RSC module ID and required
+    /// RSC chunks.
+    pub rsc_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
+    /// Same as `rsc_module_mapping`, but for Edge RSC.
+    #[serde(rename = "edgeRscModuleMapping")]
+    pub edge_rsc_module_mapping: FxIndexMap<ModuleId, ManifestNode>,
+    /// Mapping of server component path to required JS client chunks. Only emitted by Turbopack,
+    /// webpack and Rspack load these chunks through the entrypoint instead.
+    #[serde(rename = "entryJSFiles", skip_serializing_if = "IndexMap::is_empty")]
+    pub entry_js_files: FxIndexMap<String, FxIndexSet<String>>,
+}
+
+impl SerializedClientReferenceManifest {
+    /// Returns the manifest in the format written by Turbopack, which orders the fields
+    /// differently and always includes `crossOrigin` and `entryJSFiles`. Serializing the manifest
+    /// itself gives the format written by webpack and Rspack.
+    pub fn turbopack_format(&self) -> impl Serialize + '_ {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TurbopackModuleLoading<'a> {
+            prefix: &'a str,
+            cross_origin: Option<&'a str>,
+        }
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TurbopackManifest<'a> {
+            module_loading: TurbopackModuleLoading<'a>,
+            client_modules: &'a ManifestNode,
+            ssr_module_mapping: &'a FxIndexMap<ModuleId, ManifestNode>,
+            #[serde(rename = "edgeSSRModuleMapping")]
+            edge_ssr_module_mapping: &'a FxIndexMap<ModuleId, ManifestNode>,
+            rsc_module_mapping: &'a FxIndexMap<ModuleId, ManifestNode>,
+            #[serde(rename = "edgeRscModuleMapping")]
+            edge_rsc_module_mapping: &'a FxIndexMap<ModuleId, ManifestNode>,
+            #[serde(rename = "entryCSSFiles")]
+            entry_css_files: &'a FxIndexMap<String, FxIndexSet<CssResource>>,
+            #[serde(rename = "entryJSFiles")]
+            entry_js_files: &'a FxIndexMap<String, FxIndexSet<String>>,
+        }
+
+        TurbopackManifest {
+            module_loading: TurbopackModuleLoading {
+                prefix: &self.module_loading.prefix,
+                cross_origin: self.module_loading.cross_origin.as_deref(),
+            },
+            client_modules: &self.client_modules,
+            ssr_module_mapping: &self.ssr_module_mapping,
+            edge_ssr_module_mapping: &self.edge_ssr_module_mapping,
+            rsc_module_mapping: &self.rsc_module_mapping,
+            edge_rsc_module_mapping: &self.edge_rsc_module_mapping,
+            entry_css_files: &self.entry_css_files,
+            entry_js_files: &self.entry_js_files,
+        }
+    }
+
+    /// Merges the entries of `other` into this manifest, overriding existing keys. This matches the
+    /// `Object.assign` semantics used when merging the manifests of parent layouts.
+    pub fn merge(&mut self, other: &SerializedClientReferenceManifest) {
+        for (key, entry) in &other.client_modules.module_exports {
+            self.client_modules
+                .module_exports
+                .insert(key.clone(), entry.clone());
+        }
+        for (mapping, other_mapping) in [
+            (&mut self.ssr_module_mapping, &other.ssr_module_mapping),
+            (
+                &mut self.edge_ssr_module_mapping,
+                &other.edge_ssr_module_mapping,
+            ),
+            (&mut self.rsc_module_mapping, &other.rsc_module_mapping),
+            (
+                &mut self.edge_rsc_module_mapping,
+                &other.edge_rsc_module_mapping,
+            ),
+        ] {
+            for (id, node) in other_mapping {
+                mapping.insert(id.clone(), node.clone());
+            }
+        }
+        for (key, files) in &other.entry_css_files {
+            self.entry_css_files.insert(key.clone(), files.clone());
+        }
+        for (key, files) in &other.entry_js_files {
+            self.entry_js_files.insert(key.clone(), files.clone());
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, Hash, PartialEq)]
+pub struct CssResource {
+    pub path: String,
+    pub inlined: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleLoading {
+    pub prefix: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cross_origin: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestNode {
+    /// Mapping of export name to manifest node entry.
+    #[serde(flatten)]
+    pub module_exports: FxIndexMap<String, ManifestNodeEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestNodeEntry {
+    /// Bundler module ID.
+    pub id: ModuleId,
+    /// Export name.
+    pub name: String,
+    /// Chunks for the module. JS and CSS.
+    pub chunks: Vec<String>,
+    pub r#async: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Hash, Clone)]
+#[serde(untagged)]
+pub enum ModuleId {
+    String(String),
+    Number(u64),
+}
+
+impl ModuleId {
+    /// Returns the value of this ID if it is used as an array index-like key of a JavaScript
+    /// object, i.e. a canonical integer below `2^32 - 1`.
+    fn as_js_array_index(&self) -> Option<u64> {
+        let index = match self {
+            ModuleId::Number(number) => *number,
+            ModuleId::String(string) => {
+                let index = string.parse::<u64>().ok()?;
+                if index.to_string() != *string {
+                    return None;
+                }
+                index
+            }
+        };
+        (index < u32::MAX as u64).then_some(index)
+    }
+}
+
+/// Reorders a map keyed by module IDs the way JavaScript orders the keys of an object: array
+/// index-like keys first in ascending order, followed by all other keys in insertion order.
+///
+/// This allows a manifest built in Rust to be serialized identically to one built with
+/// `JSON.stringify` in the webpack plugin.
+pub fn sort_module_ids_like_js_object<V>(map: &mut FxIndexMap<ModuleId, V>) {
+    map.sort_by(
+        |a, _, b, _| match (a.as_js_array_index(), b.as_js_array_index()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        },
+    );
+}
+
+/// See next.js/packages/next/src/lib/client-reference.ts
+pub fn get_client_reference_module_key(server_path: &str, export_name: &str) -> String {
+    if export_name == "*" {
+        server_path.to_string()
+    } else {
+        format!("{server_path}#{export_name}")
+    }
+}
+
+/// Normalizes an app entry name (e.g. `app/foo/page`) to its "group name", so a page can track all
+/// the manifest items it needs from parent groups by looking up the group segments:
+/// - `app/foo/loading` -> `app/foo`
+/// - `app/foo/page` -> `app/foo`
+/// - `app/(group)/@named/foo/page` -> `app/foo`
+/// - `app/(.)foo/(..)bar/loading` -> `app/bar`
+/// - `app/[...catchAll]/page` -> `app`
+/// - `app/foo/@slot/[...catchAll]/page` -> `app/foo`
+///
+/// This is only used by webpack and Rspack, which create one entry per layout segment.
+pub fn entry_name_to_group_name(entry_name: &str) -> String {
+    static SLOT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"/@[^/]+").unwrap());
+    // The webpack plugin uses a lookahead to keep interception routes, which `regex` doesn't
+    // support, so the group has to be followed by a `/` or the end of the name instead.
+    static GROUP_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"/\([^/]+\)(/|$)").unwrap());
+    static CATCH_ALL_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"/\[?\[\.\.\.[^\]]*\]\]?").unwrap());
+    static INTERCEPT_ROOT_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^.+/\(\.\.\.\)").unwrap());
+    static INTERCEPT_SAME_LEVEL_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"/\(\.\)").unwrap());
+    static INTERCEPT_PARENT_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"/[^/]+/\(\.\.\)").unwrap());
+
+    let group_name = &entry_name[..entry_name.rfind('/').unwrap_or(0)];
+    // Remove slots
+    let group_name = SLOT_RE.replace_all(group_name, "");
+    // Remove the group, making sure it's not an interception route. Adjacent groups share the
+    // separating `/`, so this has to be repeated until there is nothing left to replace.
+    let mut group_name = group_name.into_owned();
+    loop {
+        let replaced = GROUP_RE.replace_all(&group_name, "$1");
+        if replaced == group_name {
+            break;
+        }
+        group_name = replaced.into_owned();
+    }
+    // Remove catch-all routes since they should be part of the parent group that the catch-all
+    // would apply to.
+    let group_name = CATCH_ALL_RE.replace_all(&group_name, "");
+
+    // Interception routes
+    let group_name = INTERCEPT_ROOT_RE.replace_all(&group_name, "app/");
+    let mut group_name = INTERCEPT_SAME_LEVEL_RE
+        .replace_all(&group_name, "/")
+        .into_owned();
+
+    // Interception routes (recursive)
+    while INTERCEPT_PARENT_RE.is_match(&group_name) {
+        group_name = INTERCEPT_PARENT_RE
+            .replace_all(&group_name, "/")
+            .into_owned();
+    }
+
+    group_name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_name_to_group_name() {
+        let test_cases = [
+            ("app/foo/loading", "app/foo"),
+            ("app/foo/page", "app/foo"),
+            ("app/(group)/@named/foo/page", "app/foo"),
+            ("app/(.)foo/(..)bar/loading", "app/bar"),
+            ("app/[...catchAll]/page", "app"),
+            ("app/foo/@slot/[...catchAll]/page", "app/foo"),
+            ("app/[[...optional]]/page", "app"),
+            ("app/(a)/(b)/foo/page", "app/foo"),
+            ("app/foo/(...)bar/page", "app/bar"),
+            ("app/page", "app"),
+        ];
+
+        for (entry_name, expected) in test_cases {
+            assert_eq!(
+                entry_name_to_group_name(entry_name),
+                expected,
+                "entry name: {entry_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sort_module_ids_like_js_object() {
+        let mut map = FxIndexMap::default();
+        map.insert(ModuleId::String("./b.js".into()), ());
+        map.insert(ModuleId::Number(42), ());
+        map.insert(ModuleId::String("7".into()), ());
+        map.insert(ModuleId::String("007".into()), ());
+        map.insert(ModuleId::String("./a.js".into()), ());
+
+        sort_module_ids_like_js_object(&mut map);
+
+        assert_eq!(
+            map.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                ModuleId::String("7".into()),
+                ModuleId::Number(42),
+                ModuleId::String("./b.js".into()),
+                ModuleId::String("007".into()),
+                ModuleId::String("./a.js".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serialize_manifest() {
+        let mut manifest = SerializedClientReferenceManifest::default();
+        manifest.module_loading.prefix = "/_next/".into();
+        manifest.client_modules.module_exports.insert(
+            get_client_reference_module_key("/app/button.js", "*"),
+            ManifestNodeEntry {
+                id: ModuleId::Number(1),
+                name: "*".into(),
+                chunks: vec!["2".into(), "static/chunks/2.js".into()],
+                r#async: false,
+            },
+        );
+
+        assert_eq!(
+            serde_json::to_string(&manifest).unwrap(),
+            r#"{"moduleLoading":{"prefix":"/_next/"},"ssrModuleMapping":{},"edgeSSRModuleMapping":{},"clientModules":{"/app/button.js":{"id":1,"name":"*","chunks":["2","static/chunks/2.js"],"async":false}},"entryCSSFiles":{},"rscModuleMapping":{},"edgeRscModuleMapping":{}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&manifest.turbopack_format()).unwrap(),
+            r#"{"moduleLoading":{"prefix":"/_next/","crossOrigin":null},"clientModules":{"/app/button.js":{"id":1,"name":"*","chunks":["2","static/chunks/2.js"],"async":false}},"ssrModuleMapping":{},"edgeSSRModuleMapping":{},"rscModuleMapping":{},"edgeRscModuleMapping":{},"entryCSSFiles":{},"entryJSFiles":{}}"#
+        );
+    }
+}
@@ -1,12 +1,15 @@
 #![doc = include_str!("../README.md")]
 
+pub mod client_reference_manifest;
 mod constants;
+mod encode_uri_component;
 mod patterns;
 
 use std::sync::LazyLock;
 
 use anyhow::{Context, Result, bail};
 pub use constants::*;
+pub use encode_uri_component::encode_uri_component;
 pub use patterns::*;
 use regex::Regex;
 use turbo_unix_path::{get_parent_path, get_relative_path_to, join_path, normalize_path};
@@ -554,4 +554,34 @@ internal
     )
   })
 
+internal
+  .command('module-evaluation-profile')
+  .description(
+    'Prints the time each module took to evaluate, from a profile recorded with `turbopack.profileModuleEvaluation`.'
+  )
+  .argument('profile', 'Profile file written by the Turbopack runtime.')
+  .argument(
+    '[directory]',
+    `The directory of the profiled application. ${italic(
+      'If no directory is provided, the current directory will be used.'
+    )}`
+  )
+  .addOption(
+    new Option(
+      '--trace <file>',
+      'Writes a trace for `next internal trace` instead.'
+    )
+  )
+  .action(
+    (
+      profile: string,
+      directory: string | undefined,
+      options: { trace: string | undefined }
+    ) => {
+      return import('../cli/internal/module-evaluation-profile.js').then(
+        (mod) => mod.moduleEvaluationProfileCli(profile, directory, options)
+      )
+    }
+  )
+
 program.parse(process.argv)
@@ -123,6 +123,7 @@ import { trace, flushAllTraces, setGlobal, type Span } from '../trace'
 import {
   detectConflictingPaths,
   printCustomRoutes,
+  printStandaloneReport,
   printTreeView,
   copyTracedFiles,
   isReservedPage,
@@ -142,6 +143,7 @@ import type { NextError } from '../lib/is-error'
 import { isEdgeRuntime } from '../lib/is-edge-runtime'
 import { recursiveCopy } from '../lib/recursive-copy'
 import { lockfilePatchPromise, teardownTraceSubscriber } from './swc'
+import type { StandaloneEntry, StandaloneReport } from './swc/types'
 import { installBindings } from './swc/install-bindings'
 import { getNamedRouteRegex } from '../shared/lib/router/utils/route-regex'
 import { getFilesInDir } from '../lib/get-files-in-dir'
@@ -686,12 +688,13 @@ async function writeStandaloneDirectory(
   hasInstrumentationHook: boolean,
   staticPages: Set<string>,
   loadedEnvFiles: LoadedEnvFiles,
-  appDir: string | undefined
-) {
-  await nextBuildSpan
+  appDir: string | undefined,
+  standaloneEntries: StandaloneEntry[] | undefined
+): Promise<StandaloneReport | undefined> {
+  return await nextBuildSpan
     .traceChild('write-standalone-directory')
     .traceAsyncFn(async () => {
-      await copyTracedFiles(
+      const standaloneReport = await copyTracedFiles(
         // requiredServerFiles.appDir Refers to the application directory, not App Router.
         requiredServerFiles.appDir,
         distDir,
@@ -702,7 +705,8 @@ async function writeStandaloneDirectory(
         middlewareManifest,
         hasNodeMiddleware,
         hasInstrumentationHook,
-        staticPages
+        staticPages,
+        standaloneEntries
       )
 
       for (const file of [
@@ -779,6 +783,8 @@ async function writeStandaloneDirectory(
           )
         }
       }
+
+      return standaloneReport
     })
 }
 
@@ -1558,6 +1564,9 @@ export default async function build(
 
       let buildTraceContext: undefined | BuildTraceContext
       let buildTracesPromise: Promise<any> | undefined = undefined
+      // Turbopack writes the traced files of `output: 'standalone'` itself.
+      let standaloneEntries: StandaloneEntry[] | undefined
+      let standaloneReport: StandaloneReport | undefined
 
       // If there's has a custom webpack config and disable the build worker.
       // Otherwise respect the option if it's set.
@@ -1614,6 +1623,7 @@ export default async function build(
           traceMemoryUsage('Finished build', nextBuildSpan)
 
           buildTraceContext = rest.buildTraceContext
+          standaloneEntries = rest.standaloneEntries
 
           const durationString = durationToString(compilerDuration)
           Log.event(`Compiled successfully in ${durationString}`)
@@ -4116,7 +4126,7 @@ export default async function build(
         await nextBuildSpan
           .traceChild('output-standalone')
           .traceAsyncFn(async () => {
-            await writeStandaloneDirectory(
+            standaloneReport = await writeStandaloneDirectory(
               nextBuildSpan,
               distDir,
               pageKeys,
@@ -4128,7 +4138,8 @@ export default async function build(
               hasInstrumentationHook,
               staticPages,
               loadedEnvFiles,
-              appDir
+              appDir,
+              standaloneEntries
             )
           })
       }
@@ -4152,6 +4163,10 @@ export default async function build(
           )
       }
 
+      if (debugOutput && standaloneReport) {
+        printStandaloneReport(standaloneReport)
+      }
+
       await nextBuildSpan.traceChild('print-tree-view').traceAsyncFn(() =>
         printTreeView(pageKeys, pageInfos, {
           pagesDir,
@@ -380,6 +380,24 @@ export declare function projectGetSourceForAsset(
   project: { __napiType: 'Project' },
   filePath: RcStr
 ): Promise<string | null>
+/**
+ * Sets the in-memory content of `file_path` (relative to the root of the project file system),
+ * e.g. an unsaved editor buffer. It's used instead of the file on disk until it's cleared with
+ * [project_clear_overlay_content]. Returns `false` when the content didn't change.
+ */
+export declare function projectSetOverlayContent(
+  project: { __napiType: 'Project' },
+  filePath: RcStr,
+  content: string
+): Promise<boolean>
+/**
+ * Removes the in-memory content of `file_path` set with [project_set_overlay_content], so the
+ * file is read from disk again. Returns `false` when there was no in-memory content.
+ */
+export declare function projectClearOverlayContent(
+  project: { __napiType: 'Project' },
+  filePath: RcStr
+): Promise<boolean>
 export declare function projectGetSourceMap(
   project: { __napiType: 'Project' },
   filePath: RcStr
@@ -388,10 +406,88 @@ export declare function projectGetSourceMapSync(
   project: { __napiType: 'Project' },
   filePath: RcStr
 ): string | null
+/**
+ * Aggregates a module evaluation profile (see `turbopack.profileModuleEvaluation`) by file and
+ * returns it as a table, sorted by the evaluation time of the files themselves.
+ */
+export declare function projectModuleEvaluationReport(
+  project: { __napiType: 'Project' },
+  profile: string
+): Promise<string>
+/**
+ * Converts a module evaluation profile (see `turbopack.profileModuleEvaluation`) to a trace that
+ * can be opened with the trace server.
+ */
+export declare function projectModuleEvaluationTrace(
+  project: { __napiType: 'Project' },
+  profile: string
+): Promise<string>
 export declare function projectWriteAnalyzeData(
   project: { __napiType: 'Project' },
   appDirOnly: boolean
 ): Promise<TurbopackResult>
+/**
+ * Writes `types/link.d.ts` when `turbopack.typedRoutes` is enabled. `next build` emits it with
+ * the other output assets, this is used by `next dev` when routes are added or removed.
+ */
+export declare function projectWriteTypedRoutes(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiStandaloneEntrySize {
+  /** The path of the traced chunk relative to the dist directory, e.g. `server/app/page.js`. */
+  name: RcStr
+  files: number
+  size: number
+}
+export interface NapiStandaloneReport {
+  entries: Array<NapiStandaloneEntrySize>
+  copiedSize: number
+  deduplicatedSize: number
+}
+export interface NapiStandaloneEntry {
+  /** The path of the traced chunk relative to the dist directory, e.g. `server/app/page.js`. */
+  name: RcStr
+  /** The traced files relative to the project root. */
+  files: Array<RcStr>
+}
+/**
+ * Returns the files traced for every server entry, to be written to the `standalone` directory
+ * for `output: 'standalone'` by `write_standalone_directory`. This must be called after
+ * `project_write_all_entrypoints_to_disk`.
+ */
+export declare function projectStandaloneEntries(
+  project: { __napiType: 'Project' },
+  appDirOnly: boolean
+): Promise<Array<NapiStandaloneEntry>>
+/**
+ * Copies the traced files of `entries` from `project_root`, the `outputFileTracingRoot`, to
+ * `output_dir`. `next build` selects the entries that need their traced files, i.e. no static
+ * pages or edge functions.
+ */
+export declare function writeStandaloneDirectory(
+  projectRoot: string,
+  outputDir: string,
+  entries: Array<NapiStandaloneEntry>
+): Promise<NapiStandaloneReport>
+export interface NapiTraceChainStep {
+  /**
+   * The kind of the reference from the previous step, e.g. `require` or `native binding`.
+   * Missing for the traced chunk at the start of the chain.
+   */
+  kind?: string
+  path: RcStr
+}
+/**
+ * Explains why `path`, relative to the `outputFileTracingRoot`, is included in the trace of the
+ * server chunk `name`, e.g. `server/app/page.js`. Returns every shortest reference chain from
+ * the chunk to the file.
+ */
+export declare function projectWhyTraced(
+  project: { __napiType: 'Project' },
+  appDirOnly: boolean,
+  name: RcStr,
+  path: RcStr
+): Promise<Array<Array<NapiTraceChainStep>>>
 /**
  * A version of [`NapiNextTurbopackCallbacks`] that can accepted as an argument to a napi function.
  *
@@ -432,6 +528,20 @@ export interface NapiIssue {
   source?: NapiIssueSource
   documentationLink: string
   importTraces: any
+  fixes: Array<NapiIssueFix>
+}
+export interface NapiIssueFix {
+  title: string
+  edits: Array<NapiTextEdit>
+}
+/**
+ * A replacement of `range` in the file identified by `ident` with `new_text`. An empty range
+ * inserts the text.
+ */
+export interface NapiTextEdit {
+  ident: string
+  range: NapiIssueSourceRange
+  newText: string
 }
 export interface NapiIssueSource {
   source: NapiSource
@@ -699,6 +699,12 @@ function bindingToApi(
       return napiResult
     }
 
+    async writeTypedRoutes(): Promise<TurbopackResult<void>> {
+      return (await binding.projectWriteTypedRoutes(
+        this._nativeProject
+      )) as TurbopackResult<void>
+    }
+
     async writeAllEntrypointsToDisk(
       appDirOnly: boolean
     ): Promise<TurbopackResult<Partial<RawEntrypoints>>> {
@@ -719,6 +725,22 @@ function bindingToApi(
       }
     }
 
+    async standaloneEntries(appDirOnly: boolean) {
+      return await binding.projectStandaloneEntries(
+        this._nativeProject,
+        appDirOnly
+      )
+    }
+
+    async whyTraced(appDirOnly: boolean, name: string, path: string) {
+      return await binding.projectWhyTraced(
+        this._nativeProject,
+        appDirOnly,
+        name,
+        path
+      )
+    }
+
     entrypointsSubscribe() {
       const subscription = subscribe<TurbopackResult<NapiEntrypoints | {}>>(
         false,
@@ -792,6 +814,18 @@ function bindingToApi(
       return binding.projectGetSourceForAsset(this._nativeProject, filePath)
     }
 
+    setOverlayContent(filePath: string, content: string): Promise<boolean> {
+      return binding.projectSetOverlayContent(
+        this._nativeProject,
+        filePath,
+        content
+      )
+    }
+
+    clearOverlayContent(filePath: string): Promise<boolean> {
+      return binding.projectClearOverlayContent(this._nativeProject, filePath)
+    }
+
     getSourceMap(filePath: string): Promise<string | null> {
       return binding.projectGetSourceMap(this._nativeProject, filePath)
     }
@@ -800,6 +834,14 @@ function bindingToApi(
       return binding.projectGetSourceMapSync(this._nativeProject, filePath)
     }
 
+    moduleEvaluationReport(profile: string): Promise<string> {
+      return binding.projectModuleEvaluationReport(this._nativeProject, profile)
+    }
+
+    moduleEvaluationTrace(profile: string): Promise<string> {
+      return binding.projectModuleEvaluationTrace(this._nativeProject, profile)
+    }
+
     updateInfoSubscribe(aggregationMs: number) {
       return subscribe<TurbopackResult<UpdateMessage>>(true, async (callback) =>
         binding.projectUpdateInfoSubscribe(
@@ -1372,6 +1414,15 @@ async function loadWasm(importPath = '') {
             `Only WebAssembly (WASM) bindings were loaded, and Turbopack requires native bindings.`
         )
       },
+      writeStandaloneDirectory(
+        _projectRoot: string,
+        _outputDir: string,
+        _entries: import('./types').StandaloneEntry[]
+      ): Promise<import('./types').StandaloneReport> {
+        throw new Error(
+          '`writeStandaloneDirectory` is not supported by the wasm bindings.'
+        )
+      },
     },
     mdx: {
       compile(src: string, options: any) {
@@ -1588,6 +1639,13 @@ function loadNative(importPath?: string) {
             port
           )
         },
+        writeStandaloneDirectory(projectRoot, outputDir, entries) {
+          return bindings.writeStandaloneDirectory(
+            projectRoot,
+            outputDir,
+            entries
+          )
+        },
       },
       mdx: {
         compile(src: string, options: any) {
@@ -7,9 +7,15 @@ import type {
   NapiSourceDiagnostic,
   NapiProjectOptions,
   NapiPartialProjectOptions,
+  NapiStandaloneEntry,
+  NapiStandaloneReport,
+  NapiTraceChainStep,
 } from './generated-native'
 
 export type { NapiTurboEngineOptions as TurboEngineOptions }
+export type StandaloneEntry = NapiStandaloneEntry
+export type StandaloneReport = NapiStandaloneReport
+export type TraceChainStep = NapiTraceChainStep
 
 export type Lockfile = { __napiType: 'Lockfile' }
 
@@ -29,6 +35,11 @@ export interface Binding {
       traceFilePath: string,
       port: number | undefined
     ): void
+    writeStandaloneDirectory(
+      projectRoot: string,
+      outputDir: string,
+      entries: StandaloneEntry[]
+    ): Promise<StandaloneReport>
 
     nextBuild?: any
   }
@@ -133,6 +144,18 @@ export interface Issue {
   }
   documentationLink: string
   importTraces?: PlainTraceItem[][]
+  fixes?: IssueFix[]
+}
+export interface IssueFix {
+  title: string
+  edits: {
+    ident: string
+    range: {
+      start: { line: number; column: number }
+      end: { line: number; column: number }
+    }
+    newText: string
+  }[]
 }
 export interface PlainTraceItem {
   fsName: string
@@ -265,10 +288,34 @@ export interface Project {
 
   writeAnalyzeData(appDirOnly: boolean): Promise<TurbopackResult<void>>
 
+  /**
+   * Writes `types/link.d.ts` when `turbopack.typedRoutes` is enabled. Used in
+   * development, `writeAllEntrypointsToDisk` emits it during builds.
+   */
+  writeTypedRoutes(): Promise<TurbopackResult<void>>
+
   writeAllEntrypointsToDisk(
     appDirOnly: boolean
   ): Promise<TurbopackResult<Partial<RawEntrypoints>>>
 
+  /**
+   * Returns the files traced for every server entry, which `next build` writes to
+   * `<distDir>/standalone` for `output: 'standalone'`. Must be called after
+   * `writeAllEntrypointsToDisk`.
+   */
+  standaloneEntries(appDirOnly: boolean): Promise<StandaloneEntry[]>
+
+  /**
+   * Explains why `path`, relative to the `outputFileTracingRoot`, is included in the trace of
+   * the server chunk `name`, e.g. `server/app/page.js`. Returns every shortest reference chain
+   * from the chunk to the file.
+   */
+  whyTraced(
+    appDirOnly: boolean,
+    name: string,
+    path: string
+  ): Promise<TraceChainStep[][]>
+
   entrypointsSubscribe(): AsyncIterableIterator<
     TurbopackResult<RawEntrypoints | {}>
   >
@@ -288,9 +335,30 @@ export interface Project {
 
   getSourceForAsset(filePath: string): Promise<string | null>
 
+  /**
+   * Uses `content` instead of the file on disk at `filePath` (relative to the root of the project
+   * file system), e.g. for an unsaved editor buffer.
+   */
+  setOverlayContent(filePath: string, content: string): Promise<boolean>
+  /**
+   * Reads the file at `filePath` from disk again after `setOverlayContent`.
+   */
+  clearOverlayContent(filePath: string): Promise<boolean>
+
   getSourceMap(filePath: string): Promise<string | null>
   getSourceMapSync(filePath: string): string | null
 
+  /**
+   * Aggregates a module evaluation profile recorded with
+   * `turbopack.profileModuleEvaluation` by file and returns it as a table.
+   */
+  moduleEvaluationReport(profile: string): Promise<string>
+  /**
+   * Converts a module evaluation profile to a trace that can be opened with
+   * `next internal trace`.
+   */
+  moduleEvaluationTrace(profile: string): Promise<string>
+
   traceSource(
     stackFrame: TurbopackStackFrame,
     currentDirectoryFileUrl: string
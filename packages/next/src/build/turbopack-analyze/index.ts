@@ -1,5 +1,6 @@
 import type { NextConfigComplete } from '../../server/config-shared'
 import type { __ApiPreviewProps } from '../../server/api-utils'
+import type { Binding, Project } from '../swc/types'
 
 import path from 'path'
 import { validateTurboNextConfig } from '../../lib/turbopack-warning'
@@ -31,8 +32,6 @@ export async function turbopackAnalyze(
   })
 
   const { config, dir, distDir, noMangling } = analyzeContext
-  const currentNodeJsVersion = process.versions.node
-
   const startTime = process.hrtime()
   const bindings = await loadBindings(config?.experimental?.useWasmBinary)
 
@@ -44,13 +43,47 @@ export async function turbopackAnalyze(
     )
   }
 
+  const project = await createProductionProject(bindings, {
+    config,
+    dir,
+    distDir,
+    noMangling,
+  })
+
+  try {
+    backgroundLogCompilationEvents(project)
+
+    await project.writeAnalyzeData(analyzeContext.appDirOnly)
+
+    const shutdownPromise = project.shutdown()
+
+    const time = process.hrtime(startTime)
+    return {
+      duration: time[0] + time[1] / 1e9,
+      shutdownPromise,
+    }
+  } catch (err) {
+    await project.shutdown()
+    throw err
+  }
+}
+
+/**
+ * Creates a production project to inspect a build without writing it, e.g. its
+ * module graph.
+ */
+export async function createProductionProject(
+  bindings: Binding,
+  { config, dir, distDir, noMangling }: Omit<AnalyzeContext, 'appDirOnly'>
+): Promise<Project> {
+  const currentNodeJsVersion = process.versions.node
   const dev = false
 
   const supportedBrowsers = getSupportedBrowsers(dir, dev)
 
   const persistentCaching = isFileSystemCacheEnabledForBuild(config)
   const rootPath = config.turbopack?.root || config.outputFileTracingRoot || dir
-  const project = await bindings.turbo.createProject(
+  return bindings.turbo.createProject(
     {
       rootPath: config.turbopack?.root || config.outputFileTracingRoot || dir,
       projectPath: normalizePath(path.relative(rootPath, dir) || '.'),
@@ -97,23 +130,6 @@ export async function turbopackAnalyze(
       isShortSession: true,
     }
   )
-
-  try {
-    backgroundLogCompilationEvents(project)
-
-    await project.writeAnalyzeData(analyzeContext.appDirOnly)
-
-    const shutdownPromise = project.shutdown()
-
-    const time = process.hrtime(startTime)
-    return {
-      duration: time[0] + time[1] / 1e9,
-      shutdownPromise,
-    }
-  } catch (err) {
-    await project.shutdown()
-    throw err
-  }
 }
 
 let shutdownPromise: Promise<void> | undefined
@@ -24,12 +24,14 @@ import { normalizePath } from '../../lib/normalize-path'
 import type {
   ProjectOptions,
   RawEntrypoints,
+  StandaloneEntry,
   TurbopackResult,
 } from '../swc/types'
 
 export async function turbopackBuild(): Promise<{
   duration: number
   buildTraceContext: undefined
+  standaloneEntries: StandaloneEntry[] | undefined
   shutdownPromise: Promise<void>
 }> {
   await validateTurboNextConfig({
@@ -251,12 +253,22 @@ export async function turbopackBuild(): Promise<{
       await project.writeAnalyzeData(appDirOnly)
     }
 
+    let standaloneEntries: StandaloneEntry[] | undefined
+    if (config.output === 'standalone') {
+      // Use the same entrypoints as `writeAllEntrypointsToDisk` to reuse the traces. The traced
+      // files are written once static pages are known, see `copyTracedFiles`.
+      standaloneEntries = await project.standaloneEntries(
+        NextBuildContext.appDirOnly!
+      )
+    }
+
     const shutdownPromise = project.shutdown()
 
     const time = process.hrtime(startTime)
     return {
       duration: time[0] + time[1] / 1e9,
       buildTraceContext: undefined,
+      standaloneEntries,
       shutdownPromise,
     }
   } catch (err) {
@@ -303,11 +315,13 @@ export async function workerMain(workerData: {
     const {
       shutdownPromise: resultShutdownPromise,
       buildTraceContext,
+      standaloneEntries,
       duration,
     } = await turbopackBuild()
     shutdownPromise = resultShutdownPromise
     return {
       buildTraceContext,
+      standaloneEntries,
       duration,
     }
   } finally {
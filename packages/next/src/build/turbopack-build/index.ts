@@ -33,9 +33,10 @@ async function turbopackBuildWithWorker(): ReturnType<
       config: _config,
       ...prunedBuildContext
     } = NextBuildContext
-    const { buildTraceContext, duration } = await worker.workerMain({
-      buildContext: prunedBuildContext,
-    })
+    const { buildTraceContext, standaloneEntries, duration } =
+      await worker.workerMain({
+        buildContext: prunedBuildContext,
+      })
 
     return {
       // destroy worker when Turbopack has shutdown so it's not sticking around using memory
@@ -44,6 +45,7 @@ async function turbopackBuildWithWorker(): ReturnType<
         worker.end()
       }),
       buildTraceContext,
+      standaloneEntries,
       duration,
     }
   } catch (err: any) {
@@ -0,0 +1,98 @@
+import { mkdtemp, mkdir, readFile, writeFile } from 'fs/promises'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { copyTracedFiles } from './utils'
+import type { MiddlewareManifest } from './webpack/plugins/middleware-plugin'
+import type { StandaloneEntry } from './swc/types'
+
+const writeStandaloneDirectory = jest.fn(
+  async (_root: string, _output: string, entries: StandaloneEntry[]) => ({
+    entries: entries.map(({ name, files }) => ({
+      name,
+      files: files.length,
+      size: 0,
+    })),
+    copiedSize: 0,
+    deduplicatedSize: 0,
+  })
+)
+
+jest.mock('./swc', () => ({
+  getBindingsSync: () => ({ turbo: { writeStandaloneDirectory } }),
+}))
+
+describe('copyTracedFiles', () => {
+  it('writes the Turbopack traces of server rendered pages only', async () => {
+    const root = await mkdtemp(join(tmpdir(), 'copy-traced-files'))
+    const distDir = join(root, '.next')
+    await mkdir(distDir, { recursive: true })
+    await writeFile(join(root, 'package.json'), '{}')
+
+    const middlewareManifest: MiddlewareManifest = {
+      version: 3,
+      sortedMiddleware: [],
+      middleware: {},
+      functions: {
+        '/edge': {
+          files: [],
+          name: 'pages/edge',
+          page: '/edge',
+          matchers: [],
+          wasm: [],
+          assets: [],
+          env: {},
+        },
+        '/app-edge/page': {
+          files: [],
+          name: 'app/app-edge/page',
+          page: '/app-edge/page',
+          matchers: [],
+          wasm: [],
+          assets: [],
+          env: {},
+        },
+      },
+    }
+    const entry = (name: string): StandaloneEntry => ({
+      name,
+      files: [`.next/${name}`],
+    })
+
+    const report = await copyTracedFiles(
+      root,
+      distDir,
+      ['/', '/about', '/edge', '/blog/[slug]'],
+      ['/page', '/app-edge/page'],
+      root,
+      {} as any,
+      middlewareManifest,
+      false,
+      false,
+      new Set(['/about']),
+      [
+        entry('next-minimal-server.js'),
+        entry('next-server.js'),
+        entry('server/app/app-edge/page.js'),
+        entry('server/app/page.js'),
+        entry('server/pages/about.js'),
+        entry('server/pages/blog/[slug].js'),
+        entry('server/pages/edge.js'),
+        entry('server/pages/index.js'),
+      ]
+    )
+
+    expect(writeStandaloneDirectory).toHaveBeenCalledTimes(1)
+    expect(writeStandaloneDirectory.mock.calls[0][1]).toBe(
+      join(distDir, 'standalone')
+    )
+    expect(report?.entries.map(({ name }) => name)).toEqual([
+      'server/pages/index.js',
+      'server/pages/blog/[slug].js',
+      'server/app/page.js',
+      'next-server.js',
+    ])
+    expect(
+      await readFile(join(distDir, 'standalone', 'server.js'), 'utf8')
+    ).toContain("require('next')")
+  })
+})
@@ -81,12 +81,19 @@ import type {
   AppRouteRouteModule,
 } from '../server/route-modules/app-route/module'
 import { formatIssue, isRelevantWarning } from '../shared/lib/turbopack/utils'
-import type { TurbopackResult } from './swc/types'
+import type {
+  StandaloneEntry,
+  StandaloneReport,
+  TurbopackResult,
+} from './swc/types'
+import { getBindingsSync } from './swc'
+import { normalizePath } from '../lib/normalize-path'
 import type { FunctionsConfigManifest, ManifestRoute } from './index'
 import { getNamedRouteRegex } from '../shared/lib/router/utils/route-regex'
 import { parseAppRoute } from '../shared/lib/router/routes/app'
 import { fillMetadataSegment } from '../lib/metadata/get-metadata-route'
 import { STATIC_METADATA_IMAGES } from '../lib/metadata/is-metadata-route'
+import prettyBytes from '../lib/pretty-bytes'
 
 // Build a set of static metadata image filenames for quick lookup
 const staticMetadataImageFilenames = new Set<string>(
@@ -642,6 +649,32 @@ export async function printTreeView(
   print()
 }
 
+export function printStandaloneReport({
+  entries,
+  copiedSize,
+  deduplicatedSize,
+}: StandaloneReport) {
+  print(underline('Standalone output'))
+  print(
+    textTable(
+      [
+        ['Entry', 'Files', 'Size'],
+        ...entries.map(({ name, files, size }) => [
+          name,
+          String(files),
+          prettyBytes(size),
+        ]),
+      ],
+      { align: ['l', 'r', 'r'] }
+    )
+  )
+  print()
+  print(
+    `Copied ${prettyBytes(copiedSize)}, hardlinked ${prettyBytes(deduplicatedSize)} of duplicated node_modules files`
+  )
+  print()
+}
+
 export function printCustomRoutes({
   redirects,
   rewrites,
@@ -1262,8 +1295,9 @@ export async function copyTracedFiles(
   middlewareManifest: MiddlewareManifest,
   hasNodeMiddleware: boolean,
   hasInstrumentationHook: boolean,
-  staticPages: Set<string>
-) {
+  staticPages: Set<string>,
+  standaloneEntries: StandaloneEntry[] | undefined
+): Promise<StandaloneReport | undefined> {
   const outputPath = path.join(distDir, 'standalone')
 
   // Clean up standalone directory first.
@@ -1295,7 +1329,24 @@ export async function copyTracedFiles(
   } catch {}
   const copiedFiles = new Set()
 
+  // Turbopack returns the traced files of every server entry, which are written at once below.
+  // Only the entries of the traces that would be read here are selected.
+  const standaloneEntriesByName =
+    standaloneEntries &&
+    new Map(standaloneEntries.map((entry) => [entry.name, entry]))
+  const selectedStandaloneEntries: StandaloneEntry[] = []
+
   async function handleTraceFiles(traceFilePath: string) {
+    if (standaloneEntriesByName) {
+      const name = normalizePath(path.relative(distDir, traceFilePath))
+      const entry = standaloneEntriesByName.get(
+        name.replace(/\.nft\.json$/, '')
+      )
+      if (entry) {
+        selectedStandaloneEntries.push(entry)
+      }
+      return
+    }
     const traceData = JSON.parse(
       await fs.readFile(/* turbopackIgnore: true */ traceFilePath, 'utf8')
     ) as {
@@ -1440,6 +1491,16 @@ export async function copyTracedFiles(
   }
 
   await handleTraceFiles(path.join(distDir, 'next-server.js.nft.json'))
+
+  let standaloneReport: StandaloneReport | undefined
+  if (standaloneEntries) {
+    standaloneReport = await getBindingsSync().turbo.writeStandaloneDirectory(
+      tracingRoot,
+      outputPath,
+      selectedStandaloneEntries
+    )
+  }
+
   const serverOutputPath = path.join(
     outputPath,
     path.relative(tracingRoot, dir),
@@ -1498,6 +1559,8 @@ startServer({
   process.exit(1);
 });`
   )
+
+  return standaloneReport
 }
 
 export function isReservedPage(page: string) {
@@ -48,7 +48,10 @@ import { ReactLoadablePlugin } from './webpack/plugins/react-loadable-plugin'
 import { WellKnownErrorsPlugin } from './webpack/plugins/wellknown-errors-plugin'
 import { regexLikeCss } from './webpack/config/blocks/css'
 import { CopyFilePlugin } from './webpack/plugins/copy-file-plugin'
-import { ClientReferenceManifestPlugin } from './webpack/plugins/flight-manifest-plugin'
+import {
+  ClientReferenceManifestPlugin,
+  getClientReferenceServerModules,
+} from './webpack/plugins/flight-manifest-plugin'
 import { FlightClientEntryPlugin as NextFlightClientEntryPlugin } from './webpack/plugins/flight-client-entry-plugin'
 import { RspackFlightClientEntryPlugin } from './webpack/plugins/rspack-flight-client-entry-plugin'
 import { DeferredEntriesPlugin } from './webpack/plugins/deferred-entries-plugin'
@@ -91,6 +94,7 @@ import {
   NEXT_PROJECT_ROOT_DIST_CLIENT,
 } from './next-dir-paths'
 import { getRspackCore } from '../shared/lib/get-rspack'
+import { getDeploymentIdQueryOrEmptyString } from '../shared/lib/deployment-id'
 import { RspackProfilingPlugin } from './webpack/plugins/rspack-profiling-plugin'
 import getWebpackBundler from '../shared/lib/get-webpack-bundler'
 import type { NextBuildContext } from './build-context'
@@ -175,6 +179,25 @@ const devtoolRevertWarning = execOnce(
 
 let loggedSwcDisabled = false
 let loggedIgnoredCompilerOptions = false
+/**
+ * The native plugin is only available in the `@rspack/core` binding that is
+ * built with Next.js. Older bindings don't export it, so the webpack plugin is
+ * used instead.
+ */
+function createRspackClientReferenceManifestPlugin(
+  options: ConstructorParameters<typeof ClientReferenceManifestPlugin>[0]
+) {
+  const { ClientReferenceManifestPlugin: NativePlugin } = getRspackCore()
+  if (typeof NativePlugin !== 'function') {
+    return new ClientReferenceManifestPlugin(options)
+  }
+  return new NativePlugin({
+    ...options,
+    deploymentIdChunkQuery: getDeploymentIdQueryOrEmptyString(),
+    getServerModules: getClientReferenceServerModules,
+  })
+}
+
 const reactRefreshLoaderName =
   'next/dist/compiled/@next/react-refresh-utils/dist/loader'
 
@@ -2153,11 +2176,17 @@ export default async function getBaseWebpackConfig(
         }),
       hasAppDir &&
         (isClient
-          ? new ClientReferenceManifestPlugin({
-              dev,
-              appDir,
-              experimentalInlineCss: !!config.experimental.inlineCss,
-            })
+          ? isRspack
+            ? createRspackClientReferenceManifestPlugin({
+                dev,
+                appDir,
+                experimentalInlineCss: !!config.experimental.inlineCss,
+              })
+            : new ClientReferenceManifestPlugin({
+                dev,
+                appDir,
+                experimentalInlineCss: !!config.experimental.inlineCss,
+              })
           : new FlightClientEntryPlugin({
               appDir,
               dev,
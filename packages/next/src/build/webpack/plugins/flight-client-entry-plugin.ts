@@ -68,6 +68,8 @@ type Actions = {
   [actionId: string]: {
     exportedName?: string
     filename?: string
+    // The parameters of the action, with `experimental.serverActions.argumentSchema`.
+    args?: Record<string, unknown>
     workers: {
       [name: string]: {
         moduleId: string | number
@@ -51,6 +51,20 @@ const pluginState = getProxiedPluginState({
   edgeRscModules: {} as { [rscModuleId: string]: ModuleInfo },
 })
 
+/**
+ * Returns the SSR and RSC module IDs of client references that were collected
+ * by the server compilers. Used by the native Rspack implementation of this
+ * plugin.
+ */
+export function getClientReferenceServerModules() {
+  return {
+    ssrModules: pluginState.ssrModules,
+    edgeSsrModules: pluginState.edgeSsrModules,
+    rscModules: pluginState.rscModules,
+    edgeRscModules: pluginState.edgeRscModules,
+  }
+}
+
 export interface ManifestNode {
   [moduleExport: string]: {
     /**
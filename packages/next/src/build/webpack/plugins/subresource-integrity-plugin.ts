@@ -7,7 +7,13 @@ const PLUGIN_NAME = 'SubresourceIntegrityPlugin'
 export type SubresourceIntegrityAlgorithm = 'sha256' | 'sha384' | 'sha512'
 
 export class SubresourceIntegrityPlugin {
-  constructor(private readonly algorithm: SubresourceIntegrityAlgorithm) {}
+  private readonly algorithms: SubresourceIntegrityAlgorithm[]
+
+  constructor(
+    algorithm: SubresourceIntegrityAlgorithm | SubresourceIntegrityAlgorithm[]
+  ) {
+    this.algorithms = Array.isArray(algorithm) ? algorithm : [algorithm]
+  }
 
   public apply(compiler: webpack.Compiler) {
     compiler.hooks.make.tap(PLUGIN_NAME, (compilation) => {
@@ -35,14 +41,19 @@ export class SubresourceIntegrityPlugin {
             // Get the buffer for the asset.
             const buffer = asset.source.buffer()
 
-            // Create the hash for the content.
-            const hash = crypto
-              .createHash(this.algorithm)
-              .update(buffer)
-              .digest()
-              .toString('base64')
+            // Create the hash for the content with every algorithm, joined
+            // by a space as expected by the `integrity` attribute.
+            hashes[file] = this.algorithms
+              .map((algorithm) => {
+                const hash = crypto
+                  .createHash(algorithm)
+                  .update(buffer)
+                  .digest()
+                  .toString('base64')
 
-            hashes[file] = `${this.algorithm}-${hash}`
+                return `${algorithm}-${hash}`
+              })
+              .join(' ')
           }
 
           const json = JSON.stringify(hashes, null, 2)
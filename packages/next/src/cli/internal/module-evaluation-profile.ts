@@ -0,0 +1,47 @@
+import path from 'path'
+import { readFile, writeFile } from 'fs/promises'
+import { loadBindings } from '../../build/swc'
+import { createProductionProject } from '../../build/turbopack-analyze'
+import * as Log from '../../build/output/log'
+import loadConfig from '../../server/config'
+import { getProjectDir } from '../../lib/get-project-dir'
+import { PHASE_PRODUCTION_BUILD } from '../../shared/lib/constants'
+
+/**
+ * Prints the module evaluation profile written by the Node.js runtime when
+ * `TURBOPACK_MODULE_EVALUATION_PROFILE` is set, with the module ids mapped back
+ * to the files of the project. The ids are only stable when the project didn't
+ * change since the profiled build.
+ */
+export async function moduleEvaluationProfileCli(
+  profileFile: string,
+  directory: string | undefined,
+  options: { trace: string | undefined }
+) {
+  const dir = getProjectDir(directory)
+  const config = await loadConfig(PHASE_PRODUCTION_BUILD, dir)
+  const profile = await readFile(profileFile, 'utf8')
+
+  const bindings = await loadBindings(config.experimental?.useWasmBinary)
+  const project = await createProductionProject(bindings, {
+    config,
+    dir,
+    distDir: path.join(dir, config.distDir),
+    noMangling: false,
+  })
+  try {
+    if (options.trace) {
+      await writeFile(
+        options.trace,
+        await project.moduleEvaluationTrace(profile)
+      )
+      Log.event(
+        `Wrote the trace to ${options.trace}. Run \`next internal trace ${options.trace}\` to open it.`
+      )
+    } else {
+      console.log(await project.moduleEvaluationReport(profile))
+    }
+  } finally {
+    await project.shutdown()
+  }
+}
@@ -54,7 +54,8 @@ export function getLayerAssets({
             href,
             type,
             ctx.renderOpts.crossOrigin,
-            ctx.nonce
+            ctx.nonce,
+            ctx.renderOpts.subresourceIntegrityManifest?.[fontFilename]
           )
         })
       }
@@ -52,11 +52,15 @@ export function renderCssResource(
       )
     }
 
+    const integrity =
+      ctx.renderOpts.subresourceIntegrityManifest?.[entryCssFile.path]
+
     preloadCallbacks?.push(() => {
       ctx.componentMod.preloadStyle(
         fullHref,
         ctx.renderOpts.crossOrigin,
-        ctx.nonce
+        ctx.nonce,
+        integrity
       )
     })
 
@@ -67,6 +71,7 @@ export function renderCssResource(
       precedence: precedence,
       crossOrigin: ctx.renderOpts.crossOrigin,
       nonce: ctx.nonce,
+      integrity,
     })
   })
 }
@@ -9,7 +9,8 @@ import ReactDOM from 'react-dom'
 export function preloadStyle(
   href: string,
   crossOrigin: string | undefined,
-  nonce: string | undefined
+  nonce: string | undefined,
+  integrity: string | undefined
 ) {
   const opts: any = { as: 'style' }
   if (typeof crossOrigin === 'string') {
@@ -18,6 +19,9 @@ export function preloadStyle(
   if (typeof nonce === 'string') {
     opts.nonce = nonce
   }
+  if (typeof integrity === 'string') {
+    opts.integrity = integrity
+  }
   ReactDOM.preload(href, opts)
 }
 
@@ -25,7 +29,8 @@ export function preloadFont(
   href: string,
   type: string,
   crossOrigin: string | undefined,
-  nonce: string | undefined
+  nonce: string | undefined,
+  integrity: string | undefined
 ) {
   const opts: any = { as: 'font', type }
   if (typeof crossOrigin === 'string') {
@@ -34,6 +39,9 @@ export function preloadFont(
   if (typeof nonce === 'string') {
     opts.nonce = nonce
   }
+  if (typeof integrity === 'string') {
+    opts.integrity = integrity
+  }
   ReactDOM.preload(href, opts)
 }
 
@@ -144,6 +144,7 @@ const zTurbopackModuleType = z.enum([
   'raw',
   'node',
   'bytes',
+  'sass',
 ])
 
 const zTurbopackRuleConfigItem: zod.ZodType<TurbopackRuleConfigItem> =
@@ -184,6 +185,32 @@ const zTurbopackConfig: zod.ZodType<TurbopackOptions> = z.strictObject({
       })
     )
     .optional(),
+  issueBaseline: z
+    .strictObject({
+      path: z.string().optional(),
+      update: z.boolean().optional(),
+    })
+    .optional(),
+  issueReports: z
+    .strictObject({
+      sarif: z.string().optional(),
+      jsonLines: z.string().optional(),
+    })
+    .optional(),
+  allowDuplicatePackages: z.array(z.string()).optional(),
+  httpImports: z
+    .strictObject({
+      allowedUris: z.array(z.string()),
+      lockfileLocation: z.string().optional(),
+      frozen: z.boolean().optional(),
+    })
+    .optional(),
+  routePreloadManifest: z.boolean().optional(),
+  typedRoutes: z.boolean().optional(),
+  routeTree: z.boolean().optional(),
+  pruneUnusedServerActions: z.boolean().optional(),
+  profileModuleEvaluation: z.boolean().optional(),
+  serviceWorkerPath: z.string().startsWith('/').optional(),
 })
 
 export const experimentalSchema = {
@@ -230,6 +257,7 @@ export const experimentalSchema = {
     .object({
       bodySizeLimit: zSizeLimit.optional(),
       allowedOrigins: z.array(z.string()).optional(),
+      argumentSchema: z.boolean().optional(),
     })
     .optional(),
   maxPostponedStateSize: zSizeLimit.optional(),
@@ -280,7 +308,12 @@ export const experimentalSchema = {
   scrollRestoration: z.boolean().optional(),
   sri: z
     .object({
-      algorithm: z.enum(['sha256', 'sha384', 'sha512']).optional(),
+      algorithm: z
+        .union([
+          z.enum(['sha256', 'sha384', 'sha512']),
+          z.array(z.enum(['sha256', 'sha384', 'sha512'])).nonempty(),
+        ])
+        .optional(),
     })
     .optional(),
   swcPlugins: z
@@ -325,6 +358,38 @@ export const experimentalSchema = {
   webpackMemoryOptimizations: z.boolean().optional(),
   turbopackMemoryLimit: z.number().optional(),
   turbopackMinify: z.boolean().optional(),
+  turbopackMinifyOptions: z
+    .strictObject({
+      dropConsole: z.boolean().optional(),
+      pureFuncs: z.array(z.string()).optional(),
+      keepClassnames: z.boolean().optional(),
+      keepFnames: z.boolean().optional(),
+      passes: z.number().int().positive().optional(),
+      ecma: z
+        .union([
+          z.literal(3),
+          z.literal(5),
+          z.literal(2015),
+          z.literal(2016),
+          z.literal(2017),
+          z.literal(2018),
+          z.literal(2019),
+          z.literal(2020),
+          z.literal(2021),
+          z.literal(2022),
+        ])
+        .optional(),
+      mangleProperties: z
+        .strictObject({
+          filter: z.union([
+            z.strictObject({ prefix: z.string() }),
+            z.strictObject({ regex: z.string() }),
+          ]),
+          reserved: z.array(z.string()).optional(),
+        })
+        .optional(),
+    })
+    .optional(),
   turbopackFileSystemCacheForDev: z.boolean().optional(),
   turbopackFileSystemCacheForBuild: z.boolean().optional(),
   turbopackSourceMaps: z.boolean().optional(),
@@ -339,6 +404,7 @@ export const experimentalSchema = {
   turbopackImportTypeText: z.boolean().optional(),
   turbopackUseBuiltinBabel: z.boolean().optional(),
   turbopackUseBuiltinSass: z.boolean().optional(),
+  turbopackNativeSass: z.boolean().optional(),
   turbopackModuleIds: z.enum(['named', 'deterministic']).optional(),
   turbopackInferModuleSideEffects: z.boolean().optional(),
   optimizePackageImports: z.array(z.string()).optional(),
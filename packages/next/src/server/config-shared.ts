@@ -135,6 +135,8 @@ export type TurbopackRuleCondition =
  * - `'raw'` - Return raw file contents as a string
  * - `'node'` - Process as native Node.js addon
  * - `'bytes'` - Inline file contents as bytes in JavaScript
+ * - `'sass'` - Compile Sass/SCSS natively to CSS, configured loaders are only
+ *   used for stylesheets the native compiler doesn't support
  *
  * @see [Module Types](https://nextjs.org/docs/app/api-reference/config/next-config-js/turbopack#module-types)
  */
@@ -149,6 +151,7 @@ export type TurbopackModuleType =
   | 'node'
   | 'bytes'
   | 'text'
+  | 'sass'
 
 export type TurbopackRuleConfigItem = {
   /** Loaders to apply to matched files. */
@@ -228,6 +231,125 @@ export interface TurbopackOptions {
     title?: string | RegExp
     description?: string | RegExp
   }>
+
+  /**
+   * Records the current warnings of `next build` in a baseline file and
+   * escalates all warnings that are not part of the baseline to errors in
+   * later builds. The baseline is written when it doesn't exist yet.
+   */
+  issueBaseline?: {
+    /**
+     * The baseline file, relative to the project directory. Should be
+     * committed.
+     * @default 'turbopack-issue-baseline.json'
+     */
+    path?: string
+    /**
+     * Rewrites the baseline with the current warnings instead of checking
+     * against it, e.g. after fixing some of them.
+     * @default false
+     */
+    update?: boolean
+  }
+
+  /**
+   * Writes the issues of `next build` to files, e.g. to annotate them in CI.
+   * The paths are relative to the project directory.
+   */
+  issueReports?: {
+    /**
+     * A SARIF 2.1.0 file, which is written at the end of the build.
+     */
+    sarif?: string
+    /**
+     * A JSON Lines file with one issue per line. Issues are appended while the
+     * build is running.
+     */
+    jsonLines?: string
+  }
+
+  /**
+   * Package names that are allowed to be bundled more than once in the same
+   * chunk group. Turbopack warns about all other duplicated packages.
+   */
+  allowDuplicatePackages?: string[]
+
+  /**
+   * Allows importing modules from HTTP(S) URLs, e.g.
+   * `import confetti from 'https://esm.sh/canvas-confetti'`.
+   */
+  httpImports?: {
+    /**
+     * URL prefixes that are allowed to be imported. Other URLs are treated as
+     * external URLs.
+     */
+    allowedUris: string[]
+    /**
+     * The directory storing the fetched modules and their integrity hashes,
+     * relative to the project directory. Should be committed.
+     * @default 'http-imports.lock'
+     */
+    lockfileLocation?: string
+    /**
+     * Fail for URLs that are missing from the lock directory instead of
+     * fetching them.
+     * @default false
+     */
+    frozen?: boolean
+  }
+
+  /**
+   * Emits `route-preload-manifest.json` with the scripts, styles, fonts and
+   * other static assets of every route, including their sizes and content
+   * types, so they can be preloaded before navigating to a route.
+   * @default false
+   */
+  routePreloadManifest?: boolean
+
+  /**
+   * Generates `types/link.d.ts` from the app and pages routes,
+   * so the `href` of `Link`, `Form` and the router are type-checked. It is
+   * updated incrementally when routes are added or removed in development.
+   * @default false
+   */
+  typedRoutes?: boolean
+
+  /**
+   * Emits `route-tree.json` with the resolved app and pages route tree:
+   * segments and their special files, parallel and intercepting routes,
+   * the segment config of every route and metadata routes.
+   * @default false
+   */
+  routeTree?: boolean
+
+  /**
+   * Leaves Server Actions that are never imported, neither by a Client
+   * Component nor by a Server Component, out of the server reference manifest.
+   * Unused actions are reported as warnings either way.
+   * @default false
+   */
+  pruneUnusedServerActions?: boolean
+
+  /**
+   * Includes a profiler in the Turbopack runtime that records how long each
+   * module takes to evaluate. In Node.js the profile is written to the path in
+   * the `TURBOPACK_MODULE_EVALUATION_PROFILE` environment variable when the
+   * process exits. In the browser it's available as
+   * `globalThis.__TURBOPACK_MODULE_EVALUATION_PROFILE__`.
+   *
+   * Run `next internal module-evaluation-profile <file>` to print the time per
+   * file, or add `--trace <trace-file>` to view it with `next internal trace`.
+   * @default false
+   */
+  profileModuleEvaluation?: boolean
+
+  /**
+   * The path, without the `basePath`, that service workers registered with
+   * `navigator.serviceWorker.register(new URL('./sw.js', import.meta.url))`
+   * are served at. A service worker only controls the pages below its path.
+   * @default '/sw.js'
+   */
+  serviceWorkerPath?: string
 }
 
 export interface WebpackConfigContext {
@@ -459,7 +581,11 @@ export interface ExperimentalConfig {
    */
   fallbackNodePolyfills?: false
   sri?: {
-    algorithm?: SubresourceIntegrityAlgorithm
+    /**
+     * The hash algorithm(s) used to generate integrity values. When multiple
+     * algorithms are specified, every hash is included in the integrity value.
+     */
+    algorithm?: SubresourceIntegrityAlgorithm | SubresourceIntegrityAlgorithm[]
   }
 
   webVitalsAttribution?: Array<(typeof WEB_VITALS)[number]>
@@ -502,6 +628,54 @@ export interface ExperimentalConfig {
    */
   turbopackMinify?: boolean
 
+  /**
+   * Options for the production minifier.
+   */
+  turbopackMinifyOptions?: {
+    /**
+     * Drop all `console.*` calls.
+     */
+    dropConsole?: boolean
+    /**
+     * Calls to these functions are treated as side effect free and dropped when
+     * their result is unused, e.g. `['console.info', 'invariant']`.
+     */
+    pureFuncs?: string[]
+    /**
+     * Keep class names, e.g. for code that relies on `constructor.name`.
+     */
+    keepClassnames?: boolean
+    /**
+     * Keep function names.
+     */
+    keepFnames?: boolean
+    /**
+     * The number of compress passes. Defaults to 2.
+     */
+    passes?: number
+    /**
+     * The ECMAScript version the minified output may use, e.g. `2017`.
+     * Defaults to the latest version.
+     */
+    ecma?: 3 | 5 | 2015 | 2016 | 2017 | 2018 | 2019 | 2020 | 2021 | 2022
+    /**
+     * Mangle property names, e.g. `{ filter: { prefix: '_' } }` for private
+     * fields by convention. The mangled names are the same in all chunks and
+     * are kept in `.next/cache` so they stay stable between builds.
+     */
+    mangleProperties?: {
+      /**
+       * Only property names starting with `prefix` or matching `regex` are
+       * mangled.
+       */
+      filter: { prefix: string } | { regex: string }
+      /**
+       * Property names that are never mangled.
+       */
+      reserved?: string[]
+    }
+  }
+
   /**
    * Enable support for `with {type: "bytes"}` for ESM imports.
    */
@@ -594,6 +768,14 @@ export interface ExperimentalConfig {
    */
   turbopackUseBuiltinSass?: boolean
 
+  /**
+   * Compile Sass stylesheets with the built-in native compiler instead of
+   * `sass-loader`. Stylesheets using features the native compiler doesn't
+   * support still go through `sass-loader`. Has no effect when
+   * `sassOptions.additionalData` or `sassOptions.implementation` is set.
+   */
+  turbopackNativeSass?: boolean
+
   /**
    * The module ID strategy to use for Turbopack.
    * If not set, the default is `'named'` for development and `'deterministic'`
@@ -723,6 +905,14 @@ export interface ExperimentalConfig {
      * ["my-app.com", "*.my-app.com"]
      */
     allowedOrigins?: string[]
+
+    /**
+     * Lists the parameter names, arity and simple TypeScript types of every
+     * Server Action in the server reference manifest. Only supported with
+     * Turbopack.
+     * @default false
+     */
+    argumentSchema?: boolean
   }
 
   /**
@@ -520,6 +520,14 @@ export async function initialize(opts: {
             )
           }
         }
+        if (
+          !res.getHeader('cache-control') &&
+          matchedOutput.type === 'serviceWorker'
+        ) {
+          // The service worker is emitted at a stable path, so browsers need to
+          // revalidate it to pick up updates.
+          res.setHeader('Cache-Control', 'public, max-age=0, must-revalidate')
+        }
         if (!(req.method === 'GET' || req.method === 'HEAD')) {
           res.setHeader('Allow', ['GET', 'HEAD'])
           res.statusCode = 405
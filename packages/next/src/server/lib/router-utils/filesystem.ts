@@ -56,6 +56,7 @@ export type FsOutput = {
     | 'nextStaticFolder'
     | 'legacyStaticFolder'
     | 'devVirtualFsItem'
+    | 'serviceWorker'
 
   itemPath: string
   fsPath?: string
@@ -153,6 +154,12 @@ export async function setupFsCheck(opts: {
   const publicFolderPath = path.join(opts.dir, 'public')
   const nextStaticFolderPath = path.join(distDir, 'static')
   const legacyStaticFolderPath = path.join(opts.dir, 'static')
+  // Turbopack emits service workers to `.next/service-worker/sw.js`, which is
+  // served at `turbopack.serviceWorkerPath`.
+  const serviceWorkerFolderPath = path.join(distDir, 'service-worker')
+  const serviceWorkerItemPath =
+    opts.config.turbopack?.serviceWorkerPath ?? '/sw.js'
+  const serviceWorkerItems = new Set<string>()
   let customRoutes: UnwrapPromise<ReturnType<typeof loadCustomRoutes>> = {
     redirects: [],
     rewrites: {
@@ -216,6 +223,10 @@ export async function setupFsCheck(opts: {
       if (opts.config.output !== 'standalone') throw err
     }
 
+    if (await fileExists(path.join(serviceWorkerFolderPath, 'sw.js'))) {
+      serviceWorkerItems.add(serviceWorkerItemPath)
+    }
+
     const routesManifestPath = path.join(distDir, ROUTES_MANIFEST)
     const prerenderManifestPath = path.join(distDir, PRERENDER_MANIFEST)
     const middlewareManifestPath = path.join(
@@ -527,6 +538,7 @@ export async function setupFsCheck(opts: {
       const itemsToCheck: Array<[Set<string>, FsOutput['type']]> = [
         [this.devVirtualFsItems, 'devVirtualFsItem'],
         [nextStaticFolderItems, 'nextStaticFolder'],
+        [serviceWorkerItems, 'serviceWorker'],
         [legacyStaticFolderItems, 'legacyStaticFolder'],
         [publicFolderItems, 'publicFolder'],
         [appFiles, 'appFile'],
@@ -582,6 +594,10 @@ export async function setupFsCheck(opts: {
           continue
         }
 
+        if (type === 'serviceWorker' && curItemPath !== serviceWorkerItemPath) {
+          continue
+        }
+
         const nextDataPrefix = `/_next/data/${buildId}/`
 
         if (
@@ -645,6 +661,11 @@ export async function setupFsCheck(opts: {
               itemsRoot = legacyStaticFolderPath
               break
             }
+            case 'serviceWorker': {
+              itemsRoot = serviceWorkerFolderPath
+              curItemPath = '/sw.js'
+              break
+            }
             case 'publicFolder': {
               itemsRoot = publicFolderPath
               break
@@ -670,6 +691,7 @@ export async function setupFsCheck(opts: {
             const isStaticAsset = (
               [
                 'nextStaticFolder',
+                'serviceWorker',
                 'publicFolder',
                 'legacyStaticFolder',
               ] as (typeof type)[]
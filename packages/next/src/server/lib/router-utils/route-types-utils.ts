@@ -372,8 +372,9 @@ export async function writeRouteTypesManifest(
       : generateRouteTypesFile(manifest)
   )
 
-  // Write the link.d.ts file if typedRoutes is enabled
-  if (config.typedRoutes === true) {
+  // Write the link.d.ts file if typedRoutes is enabled, unless Turbopack
+  // generates it from the app routes
+  if (config.typedRoutes === true && !config.turbopack?.typedRoutes) {
     const linkTypesPath = path.join(dirname, 'link.d.ts')
     await fs.promises.writeFile(linkTypesPath, generateLinkTypesFile(manifest))
   }
@@ -1179,6 +1179,13 @@ async function startWatcher(
             routeTypesFilePath,
             opts.nextConfig
           )
+          if (
+            opts.nextConfig.turbopack?.typedRoutes &&
+            hotReloader.turbopackProject
+          ) {
+            // Turbopack generates `link.d.ts` from its own view of the routes
+            await hotReloader.turbopackProject.writeTypedRoutes()
+          }
           await writeValidatorFile(
             routeTypesManifest,
             validatorFilePath,
@@ -1,9 +1,22 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
+export interface NapiClientReferenceManifestPluginOptions {
+  dev: boolean
+  appDir: string
+  experimentalInlineCss: boolean
+  deploymentIdChunkQuery: string
+  getServerModules: () => NapiServerModules
+}
+
 export interface NapiExperimentalConfig {
   esmExternals?: string | boolean
 }
 
+export interface NapiModuleInfo {
+  moduleId: string | number
+  async: boolean
+}
+
 export interface NapiNextConfigComplete {
   experimental: NapiExperimentalConfig
   bundlePagesRouterDependencies?: boolean
@@ -18,4 +31,13 @@ export interface NapiNextExternalsPluginOptions {
   defaultOverrides: Record<string, string>
 }
 
+export interface NapiServerModules {
+  ssrModules: Record<string, NapiModuleInfo>
+  edgeSsrModules: Record<string, NapiModuleInfo>
+  rscModules: Record<string, NapiModuleInfo>
+  edgeRscModules: Record<string, NapiModuleInfo>
+}
+
+export declare function registerClientReferenceManifestPlugin(): void
+
 export declare function registerNextExternalsPlugin(): void
@@ -0,0 +1,834 @@
+use std::{
+    path::{Component, MAIN_SEPARATOR, MAIN_SEPARATOR_STR, Path},
+    pin::Pin,
+    sync::LazyLock,
+};
+
+use next_taskless::{
+    client_reference_manifest::{
+        CssResource, ManifestNode, ManifestNodeEntry, ModuleId, SerializedClientReferenceManifest,
+        entry_name_to_group_name, sort_module_ids_like_js_object,
+    },
+    encode_uri_component,
+};
+use regex::Regex;
+use rspack_core::{
+    ApplyContext, ChunkGraph, ChunkGroupUkey, ChunkUkey, Compilation, CompilationAsset,
+    CompilationProcessAssets, CrossOriginLoading, ModuleGraph, ModuleIdentifier, Plugin,
+    PublicPath,
+};
+use rspack_error::{Result, error};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_sources::{RawStringSource, SourceExt};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+const CLIENT_STATIC_FILES_RUNTIME_MAIN: &str = "main";
+const CLIENT_STATIC_FILES_RUNTIME_MAIN_APP: &str = "main-app";
+const CLIENT_STATIC_FILES_RUNTIME_REACT_REFRESH: &str = "react-refresh";
+const APP_CLIENT_INTERNALS: &str = "app-pages-internals";
+const APP_PAGES_BROWSER_LAYER: &str = "app-pages-browser";
+const BARREL_OPTIMIZATION_PREFIX: &str = "__barrel_optimize__";
+const CLIENT_REFERENCE_MANIFEST: &str = "client-reference-manifest";
+
+static ROOT_MAIN_FILE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\.(js|css)($|\?)").unwrap());
+static NEXT_DIST_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[\\/]next[\\/]dist[\\/]").unwrap());
+static APP_ENTRY_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^app[\\/]").unwrap());
+static PAGE_ENTRY_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"/page(\.[^/]+)?$").unwrap());
+
+/// The SSR and RSC module IDs of client references, collected by the flight client entry plugin
+/// of the server compilers.
+#[derive(Debug, Default)]
+pub struct ServerModules {
+    pub ssr_modules: FxHashMap<String, ModuleInfo>,
+    pub edge_ssr_modules: FxHashMap<String, ModuleInfo>,
+    pub rsc_modules: FxHashMap<String, ModuleInfo>,
+    pub edge_rsc_modules: FxHashMap<String, ModuleInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleInfo {
+    pub module_id: ModuleId,
+    pub r#async: bool,
+}
+
+pub type GetServerModulesFn =
+    Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<ServerModules>> + Send>> + Send + Sync>;
+
+pub struct ClientReferenceManifestPluginOptions {
+    pub dev: bool,
+    pub app_dir: String,
+    pub experimental_inline_css: bool,
+    /// The `?dpl=` query appended to chunk URLs, or an empty string.
+    pub deployment_id_chunk_query: String,
+    /// Called every time the manifest is created, as the server compilers might have been rebuilt
+    /// in the meantime.
+    pub get_server_modules: GetServerModulesFn,
+}
+
+impl std::fmt::Debug for ClientReferenceManifestPluginOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientReferenceManifestPluginOptions")
+            .field("dev", &self.dev)
+            .field("app_dir", &self.app_dir)
+            .field("experimental_inline_css", &self.experimental_inline_css)
+            .field("deployment_id_chunk_query", &self.deployment_id_chunk_query)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Native port of `ClientReferenceManifestPlugin` from
+/// `packages/next/src/build/webpack/plugins/flight-manifest-plugin.ts`. The emitted manifests
+/// are identical to the ones of the JavaScript plugin.
+#[derive(Debug)]
+#[plugin]
+pub struct ClientReferenceManifestPlugin {
+    options: ClientReferenceManifestPluginOptions,
+    app_dir_base: String,
+}
+
+impl ClientReferenceManifestPlugin {
+    pub fn new(options: ClientReferenceManifestPluginOptions) -> Self {
+        let app_dir_base = format!(
+            "{}{MAIN_SEPARATOR}",
+            Path::new(&options.app_dir)
+                .parent()
+                .map(|parent| parent.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        );
+        Self::new_inner(options, app_dir_base)
+    }
+}
+
+impl Plugin for ClientReferenceManifestPlugin {
+    fn name(&self) -> &'static str {
+        "ClientReferenceManifestPlugin"
+    }
+
+    fn apply(&self, ctx: &mut ApplyContext<'_>) -> Result<()> {
+        ctx.compilation_hooks
+            .process_assets
+            .tap(process_assets::new(self));
+        Ok(())
+    }
+}
+
+// Has to be in the analyse stage to run after updating the CSS asset hash via the CSS extract
+// plugin.
+#[plugin_hook(CompilationProcessAssets for ClientReferenceManifestPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ANALYSE)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+    let server_modules = (self.options.get_server_modules)().await?;
+
+    let cross_origin = match &compilation.options.output.cross_origin_loading {
+        CrossOriginLoading::Enable(value) if value == "use-credentials" => Some(value.clone()),
+        CrossOriginLoading::Enable(_) => Some(String::new()),
+        CrossOriginLoading::Disable => None,
+    };
+    let public_path = match &compilation.options.output.public_path {
+        PublicPath::Filename(filename) => filename.template(),
+        PublicPath::Auto => None,
+    };
+    let prefix = match public_path {
+        Some(public_path) => public_path.to_string(),
+        None => {
+            return Err(error!(
+                "Expected rspack publicPath to be a string when using App Router. To customize \
+                 where static assets are loaded from, use the `assetPrefix` option in \
+                 next.config.js. If you are customizing your rspack config please make sure you \
+                 are not modifying or removing the publicPath configuration option"
+            ));
+        }
+    };
+    let new_manifest = || {
+        let mut manifest = SerializedClientReferenceManifest::default();
+        manifest.module_loading.prefix = prefix.clone();
+        manifest.module_loading.cross_origin = cross_origin.clone();
+        manifest
+    };
+
+    // We want to omit any files that will always be loaded on any App Router page because they
+    // will already be loaded by the main entrypoint.
+    let root_main_files: FxHashSet<String> = compilation
+        .entrypoints()
+        .get(CLIENT_STATIC_FILES_RUNTIME_MAIN_APP)
+        .map(|ukey| {
+            chunk_group_files(compilation, ukey)
+                .into_iter()
+                .filter(|file| is_root_main_file(file))
+                .map(|file| file.replace('\\', "/"))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut entry_manifests = EntryManifests::default();
+
+    for (entry_name, entrypoint) in compilation.entrypoints() {
+        let entry_name = if entry_name == CLIENT_STATIC_FILES_RUNTIME_MAIN_APP
+            || entry_name == APP_CLIENT_INTERNALS
+        {
+            ""
+        } else if APP_ENTRY_RE.is_match(entry_name) {
+            entry_name.as_str()
+        } else {
+            continue;
+        };
+
+        let mut manifest = new_manifest();
+
+        // Absolute path without the extension
+        let chunk_entry_name =
+            format!("{}{entry_name}", self.app_dir_base).replace(['\\', '/'], MAIN_SEPARATOR_STR);
+
+        let entry_css_files = chunk_group_files(compilation, entrypoint)
+            .into_iter()
+            .filter(|file| !file.starts_with("static/css/pages/") && file.ends_with(".css"))
+            .map(|file| {
+                // Inline CSS currently does not work properly with HMR, so we only inline CSS in
+                // production.
+                if self.options.experimental_inline_css && !self.options.dev {
+                    let content = compilation
+                        .assets()
+                        .get(&file)
+                        .and_then(|asset| asset.get_source())
+                        .map(|source| source.source().into_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    CssResource {
+                        path: file,
+                        inlined: true,
+                        content: Some(content),
+                    }
+                } else {
+                    CssResource {
+                        path: file,
+                        inlined: false,
+                        content: None,
+                    }
+                }
+            })
+            .collect();
+        manifest
+            .entry_css_files
+            .insert(chunk_entry_name, entry_css_files);
+
+        let required_chunks =
+            self.app_path_required_chunks(compilation, entrypoint, &root_main_files);
+
+        let mut checked_chunk_groups = FxHashSet::default();
+        let mut checked_chunks = FxHashSet::default();
+        let mut chunk_groups = vec![*entrypoint];
+        while let Some(chunk_group_ukey) = chunk_groups.pop() {
+            // Ensure recursion is stopped if we've already checked this chunk group.
+            if !checked_chunk_groups.insert(chunk_group_ukey) {
+                continue;
+            }
+            let chunk_group = compilation
+                .chunk_group_by_ukey
+                .expect_get(&chunk_group_ukey);
+            for chunk_ukey in &chunk_group.chunks {
+                // Ensure recursion is stopped if we've already checked this chunk.
+                if !checked_chunks.insert(*chunk_ukey) {
+                    continue;
+                }
+                self.record_chunk(
+                    compilation,
+                    chunk_ukey,
+                    &required_chunks,
+                    &server_modules,
+                    &mut manifest,
+                );
+            }
+            // Walk through all children chunk groups too, in the same order as the recursive
+            // walk of the webpack plugin.
+            let children = chunk_group.children_iterable().copied().collect::<Vec<_>>();
+            chunk_groups.extend(children.into_iter().rev());
+        }
+
+        entry_manifests.add(entry_name, manifest);
+    }
+
+    for (filename, source) in entry_manifests.page_manifests(new_manifest)? {
+        compilation.emit_asset(
+            filename,
+            CompilationAsset::from(RawStringSource::from(source).boxed()),
+        );
+    }
+
+    Ok(())
+}
+
+/// The manifests of the app entrypoints, grouped by the layout segment they belong to.
+#[derive(Default)]
+struct EntryManifests {
+    per_group: FxHashMap<String, Vec<SerializedClientReferenceManifest>>,
+    /// The entry names of the pages and route handlers, which get a manifest file.
+    page_names: Vec<String>,
+}
+
+impl EntryManifests {
+    fn add(&mut self, entry_name: &str, manifest: SerializedClientReferenceManifest) {
+        // A page's entry name can have extensions. For example, these are both valid:
+        // - app/foo/page
+        // - app/foo/page.page
+        if PAGE_ENTRY_RE.is_match(entry_name) {
+            self.page_names
+                .push(PAGE_ENTRY_RE.replace(entry_name, "/page").into_owned());
+        }
+
+        // We also need to create manifests for route handler entrypoints to enable `'use cache'`.
+        if entry_name.ends_with("/route") {
+            self.page_names.push(entry_name.to_string());
+        }
+
+        self.per_group
+            .entry(entry_name_to_group_name(entry_name))
+            .or_default()
+            .push(manifest);
+    }
+
+    /// Merges the manifests of every page with the ones of its parent layouts, and returns the
+    /// file name and source of its `_client-reference-manifest.js`.
+    fn page_manifests(
+        &self,
+        new_manifest: impl Fn() -> SerializedClientReferenceManifest,
+    ) -> Result<Vec<(String, String)>> {
+        self.page_names
+            .iter()
+            .map(|page_name| {
+                let mut merged_manifest = new_manifest();
+
+                let group_name = entry_name_to_group_name(page_name);
+                // Route handlers don't render React components and don't need client component
+                // references from parent layouts/pages. They only need their own entry's manifest
+                // (for 'use cache' support).
+                if page_name.ends_with("/route") {
+                    for manifest in self.per_group.get(&group_name).into_iter().flatten() {
+                        merged_manifest.merge(manifest);
+                    }
+                } else {
+                    // Pages need manifests merged from parent layouts
+                    let mut group = String::new();
+                    for segment in group_name.split('/').chain(["page"]) {
+                        for manifest in self.per_group.get(&group).into_iter().flatten() {
+                            merged_manifest.merge(manifest);
+                        }
+                        if !group.is_empty() {
+                            group.push('/');
+                        }
+                        group.push_str(segment);
+                    }
+                }
+
+                sort_module_ids_like_js_object(&mut merged_manifest.ssr_module_mapping);
+                sort_module_ids_like_js_object(&mut merged_manifest.edge_ssr_module_mapping);
+                sort_module_ids_like_js_object(&mut merged_manifest.rsc_module_mapping);
+                sort_module_ids_like_js_object(&mut merged_manifest.edge_rsc_module_mapping);
+
+                let json = serde_json::to_string(&merged_manifest).map_err(|e| error!("{e}"))?;
+
+                let page_path = page_name.replace("%5F", "_");
+                let page_path = &page_path["app".len()..];
+                Ok((
+                    format!(
+                        "server/app{}_{CLIENT_REFERENCE_MANIFEST}.js",
+                        normalize_page_path(page_path)
+                    ),
+                    format!(
+                        "globalThis.__RSC_MANIFEST=(globalThis.__RSC_MANIFEST||{{}});globalThis.\
+                         __RSC_MANIFEST[{}]={json};",
+                        serde_json::to_string(page_path).map_err(|e| error!("{e}"))?
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl ClientReferenceManifestPlugin {
+    /// Returns the chunk IDs and file names of all JS chunks that need to be loaded for a client
+    /// reference of the entrypoint, as a flat list of `[chunkId, file, chunkId, file, ...]`.
+    fn app_path_required_chunks(
+        &self,
+        compilation: &Compilation,
+        chunk_group_ukey: &ChunkGroupUkey,
+        excluded_files: &FxHashSet<String>,
+    ) -> Vec<String> {
+        let chunk_group = compilation.chunk_group_by_ukey.expect_get(chunk_group_ukey);
+        let mut chunks = Vec::new();
+        for chunk_ukey in &chunk_group.chunks {
+            let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+            if chunk.name().is_some_and(|name| {
+                name == CLIENT_STATIC_FILES_RUNTIME_MAIN
+                    || name == CLIENT_STATIC_FILES_RUNTIME_MAIN_APP
+                    || name == CLIENT_STATIC_FILES_RUNTIME_REACT_REFRESH
+            }) {
+                continue;
+            }
+
+            // Get the actual chunk file names from the chunk file list.
+            let Some(chunk_id) = chunk.id(&compilation.chunk_ids_artifact) else {
+                continue;
+            };
+            let mut files = chunk.files().iter().collect::<Vec<_>>();
+            files.sort_unstable();
+            for file in files {
+                // It's possible that a chunk also emits CSS files, that will be handled
+                // separately.
+                if !file.ends_with(".js")
+                    || file.ends_with(".hot-update.js")
+                    || excluded_files.contains(file)
+                {
+                    continue;
+                }
+
+                // We encode the file as a URI because our server (and many other services such
+                // as S3) expect to receive reserved characters such as `[` and `]` as encoded.
+                chunks.push(chunk_id.to_string());
+                chunks.push(format!(
+                    "{}{}",
+                    file.split('/')
+                        .map(encode_uri_component)
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                    self.options.deployment_id_chunk_query
+                ));
+            }
+        }
+        chunks
+    }
+
+    /// Records the client references of the flight client entry modules of a chunk.
+    ///
+    /// Only client module requests from client entries are added, as other client modules are
+    /// never referenced by the server/client boundary. This saves a lot of bytes in the
+    /// manifest.
+    fn record_chunk(
+        &self,
+        compilation: &Compilation,
+        chunk_ukey: &ChunkUkey,
+        required_chunks: &[String],
+        server_modules: &ServerModules,
+        manifest: &mut SerializedClientReferenceManifest,
+    ) {
+        let module_graph = compilation.get_module_graph();
+        for entry_module in compilation.chunk_graph.get_chunk_entry_modules(chunk_ukey) {
+            let Some(module) = module_graph.module_by_identifier(&entry_module) else {
+                continue;
+            };
+            if module.get_layer().map(String::as_str) != Some(APP_PAGES_BROWSER_LAYER) {
+                continue;
+            }
+            let Some(normal_module) = module.as_normal_module() else {
+                continue;
+            };
+            if !normal_module
+                .request()
+                .contains("next-flight-client-entry-loader.js?")
+            {
+                continue;
+            }
+
+            for dependency_id in module_graph.get_outgoing_deps_in_order(&entry_module) {
+                let Some(connection) = module_graph.connection_by_dependency_id(dependency_id)
+                else {
+                    continue;
+                };
+                let client_entry_module = *connection.resolved_module();
+                let module_id = ChunkGraph::get_module_id(
+                    &compilation.module_ids_artifact,
+                    client_entry_module,
+                )
+                // If this is a concatenation, register each child to the parent ID.
+                .or_else(|| {
+                    ChunkGraph::get_module_id(
+                        &compilation.module_ids_artifact,
+                        *connection.module_identifier(),
+                    )
+                });
+
+                if let Some(module_id) = module_id {
+                    self.record_module(
+                        compilation,
+                        &module_graph,
+                        manifest_module_id(&module_id.to_string()),
+                        &client_entry_module,
+                        required_chunks,
+                        server_modules,
+                        manifest,
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn record_module(
+        &self,
+        compilation: &Compilation,
+        module_graph: &ModuleGraph,
+        module_id: ModuleId,
+        module_identifier: &ModuleIdentifier,
+        required_chunks: &[String],
+        server_modules: &ServerModules,
+        manifest: &mut SerializedClientReferenceManifest,
+    ) {
+        let Some(module) = module_graph.module_by_identifier(module_identifier) else {
+            return;
+        };
+        let normal_module = module.as_normal_module();
+        let mut resource = if module.module_type().as_str() == "css/mini-extract" {
+            let identifier = module.identifier();
+            identifier[identifier.rfind('!').map_or(0, |index| index + 1)..].to_string()
+        } else if let Some(normal_module) = normal_module {
+            normal_module
+                .resource_resolved_data()
+                .resource()
+                .to_string()
+        } else {
+            return;
+        };
+        if resource.is_empty() {
+            return;
+        }
+
+        let context = compilation.options.context.as_str();
+        let resource_path = normal_module
+            .and_then(|module| module.resource_resolved_data().path())
+            .map(|path| path.as_str().to_string())
+            .unwrap_or_else(|| resource.clone());
+
+        // Note that this isn't that reliable as the bundler is still possible to assign additional
+        // queries to make sure there's no conflict even using the `named` module ID strategy.
+        let rsc_named_module_id = relative_path(context, &resource_path);
+        let mut ssr_named_module_id = if rsc_named_module_id.starts_with('.') {
+            rsc_named_module_id.clone()
+        } else {
+            format!("./{}", rsc_named_module_id.replace('\\', "/"))
+        };
+
+        // The client compiler will always use the CJS Next.js build, so here we also add the
+        // mapping for the ESM build (Edge runtime) to consume.
+        let esm_resource = NEXT_DIST_RE.is_match(&resource).then(|| {
+            NEXT_DIST_RE
+                .replace(
+                    &resource,
+                    "/next/dist/esm/".replace('/', MAIN_SEPARATOR_STR),
+                )
+                .into_owned()
+        });
+
+        // An extra query param is added to the resource key when it's optimized through the
+        // Barrel Loader. That's because the same file might be created as multiple modules
+        // (depending on what you import from it).
+        // See also: webpack/loaders/next-flight-loader/index.ts.
+        if let Some(match_resource) = normal_module
+            .and_then(|module| module.match_resource())
+            .map(|match_resource| match_resource.resource())
+            && match_resource.starts_with(BARREL_OPTIMIZATION_PREFIX)
+        {
+            ssr_named_module_id = format!("{ssr_named_module_id}@{match_resource}");
+            resource = format!("{resource}@{match_resource}");
+        }
+
+        let is_async = ModuleGraph::is_async(compilation, module_identifier)
+            || server_modules
+                .ssr_modules
+                .get(&ssr_named_module_id)
+                .is_some_and(|info| info.r#async)
+            || server_modules
+                .edge_ssr_modules
+                .get(&ssr_named_module_id)
+                .is_some_and(|info| info.r#async);
+        let client_entry = ManifestNodeEntry {
+            id: module_id.clone(),
+            name: "*".to_string(),
+            chunks: required_chunks.to_vec(),
+            r#async: is_async,
+        };
+        manifest
+            .client_modules
+            .module_exports
+            .insert(resource, client_entry.clone());
+        if let Some(esm_resource) = esm_resource {
+            manifest
+                .client_modules
+                .module_exports
+                .insert(esm_resource, client_entry.clone());
+        }
+
+        // During SSR, we don't have external chunks to load on the server side with our
+        // architecture of Webpack / Turbopack. We can keep this field empty to save some bytes.
+        let server_entry = |info: &ModuleInfo| {
+            let mut node = ManifestNode::default();
+            node.module_exports.insert(
+                "*".to_string(),
+                ManifestNodeEntry {
+                    id: info.module_id.clone(),
+                    name: client_entry.name.clone(),
+                    chunks: vec![],
+                    r#async: info.r#async,
+                },
+            );
+            node
+        };
+        for (mapping, modules, named_module_id) in [
+            (
+                &mut manifest.ssr_module_mapping,
+                &server_modules.ssr_modules,
+                &ssr_named_module_id,
+            ),
+            (
+                &mut manifest.edge_ssr_module_mapping,
+                &server_modules.edge_ssr_modules,
+                &ssr_named_module_id,
+            ),
+            (
+                &mut manifest.rsc_module_mapping,
+                &server_modules.rsc_modules,
+                &rsc_named_module_id,
+            ),
+            // The webpack plugin looks up the Edge RSC mapping in the SSR modules, which is kept
+            // here so that both plugins produce identical manifests.
+            (
+                &mut manifest.edge_rsc_module_mapping,
+                &server_modules.ssr_modules,
+                &rsc_named_module_id,
+            ),
+        ] {
+            if let Some(info) = modules.get(named_module_id) {
+                mapping.insert(module_id.clone(), server_entry(info));
+            }
+        }
+    }
+}
+
+/// Collects the files of all chunks of a chunk group, like `ChunkGroup.getFiles()` in webpack.
+fn chunk_group_files(compilation: &Compilation, chunk_group_ukey: &ChunkGroupUkey) -> Vec<String> {
+    compilation
+        .chunk_group_by_ukey
+        .expect_get(chunk_group_ukey)
+        .get_files(&compilation.chunk_by_ukey)
+}
+
+/// Matches `/(?<!\.hot-update)\.(js|css)($|\?)/` of the webpack plugin.
+fn is_root_main_file(file: &str) -> bool {
+    ROOT_MAIN_FILE_RE
+        .find_iter(file)
+        .any(|m| !file[..m.start()].ends_with(".hot-update"))
+}
+
+/// Bundler module IDs are numbers when they are numeric, e.g. with `moduleIds: 'deterministic'`.
+fn manifest_module_id(module_id: &str) -> ModuleId {
+    match module_id.parse::<u64>() {
+        Ok(number) if number.to_string() == module_id => ModuleId::Number(number),
+        _ => ModuleId::String(module_id.to_string()),
+    }
+}
+
+/// Equivalent of Node.js' `path.relative(from, to)` for absolute paths.
+fn relative_path(from: &str, to: &str) -> String {
+    let from = Path::new(from)
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect::<Vec<_>>();
+    let to = Path::new(to)
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect::<Vec<_>>();
+    let common = from
+        .iter()
+        .zip(&to)
+        .take_while(|(from, to)| from == to)
+        .count();
+    std::iter::repeat_n("..".to_string(), from.len() - common)
+        .chain(
+            to[common..]
+                .iter()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned()),
+        )
+        .collect::<Vec<_>>()
+        .join(MAIN_SEPARATOR_STR)
+}
+
+/// See `normalizePagePath` in `packages/next/src/shared/lib/page-path/normalize-page-path.ts`.
+fn normalize_page_path(page: &str) -> String {
+    let is_dynamic_route = page.contains('[');
+    if (page == "/index" || page.starts_with("/index/")) && !is_dynamic_route {
+        format!("/index{page}")
+    } else if page == "/" {
+        "/index".to_string()
+    } else if page.starts_with('/') {
+        page.to_string()
+    } else {
+        format!("/{page}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[test]
+    fn test_relative_path() {
+        let relative = |parts: &[&str]| parts.join(MAIN_SEPARATOR_STR);
+        assert_eq!(
+            relative_path("/project", "/project/app/button.js"),
+            relative(&["app", "button.js"])
+        );
+        assert_eq!(
+            relative_path("/project/app", "/project/node_modules/ui/index.js"),
+            relative(&["..", "node_modules", "ui", "index.js"])
+        );
+        assert_eq!(
+            relative_path("/project/./app", "/lib/a.js"),
+            relative(&["..", "..", "lib", "a.js"])
+        );
+        assert_eq!(relative_path("/project", "/project"), "");
+    }
+
+    #[test]
+    fn test_normalize_page_path() {
+        assert_eq!(normalize_page_path("/"), "/index");
+        assert_eq!(normalize_page_path("/index"), "/index/index");
+        assert_eq!(normalize_page_path("/index/foo"), "/index/index/foo");
+        assert_eq!(normalize_page_path("/index/[slug]"), "/index/[slug]");
+        assert_eq!(normalize_page_path("/indexes/page"), "/indexes/page");
+        assert_eq!(normalize_page_path("/blog/page"), "/blog/page");
+        assert_eq!(normalize_page_path("blog/page"), "/blog/page");
+    }
+
+    #[test]
+    fn test_is_root_main_file() {
+        assert!(is_root_main_file("static/chunks/main-app.js"));
+        assert!(is_root_main_file("static/css/app/layout.css?v=1"));
+        assert!(!is_root_main_file("static/chunks/main-app.js.map"));
+        assert!(!is_root_main_file(
+            "static/webpack/main-app.0f1e2d.hot-update.js"
+        ));
+        assert!(!is_root_main_file("static/media/font.woff2"));
+    }
+
+    #[test]
+    fn test_manifest_module_id() {
+        assert_eq!(manifest_module_id("42"), ModuleId::Number(42));
+        assert_eq!(manifest_module_id("0"), ModuleId::Number(0));
+        assert_eq!(manifest_module_id("042"), ModuleId::String("042".into()));
+        assert_eq!(manifest_module_id("-1"), ModuleId::String("-1".into()));
+        assert_eq!(
+            manifest_module_id("18446744073709551616"),
+            ModuleId::String("18446744073709551616".into())
+        );
+        assert_eq!(
+            manifest_module_id("./app/button.js"),
+            ModuleId::String("./app/button.js".into())
+        );
+    }
+
+    #[derive(Deserialize)]
+    struct FixtureEntry {
+        name: String,
+        manifest: SerializedClientReferenceManifest,
+    }
+
+    /// `expected/` is the output of `generate-expected.js`, which merges the entry manifests with
+    /// the code of the webpack plugin.
+    #[test]
+    fn test_page_manifests_match_webpack_plugin() {
+        let fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/client-reference-manifest");
+        let entries: Vec<FixtureEntry> =
+            serde_json::from_str(&fs::read_to_string(fixture.join("entries.json")).unwrap())
+                .unwrap();
+
+        let mut entry_manifests = EntryManifests::default();
+        for FixtureEntry { name, manifest } in entries {
+            entry_manifests.add(&name, manifest);
+        }
+        let page_manifests = entry_manifests
+            .page_manifests(|| {
+                let mut manifest = SerializedClientReferenceManifest::default();
+                manifest.module_loading.prefix = "/_next/".to_string();
+                manifest
+            })
+            .unwrap();
+
+        assert_eq!(
+            page_manifests
+                .iter()
+                .map(|(filename, _)| filename.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "server/app/page_client-reference-manifest.js",
+                "server/app/(marketing)/blog/[slug]/page_client-reference-manifest.js",
+                "server/app/api/health/route_client-reference-manifest.js",
+            ]
+        );
+        for (filename, source) in &page_manifests {
+            let expected = fs::read_to_string(fixture.join("expected").join(filename)).unwrap();
+            assert_eq!(source, &expected, "{filename}");
+        }
+
+        let manifests = page_manifests
+            .iter()
+            .map(|(_, source)| {
+                let (_, json) = source.split_once("]=").unwrap();
+                let json = json.strip_suffix(';').unwrap();
+                // webpack loads the JS chunks of an entry through the entrypoint, so unlike
+                // Turbopack it never emits `entryJSFiles`.
+                assert!(!json.contains("entryJSFiles"));
+                serde_json::from_str::<SerializedClientReferenceManifest>(json).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // The Edge SSR mapping and the CSS files are merged from the parent layouts of a page, in
+        // the order of the webpack plugin.
+        let blog_page = &manifests[1];
+        assert_eq!(
+            blog_page
+                .edge_ssr_module_mapping
+                .keys()
+                .map(|id| match id {
+                    ModuleId::String(id) => id.clone(),
+                    ModuleId::Number(id) => id.to_string(),
+                })
+                .collect::<Vec<_>>(),
+            ["1042", "./app/(marketing)/blog/[slug]/share.js"]
+        );
+        assert_eq!(
+            blog_page
+                .entry_css_files
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            [
+                "/project/app/layout",
+                "/project/app/page",
+                "/project/app/(marketing)/layout",
+                "/project/app/(marketing)/blog/[slug]/page",
+            ]
+        );
+        assert_eq!(
+            blog_page.entry_css_files["/project/app/(marketing)/blog/[slug]/page"]
+                .first()
+                .and_then(|css| css.content.as_deref()),
+            Some(".share{display:flex}")
+        );
+
+        // Route handlers only get the manifest of their own entry.
+        let health_route = &manifests[2];
+        assert_eq!(
+            health_route
+                .entry_css_files
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            ["/project/app/api/health/route"]
+        );
+        assert!(health_route.edge_ssr_module_mapping.is_empty());
+        assert!(manifests.iter().all(|m| m.entry_js_files.is_empty()));
+    }
+}
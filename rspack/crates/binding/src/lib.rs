@@ -1,17 +1,27 @@
 #![feature(impl_trait_in_bindings)]
 
+mod client_reference_manifest_plugin;
 mod config_shared;
 mod handle_externals;
 mod next_externals_plugin;
 
+use std::sync::Arc;
+
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
+use next_taskless::client_reference_manifest::ModuleId;
 use rspack_binding_builder_macros::register_plugin;
 use rspack_core::BoxPlugin;
+use rspack_error::ToStringResultToRspackResultExt;
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_regex::RspackRegex;
 use rustc_hash::FxHashMap;
 
 use crate::{
+    client_reference_manifest_plugin::{
+        ClientReferenceManifestPlugin, ClientReferenceManifestPluginOptions, ModuleInfo,
+        ServerModules,
+    },
     config_shared::{EsmExternalsConfig, ExperimentalConfig, NextConfigComplete},
     next_externals_plugin::{NextExternalsPlugin, NextExternalsPluginOptions},
 };
@@ -107,3 +117,112 @@ register_plugin!("NextExternalsPlugin", |env: Env, object: Unknown<'_>| {
         unsafe { FromNapiValue::from_napi_value(env.raw(), object.raw())? };
     Ok(Box::new(NextExternalsPlugin::new(napi_options.into())) as BoxPlugin)
 });
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct NapiModuleInfo {
+    pub module_id: Either<String, f64>,
+    #[napi(js_name = "async")]
+    pub is_async: bool,
+}
+
+impl From<NapiModuleInfo> for ModuleInfo {
+    fn from(value: NapiModuleInfo) -> Self {
+        let NapiModuleInfo {
+            module_id,
+            is_async,
+        } = value;
+        ModuleInfo {
+            module_id: match module_id {
+                Either::A(s) => ModuleId::String(s),
+                Either::B(n) => ModuleId::Number(n as u64),
+            },
+            r#async: is_async,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct NapiServerModules {
+    #[napi(ts_type = "Record<string, NapiModuleInfo>")]
+    pub ssr_modules: FxHashMap<String, NapiModuleInfo>,
+    #[napi(ts_type = "Record<string, NapiModuleInfo>")]
+    pub edge_ssr_modules: FxHashMap<String, NapiModuleInfo>,
+    #[napi(ts_type = "Record<string, NapiModuleInfo>")]
+    pub rsc_modules: FxHashMap<String, NapiModuleInfo>,
+    #[napi(ts_type = "Record<string, NapiModuleInfo>")]
+    pub edge_rsc_modules: FxHashMap<String, NapiModuleInfo>,
+}
+
+impl From<NapiServerModules> for ServerModules {
+    fn from(value: NapiServerModules) -> Self {
+        fn convert(modules: FxHashMap<String, NapiModuleInfo>) -> FxHashMap<String, ModuleInfo> {
+            modules
+                .into_iter()
+                .map(|(key, info)| (key, info.into()))
+                .collect()
+        }
+
+        let NapiServerModules {
+            ssr_modules,
+            edge_ssr_modules,
+            rsc_modules,
+            edge_rsc_modules,
+        } = value;
+        ServerModules {
+            ssr_modules: convert(ssr_modules),
+            edge_ssr_modules: convert(edge_ssr_modules),
+            rsc_modules: convert(rsc_modules),
+            edge_rsc_modules: convert(edge_rsc_modules),
+        }
+    }
+}
+
+#[napi(object, object_to_js = false)]
+pub struct NapiClientReferenceManifestPluginOptions {
+    pub dev: bool,
+    pub app_dir: String,
+    pub experimental_inline_css: bool,
+    pub deployment_id_chunk_query: String,
+    #[napi(ts_type = "() => NapiServerModules")]
+    pub get_server_modules: ThreadsafeFunction<(), NapiServerModules>,
+}
+
+impl From<NapiClientReferenceManifestPluginOptions> for ClientReferenceManifestPluginOptions {
+    fn from(value: NapiClientReferenceManifestPluginOptions) -> Self {
+        let NapiClientReferenceManifestPluginOptions {
+            dev,
+            app_dir,
+            experimental_inline_css,
+            deployment_id_chunk_query,
+            get_server_modules,
+        } = value;
+        let get_server_modules = Arc::new(get_server_modules);
+        ClientReferenceManifestPluginOptions {
+            dev,
+            app_dir,
+            experimental_inline_css,
+            deployment_id_chunk_query,
+            get_server_modules: Box::new(move || {
+                let get_server_modules = get_server_modules.clone();
+                Box::pin(async move {
+                    let server_modules = get_server_modules
+                        .call_with_sync(())
+                        .await
+                        .to_rspack_result()?;
+                    Ok(server_modules.into())
+                })
+            }),
+        }
+    }
+}
+
+register_plugin!(
+    "ClientReferenceManifestPlugin",
+    |env: Env, object: Unknown<'_>| {
+        let napi_options: NapiClientReferenceManifestPluginOptions =
+            unsafe { FromNapiValue::from_napi_value(env.raw(), object.raw())? };
+        Ok(Box::new(ClientReferenceManifestPlugin::new(napi_options.into())) as BoxPlugin)
+    }
+);
@@ -0,0 +1 @@
+globalThis.__RSC_MANIFEST=(globalThis.__RSC_MANIFEST||{});globalThis.__RSC_MANIFEST["/api/health/route"]={"moduleLoading":{"prefix":"/_next/"},"ssrModuleMapping":{},"edgeSSRModuleMapping":{},"clientModules":{},"entryCSSFiles":{"/project/app/api/health/route":[]},"rscModuleMapping":{"404":{"*":{"id":405,"name":"*","chunks":[],"async":false}}},"edgeRscModuleMapping":{}};
\ No newline at end of file
@@ -0,0 +1 @@
+globalThis.__RSC_MANIFEST=(globalThis.__RSC_MANIFEST||{});globalThis.__RSC_MANIFEST["/page"]={"moduleLoading":{"prefix":"/_next/"},"ssrModuleMapping":{"7":{"*":{"id":88,"name":"*","chunks":[],"async":false}},"15":{"*":{"id":16,"name":"*","chunks":[],"async":false}},"301":{"*":{"id":4410,"name":"*","chunks":[],"async":false}},"9412":{"*":{"id":2087,"name":"*","chunks":[],"async":false}},"./app/(marketing)/nav.js":{"*":{"id":"./app/(marketing)/nav.js","name":"*","chunks":[],"async":false}}},"edgeSSRModuleMapping":{},"clientModules":{"/project/node_modules/next/dist/client/components/layout-router.js":{"id":9412,"name":"*","chunks":[],"async":false},"/project/node_modules/next/dist/esm/client/components/layout-router.js":{"id":9412,"name":"*","chunks":[],"async":false},"/project/app/theme.js":{"id":301,"name":"*","chunks":["177","static/chunks/app/layout.js"],"async":false},"/project/app/counter.js":{"id":7,"name":"*","chunks":["931","static/chunks/app/page.js"],"async":false}},"entryCSSFiles":{"/project/app/layout":[{"path":"static/css/app/layout.css","inlined":false}],"/project/app/page":[],"/project/app/(marketing)/layout":[]},"rscModuleMapping":{"7":{"*":{"id":612,"name":"*","chunks":[],"async":false}},"9412":{"*":{"id":5531,"name":"*","chunks":[],"async":false}}},"edgeRscModuleMapping":{}};
\ No newline at end of file
@@ -0,0 +1,124 @@
+// Regenerates `expected/` from `entries.json` with the page manifest merging of
+// `packages/next/src/build/webpack/plugins/flight-manifest-plugin.ts`, which is
+// copied below. Run with `node generate-expected.js` after changing the plugin.
+
+const fs = require('fs')
+const path = require('path')
+
+const CLIENT_REFERENCE_MANIFEST = 'client-reference-manifest'
+const prefix = '/_next/'
+const crossOriginMode = undefined
+
+function entryNameToGroupName(entryName) {
+  let groupName = entryName
+    .slice(0, entryName.lastIndexOf('/'))
+    .replace(/\/@[^/]+/g, '')
+    .replace(/\/\([^/]+\)(?=(\/|$))/g, '')
+    .replace(/\/\[?\[\.\.\.[^\]]*]]?/g, '')
+
+  groupName = groupName
+    .replace(/^.+\/\(\.\.\.\)/g, 'app/')
+    .replace(/\/\(\.\)/g, '/')
+
+  while (/\/[^/]+\/\(\.\.\)/.test(groupName)) {
+    groupName = groupName.replace(/\/[^/]+\/\(\.\.\)/g, '/')
+  }
+
+  return groupName
+}
+
+function mergeManifest(manifest, manifestToMerge) {
+  Object.assign(manifest.clientModules, manifestToMerge.clientModules)
+  Object.assign(manifest.ssrModuleMapping, manifestToMerge.ssrModuleMapping)
+  Object.assign(
+    manifest.edgeSSRModuleMapping,
+    manifestToMerge.edgeSSRModuleMapping
+  )
+  Object.assign(manifest.entryCSSFiles, manifestToMerge.entryCSSFiles)
+  Object.assign(manifest.rscModuleMapping, manifestToMerge.rscModuleMapping)
+  Object.assign(
+    manifest.edgeRscModuleMapping,
+    manifestToMerge.edgeRscModuleMapping
+  )
+}
+
+// The fixture doesn't contain dynamic `/index` pages.
+function normalizePagePath(page) {
+  return /^\/index(\/|$)/.test(page)
+    ? `/index${page}`
+    : page === '/'
+      ? '/index'
+      : page
+}
+
+const entries = JSON.parse(
+  fs.readFileSync(path.join(__dirname, 'entries.json'), 'utf8')
+)
+const manifestsPerGroup = new Map()
+const manifestEntryFiles = []
+for (const { name: entryName, manifest } of entries) {
+  if (/\/page(\.[^/]+)?$/.test(entryName)) {
+    manifestEntryFiles.push(entryName.replace(/\/page(\.[^/]+)?$/, '/page'))
+  }
+  if (/\/route$/.test(entryName)) {
+    manifestEntryFiles.push(entryName)
+  }
+  const groupName = entryNameToGroupName(entryName)
+  if (!manifestsPerGroup.has(groupName)) {
+    manifestsPerGroup.set(groupName, [])
+  }
+  manifestsPerGroup.get(groupName).push(manifest)
+}
+
+for (const pageName of manifestEntryFiles) {
+  const mergedManifest = {
+    moduleLoading: {
+      prefix,
+      crossOrigin: crossOriginMode,
+    },
+    ssrModuleMapping: {},
+    edgeSSRModuleMapping: {},
+    clientModules: {},
+    entryCSSFiles: {},
+    rscModuleMapping: {},
+    edgeRscModuleMapping: {},
+  }
+
+  const isRouteHandler = /\/route$/.test(pageName)
+
+  if (isRouteHandler) {
+    const groupName = entryNameToGroupName(pageName)
+    for (const manifest of manifestsPerGroup.get(groupName) || []) {
+      mergeManifest(mergedManifest, manifest)
+    }
+  } else {
+    const segments = [...entryNameToGroupName(pageName).split('/'), 'page']
+    let group = ''
+    for (const segment of segments) {
+      for (const manifest of manifestsPerGroup.get(group) || []) {
+        mergeManifest(mergedManifest, manifest)
+      }
+      group += (group ? '/' : '') + segment
+    }
+  }
+
+  const json = JSON.stringify(mergedManifest)
+
+  const pagePath = pageName.replace(/%5F/g, '_')
+  const pageBundlePath = normalizePagePath(pagePath.slice('app'.length))
+  const file = path.join(
+    __dirname,
+    'expected/server/app' +
+      pageBundlePath +
+      '_' +
+      CLIENT_REFERENCE_MANIFEST +
+      '.js'
+  )
+  fs.mkdirSync(path.dirname(file), { recursive: true })
+  fs.writeFileSync(
+    file,
+    `globalThis.__RSC_MANIFEST=(globalThis.__RSC_MANIFEST||{});globalThis.__RSC_MANIFEST[${JSON.stringify(
+      pagePath.slice('app'.length)
+    )}]=${json};`
+  )
+}
@@ -1,5 +1,8 @@
 import * as RspackCore from '@rspack/core'
-import { NapiNextExternalsPluginOptions } from '@next/rspack-binding'
+import {
+  NapiClientReferenceManifestPluginOptions,
+  NapiNextExternalsPluginOptions,
+} from '@next/rspack-binding'
 
 // eslint-disable-next-line @typescript-eslint/no-unused-vars
 declare class NextExternalsPlugin {
@@ -9,8 +12,14 @@ declare class NextExternalsPlugin {
   constructor(options: NapiNextExternalsPluginOptions)
 }
 
+// eslint-disable-next-line @typescript-eslint/no-unused-vars
+declare class ClientReferenceManifestPlugin {
+  constructor(options: NapiClientReferenceManifestPluginOptions)
+}
+
 declare const core: typeof RspackCore & {
   NextExternalsPlugin: typeof NextExternalsPlugin
+  ClientReferenceManifestPlugin: typeof ClientReferenceManifestPlugin
 }
 
 export = core
@@ -4,8 +4,10 @@ process.env.RSPACK_BINDING = require('node:path').dirname(
 
 const binding = require('@next/rspack-binding')
 
-// Register the plugin `NextExternalsPlugin` exported by `crates/binding/src/lib.rs`.
+// Register the plugins `NextExternalsPlugin` and `ClientReferenceManifestPlugin` exported by
+// `crates/binding/src/lib.rs`.
 binding.registerNextExternalsPlugin()
+binding.registerClientReferenceManifestPlugin()
 
 const core = require('@rspack/core')
 
@@ -20,4 +22,15 @@ Object.defineProperty(core, 'NextExternalsPlugin', {
   value: NextExternalsPlugin,
 })
 
+const ClientReferenceManifestPlugin = core.experiments.createNativePlugin(
+  'ClientReferenceManifestPlugin',
+  function (options) {
+    return options
+  }
+)
+
+Object.defineProperty(core, 'ClientReferenceManifestPlugin', {
+  value: ClientReferenceManifestPlugin,
+})
+
 module.exports = core
@@ -103,6 +103,11 @@ impl FetchClientConfig {
             .and_then(|r| r.error_for_status())?;
 
             let status = response.status().as_u16();
+            let content_type = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(RcStr::from);
 
             let body = {
                 let _span = duration_span!("fetch response", url = url_ref);
@@ -112,6 +117,7 @@ impl FetchClientConfig {
 
             Ok(HttpResponse {
                 status,
+                content_type,
                 body: HttpResponseBody(body).resolved_cell(),
             })
         }
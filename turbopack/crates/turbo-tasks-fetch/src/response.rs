@@ -11,6 +11,8 @@ pub struct FetchResult(Result<ResolvedVc<HttpResponse>, ResolvedVc<FetchError>>)
 #[derive(Debug)]
 pub struct HttpResponse {
     pub status: u16,
+    /// The `Content-Type` header of the response, if there is one.
+    pub content_type: Option<RcStr>,
     pub body: ResolvedVc<HttpResponseBody>,
 }
 
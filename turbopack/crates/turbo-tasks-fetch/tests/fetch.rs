@@ -81,6 +81,40 @@ async fn sends_user_agent() {
     .unwrap()
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn returns_content_type() {
+    let _guard = GLOBAL_TEST_LOCK.lock().await;
+    run_once(&REGISTRATION, || async {
+        let mut server = mockito::Server::new_async().await;
+        let resource_mock = server
+            .mock("GET", "/index.mjs")
+            .with_header("Content-Type", "application/javascript; charset=utf-8")
+            .with_body("export default 1")
+            .create_async()
+            .await;
+
+        let client_vc = FetchClientConfig::default().cell();
+        let response = &*client_vc
+            .fetch(
+                RcStr::from(format!("{}/index.mjs", server.url())),
+                /* user_agent */ None,
+            )
+            .await?
+            .unwrap()
+            .await?;
+
+        resource_mock.assert_async().await;
+
+        assert_eq!(
+            response.content_type.as_deref(),
+            Some("application/javascript; charset=utf-8")
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
 // This is temporary behavior.
 // TODO: Implement invalidation that respects Cache-Control headers.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -23,6 +23,7 @@ pub mod invalidation;
 mod invalidator_map;
 pub mod json;
 mod mutex_map;
+mod overlay_fs;
 mod path_map;
 mod read_glob;
 mod retry;
@@ -31,6 +32,7 @@ pub mod source_context;
 pub mod util;
 pub(crate) mod virtual_fs;
 mod watcher;
+mod zip_fs;
 
 use std::{
     borrow::Cow,
@@ -88,7 +90,10 @@ use crate::{
     util::extract_disk_access,
     watcher::DiskWatcher,
 };
-pub use crate::{read_glob::ReadGlobResult, virtual_fs::VirtualFileSystem};
+pub use crate::{
+    overlay_fs::OverlayFileSystem, read_glob::ReadGlobResult, virtual_fs::VirtualFileSystem,
+    zip_fs::ZipFileSystem,
+};
 
 /// A (somewhat arbitrary) filename limit that we should try to keep output file names below.
 ///
@@ -2663,7 +2668,7 @@ pub async fn to_sys_path(mut path: FileSystemPath) -> Result<Option<PathBuf>> {
             continue;
         }
 
-        if let Some(fs) = ResolvedVc::try_downcast_type::<DiskFileSystem>(path.fs) {
+        if let Some(fs) = disk_file_system(path.fs).await? {
             let sys_path = fs.await?.to_sys_path(&path);
             return Ok(Some(sys_path));
         }
@@ -2672,6 +2677,16 @@ pub async fn to_sys_path(mut path: FileSystemPath) -> Result<Option<PathBuf>> {
     }
 }
 
+/// Returns the [DiskFileSystem] backing `fs`, looking through an [OverlayFileSystem].
+pub async fn disk_file_system(
+    fs: ResolvedVc<Box<dyn FileSystem>>,
+) -> Result<Option<ResolvedVc<DiskFileSystem>>> {
+    if let Some(fs) = ResolvedVc::try_downcast_type::<OverlayFileSystem>(fs) {
+        return Ok(Some(fs.await?.disk_fs()));
+    }
+    Ok(ResolvedVc::try_downcast_type::<DiskFileSystem>(fs))
+}
+
 #[turbo_tasks::function]
 async fn read_dir(path: FileSystemPath) -> Result<Vc<DirectoryContent>> {
     let fs = path.fs().to_resolved().await?;
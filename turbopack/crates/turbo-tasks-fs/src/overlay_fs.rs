@@ -0,0 +1,399 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Mutex, Weak},
+};
+
+use anyhow::Result;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tokio::runtime::Handle;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{
+    Invalidator, NonLocalValue, ResolvedVc, TurboTasksApi, ValueToString, Vc,
+    debug::ValueDebugFormat, mark_session_dependent, parallel, trace::TraceRawVcs,
+    turbo_tasks_weak,
+};
+use turbo_unix_path::get_parent_path;
+
+use crate::{
+    DiskFileSystem, File, FileContent, FileMeta, FileSystem, FileSystemPath, LinkContent,
+    RawDirectoryContent, RawDirectoryEntry, invalidation::WatchChange, rope::Rope,
+};
+
+/// A [DiskFileSystem] with in-memory content layered on top of it, e.g. the unsaved buffers of
+/// an editor.
+///
+/// Reads of a path with overlay content return that content instead of the file on disk, and
+/// directory listings include overlay files that don't exist on disk yet. Setting or clearing
+/// overlay content invalidates readers in the same way as a change on disk does. Writes go to
+/// the underlying [DiskFileSystem].
+#[derive(ValueToString)]
+#[value_to_string(self.inner.name)]
+#[turbo_tasks::value(serialization = "none", cell = "new", eq = "manual")]
+pub struct OverlayFileSystem {
+    inner: Arc<OverlayFileSystemInner>,
+}
+
+#[derive(TraceRawVcs, ValueDebugFormat, NonLocalValue)]
+struct OverlayFileSystemInner {
+    /// The name of the underlying file system, so paths display the same with and without the
+    /// overlay.
+    name: RcStr,
+    disk_fs: ResolvedVc<DiskFileSystem>,
+    /// Overlay content by path relative to the file system root.
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    content: Mutex<FxHashMap<RcStr, Rope>>,
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    invalidators: Mutex<FxHashMap<RcStr, FxHashSet<Invalidator>>>,
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    dir_invalidators: Mutex<FxHashMap<RcStr, FxHashSet<Invalidator>>>,
+    /// Used by invalidators when called from a non-turbo-tasks thread, e.g. a napi call.
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    turbo_tasks: Weak<dyn TurboTasksApi>,
+    /// Used by invalidators when called from a non-tokio thread.
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    tokio_handle: Handle,
+}
+
+impl OverlayFileSystemInner {
+    /// Registers the current task to be invalidated when the overlay content of `path` changes.
+    /// Has to be called within a turbo-tasks function.
+    fn register_read_invalidator(&self, path: &RcStr) {
+        if let Some(invalidator) = turbo_tasks::get_invalidator() {
+            self.invalidators
+                .lock()
+                .unwrap()
+                .entry(path.clone())
+                .or_default()
+                .insert(invalidator);
+        }
+    }
+
+    /// Registers the current task to be invalidated when overlay content is set or cleared
+    /// inside of the directory `path`. Has to be called within a turbo-tasks function.
+    fn register_dir_invalidator(&self, path: &RcStr) {
+        if let Some(invalidator) = turbo_tasks::get_invalidator() {
+            self.dir_invalidators
+                .lock()
+                .unwrap()
+                .entry(path.clone())
+                .or_default()
+                .insert(invalidator);
+        }
+    }
+
+    /// Invalidates all readers of `path` and of all of its parent directories.
+    fn invalidate(&self, path: &RcStr) {
+        let _span =
+            tracing::info_span!("invalidate overlay", name = &*self.name, path = &**path).entered();
+        let Some(turbo_tasks) = self.turbo_tasks.upgrade() else {
+            return;
+        };
+        let _guard = self.tokio_handle.enter();
+
+        let mut invalidators = self
+            .invalidators
+            .lock()
+            .unwrap()
+            .remove(path)
+            .unwrap_or_default();
+        {
+            let mut dir_invalidators = self.dir_invalidators.lock().unwrap();
+            let mut dir = path.as_str();
+            while !dir.is_empty() {
+                dir = get_parent_path(dir);
+                if let Some(dir_invalidators) = dir_invalidators.remove(dir) {
+                    invalidators.extend(dir_invalidators);
+                }
+            }
+        }
+
+        let path = format!("[{}]/{path}", self.name);
+        parallel::for_each_owned(invalidators, |invalidator| {
+            invalidator.invalidate_with_reason(&*turbo_tasks, WatchChange { path: path.clone() })
+        });
+    }
+}
+
+impl OverlayFileSystem {
+    /// The file system that is read for paths without overlay content.
+    pub fn disk_fs(&self) -> ResolvedVc<DiskFileSystem> {
+        self.inner.disk_fs
+    }
+
+    /// Sets the overlay content of `path`. Returns `false` when the content didn't change.
+    pub fn set_content(&self, path: &FileSystemPath, content: Rope) -> bool {
+        let previous = self
+            .inner
+            .content
+            .lock()
+            .unwrap()
+            .insert(path.path.clone(), content.clone());
+        if previous.is_some_and(|previous| previous == content) {
+            return false;
+        }
+        self.inner.invalidate(&path.path);
+        true
+    }
+
+    /// Removes the overlay content of `path`, so it's read from disk again. Returns `false` when
+    /// `path` had no overlay content.
+    pub fn clear_content(&self, path: &FileSystemPath) -> bool {
+        let previous = self.inner.content.lock().unwrap().remove(&path.path);
+        if previous.is_none() {
+            return false;
+        }
+        self.inner.invalidate(&path.path);
+        true
+    }
+
+    /// Removes all overlay content.
+    pub fn clear_all_content(&self) {
+        let paths = self
+            .inner
+            .content
+            .lock()
+            .unwrap()
+            .drain()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        for path in paths {
+            self.inner.invalidate(&path);
+        }
+    }
+
+    fn overlay_content(&self, path: &RcStr) -> Option<Rope> {
+        self.inner.content.lock().unwrap().get(path).cloned()
+    }
+
+    fn disk_path(&self, fs_path: &FileSystemPath) -> FileSystemPath {
+        FileSystemPath {
+            fs: ResolvedVc::upcast(self.inner.disk_fs),
+            path: fs_path.path.clone(),
+        }
+    }
+
+    /// The metadata of the file on disk, so overlay content keeps e.g. the permissions of the
+    /// file it replaces.
+    async fn disk_meta(&self, fs_path: &FileSystemPath) -> Result<FileMeta> {
+        Ok(match &*self.disk_path(fs_path).read().await? {
+            FileContent::Content(file) => file.meta().clone(),
+            FileContent::NotFound => FileMeta::default(),
+        })
+    }
+}
+
+impl Debug for OverlayFileSystem {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "overlay of {}", self.inner.name)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OverlayFileSystem {
+    /// Creates an overlay over `disk_fs`. Overlays of the same file system share their content.
+    #[turbo_tasks::function]
+    pub async fn new(disk_fs: ResolvedVc<DiskFileSystem>) -> Result<Vc<Self>> {
+        let name = disk_fs.await?.name().clone();
+        Ok(Self::cell(OverlayFileSystem {
+            inner: Arc::new(OverlayFileSystemInner {
+                name,
+                disk_fs,
+                content: Default::default(),
+                invalidators: Default::default(),
+                dir_invalidators: Default::default(),
+                turbo_tasks: turbo_tasks_weak(),
+                tokio_handle: Handle::current(),
+            }),
+        }))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystem for OverlayFileSystem {
+    #[turbo_tasks::function]
+    async fn read(&self, fs_path: FileSystemPath) -> Result<Vc<FileContent>> {
+        mark_session_dependent();
+        self.inner.register_read_invalidator(&fs_path.path);
+
+        let Some(content) = self.overlay_content(&fs_path.path) else {
+            return Ok(self.disk_path(&fs_path).read());
+        };
+        Ok(FileContent::Content(File {
+            content,
+            meta: self.disk_meta(&fs_path).await?,
+        })
+        .cell())
+    }
+
+    #[turbo_tasks::function]
+    fn read_link(&self, fs_path: FileSystemPath) -> Vc<LinkContent> {
+        self.disk_path(&fs_path).read_link()
+    }
+
+    #[turbo_tasks::function]
+    async fn raw_read_dir(&self, fs_path: FileSystemPath) -> Result<Vc<RawDirectoryContent>> {
+        mark_session_dependent();
+        self.inner.register_dir_invalidator(&fs_path.path);
+
+        let disk_content = self.disk_path(&fs_path).raw_read_dir();
+        let prefix = if fs_path.path.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", fs_path.path)
+        };
+        // Files that only exist in the overlay, and the directories leading to them
+        let overlay_entries = self
+            .inner
+            .content
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|path| {
+                let rest = path.strip_prefix(&prefix)?;
+                Some(match rest.split_once('/') {
+                    Some((dir, _)) => (RcStr::from(dir), RawDirectoryEntry::Directory),
+                    None => (RcStr::from(rest), RawDirectoryEntry::File),
+                })
+            })
+            .collect::<Vec<_>>();
+        if overlay_entries.is_empty() {
+            return Ok(disk_content);
+        }
+
+        let mut entries = match &*disk_content.await? {
+            RawDirectoryContent::Entries(entries) => entries.clone(),
+            RawDirectoryContent::NotFound => Default::default(),
+        };
+        for (name, entry) in overlay_entries {
+            entries.entry(name).or_insert(entry);
+        }
+        Ok(RawDirectoryContent::Entries(entries).cell())
+    }
+
+    #[turbo_tasks::function]
+    fn write(&self, fs_path: FileSystemPath, content: Vc<FileContent>) -> Vc<()> {
+        self.disk_path(&fs_path).write(content)
+    }
+
+    #[turbo_tasks::function]
+    fn write_link(&self, fs_path: FileSystemPath, target: Vc<LinkContent>) -> Vc<()> {
+        self.disk_path(&fs_path).write_symbolic_link_dir(target)
+    }
+
+    #[turbo_tasks::function]
+    async fn metadata(&self, fs_path: FileSystemPath) -> Result<Vc<FileMeta>> {
+        mark_session_dependent();
+        self.inner.register_read_invalidator(&fs_path.path);
+
+        if self.overlay_content(&fs_path.path).is_none() {
+            return Ok(self.disk_path(&fs_path).metadata());
+        }
+        Ok(self.disk_meta(&fs_path).await?.cell())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::rcstr;
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+
+    use super::*;
+
+    #[turbo_tasks::function(operation)]
+    async fn read_operation(fs: ResolvedVc<OverlayFileSystem>, path: RcStr) -> Result<Vc<RcStr>> {
+        let path = fs.root().await?.join(&path)?;
+        Ok(Vc::cell(match &*path.read().await? {
+            FileContent::Content(file) => file.content().to_str()?.into(),
+            FileContent::NotFound => rcstr!("not found"),
+        }))
+    }
+
+    #[turbo_tasks::function(operation)]
+    async fn read_dir_operation(fs: ResolvedVc<OverlayFileSystem>) -> Result<Vc<Vec<RcStr>>> {
+        let RawDirectoryContent::Entries(entries) = &*fs.root().await?.raw_read_dir().await? else {
+            return Ok(Vc::cell(Vec::new()));
+        };
+        let mut names = entries.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        Ok(Vc::cell(names))
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_overlay_content() {
+        let scratch = tempfile::tempdir().unwrap();
+        std::fs::write(scratch.path().join("a.txt"), "disk").unwrap();
+        let root = RcStr::from(scratch.path().to_str().unwrap());
+
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            let fs = OverlayFileSystem::new(DiskFileSystem::new(rcstr!("test"), root))
+                .to_resolved()
+                .await?;
+            let root_path = fs.root().await?;
+            let a = root_path.join("a.txt")?;
+            let b = root_path.join("dir/b.txt")?;
+
+            let read_a = read_operation(fs, rcstr!("a.txt"));
+            let read_b = read_operation(fs, rcstr!("dir/b.txt"));
+            let read_dir = read_dir_operation(fs);
+            assert_eq!(&*read_a.read_strongly_consistent().await?, "disk");
+            assert_eq!(&*read_b.read_strongly_consistent().await?, "not found");
+            assert_eq!(
+                *read_dir.read_strongly_consistent().await?,
+                vec![rcstr!("a.txt")]
+            );
+
+            // Readers are invalidated when overlay content is set
+            assert!(
+                fs.await?
+                    .set_content(&a, Rope::from("overlay a".to_string()))
+            );
+            assert!(
+                !fs.await?
+                    .set_content(&a, Rope::from("overlay a".to_string()))
+            );
+            assert!(
+                fs.await?
+                    .set_content(&b, Rope::from("overlay b".to_string()))
+            );
+            assert_eq!(&*read_a.read_strongly_consistent().await?, "overlay a");
+            assert_eq!(&*read_b.read_strongly_consistent().await?, "overlay b");
+            assert_eq!(
+                *read_dir.read_strongly_consistent().await?,
+                vec![rcstr!("a.txt"), rcstr!("dir")]
+            );
+
+            // Overlays of the same file system share their content
+            let other = OverlayFileSystem::new(DiskFileSystem::new(
+                rcstr!("test"),
+                RcStr::from(scratch.path().to_str().unwrap()),
+            ))
+            .to_resolved()
+            .await?;
+            assert_eq!(
+                &*read_operation(other, rcstr!("a.txt"))
+                    .read_strongly_consistent()
+                    .await?,
+                "overlay a"
+            );
+
+            // Clearing the overlay content reads from disk again
+            assert!(fs.await?.clear_content(&a));
+            assert!(!fs.await?.clear_content(&a));
+            fs.await?.clear_all_content();
+            assert_eq!(&*read_a.read_strongly_consistent().await?, "disk");
+            assert_eq!(&*read_b.read_strongly_consistent().await?, "not found");
+            assert_eq!(
+                *read_dir.read_strongly_consistent().await?,
+                vec![rcstr!("a.txt")]
+            );
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+}
@@ -4,9 +4,8 @@ use std::{
 };
 
 use anyhow::{Context, Result, anyhow};
-use turbo_tasks::ResolvedVc;
 
-use crate::{DiskFileSystem, FileSystemPath};
+use crate::{FileSystemPath, disk_file_system};
 
 /// Converts a disk access Result<T> into a Result<Some<T>>, where a NotFound
 /// error results in a None value. This is purely to reduce boilerplate code
@@ -21,7 +20,8 @@ pub fn extract_disk_access<T>(value: io::Result<T>, path: &Path) -> Result<Optio
 
 pub async fn uri_from_file(root: FileSystemPath, path: Option<&str>) -> Result<String> {
     let root_fs = root.fs;
-    let root_fs = &*ResolvedVc::try_downcast_type::<DiskFileSystem>(root_fs)
+    let root_fs = &*disk_file_system(root_fs)
+        .await?
         .context("Expected root to have a DiskFileSystem")?
         .await?;
 
@@ -0,0 +1,454 @@
+use std::io::{Cursor, Read};
+
+use anyhow::{Context, Result, bail};
+use auto_hash_map::AutoMap;
+use bincode::{Decode, Encode};
+use flate2::read::DeflateDecoder;
+use rustc_hash::FxHashMap;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{NonLocalValue, ValueToString, Vc, trace::TraceRawVcs};
+use zip::{CompressionMethod, ZipArchive};
+
+use crate::{
+    File, FileContent, FileMeta, FileSystem, FileSystemPath, LinkContent, LinkType, Permissions,
+    RawDirectoryContent, RawDirectoryEntry,
+};
+
+const S_IFMT: u32 = 0o170000;
+const S_IFLNK: u32 = 0o120000;
+
+/// A read-only file system over the contents of a zip archive, e.g. a package in Yarn's
+/// `.yarn/cache`.
+///
+/// The archive itself is read through its [`FileSystemPath`], so changes to the archive invalidate
+/// everything that was read from this file system.
+#[derive(ValueToString)]
+#[value_to_string("zip {archive}")]
+#[turbo_tasks::value]
+pub struct ZipFileSystem {
+    archive: FileSystemPath,
+}
+
+#[turbo_tasks::value_impl]
+impl ZipFileSystem {
+    /// Creates a file system for the zip archive at `archive`. File systems for the same archive
+    /// are equivalent, so paths created from either of them are interoperable.
+    #[turbo_tasks::function]
+    pub fn new(archive: FileSystemPath) -> Vc<Self> {
+        ZipFileSystem { archive }.cell()
+    }
+
+    /// The path of the zip archive.
+    #[turbo_tasks::function]
+    pub fn archive(&self) -> Vc<FileSystemPath> {
+        self.archive.clone().cell()
+    }
+
+    #[turbo_tasks::function]
+    fn index(&self) -> Vc<ZipIndex> {
+        zip_index(self.archive.clone())
+    }
+
+    /// Reads and decompresses a single entry of the archive.
+    #[turbo_tasks::function]
+    async fn read_entry(self: Vc<Self>, path: RcStr) -> Result<Vc<ZipEntryContent>> {
+        let index = self.index().await?;
+        let Some(entry) = index.files.get(&path) else {
+            return Ok(ZipEntryContent::NotFound.cell());
+        };
+        let this = self.await?;
+        let FileContent::Content(file) = &*this.archive.read().await? else {
+            return Ok(ZipEntryContent::NotFound.cell());
+        };
+        let bytes = file.content().to_bytes();
+        let data = usize::try_from(entry.data_start)
+            .ok()
+            .zip(usize::try_from(entry.data_start + entry.compressed_size).ok())
+            .and_then(|(start, end)| bytes.get(start..end))
+            .with_context(|| format!("{path} is out of bounds of zip archive {}", this.archive))?;
+        let mut content = Vec::with_capacity(entry.size as usize);
+        match entry.compression {
+            ZipCompression::Stored => content.extend_from_slice(data),
+            ZipCompression::Deflated => {
+                DeflateDecoder::new(data)
+                    .read_to_end(&mut content)
+                    .with_context(|| {
+                        format!(
+                            "failed to decompress {path} from zip archive {}",
+                            this.archive
+                        )
+                    })?;
+            }
+        }
+        Ok(ZipEntryContent::Content {
+            content,
+            executable: entry.executable,
+        }
+        .cell())
+    }
+}
+
+/// Reads the central directory of the zip archive at `archive`. This is shared by all file systems
+/// for the archive, entries are read from the archive at the offsets stored in the index.
+#[turbo_tasks::function]
+async fn zip_index(archive: FileSystemPath) -> Result<Vc<ZipIndex>> {
+    let FileContent::Content(file) = &*archive.read().await? else {
+        return Ok(ZipIndex::default().cell());
+    };
+    let bytes = file.content().to_bytes();
+    let mut zip = ZipArchive::new(Cursor::new(&*bytes))
+        .with_context(|| format!("failed to read zip archive {archive}"))?;
+
+    let mut index = ZipIndex::default();
+    index.directories.insert(RcStr::default(), AutoMap::new());
+    for i in 0..zip.len() {
+        let entry = zip
+            .by_index_raw(i)
+            .with_context(|| format!("failed to read zip archive {archive}"))?;
+        let name = entry.name();
+        let (name, mut kind) = match name.strip_suffix('/') {
+            Some(dir) => (dir, RawDirectoryEntry::Directory),
+            None => (name, RawDirectoryEntry::File),
+        };
+        if name.is_empty()
+            || name
+                .split('/')
+                .any(|s| s.is_empty() || s == "." || s == "..")
+        {
+            // Skip entries that would escape the archive root or can't be represented as a
+            // normalized path.
+            continue;
+        }
+        if kind == RawDirectoryEntry::File {
+            let compression = match entry.compression() {
+                CompressionMethod::Stored => ZipCompression::Stored,
+                CompressionMethod::Deflated => ZipCompression::Deflated,
+                method => bail!(
+                    "{name} in zip archive {archive} uses the unsupported compression {method:?}"
+                ),
+            };
+            if entry.encrypted() {
+                bail!("{name} in zip archive {archive} is encrypted");
+            }
+            let mode = entry.unix_mode();
+            if mode.is_some_and(|mode| mode & S_IFMT == S_IFLNK) {
+                kind = RawDirectoryEntry::Symlink;
+            }
+            index.files.insert(
+                name.into(),
+                ZipIndexEntry {
+                    data_start: entry.data_start(),
+                    compressed_size: entry.compressed_size(),
+                    size: entry.size(),
+                    compression,
+                    executable: mode.is_some_and(|mode| mode & 0o111 != 0),
+                },
+            );
+        }
+        index.insert(name, kind);
+    }
+    Ok(index.cell())
+}
+
+#[turbo_tasks::value]
+#[derive(Default)]
+struct ZipIndex {
+    /// Directory paths (relative to the archive root) to their entries.
+    directories: FxHashMap<RcStr, AutoMap<RcStr, RawDirectoryEntry>>,
+    /// The location of the data of every file or symlink entry.
+    files: FxHashMap<RcStr, ZipIndexEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+struct ZipIndexEntry {
+    /// Offset of the (compressed) data in the archive.
+    data_start: u64,
+    compressed_size: u64,
+    size: u64,
+    compression: ZipCompression,
+    executable: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+enum ZipCompression {
+    Stored,
+    Deflated,
+}
+
+impl ZipIndex {
+    /// Adds an entry and all of its parent directories. Not all archives contain explicit entries
+    /// for directories.
+    fn insert(&mut self, path: &str, kind: RawDirectoryEntry) {
+        if kind == RawDirectoryEntry::Directory {
+            self.directories.entry(path.into()).or_default();
+        }
+        let (parent, name) = path.rsplit_once('/').unwrap_or(("", path));
+        let entries = self.directories.entry(parent.into()).or_default();
+        entries.insert(name.into(), kind);
+        if !parent.is_empty() && entries.len() == 1 {
+            self.insert(parent, RawDirectoryEntry::Directory);
+        }
+    }
+
+    fn kind(&self, path: &str) -> Option<&RawDirectoryEntry> {
+        if path.is_empty() {
+            return Some(&RawDirectoryEntry::Directory);
+        }
+        let (parent, name) = path.rsplit_once('/').unwrap_or(("", path));
+        self.directories.get(parent)?.get(name)
+    }
+}
+
+#[turbo_tasks::value(serialization = "none")]
+enum ZipEntryContent {
+    Content {
+        #[turbo_tasks(trace_ignore)]
+        content: Vec<u8>,
+        executable: bool,
+    },
+    NotFound,
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystem for ZipFileSystem {
+    #[turbo_tasks::function]
+    async fn read(self: Vc<Self>, fs_path: FileSystemPath) -> Result<Vc<FileContent>> {
+        let index = self.index().await?;
+        if index.kind(&fs_path.path) != Some(&RawDirectoryEntry::File) {
+            return Ok(FileContent::NotFound.cell());
+        }
+        let ZipEntryContent::Content {
+            content,
+            executable,
+        } = &*self.read_entry(fs_path.path.clone()).await?
+        else {
+            return Ok(FileContent::NotFound.cell());
+        };
+        let meta = FileMeta {
+            permissions: if *executable {
+                Permissions::Executable
+            } else {
+                Permissions::Readable
+            },
+            content_type: None,
+        };
+        Ok(FileContent::Content(File::new(meta, content.clone())).cell())
+    }
+
+    #[turbo_tasks::function]
+    async fn read_link(self: Vc<Self>, fs_path: FileSystemPath) -> Result<Vc<LinkContent>> {
+        let index = self.index().await?;
+        if index.kind(&fs_path.path) != Some(&RawDirectoryEntry::Symlink) {
+            return Ok(LinkContent::NotFound.cell());
+        }
+        let ZipEntryContent::Content { content, .. } =
+            &*self.read_entry(fs_path.path.clone()).await?
+        else {
+            return Ok(LinkContent::NotFound.cell());
+        };
+        let target = std::str::from_utf8(content)
+            .with_context(|| format!("symlink {fs_path} has a non utf-8 target"))?;
+        if target.starts_with('/') {
+            // Absolute links can't point into the archive
+            return Ok(LinkContent::Invalid.cell());
+        }
+        Ok(LinkContent::Link {
+            target: target.into(),
+            link_type: LinkType::empty(),
+        }
+        .cell())
+    }
+
+    #[turbo_tasks::function]
+    async fn raw_read_dir(
+        self: Vc<Self>,
+        fs_path: FileSystemPath,
+    ) -> Result<Vc<RawDirectoryContent>> {
+        let index = self.index().await?;
+        Ok(match index.directories.get(&fs_path.path) {
+            Some(entries) => RawDirectoryContent::new(entries.clone()),
+            None => RawDirectoryContent::not_found(),
+        })
+    }
+
+    #[turbo_tasks::function]
+    fn write(&self, _fs_path: FileSystemPath, _content: Vc<FileContent>) -> Result<Vc<()>> {
+        bail!("Writing is not possible to zip archives")
+    }
+
+    #[turbo_tasks::function]
+    fn write_link(&self, _fs_path: FileSystemPath, _target: Vc<LinkContent>) -> Result<Vc<()>> {
+        bail!("Writing is not possible to zip archives")
+    }
+
+    #[turbo_tasks::function]
+    async fn metadata(self: Vc<Self>, fs_path: FileSystemPath) -> Result<Vc<FileMeta>> {
+        let index = self.index().await?;
+        if index.kind(&fs_path.path).is_none() {
+            bail!("path not found, can't read metadata");
+        }
+        Ok(FileMeta::default().cell())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::rcstr;
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+
+    use super::*;
+    use crate::DiskFileSystem;
+
+    /// A path in `tests/fixtures/package.zip`, which contains a package in the layout of Yarn's
+    /// cache: `node_modules/pkg/{package.json,index.js,bin/cli.js,link.js}`. Only `node_modules/`
+    /// has a directory entry, `bin/cli.js` is executable, `link.js` is a symlink to `index.js`, and
+    /// the `../outside.js` entry would escape the archive.
+    async fn zip_path(path: &str) -> Result<FileSystemPath> {
+        let fixtures = DiskFileSystem::new(
+            rcstr!("fixtures"),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures").into(),
+        );
+        let archive = fixtures.root().await?.join("package.zip")?;
+        ZipFileSystem::new(archive).root().await?.join(path)
+    }
+
+    #[turbo_tasks::function(operation)]
+    async fn read_operation(path: RcStr) -> Result<Vc<RcStr>> {
+        Ok(Vc::cell(match &*zip_path(&path).await?.read().await? {
+            FileContent::Content(file) => {
+                let content = file.content().to_str()?;
+                match file.meta().permissions {
+                    Permissions::Executable => format!("{content} (executable)").into(),
+                    _ => content.into(),
+                }
+            }
+            FileContent::NotFound => rcstr!("not found"),
+        }))
+    }
+
+    #[turbo_tasks::function(operation)]
+    async fn read_dir_operation(path: RcStr) -> Result<Vc<Vec<RcStr>>> {
+        let RawDirectoryContent::Entries(entries) = &*zip_path(&path).await?.raw_read_dir().await?
+        else {
+            return Ok(Vc::cell(vec![rcstr!("not found")]));
+        };
+        let mut entries = entries
+            .iter()
+            .map(|(name, kind)| format!("{name} {kind:?}").into())
+            .collect::<Vec<_>>();
+        entries.sort();
+        Ok(Vc::cell(entries))
+    }
+
+    #[turbo_tasks::function(operation)]
+    async fn read_link_operation(path: RcStr) -> Result<Vc<RcStr>> {
+        Ok(Vc::cell(
+            match &*zip_path(&path).await?.read_link().await? {
+                LinkContent::Link { target, .. } => target.clone(),
+                _ => rcstr!("not a link"),
+            },
+        ))
+    }
+
+    #[turbo_tasks::function(operation)]
+    async fn has_metadata_operation(path: RcStr) -> Result<Vc<bool>> {
+        Ok(Vc::cell(zip_path(&path).await?.metadata().await.is_ok()))
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_zip_read() {
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            let read = async |path: &str| -> Result<RcStr> {
+                Ok((*read_operation(path.into())
+                    .read_strongly_consistent()
+                    .await?)
+                    .clone())
+            };
+            assert_eq!(
+                read("node_modules/pkg/package.json").await?,
+                "{\"name\":\"pkg\",\"main\":\"index.js\"}\n"
+            );
+            assert_eq!(
+                read("node_modules/pkg/index.js").await?,
+                "module.exports = 42\n"
+            );
+            assert_eq!(
+                read("node_modules/pkg/bin/cli.js").await?,
+                "#!/usr/bin/env node\n (executable)"
+            );
+            // Directories, symlinks and missing or escaping entries aren't files
+            assert_eq!(read("node_modules/pkg").await?, "not found");
+            assert_eq!(read("node_modules/pkg/link.js").await?, "not found");
+            assert_eq!(read("node_modules/pkg/missing.js").await?, "not found");
+            assert_eq!(read("outside.js").await?, "not found");
+
+            assert_eq!(
+                &*read_link_operation(rcstr!("node_modules/pkg/link.js"))
+                    .read_strongly_consistent()
+                    .await?,
+                "index.js"
+            );
+            assert_eq!(
+                &*read_link_operation(rcstr!("node_modules/pkg/index.js"))
+                    .read_strongly_consistent()
+                    .await?,
+                "not a link"
+            );
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_zip_read_dir_and_metadata() {
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            let read_dir = async |path: &str| -> Result<Vec<RcStr>> {
+                Ok((*read_dir_operation(path.into())
+                    .read_strongly_consistent()
+                    .await?)
+                    .clone())
+            };
+            assert_eq!(read_dir("").await?, vec![rcstr!("node_modules Directory")]);
+            // Directories without an entry of their own are derived from the paths of their files
+            assert_eq!(
+                read_dir("node_modules/pkg").await?,
+                vec![
+                    rcstr!("bin Directory"),
+                    rcstr!("index.js File"),
+                    rcstr!("link.js Symlink"),
+                    rcstr!("package.json File"),
+                ]
+            );
+            assert_eq!(
+                read_dir("node_modules/pkg/bin").await?,
+                vec![rcstr!("cli.js File")]
+            );
+            assert_eq!(
+                read_dir("node_modules/pkg/index.js").await?,
+                vec![rcstr!("not found")]
+            );
+
+            let has_metadata = async |path: &str| -> Result<bool> {
+                Ok(*has_metadata_operation(path.into())
+                    .read_strongly_consistent()
+                    .await?)
+            };
+            assert!(has_metadata("node_modules/pkg").await?);
+            assert!(has_metadata("node_modules/pkg/index.js").await?);
+            assert!(has_metadata("node_modules/pkg/link.js").await?);
+            assert!(!has_metadata("node_modules/pkg/missing.js").await?);
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+}
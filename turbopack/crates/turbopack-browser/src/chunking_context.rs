@@ -18,6 +18,7 @@ use turbopack_core::{
         availability_info::AvailabilityInfo,
         chunk_group::{MakeChunkGroupResult, make_chunk_group},
         chunk_id_strategy::ModuleIdStrategy,
+        property_name_cache::{OptionPropertyNameCache, PropertyNameCache},
     },
     environment::Environment,
     ident::AssetIdent,
@@ -40,7 +41,9 @@ use crate::ecmascript::{
     chunk::EcmascriptBrowserChunk,
     evaluate::chunk::EcmascriptBrowserEvaluateChunk,
     list::asset::{EcmascriptDevChunkList, EcmascriptDevChunkListSource},
+    service_worker::EcmascriptBrowserServiceWorkerEntrypoint,
     worker::EcmascriptBrowserWorkerEntrypoint,
+    worklet::EcmascriptBrowserWorkletEntrypoint,
 };
 
 #[turbo_tasks::value]
@@ -152,6 +155,14 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn property_name_cache(
+        mut self,
+        property_name_cache: Option<ResolvedVc<PropertyNameCache>>,
+    ) -> Self {
+        self.chunking_context.property_name_cache = property_name_cache;
+        self
+    }
+
     pub fn source_maps(mut self, source_maps: SourceMapsType) -> Self {
         self.chunking_context.source_maps_type = source_maps;
         self
@@ -182,6 +193,11 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn profile_module_evaluation(mut self, profile_module_evaluation: bool) -> Self {
+        self.chunking_context.profile_module_evaluation = profile_module_evaluation;
+        self
+    }
+
     pub fn should_use_absolute_url_references(
         mut self,
         should_use_absolute_url_references: bool,
@@ -243,6 +259,13 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    /// Emits service workers at `path`, which is served at `url`.
+    pub fn service_worker_path(mut self, path: FileSystemPath, url: RcStr) -> Self {
+        self.chunking_context.service_worker_path = Some(path);
+        self.chunking_context.service_worker_url = Some(url);
+        self
+    }
+
     pub fn build(self) -> Vc<BrowserChunkingContext> {
         BrowserChunkingContext::cell(self.chunking_context)
     }
@@ -308,12 +331,16 @@ pub struct BrowserChunkingContext {
     enable_dynamic_chunk_content_loading: bool,
     /// Enable debug IDs for chunks and source maps.
     debug_ids: bool,
+    /// Whether the runtime records the evaluation time of each module
+    profile_module_evaluation: bool,
     /// The environment chunks will be evaluated in.
     environment: ResolvedVc<Environment>,
     /// The kind of runtime to include in the output.
     runtime_type: RuntimeType,
     /// Whether to minify resulting chunks
     minify_type: MinifyType,
+    /// The mangled property names of the build, if properties are mangled
+    property_name_cache: Option<ResolvedVc<PropertyNameCache>>,
     /// Whether content hashing is enabled.
     content_hashing: Option<ContentHashing>,
     /// Whether to generate source maps
@@ -337,6 +364,12 @@ pub struct BrowserChunkingContext {
     /// The global variable name used for chunk loading.
     /// Default: "TURBOPACK"
     chunk_loading_global: Option<RcStr>,
+    /// The path service workers are emitted at.
+    /// Default: `sw.js` in the client root
+    service_worker_path: Option<FileSystemPath>,
+    /// The URL service workers are served at.
+    /// Default: the asset URL of `service_worker_path`
+    service_worker_url: Option<RcStr>,
 }
 
 impl BrowserChunkingContext {
@@ -374,9 +407,11 @@ impl BrowserChunkingContext {
                 enable_module_merging: false,
                 enable_dynamic_chunk_content_loading: false,
                 debug_ids: false,
+                profile_module_evaluation: false,
                 environment,
                 runtime_type,
                 minify_type: MinifyType::NoMinify,
+                property_name_cache: None,
                 content_hashing: None,
                 source_maps_type: SourceMapsType::Full,
                 current_chunk_method: CurrentChunkMethod::StringLiteral,
@@ -388,6 +423,8 @@ impl BrowserChunkingContext {
                 should_use_absolute_url_references: false,
                 worker_forwarded_globals: vec![],
                 chunk_loading_global: Default::default(),
+                service_worker_path: None,
+                service_worker_url: None,
             },
         }
     }
@@ -487,7 +524,19 @@ impl BrowserChunkingContext {
     /// Returns the minify type.
     #[turbo_tasks::function]
     pub fn minify_type(&self) -> Vc<MinifyType> {
-        self.minify_type.cell()
+        self.minify_type.clone().cell()
+    }
+
+    /// Returns the mangled property names, if properties are mangled.
+    #[turbo_tasks::function]
+    pub fn property_name_cache(&self) -> Vc<OptionPropertyNameCache> {
+        Vc::cell(self.property_name_cache)
+    }
+
+    /// Returns whether the runtime records the evaluation time of each module.
+    #[turbo_tasks::function]
+    pub fn profile_module_evaluation(&self) -> Vc<bool> {
+        Vc::cell(self.profile_module_evaluation)
     }
 
     /// Returns the chunk path information.
@@ -511,6 +560,36 @@ impl BrowserChunkingContext {
                 .unwrap_or_else(|| rcstr!("TURBOPACK")),
         )
     }
+
+    /// Returns the root path of the client output assets.
+    #[turbo_tasks::function]
+    pub fn client_root(&self) -> Vc<FileSystemPath> {
+        self.client_root.clone().cell()
+    }
+
+    /// Returns the path service workers are emitted at.
+    /// Defaults to `sw.js` in the client root if not set.
+    #[turbo_tasks::function]
+    pub fn service_worker_path(&self) -> Result<Vc<FileSystemPath>> {
+        Ok(match &self.service_worker_path {
+            Some(path) => path.clone().cell(),
+            None => self.client_root.join("sw.js")?.cell(),
+        })
+    }
+
+    /// Returns whether the file name of the output asset at `path` contains a hash of its content,
+    /// i.e. whether its URL changes whenever its content changes.
+    #[turbo_tasks::function]
+    pub fn is_content_hashed_path(&self, path: FileSystemPath) -> Vc<bool> {
+        Vc::cell(
+            path.is_inside_ref(&self.asset_root_path)
+                || self
+                    .asset_root_paths
+                    .values()
+                    .any(|asset_root_path| path.is_inside_ref(asset_root_path))
+                || (self.content_hashing.is_some() && path.is_inside_ref(&self.chunk_root_path)),
+        )
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -723,7 +802,7 @@ impl ChunkingContext for BrowserChunkingContext {
 
     #[turbo_tasks::function]
     pub fn minify_type(&self) -> Vc<MinifyType> {
-        self.minify_type.cell()
+        self.minify_type.clone().cell()
     }
 
     #[turbo_tasks::function]
@@ -975,6 +1054,34 @@ impl ChunkingContext for BrowserChunkingContext {
         let entrypoint = EcmascriptBrowserWorkerEntrypoint::new(*resolved, forwarded_globals);
         Ok(Vc::upcast(entrypoint))
     }
+
+    #[turbo_tasks::function]
+    fn worklet_entrypoint(
+        self: Vc<Self>,
+        ident: Vc<AssetIdent>,
+        chunks: Vc<OutputAssets>,
+    ) -> Vc<Box<dyn OutputAsset>> {
+        Vc::upcast(EcmascriptBrowserWorkletEntrypoint::new(self, ident, chunks))
+    }
+
+    #[turbo_tasks::function]
+    fn service_worker_entrypoint(
+        self: Vc<Self>,
+        ident: Vc<AssetIdent>,
+        chunks: Vc<OutputAssets>,
+    ) -> Vc<Box<dyn OutputAsset>> {
+        Vc::upcast(EcmascriptBrowserServiceWorkerEntrypoint::new(
+            self, ident, chunks,
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn service_worker_url(self: Vc<Self>) -> Result<Vc<RcStr>> {
+        if let Some(url) = &self.await?.service_worker_url {
+            return Ok(Vc::cell(url.clone()));
+        }
+        Ok(self.asset_url(self.service_worker_path().owned().await?, None))
+    }
 }
 
 #[turbo_tasks::value]
@@ -127,8 +127,14 @@ impl EcmascriptBrowserChunkContent {
 
         let mut code = code.build();
 
-        if let MinifyType::Minify { mangle } = *this.chunking_context.minify_type().await? {
-            code = minify(code, source_maps, mangle)?;
+        if let MinifyType::Minify(options) = &*this.chunking_context.minify_type().await? {
+            let property_names =
+                if let Some(cache) = *this.chunking_context.property_name_cache().await? {
+                    Some(cache.await?)
+                } else {
+                    None
+                };
+            code = minify(code, source_maps, options, property_names.as_deref())?;
         }
 
         Ok(code.cell())
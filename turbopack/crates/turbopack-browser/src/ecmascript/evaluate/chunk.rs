@@ -183,6 +183,7 @@ impl EcmascriptBrowserEvaluateChunk {
                     output_root_to_root_path,
                     source_maps,
                     this.chunking_context.chunk_loading_global(),
+                    *this.chunking_context.profile_module_evaluation().await?,
                 );
                 code.push_code(&*runtime_code.await?);
             }
@@ -195,8 +196,14 @@ impl EcmascriptBrowserEvaluateChunk {
 
         let mut code = code.build();
 
-        if let MinifyType::Minify { mangle } = *this.chunking_context.minify_type().await? {
-            code = minify(code, source_maps, mangle)?;
+        if let MinifyType::Minify(options) = &*this.chunking_context.minify_type().await? {
+            let property_names =
+                if let Some(cache) = *this.chunking_context.property_name_cache().await? {
+                    Some(cache.await?)
+                } else {
+                    None
+                };
+            code = minify(code, source_maps, options, property_names.as_deref())?;
         }
 
         Ok(code.cell())
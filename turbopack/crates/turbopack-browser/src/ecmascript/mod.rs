@@ -4,10 +4,16 @@ pub(crate) mod content_entry;
 pub(crate) mod evaluate;
 pub(crate) mod list;
 pub(crate) mod merged;
+pub(crate) mod service_worker;
 pub(crate) mod update;
 pub(crate) mod version;
 pub(crate) mod worker;
+pub(crate) mod worklet;
 
 pub use chunk::EcmascriptBrowserChunk;
 pub use content::EcmascriptBrowserChunkContent;
+pub use service_worker::{
+    EcmascriptBrowserServiceWorkerEntrypoint, with_service_worker_precache_manifests,
+};
 pub use worker::EcmascriptBrowserWorkerEntrypoint;
+pub use worklet::EcmascriptBrowserWorkletEntrypoint;
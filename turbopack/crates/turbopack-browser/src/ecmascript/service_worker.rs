@@ -0,0 +1,256 @@
+use anyhow::Result;
+use serde::Serialize;
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{ResolvedVc, TryJoinIterExt, ValueToString, Vc};
+use turbo_tasks_fs::{File, FileContent, FileSystemPath};
+use turbo_tasks_hash::HashAlgorithm;
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    chunk::ChunkingContext,
+    ident::AssetIdent,
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    output::{ExpandedOutputAssets, OutputAsset, OutputAssets, OutputAssetsReference},
+};
+use turbopack_ecmascript::utils::StringifyJs;
+
+use crate::{BrowserChunkingContext, ecmascript::worklet::inlined_chunks_code};
+
+/// A single script containing all chunks of a service worker, like
+/// [`EcmascriptBrowserWorkletEntrypoint`](super::EcmascriptBrowserWorkletEntrypoint).
+///
+/// The browser checks the registered URL for updates and limits the scope of the service worker to
+/// the path of that URL, so the script is emitted at the configured service worker path (`sw.js` in
+/// the client root by default) instead of a hashed chunk path. It works both as a classic and as a
+/// module script.
+///
+/// The script also sets the precache manifest, which is exposed to the service worker by the
+/// `@vercel/turbopack-ecmascript-runtime/browser/service-worker/precache-manifest` module. It lists
+/// all other client output assets, which are only known once all of them are collected for
+/// emitting, so it's added by [`with_service_worker_precache_manifests`].
+#[turbo_tasks::value(shared)]
+#[derive(ValueToString)]
+#[value_to_string("Ecmascript Browser Service Worker Entrypoint")]
+pub struct EcmascriptBrowserServiceWorkerEntrypoint {
+    chunking_context: ResolvedVc<BrowserChunkingContext>,
+    ident: ResolvedVc<AssetIdent>,
+    chunks: ResolvedVc<OutputAssets>,
+    precache_assets: Option<ResolvedVc<OutputAssets>>,
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptBrowserServiceWorkerEntrypoint {
+    #[turbo_tasks::function]
+    pub fn new(
+        chunking_context: ResolvedVc<BrowserChunkingContext>,
+        ident: ResolvedVc<AssetIdent>,
+        chunks: ResolvedVc<OutputAssets>,
+    ) -> Vc<Self> {
+        EcmascriptBrowserServiceWorkerEntrypoint {
+            chunking_context,
+            ident,
+            chunks,
+            precache_assets: None,
+        }
+        .cell()
+    }
+
+    /// Returns the same service worker, with `precache_assets` in its precache manifest.
+    #[turbo_tasks::function]
+    pub fn with_precache_assets(&self, precache_assets: ResolvedVc<OutputAssets>) -> Vc<Self> {
+        EcmascriptBrowserServiceWorkerEntrypoint {
+            chunking_context: self.chunking_context,
+            ident: self.ident,
+            chunks: self.chunks,
+            precache_assets: Some(precache_assets),
+        }
+        .cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAssetsReference for EcmascriptBrowserServiceWorkerEntrypoint {}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for EcmascriptBrowserServiceWorkerEntrypoint {
+    #[turbo_tasks::function]
+    fn path(&self) -> Vc<FileSystemPath> {
+        self.chunking_context.service_worker_path()
+    }
+}
+
+/// An entry of the precache manifest, in the format used by Workbox.
+#[derive(Serialize)]
+struct PrecacheEntry {
+    url: RcStr,
+    /// `None` if the URL already changes whenever the content changes.
+    revision: Option<RcStr>,
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for EcmascriptBrowserServiceWorkerEntrypoint {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let precache_manifest = match self.precache_assets {
+            Some(precache_assets) => {
+                precache_assets
+                    .await?
+                    .iter()
+                    .map(async |asset| {
+                        let path = asset.path().owned().await?;
+                        let url = self
+                            .chunking_context
+                            .asset_url(path.clone(), None)
+                            .owned()
+                            .await?;
+                        let revision =
+                            if *self.chunking_context.is_content_hashed_path(path).await? {
+                                None
+                            } else {
+                                asset
+                                    .content()
+                                    .content_hash(HashAlgorithm::default())
+                                    .owned()
+                                    .await?
+                            };
+                        Ok(PrecacheEntry { url, revision })
+                    })
+                    .try_join()
+                    .await?
+            }
+            None => vec![],
+        };
+
+        let mut code = format!(
+            "self.TURBOPACK_PRECACHE_MANIFEST = {};\n",
+            StringifyJs(&precache_manifest)
+        );
+        code.push_str(&inlined_chunks_code(*self.chunking_context, *self.chunks).await?);
+
+        Ok(AssetContent::file(
+            FileContent::Content(File::from(code)).cell(),
+        ))
+    }
+}
+
+/// Adds the precache manifest to all service workers in `assets`. It lists all other assets in the
+/// client root of the service worker, except for source maps.
+///
+/// Only a single asset can be emitted at the service worker path. When a service worker collides
+/// with another asset, an issue is emitted and only the first of them is kept.
+#[turbo_tasks::function]
+pub async fn with_service_worker_precache_manifests(
+    assets: Vc<ExpandedOutputAssets>,
+) -> Result<Vc<ExpandedOutputAssets>> {
+    let assets_ref = assets.await?;
+    if !assets_ref.iter().any(|&asset| {
+        ResolvedVc::try_downcast_type::<EcmascriptBrowserServiceWorkerEntrypoint>(asset).is_some()
+    }) {
+        return Ok(assets);
+    }
+
+    let paths = assets_ref
+        .iter()
+        .map(async |asset| asset.path().owned().await)
+        .try_join()
+        .await?;
+
+    let mut result = Vec::with_capacity(assets_ref.len());
+    for (index, (&asset, path)) in assets_ref.iter().zip(&paths).enumerate() {
+        let Some(service_worker) =
+            ResolvedVc::try_downcast_type::<EcmascriptBrowserServiceWorkerEntrypoint>(asset)
+        else {
+            result.push(asset);
+            continue;
+        };
+        let mut same_path = paths
+            .iter()
+            .enumerate()
+            .filter(|&(_, other_path)| other_path == path)
+            .map(|(other_index, _)| other_index);
+        let first = same_path.next();
+        if same_path.next().is_some() {
+            ServiceWorkerPathCollisionIssue {
+                path: path.clone(),
+                source_path: service_worker.await?.ident.path().owned().await?,
+            }
+            .resolved_cell()
+            .emit();
+            if first != Some(index) {
+                continue;
+            }
+        }
+        let client_root = service_worker
+            .await?
+            .chunking_context
+            .client_root()
+            .owned()
+            .await?;
+        let precache_assets = assets_ref
+            .iter()
+            .zip(&paths)
+            .filter(|&(&asset, path)| {
+                path.is_inside_ref(&client_root)
+                    && path.extension_ref() != Some("map")
+                    && ResolvedVc::try_downcast_type::<EcmascriptBrowserServiceWorkerEntrypoint>(
+                        asset,
+                    )
+                    .is_none()
+            })
+            .map(|(&asset, _)| asset)
+            .collect();
+        result.push(ResolvedVc::upcast(
+            service_worker
+                .with_precache_assets(Vc::cell(precache_assets))
+                .to_resolved()
+                .await?,
+        ));
+    }
+    Ok(Vc::cell(result))
+}
+
+/// A service worker is emitted at the same path as another output asset, e.g. because the app
+/// registers multiple service workers.
+#[turbo_tasks::value(shared)]
+struct ServiceWorkerPathCollisionIssue {
+    path: FileSystemPath,
+    source_path: FileSystemPath,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for ServiceWorkerPathCollisionIssue {
+    fn severity(&self) -> IssueSeverity {
+        IssueSeverity::Error
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(rcstr!(
+            "Service worker conflicts with another asset at the service worker path"
+        ))
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Misc.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.source_path.clone().cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Line(vec![
+                StyledString::Text(rcstr!("Only a single asset can be emitted at ")),
+                StyledString::Code(self.path.path.clone()),
+                StyledString::Text(rcstr!(
+                    ". Register a single service worker and import the other scripts from it."
+                )),
+            ])
+            .resolved_cell(),
+        ))
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::Write;
+
+use anyhow::{Result, bail};
+use indoc::writedoc;
+use turbo_rcstr::rcstr;
+use turbo_tasks::{ResolvedVc, TryFlatJoinIterExt, ValueToString, Vc};
+use turbo_tasks_fs::{File, FileContent, FileSystemPath};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    chunk::{AssetSuffix, ChunkingContext},
+    ident::AssetIdent,
+    output::{OutputAsset, OutputAssets, OutputAssetsReference},
+};
+use turbopack_ecmascript::utils::StringifyJs;
+
+use crate::{BrowserChunkingContext, ecmascript::list::asset::EcmascriptDevChunkList};
+
+/// A single script containing all chunks of a worklet, followed by the evaluate chunk with the
+/// runtime.
+///
+/// Worklets have neither `importScripts` nor `document.currentScript`. The chunks register
+/// themselves when the runtime starts, so the bootstrap code provides their URLs the same way the
+/// web worker entrypoint does, and the runtime finds them already registered instead of loading
+/// them.
+#[turbo_tasks::value(shared)]
+#[derive(ValueToString)]
+#[value_to_string("Ecmascript Browser Worklet Entrypoint")]
+pub struct EcmascriptBrowserWorkletEntrypoint {
+    chunking_context: ResolvedVc<BrowserChunkingContext>,
+    ident: ResolvedVc<AssetIdent>,
+    chunks: ResolvedVc<OutputAssets>,
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptBrowserWorkletEntrypoint {
+    #[turbo_tasks::function]
+    pub fn new(
+        chunking_context: ResolvedVc<BrowserChunkingContext>,
+        ident: ResolvedVc<AssetIdent>,
+        chunks: ResolvedVc<OutputAssets>,
+    ) -> Vc<Self> {
+        EcmascriptBrowserWorkletEntrypoint {
+            chunking_context,
+            ident,
+            chunks,
+        }
+        .cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAssetsReference for EcmascriptBrowserWorkletEntrypoint {}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for EcmascriptBrowserWorkletEntrypoint {
+    #[turbo_tasks::function]
+    async fn path(self: Vc<Self>) -> Result<Vc<FileSystemPath>> {
+        let this = self.await?;
+        Ok(this.chunking_context.chunk_path(
+            Some(Vc::upcast(self)),
+            *this.ident,
+            Some(rcstr!("[worklet]")),
+            rcstr!(".js"),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for EcmascriptBrowserWorkletEntrypoint {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let code = inlined_chunks_code(*self.chunking_context, *self.chunks).await?;
+        Ok(AssetContent::file(
+            FileContent::Content(File::from(code)).cell(),
+        ))
+    }
+}
+
+/// Returns the code of a single script that runs all `chunks` without loading any of them.
+pub(crate) async fn inlined_chunks_code(
+    chunking_context: Vc<BrowserChunkingContext>,
+    chunks: Vc<OutputAssets>,
+) -> Result<String> {
+    let output_root = chunking_context.output_root().owned().await?;
+    let chunk_base_path = chunking_context.chunk_base_path().owned().await?;
+    // The runtime can't infer the suffix without a script element and neither worklets nor
+    // service workers can access the main thread's globals, so only a constant suffix is known.
+    let asset_suffix = match &*chunking_context.asset_suffix().await? {
+        AssetSuffix::Constant(suffix) => suffix.clone(),
+        AssetSuffix::None | AssetSuffix::Inferred | AssetSuffix::FromGlobal(_) => rcstr!(""),
+    };
+
+    let chunks = chunks
+        .await?
+        .iter()
+        .map(async |&chunk| {
+            // Neither worklets nor service workers support HMR, so there is no need to register
+            // the chunk list.
+            if ResolvedVc::try_downcast_type::<EcmascriptDevChunkList>(chunk).is_some() {
+                return Ok(None);
+            }
+            let path = chunk.path().await?;
+            if path.extension_ref() != Some("js") {
+                return Ok(None);
+            }
+            let Some(chunk_path) = output_root.get_path_to(&path) else {
+                bail!("chunk path {path} is not in output root {output_root}");
+            };
+            let FileContent::Content(file) = &*chunk.content().file_content().await? else {
+                bail!("chunk {path} has no content");
+            };
+            Ok(Some((
+                chunk_path.to_string(),
+                file.content().to_str()?.into_owned(),
+            )))
+        })
+        .try_flat_join()
+        .await?;
+
+    // `TURBOPACK_NEXT_CHUNK_URLS` is popped by the runtime, so it's stored in reverse order.
+    let chunk_paths: Vec<_> = chunks.iter().rev().map(|(path, _)| path).collect();
+    let mut code = String::new();
+    writedoc!(
+        code,
+        r#"
+            if (typeof self === "undefined") {{
+                globalThis.self = globalThis;
+            }}
+            self.TURBOPACK_ASSET_SUFFIX = {asset_suffix};
+            self.TURBOPACK_NEXT_CHUNK_URLS = {chunk_paths}.map(
+                (path) => {chunk_base_path} + path.split("/").map(encodeURIComponent).join("/") + {asset_suffix}
+            );
+        "#,
+        asset_suffix = StringifyJs(&asset_suffix),
+        chunk_paths = StringifyJs(&chunk_paths),
+        chunk_base_path = StringifyJs(chunk_base_path.as_deref().unwrap_or("")),
+    )?;
+    for (_, content) in &chunks {
+        // The source maps of the chunks aren't emitted, as the chunks are only inlined.
+        for line in content.lines() {
+            if !line.starts_with("//# sourceMappingURL=") {
+                writeln!(code, "{line}")?;
+            }
+        }
+    }
+    Ok(code)
+}
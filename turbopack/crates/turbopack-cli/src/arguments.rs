@@ -154,6 +154,14 @@ pub struct BuildArguments {
     #[clap(long)]
     pub no_scope_hoist: bool,
 
+    /// Write all issues to a SARIF 2.1.0 file, e.g. for code scanning.
+    #[clap(long, value_parser)]
+    pub issues_sarif: Option<PathBuf>,
+
+    /// Write all issues to a JSON Lines file, one issue per line.
+    #[clap(long, value_parser)]
+    pub issues_json_lines: Option<PathBuf>,
+
     /// Drop the `TurboTasks` object upon exit. By default we intentionally leak this memory, as
     /// we're about to exit the process anyways, but that can cause issues with valgrind or other
     /// leak detectors.
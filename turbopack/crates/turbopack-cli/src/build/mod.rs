@@ -1,7 +1,9 @@
 use std::{
     env::current_dir,
+    fs::File,
+    io::BufWriter,
     mem::forget,
-    path::{MAIN_SEPARATOR, PathBuf},
+    path::{MAIN_SEPARATOR, Path, PathBuf},
     sync::Arc,
 };
 
@@ -13,27 +15,34 @@ use turbo_tasks::{ResolvedVc, TransientInstance, TryJoinIterExt, TurboTasks, Vc,
 use turbo_tasks_backend::{
     BackendOptions, NoopBackingStorage, TurboTasksBackend, noop_backing_storage,
 };
-use turbo_tasks_fs::FileSystem;
+use turbo_tasks_fs::{FileSystem, util::uri_from_path_buf};
 use turbo_unix_path::join_path;
 use turbopack::global_module_ids::get_global_module_id_strategy;
-use turbopack_browser::{BrowserChunkingContext, ContentHashing, CurrentChunkMethod};
+use turbopack_browser::{
+    BrowserChunkingContext, ContentHashing, CurrentChunkMethod,
+    ecmascript::with_service_worker_precache_manifests,
+};
 use turbopack_cli_utils::issue::{ConsoleUi, LogOptions};
 use turbopack_core::{
     asset::Asset,
     chunk::{
         ChunkingConfig, ChunkingContext, ChunkingContextExt, EvaluatableAsset, EvaluatableAssets,
-        MangleType, MinifyType, SourceMapsType, availability_info::AvailabilityInfo,
+        MangleType, MinifyOptions, MinifyType, SourceMapsType, availability_info::AvailabilityInfo,
     },
     environment::{BrowserEnvironment, Environment, ExecutionEnvironment, NodeJsEnvironment},
     ident::AssetIdent,
-    issue::{IssueReporter, IssueSeverity, handle_issues},
+    issue::{
+        CollectibleIssuesExt, IssueFilter, IssueReporter, IssueSeverity, PlainIssue,
+        export::{IssueJsonLinesWriter, SarifTool, write_sarif},
+        handle_issues,
+    },
     module::Module,
     module_graph::{
         ModuleGraph, SingleModuleGraph,
         binding_usage_info::compute_binding_usage_info,
         chunk_group_info::{ChunkGroup, ChunkGroupEntry},
     },
-    output::{OutputAsset, OutputAssets, OutputAssetsWithReferenced},
+    output::{ExpandedOutputAssets, OutputAsset, OutputAssets, OutputAssetsWithReferenced},
     reference_type::{EntryReferenceSubType, ReferenceType},
     resolve::{
         origin::{PlainResolveOrigin, ResolveOrigin, ResolveOriginExt},
@@ -70,6 +79,8 @@ pub struct TurbopackBuildBuilder {
     minify_type: MinifyType,
     target: Target,
     scope_hoist: bool,
+    issues_sarif: Option<PathBuf>,
+    issues_json_lines: Option<PathBuf>,
 }
 
 impl TurbopackBuildBuilder {
@@ -86,11 +97,14 @@ impl TurbopackBuildBuilder {
             show_all: false,
             log_detail: false,
             source_maps_type: SourceMapsType::Full,
-            minify_type: MinifyType::Minify {
+            minify_type: MinifyType::Minify(MinifyOptions {
                 mangle: Some(MangleType::OptimalSize),
-            },
+                ..Default::default()
+            }),
             target: Target::Node,
             scope_hoist: true,
+            issues_sarif: None,
+            issues_json_lines: None,
         }
     }
 
@@ -139,7 +153,21 @@ impl TurbopackBuildBuilder {
         self
     }
 
+    /// Writes all issues at or above the log level to a SARIF 2.1.0 file.
+    pub fn issues_sarif(mut self, issues_sarif: Option<PathBuf>) -> Self {
+        self.issues_sarif = issues_sarif;
+        self
+    }
+
+    /// Writes all issues at or above the log level to a JSON Lines file.
+    pub fn issues_json_lines(mut self, issues_json_lines: Option<PathBuf>) -> Self {
+        self.issues_json_lines = issues_json_lines;
+        self
+    }
+
     pub async fn build(self) -> Result<()> {
+        // Issues are reported relative to the root of the project file system
+        let src_root_uri = format!("{}/", uri_from_path_buf(PathBuf::from(&*self.root_dir)));
         self.turbo_tasks
             .run_once(async move {
                 let build_result_op = build_internal(
@@ -158,6 +186,24 @@ impl TurbopackBuildBuilder {
 
                 apply_effects(build_result_op).await?;
 
+                if self.issues_sarif.is_some() || self.issues_json_lines.is_some() {
+                    let issues = build_result_op
+                        .peek_issues()
+                        .get_plain_issues(IssueFilter::everything())
+                        .await?;
+                    let issues = issues
+                        .iter()
+                        .filter(|issue| issue.severity <= self.log_level)
+                        .map(|issue| &**issue)
+                        .collect::<Vec<_>>();
+                    write_issue_reports(
+                        &issues,
+                        &src_root_uri,
+                        self.issues_sarif.as_deref(),
+                        self.issues_json_lines.as_deref(),
+                    )?;
+                }
+
                 let issue_reporter: Vc<Box<dyn IssueReporter>> =
                     Vc::upcast(ConsoleUi::new(TransientInstance::new(LogOptions {
                         project_dir: PathBuf::from(self.project_dir),
@@ -182,6 +228,37 @@ impl TurbopackBuildBuilder {
     }
 }
 
+fn write_issue_reports(
+    issues: &[&PlainIssue],
+    src_root_uri: &str,
+    sarif: Option<&Path>,
+    json_lines: Option<&Path>,
+) -> Result<()> {
+    if let Some(path) = sarif {
+        let file =
+            File::create(path).with_context(|| format!("Unable to create {}", path.display()))?;
+        write_sarif(
+            BufWriter::new(file),
+            &SarifTool {
+                name: "turbopack",
+                version: env!("CARGO_PKG_VERSION"),
+                information_uri: "https://turbo.build/pack",
+            },
+            src_root_uri,
+            issues.iter().copied(),
+        )?;
+    }
+    if let Some(path) = json_lines {
+        let file =
+            File::create(path).with_context(|| format!("Unable to create {}", path.display()))?;
+        let mut writer = IssueJsonLinesWriter::new(BufWriter::new(file));
+        for issue in issues {
+            writer.write_issue(issue)?;
+        }
+    }
+    Ok(())
+}
+
 #[turbo_tasks::function(operation)]
 async fn build_internal(
     project_dir: RcStr,
@@ -503,6 +580,11 @@ async fn build_internal(
     }
     .instrument(tracing::info_span!("list chunks"))
     .await?;
+    // Service workers precache the other assets, which are only known at this point.
+    let all_assets = with_service_worker_precache_manifests(Vc::<ExpandedOutputAssets>::cell(
+        all_assets.into_iter().collect(),
+    ))
+    .await?;
 
     all_assets
         .iter()
@@ -543,12 +625,15 @@ pub async fn build(args: &BuildArguments) -> Result<()> {
         .minify_type(if args.no_minify {
             MinifyType::NoMinify
         } else {
-            MinifyType::Minify {
+            MinifyType::Minify(MinifyOptions {
                 mangle: Some(MangleType::OptimalSize),
-            }
+                ..Default::default()
+            })
         })
         .scope_hoist(!args.no_scope_hoist)
         .target(args.common.target.unwrap_or(Target::Node))
+        .issues_sarif(args.issues_sarif.clone())
+        .issues_json_lines(args.issues_json_lines.clone())
         .show_all(args.common.show_all);
 
     for entry in normalize_entries(&args.common.entries) {
@@ -3,7 +3,9 @@ use bincode::{Decode, Encode};
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use turbo_rcstr::RcStr;
-use turbo_tasks::{NonLocalValue, ResolvedVc, TaskInput, Upcast, Vc, trace::TraceRawVcs};
+use turbo_tasks::{
+    NonLocalValue, OperationValue, ResolvedVc, TaskInput, Upcast, Vc, trace::TraceRawVcs,
+};
 use turbo_tasks_fs::FileSystemPath;
 use turbo_tasks_hash::DeterministicHash;
 
@@ -48,20 +50,111 @@ pub enum MangleType {
     Deterministic,
 }
 
+/// Selects the property names that are mangled, see [ManglePropertiesOptions].
+#[derive(
+    Debug,
+    TaskInput,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Deserialize,
+    TraceRawVcs,
+    DeterministicHash,
+    NonLocalValue,
+    OperationValue,
+    Encode,
+    Decode,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyManglingFilter {
+    /// Property names starting with the prefix, e.g. `_`.
+    Prefix(RcStr),
+    /// Property names matching the regular expression, e.g. `^_[a-z]`.
+    Regex(RcStr),
+}
+
+/// Options for mangling property names, see [MinifyOptions::mangle_properties].
+#[derive(
+    Debug,
+    TaskInput,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Deserialize,
+    TraceRawVcs,
+    DeterministicHash,
+    NonLocalValue,
+    OperationValue,
+    Encode,
+    Decode,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ManglePropertiesOptions {
+    pub filter: PropertyManglingFilter,
+    /// Property names that are never mangled, even when they match the filter.
+    #[serde(default)]
+    pub reserved: Vec<RcStr>,
+}
+
+/// Options for minifying ECMAScript output, see [MinifyType::Minify].
+#[derive(
+    Debug,
+    Default,
+    TaskInput,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Deserialize,
+    TraceRawVcs,
+    DeterministicHash,
+    NonLocalValue,
+    Encode,
+    Decode,
+)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MinifyOptions {
+    /// How to mangle identifiers. `None` disables mangling.
+    pub mangle: Option<MangleType>,
+    /// Drops all `console.*` calls.
+    pub drop_console: bool,
+    /// Calls to these functions are treated as side effect free and dropped when their result
+    /// is unused, e.g. `["console.info", "invariant"]`.
+    pub pure_funcs: Vec<RcStr>,
+    /// Keeps class names when mangling, e.g. for code that relies on `constructor.name`.
+    pub keep_classnames: bool,
+    /// Keeps function names when mangling.
+    pub keep_fnames: bool,
+    /// The number of compress passes. Defaults to 2, which is a tradeoff between performance and
+    /// compression size.
+    pub passes: Option<usize>,
+    /// The ECMAScript version the minified output may use, e.g. `2017`. Defaults to the latest
+    /// version.
+    pub ecma: Option<u16>,
+    /// Mangles property names, in addition to identifiers. This only applies when `mangle` is
+    /// set.
+    ///
+    /// Mangled names need to be the same in all chunks, so they are looked up in the
+    /// [PropertyNameCache](super::property_name_cache::PropertyNameCache) of the chunking context.
+    /// Properties that aren't in the cache are left as is.
+    pub mangle_properties: Option<ManglePropertiesOptions>,
+}
+
 #[turbo_tasks::value(shared)]
-#[derive(Debug, TaskInput, Clone, Copy, Hash, DeterministicHash, Deserialize)]
+#[derive(Debug, TaskInput, Clone, Hash, DeterministicHash, Deserialize)]
 pub enum MinifyType {
-    // TODO instead of adding a new property here,
-    // refactor that to Minify(MinifyOptions) to allow defaults on MinifyOptions
-    Minify { mangle: Option<MangleType> },
+    Minify(MinifyOptions),
     NoMinify,
 }
 
 impl Default for MinifyType {
     fn default() -> Self {
-        Self::Minify {
+        Self::Minify(MinifyOptions {
             mangle: Some(MangleType::OptimalSize),
-        }
+            ..Default::default()
+        })
     }
 }
 
@@ -498,6 +591,44 @@ pub trait ChunkingContext {
             name = self.name().await?
         );
     }
+
+    /// Returns a single output asset containing all `chunks` of a worklet's evaluated chunk group.
+    /// Worklets can neither load scripts nor import modules dynamically, so the chunks can't be
+    /// loaded by the runtime and need to be inlined instead.
+    #[turbo_tasks::function]
+    async fn worklet_entrypoint(
+        self: Vc<Self>,
+        _ident: Vc<AssetIdent>,
+        _chunks: Vc<OutputAssets>,
+    ) -> Result<Vc<Box<dyn OutputAsset>>> {
+        bail!(
+            "Worklets are not supported by {name}",
+            name = self.name().await?
+        );
+    }
+
+    /// Returns a single output asset containing all `chunks` of a service worker's evaluated chunk
+    /// group. Unlike other chunks, it's emitted at a stable path derived from `ident`, as the
+    /// browser checks the registered URL for updates.
+    #[turbo_tasks::function]
+    async fn service_worker_entrypoint(
+        self: Vc<Self>,
+        _ident: Vc<AssetIdent>,
+        _chunks: Vc<OutputAssets>,
+    ) -> Result<Vc<Box<dyn OutputAsset>>> {
+        bail!(
+            "Service workers are not supported by {name}",
+            name = self.name().await?
+        );
+    }
+    /// Returns the URL the service workers of this chunking context are served at.
+    #[turbo_tasks::function]
+    async fn service_worker_url(self: Vc<Self>) -> Result<Vc<RcStr>> {
+        bail!(
+            "Service workers are not supported by {name}",
+            name = self.name().await?
+        );
+    }
 }
 pub trait ChunkingContextExt {
     fn root_chunk_group(
@@ -7,6 +7,7 @@ pub mod chunking;
 pub(crate) mod chunking_context;
 pub(crate) mod data;
 pub(crate) mod evaluate;
+pub mod property_name_cache;
 
 use std::fmt::Display;
 
@@ -28,8 +29,9 @@ pub use crate::chunk::{
     },
     chunking_context::{
         AssetSuffix, ChunkGroupResult, ChunkGroupType, ChunkingConfig, ChunkingConfigs,
-        ChunkingContext, ChunkingContextExt, EntryChunkGroupResult, MangleType, MinifyType,
-        SourceMapSourceType, SourceMapsType, UnusedReferences, UrlBehavior,
+        ChunkingContext, ChunkingContextExt, EntryChunkGroupResult, ManglePropertiesOptions,
+        MangleType, MinifyOptions, MinifyType, PropertyManglingFilter, SourceMapSourceType,
+        SourceMapsType, UnusedReferences, UrlBehavior,
     },
     data::{ChunkData, ChunkDataOption, ChunksData},
     evaluate::{EvaluatableAsset, EvaluatableAssetExt, EvaluatableAssets},
@@ -0,0 +1,12 @@
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, ResolvedVc};
+
+/// Maps property names to their mangled names.
+///
+/// When mangling properties, the same names need to be used in all chunks of a build, so this is
+/// computed once from the whole module graph and passed to the chunking contexts.
+#[turbo_tasks::value(transparent)]
+pub struct PropertyNameCache(#[bincode(with = "turbo_bincode::indexmap")] FxIndexMap<RcStr, RcStr>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionPropertyNameCache(Option<ResolvedVc<PropertyNameCache>>);
@@ -0,0 +1,235 @@
+use std::collections::BTreeSet;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::ReadRef;
+use turbo_tasks_hash::Xxh3Hash64Hasher;
+
+use super::{IssueSeverity, PlainIssue};
+
+const BASELINE_VERSION: u32 = 1;
+
+/// A known issue in an [IssueBaseline]. Entries are ordered by file path first, so that the
+/// issues of a file are next to each other in the baseline file.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueBaselineEntry {
+    pub file_path: RcStr,
+    pub title: RcStr,
+    /// Hash of the severity, file path, stage and title as hex string. The source range and the
+    /// description are not part of it, as they often contain the location of the issue, so that an
+    /// issue stays baselined when unrelated code above it changes.
+    pub hash: RcStr,
+}
+
+impl IssueBaselineEntry {
+    pub fn from_issue(issue: &PlainIssue) -> Self {
+        let mut hasher = Xxh3Hash64Hasher::new();
+        hasher.write_ref(&issue.severity);
+        hasher.write_ref(&issue.file_path);
+        hasher.write_ref(&issue.stage);
+        hasher.write_ref(&issue.title);
+        Self {
+            file_path: issue.file_path.clone(),
+            title: issue.title.to_unstyled_string().into(),
+            hash: format!("{:016x}", hasher.finish()).into(),
+        }
+    }
+}
+
+/// The set of issues that were accepted at some point, e.g. the warnings of a legacy app. Issues
+/// that are not part of the baseline can be escalated to errors, so that no new warnings are
+/// introduced while the existing ones are fixed gradually.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IssueBaseline {
+    entries: BTreeSet<IssueBaselineEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IssueBaselineFile {
+    version: u32,
+    issues: Vec<IssueBaselineEntry>,
+}
+
+impl IssueBaseline {
+    /// Creates a baseline of the warnings. Errors are not part of the baseline, as they have to be
+    /// fixed anyway, and hints and infos are never escalated.
+    pub fn from_issues<'a>(issues: impl IntoIterator<Item = &'a PlainIssue>) -> Self {
+        Self {
+            entries: issues
+                .into_iter()
+                .filter(|issue| issue.severity == IssueSeverity::Warning)
+                .map(IssueBaselineEntry::from_issue)
+                .collect(),
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let file: IssueBaselineFile =
+            serde_json::from_str(json).context("Unable to parse the issue baseline")?;
+        if file.version != BASELINE_VERSION {
+            bail!(
+                "Unsupported issue baseline version {}, expected {BASELINE_VERSION}",
+                file.version
+            );
+        }
+        Ok(Self {
+            entries: file.issues.into_iter().collect(),
+        })
+    }
+
+    /// Serializes the baseline with sorted entries, so that it produces small diffs when it's
+    /// checked in.
+    pub fn to_json(&self) -> Result<String> {
+        let mut json = serde_json::to_string_pretty(&IssueBaselineFile {
+            version: BASELINE_VERSION,
+            issues: self.entries.iter().cloned().collect(),
+        })?;
+        json.push('\n');
+        Ok(json)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, issue: &PlainIssue) -> bool {
+        self.entries
+            .contains(&IssueBaselineEntry::from_issue(issue))
+    }
+
+    /// Escalates every warning which is not part of the baseline to an error.
+    pub fn escalate_new_issues(&self, issues: &mut [ReadRef<PlainIssue>]) {
+        for issue in issues {
+            if issue.severity == IssueSeverity::Warning && !self.contains(issue) {
+                let mut escalated = (**issue).clone();
+                escalated.severity = IssueSeverity::Error;
+                *issue = ReadRef::new_owned(escalated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::{RcStr, rcstr};
+    use turbo_tasks::ReadRef;
+    use turbo_tasks_fs::FileContent;
+
+    use super::IssueBaseline;
+    use crate::{
+        issue::{
+            IssueSeverity, IssueStage, PlainIssue, PlainIssueSource, PlainSource, StyledString,
+        },
+        source_pos::SourcePos,
+    };
+
+    fn issue(severity: IssueSeverity, file_path: RcStr, title: &str) -> PlainIssue {
+        PlainIssue {
+            severity,
+            stage: IssueStage::Resolve,
+            title: StyledString::Text(title.into()),
+            file_path,
+            description: None,
+            detail: None,
+            documentation_link: rcstr!(""),
+            source: None,
+            import_traces: vec![],
+            fixes: vec![],
+        }
+    }
+
+    #[test]
+    fn escalates_issues_missing_from_the_baseline() {
+        let known = issue(IssueSeverity::Warning, rcstr!("[project]/a.js"), "Known");
+        let hint = issue(IssueSeverity::Hint, rcstr!("[project]/c.js"), "Hint");
+        let baseline = IssueBaseline::from_issues([
+            &known,
+            &issue(IssueSeverity::Error, rcstr!("[project]/b.js"), "Error"),
+            &hint,
+        ]);
+        assert_eq!(baseline.len(), 1);
+
+        let mut issues = vec![
+            ReadRef::new_owned(known.clone()),
+            ReadRef::new_owned(issue(
+                IssueSeverity::Warning,
+                rcstr!("[project]/a.js"),
+                "New",
+            )),
+            ReadRef::new_owned(hint),
+        ];
+        baseline.escalate_new_issues(&mut issues);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(issues[1].severity, IssueSeverity::Error);
+        assert_eq!(issues[2].severity, IssueSeverity::Hint);
+    }
+
+    #[test]
+    fn ignores_the_source_range() {
+        let with_range = |line| PlainIssue {
+            source: Some(PlainIssueSource {
+                asset: ReadRef::new_owned(PlainSource {
+                    ident: ReadRef::new_owned(rcstr!("[project]/a.js")),
+                    content: ReadRef::new_owned(FileContent::NotFound),
+                }),
+                range: Some((SourcePos::new(line), SourcePos::new(line + 1))),
+            }),
+            ..issue(IssueSeverity::Warning, rcstr!("[project]/a.js"), "Known")
+        };
+        let baseline = IssueBaseline::from_issues([&with_range(3)]);
+
+        // e.g. lines were added above the issue
+        assert!(baseline.contains(&with_range(10)));
+        assert!(!baseline.contains(&PlainIssue {
+            title: StyledString::Text(rcstr!("Another problem")),
+            ..with_range(3)
+        }));
+    }
+
+    #[test]
+    fn matches_issues_moved_within_a_file() {
+        let at_line = |line: u32| PlainIssue {
+            source: Some(PlainIssueSource {
+                asset: ReadRef::new_owned(PlainSource {
+                    ident: ReadRef::new_owned(rcstr!("[project]/a.js")),
+                    content: ReadRef::new_owned(FileContent::NotFound),
+                }),
+                range: Some((SourcePos::new(line), SourcePos::new(line))),
+            }),
+            description: Some(StyledString::Text(
+                format!("`foo` is imported at line {line}, but never exported").into(),
+            )),
+            ..issue(
+                IssueSeverity::Warning,
+                rcstr!("[project]/a.js"),
+                "Missing export",
+            )
+        };
+        let baseline = IssueBaseline::from_issues([&at_line(3)]);
+
+        // e.g. the import was moved to the bottom of the file
+        assert!(baseline.contains(&at_line(42)));
+        assert!(!baseline.contains(&PlainIssue {
+            file_path: rcstr!("[project]/b.js"),
+            ..at_line(3)
+        }));
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let baseline = IssueBaseline::from_issues([
+            &issue(IssueSeverity::Warning, rcstr!("[project]/b.js"), "B"),
+            &issue(IssueSeverity::Warning, rcstr!("[project]/a.js"), "A"),
+        ]);
+        let json = baseline.to_json().unwrap();
+        assert!(json.find("a.js").unwrap() < json.find("b.js").unwrap());
+        assert_eq!(IssueBaseline::from_json(&json).unwrap(), baseline);
+        assert!(IssueBaseline::from_json(r#"{"version":2,"issues":[]}"#).is_err());
+    }
+}
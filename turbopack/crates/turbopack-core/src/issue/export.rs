@@ -0,0 +1,341 @@
+//! Machine-readable exports of [PlainIssue]s, e.g. for code scanning dashboards and CI
+//! annotations.
+
+use std::io::Write;
+
+use anyhow::Result;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use super::{IssueSeverity, PlainIssue, PlainTrace, StyledString};
+use crate::source_pos::SourcePos;
+
+const PROJECT_PREFIX: &str = "[project]/";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_SRCROOT: &str = "%SRCROOT%";
+
+/// The tool that reported the issues in a SARIF log.
+pub struct SarifTool<'a> {
+    pub name: &'a str,
+    pub version: &'a str,
+    pub information_uri: &'a str,
+}
+
+/// Converts the issues to a SARIF 2.1.0 log with a single run.
+///
+/// Files of the project file system are referenced relative to `%SRCROOT%`, i.e. the root
+/// directory of the project, which is resolved to `src_root_uri`. It has to be an absolute
+/// `file://` URI ending with a slash. Lines and columns are 1-indexed, as required by SARIF.
+pub fn sarif_log<'a>(
+    tool: &SarifTool,
+    src_root_uri: &str,
+    issues: impl IntoIterator<Item = &'a PlainIssue>,
+) -> Value {
+    let mut rules = Vec::new();
+    let mut rule_ids = FxHashSet::default();
+    let results = issues
+        .into_iter()
+        .map(|issue| {
+            let rule_id = sarif_rule_id(issue);
+            if rule_ids.insert(rule_id.clone()) {
+                rules.push(json!({
+                    "id": rule_id,
+                    "name": issue.stage.to_string(),
+                }));
+            }
+            sarif_result(issue, rule_id)
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": tool.name,
+                    "version": tool.version,
+                    "informationUri": tool.information_uri,
+                    "rules": rules,
+                },
+            },
+            "originalUriBaseIds": {
+                SARIF_SRCROOT: { "uri": src_root_uri },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Writes [sarif_log] to `writer`.
+pub fn write_sarif<'a>(
+    mut writer: impl Write,
+    tool: &SarifTool,
+    src_root_uri: &str,
+    issues: impl IntoIterator<Item = &'a PlainIssue>,
+) -> Result<()> {
+    serde_json::to_writer_pretty(&mut writer, &sarif_log(tool, src_root_uri, issues))?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn sarif_rule_id(issue: &PlainIssue) -> String {
+    format!("turbopack/{}", issue.stage.to_string().replace(' ', "-"))
+}
+
+fn sarif_level(severity: IssueSeverity) -> &'static str {
+    match severity {
+        IssueSeverity::Bug | IssueSeverity::Fatal | IssueSeverity::Error => "error",
+        IssueSeverity::Warning => "warning",
+        IssueSeverity::Hint
+        | IssueSeverity::Note
+        | IssueSeverity::Suggestion
+        | IssueSeverity::Info => "note",
+    }
+}
+
+fn sarif_result(issue: &PlainIssue, rule_id: String) -> Value {
+    let mut message = issue.title.to_unstyled_string();
+    if let Some(description) = &issue.description {
+        message.push_str("\n\n");
+        message.push_str(&description.to_unstyled_string());
+    }
+
+    let mut artifact_location = json!({ "uri": issue.file_path.as_str() });
+    if let Some(path) = issue.file_path.strip_prefix(PROJECT_PREFIX) {
+        artifact_location = json!({ "uri": path, "uriBaseId": SARIF_SRCROOT });
+    }
+    let mut physical_location = json!({ "artifactLocation": artifact_location });
+    if let Some((start, end)) = issue_range(issue) {
+        physical_location["region"] = json!({
+            "startLine": start.line + 1,
+            "startColumn": start.column + 1,
+            "endLine": end.line + 1,
+            "endColumn": end.column + 1,
+        });
+    }
+
+    let mut properties = json!({
+        "severity": issue.severity.as_str(),
+        "stage": issue.stage.to_string(),
+        "importTraces": issue.import_traces,
+    });
+    if !issue.documentation_link.is_empty() {
+        properties["documentationLink"] = json!(issue.documentation_link);
+    }
+
+    json!({
+        "ruleId": rule_id,
+        "level": sarif_level(issue.severity),
+        "message": { "text": message },
+        "locations": [{ "physicalLocation": physical_location }],
+        "partialFingerprints": {
+            "turbopackIssueHash/v1": format!("{:016x}", issue.internal_hash_ref(false)),
+        },
+        "properties": properties,
+    })
+}
+
+/// The range of the issue in the file at [PlainIssue::file_path]. The range of the source is
+/// dropped when it was mapped to another file, e.g. through a source map.
+fn issue_range(issue: &PlainIssue) -> Option<(SourcePos, SourcePos)> {
+    let source = issue.source.as_ref()?;
+    if !source.asset.ident.starts_with(issue.file_path.as_str()) {
+        return None;
+    }
+    source.range
+}
+
+/// A line of the JSON Lines export. Lines and columns are 0-indexed.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLinesIssue<'a> {
+    severity: &'static str,
+    stage: String,
+    file_path: &'a str,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    documentation_link: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<JsonLinesIssueSource<'a>>,
+    import_traces: &'a [PlainTrace],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLinesIssueSource<'a> {
+    ident: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<JsonLinesRange>,
+}
+
+#[derive(Serialize)]
+struct JsonLinesRange {
+    start: SourcePos,
+    end: SourcePos,
+}
+
+/// Writes issues as [JSON Lines](https://jsonlines.org/), one object per issue. Every issue is
+/// written as soon as it's passed in, so consumers can process the output while it's written.
+///
+/// An issue that is reported again, e.g. by a later phase of the build, is only written once.
+pub struct IssueJsonLinesWriter<W: Write> {
+    writer: W,
+    written: FxHashSet<u64>,
+}
+
+impl<W: Write> IssueJsonLinesWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            written: FxHashSet::default(),
+        }
+    }
+
+    pub fn write_issue(&mut self, issue: &PlainIssue) -> Result<()> {
+        if !self.written.insert(issue.internal_hash_ref(false)) {
+            return Ok(());
+        }
+        let line = JsonLinesIssue {
+            severity: issue.severity.as_str(),
+            stage: issue.stage.to_string(),
+            file_path: &issue.file_path,
+            title: issue.title.to_unstyled_string(),
+            description: issue
+                .description
+                .as_ref()
+                .map(StyledString::to_unstyled_string),
+            detail: issue.detail.as_ref().map(StyledString::to_unstyled_string),
+            documentation_link: &issue.documentation_link,
+            source: issue.source.as_ref().map(|source| JsonLinesIssueSource {
+                ident: &source.asset.ident,
+                range: source
+                    .range
+                    .map(|(start, end)| JsonLinesRange { start, end }),
+            }),
+            import_traces: &issue.import_traces,
+        };
+        serde_json::to_writer(&mut self.writer, &line)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::rcstr;
+    use turbo_tasks::ReadRef;
+    use turbo_tasks_fs::FileContent;
+
+    use super::{IssueJsonLinesWriter, SarifTool, sarif_log};
+    use crate::{
+        issue::{
+            IssueSeverity, IssueStage, PlainIssue, PlainIssueSource, PlainSource, PlainTraceItem,
+            StyledString,
+        },
+        source_pos::SourcePos,
+    };
+
+    fn issue() -> PlainIssue {
+        PlainIssue {
+            severity: IssueSeverity::Warning,
+            stage: IssueStage::CodeGen,
+            title: StyledString::Text(rcstr!("Module not found")),
+            file_path: rcstr!("[project]/src/index.js"),
+            description: Some(StyledString::Text(rcstr!("Can't resolve './missing'"))),
+            detail: None,
+            documentation_link: rcstr!(""),
+            source: Some(PlainIssueSource {
+                asset: ReadRef::new_owned(PlainSource {
+                    ident: ReadRef::new_owned(rcstr!(
+                        "[project]/src/index.js [client] (ecmascript)"
+                    )),
+                    content: ReadRef::new_owned(FileContent::NotFound),
+                }),
+                range: Some((
+                    SourcePos { line: 2, column: 0 },
+                    SourcePos {
+                        line: 2,
+                        column: 25,
+                    },
+                )),
+            }),
+            import_traces: vec![vec![PlainTraceItem {
+                fs_name: rcstr!("project"),
+                root_path: rcstr!("/app"),
+                path: rcstr!("src/index.js"),
+                layer: None,
+            }]],
+            fixes: vec![],
+        }
+    }
+
+    #[test]
+    fn sarif_uses_one_based_regions_relative_to_the_project() {
+        let tool = SarifTool {
+            name: "turbopack",
+            version: "0.0.0",
+            information_uri: "https://turbo.build/pack",
+        };
+        let log = sarif_log(&tool, "file:///app/", [&issue()]);
+        let run = &log["runs"][0];
+        assert_eq!(
+            run["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///app/"
+        );
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["id"],
+            "turbopack/code-gen"
+        );
+
+        let result = &run["results"][0];
+        assert_eq!(result["level"], "warning");
+        assert_eq!(
+            result["message"]["text"],
+            "Module not found\n\nCan't resolve './missing'"
+        );
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/index.js");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 1);
+        assert_eq!(location["region"]["endColumn"], 26);
+        assert_eq!(
+            result["properties"]["importTraces"][0][0]["path"],
+            "src/index.js"
+        );
+    }
+
+    #[test]
+    fn json_lines_writes_one_object_per_line() {
+        let mut writer = IssueJsonLinesWriter::new(Vec::new());
+        writer.write_issue(&issue()).unwrap();
+        writer
+            .write_issue(&PlainIssue {
+                title: StyledString::Text(rcstr!("Another issue")),
+                ..issue()
+            })
+            .unwrap();
+        // e.g. reported again by a later phase of the build
+        writer.write_issue(&issue()).unwrap();
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+
+        let line: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(line["severity"], "warning");
+        assert_eq!(line["stage"], "code gen");
+        assert_eq!(line["source"]["range"]["start"]["line"], 2);
+        assert!(line.get("detail").is_none());
+    }
+}
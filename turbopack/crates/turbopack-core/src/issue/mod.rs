@@ -1,5 +1,7 @@
 pub mod analyze;
+pub mod baseline;
 pub mod code_gen;
+pub mod export;
 pub mod module;
 pub mod resolve;
 
@@ -181,8 +183,34 @@ pub trait Issue {
     fn source(self: Vc<Self>) -> Vc<OptionIssueSource> {
         Vc::cell(None)
     }
+
+    /// Machine-applicable fixes for the issue, e.g. adding a missing directive or correcting a
+    /// misspelled import path. Editors and the dev overlay can offer them as one-click fixes.
+    #[turbo_tasks::function]
+    fn fixes(self: Vc<Self>) -> Vc<IssueFixes> {
+        Vc::cell(vec![])
+    }
+}
+
+/// A fix for an [Issue] that can be applied without user input, see [Issue::fixes].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, TraceRawVcs, NonLocalValue, Encode, Decode)]
+pub struct IssueFix {
+    /// A short description of the fix, e.g. `Add "use client"`.
+    pub title: RcStr,
+    /// The edits to apply. They must not overlap.
+    pub edits: Vec<TextEdit>,
 }
 
+/// Replaces the range of `source` with `new_text`. An empty range inserts the text.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, TraceRawVcs, NonLocalValue, Encode, Decode)]
+pub struct TextEdit {
+    pub source: IssueSource,
+    pub new_text: RcStr,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct IssueFixes(Vec<IssueFix>);
+
 // A collectible trait that allows traces to be computed for a given module.
 #[turbo_tasks::value_trait]
 pub trait ImportTracer {
@@ -616,10 +644,20 @@ impl IssueSource {
         })
     }
 
+    /// Returns an empty range at the start of `source`, e.g. to insert a directive with a
+    /// [TextEdit].
+    pub fn start_of(source: ResolvedVc<Box<dyn Source>>) -> Self {
+        Self::from_line_col(source, SourcePos::new(0), SourcePos::new(0))
+    }
+
     /// Returns the file path for the source file.
     pub fn file_path(&self) -> Vc<FileSystemPath> {
         self.source.ident().path()
     }
+
+    pub fn source(&self) -> ResolvedVc<Box<dyn Source>> {
+        self.source
+    }
 }
 
 impl IssueSource {
@@ -641,6 +679,35 @@ impl IssueSource {
             _ => None,
         })
     }
+
+    /// Returns the source code in the range.
+    pub async fn text(&self) -> Result<Option<String>> {
+        let Some((start, end)) = self.to_swc_offsets().await? else {
+            return Ok(None);
+        };
+        let FileContent::Content(file) = &*self.source.content().file_content().await? else {
+            return Ok(None);
+        };
+        let Ok(content) = file.content().to_str() else {
+            return Ok(None);
+        };
+        Ok(content
+            .get(start as usize - 1..end as usize - 1)
+            .map(|text| text.to_string()))
+    }
+
+    /// Narrows the range to the first occurrence of `needle` in it, e.g. to find the request of
+    /// an import statement.
+    pub async fn find(&self, needle: &str) -> Result<Option<Self>> {
+        let (Some((start, _)), Some(text)) = (self.to_swc_offsets().await?, self.text().await?)
+        else {
+            return Ok(None);
+        };
+        Ok(text.find(needle).map(|index| {
+            let start = start + index as u32;
+            Self::from_swc_offsets(self.source, start, start + needle.len() as u32)
+        }))
+    }
 }
 
 async fn source_pos(
@@ -881,6 +948,7 @@ pub struct PlainIssue {
 
     pub source: Option<PlainIssueSource>,
     pub import_traces: Vec<PlainTrace>,
+    pub fixes: Vec<PlainIssueFix>,
 }
 
 fn hash_plain_issue(issue: &PlainIssue, hasher: &mut Xxh3Hash64Hasher, full: bool) {
@@ -970,10 +1038,58 @@ impl PlainIssue {
                 }
                 None => vec![],
             },
+            fixes: issue
+                .fixes()
+                .await?
+                .iter()
+                .map(PlainIssueFix::from_fix)
+                .try_flat_join()
+                .await?,
         }))
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, TraceRawVcs, NonLocalValue)]
+pub struct PlainIssueFix {
+    pub title: RcStr,
+    pub edits: Vec<PlainTextEdit>,
+}
+
+impl PlainIssueFix {
+    /// Returns `None` when the range of an edit can't be resolved, as the fix can't be applied
+    /// then.
+    async fn from_fix(fix: &IssueFix) -> Result<Option<Self>> {
+        let edits = fix
+            .edits
+            .iter()
+            .map(async |edit| {
+                let PlainIssueSource { asset, range } = edit.source.into_plain().await?;
+                Ok(range.map(|range| PlainTextEdit {
+                    asset,
+                    range,
+                    new_text: edit.new_text.clone(),
+                }))
+            })
+            .try_join()
+            .await?;
+        Ok(edits
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .map(|edits| Self {
+                title: fix.title.clone(),
+                edits,
+            }))
+    }
+}
+
+/// Replaces `range` of `asset` with `new_text`. An empty range inserts the text.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, TraceRawVcs, NonLocalValue)]
+pub struct PlainTextEdit {
+    pub asset: ReadRef<PlainSource>,
+    pub range: (SourcePos, SourcePos),
+    pub new_text: RcStr,
+}
+
 #[turbo_tasks::value(serialization = "none")]
 #[derive(Clone, Debug, PartialOrd, Ord)]
 pub struct PlainIssueSource {
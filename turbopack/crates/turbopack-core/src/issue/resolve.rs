@@ -1,16 +1,18 @@
 use std::fmt::Write;
 
 use anyhow::Result;
+use strsim::levenshtein;
 use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{PrettyPrintError, ReadRef, ResolvedVc, ValueToString, Vc};
-use turbo_tasks_fs::FileSystemPath;
+use turbo_tasks_fs::{DirectoryContent, DirectoryEntry, FileSystemPath};
 
 use super::{Issue, IssueSource, IssueStage, OptionStyledString, StyledString};
 use crate::{
-    issue::{IssueSeverity, OptionIssueSource},
+    issue::{IssueFix, IssueFixes, IssueSeverity, OptionIssueSource, TextEdit},
     resolve::{
         options::{ImportMap, ImportMapResult, ResolveOptions},
         parse::Request,
+        pattern::Pattern,
     },
 };
 
@@ -118,10 +120,82 @@ impl Issue for ResolvingIssue {
         Vc::cell(self.source)
     }
 
+    /// Suggests a file or directory next to the requested one with a similar name, e.g. for a
+    /// typo or a wrong casing.
+    #[turbo_tasks::function]
+    async fn fixes(&self) -> Result<Vc<IssueFixes>> {
+        let Some(source) = self.source else {
+            return Ok(Vc::cell(vec![]));
+        };
+        let Request::Relative {
+            path: Pattern::Constant(path),
+            ..
+        } = &*self.request.await?
+        else {
+            return Ok(Vc::cell(vec![]));
+        };
+        let Some(suggestion) = closest_relative_request(&self.file_path, path).await? else {
+            return Ok(Vc::cell(vec![]));
+        };
+        let Some(request_source) = source.find(path).await? else {
+            return Ok(Vc::cell(vec![]));
+        };
+        Ok(Vc::cell(vec![IssueFix {
+            title: format!("Change to \"{suggestion}\"").into(),
+            edits: vec![TextEdit {
+                source: request_source,
+                new_text: suggestion,
+            }],
+        }]))
+    }
+
     // TODO add sub_issue for a description of resolve_options
     // TODO add source link
 }
 
+/// Finds the entry of the requested directory whose name is closest to the requested one.
+/// Extensions are ignored when the request has none, as they are usually resolved implicitly.
+async fn closest_relative_request(origin: &FileSystemPath, request: &str) -> Result<Option<RcStr>> {
+    let Some((dir, name)) = request.rsplit_once('/') else {
+        return Ok(None);
+    };
+    if name.is_empty() {
+        return Ok(None);
+    }
+    let Some(dir_path) = origin.parent().try_join(dir) else {
+        return Ok(None);
+    };
+    let DirectoryContent::Entries(entries) = &*dir_path.read_dir().await? else {
+        return Ok(None);
+    };
+
+    let compare_stems = !name.contains('.');
+    let max_distance = (name.len() / 4).max(1);
+    let suggestion = entries
+        .iter()
+        .filter_map(|(entry_name, entry)| {
+            let candidate = match entry {
+                DirectoryEntry::File(_) if compare_stems => entry_name
+                    .split_once('.')
+                    .map_or(entry_name.as_str(), |(stem, _)| stem),
+                DirectoryEntry::File(_) | DirectoryEntry::Directory(_) => entry_name.as_str(),
+                _ => return None,
+            };
+            if candidate.is_empty() || candidate == name {
+                return None;
+            }
+            // A different casing is the most likely mistake
+            let distance = if candidate.eq_ignore_ascii_case(name) {
+                0
+            } else {
+                levenshtein(candidate, name)
+            };
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .min();
+    Ok(suggestion.map(|(_, candidate)| format!("{dir}/{candidate}").into()))
+}
+
 async fn lookup_import_map(
     import_map: Vc<ImportMap>,
     file_path: FileSystemPath,
@@ -134,3 +208,70 @@ async fn lookup_import_map(
     }
     Ok(Some(result.cell().to_string().await?))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use turbo_rcstr::rcstr;
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+    use turbo_tasks_fs::{DiskFileSystem, FileSystem};
+
+    use super::closest_relative_request;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_closest_relative_request() {
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            let root = DiskFileSystem::new(
+                rcstr!("test"),
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests/issue/closest_relative_request")
+                    .to_str()
+                    .unwrap()
+                    .into(),
+            )
+            .root()
+            .owned()
+            .await?;
+            let origin = root.join("index.js")?;
+
+            let suggest = async |request: &str| {
+                anyhow::Ok(
+                    closest_relative_request(&origin, request)
+                        .await?
+                        .map(|s| s.to_string()),
+                )
+            };
+
+            // wrong casing, the extension is resolved implicitly
+            assert_eq!(
+                suggest("./components/button").await?.as_deref(),
+                Some("./components/Button")
+            );
+            // typo
+            assert_eq!(
+                suggest("./components/heaader").await?.as_deref(),
+                Some("./components/header")
+            );
+            // directories are suggested as well
+            assert_eq!(suggest("./util").await?.as_deref(), Some("./utils"));
+            // extensions are compared when the request has one
+            assert_eq!(
+                suggest("./styles.modul.css").await?.as_deref(),
+                Some("./styles.module.css")
+            );
+            // nothing close enough
+            assert_eq!(suggest("./components/footer").await?, None);
+            // missing directory
+            assert_eq!(suggest("./missing/button").await?, None);
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
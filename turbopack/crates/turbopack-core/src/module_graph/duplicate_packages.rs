@@ -0,0 +1,411 @@
+//! Detects packages that are bundled more than once into the same chunk group.
+//!
+//! Two copies of a package (e.g. because of mismatching version ranges) not only increase the
+//! bundle size, but break packages that rely on module-level state, like `react` or `three`.
+
+use anyhow::Result;
+use bincode::{Decode, Encode};
+use roaring::RoaringBitmap;
+use rustc_hash::FxHashMap;
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{
+    FxIndexMap, NonLocalValue, ResolvedVc, TryFlatJoinIterExt, TryJoinIterExt, Vc,
+    trace::TraceRawVcs,
+};
+use turbo_tasks_fs::{FileJsonContent, FileSystemPath};
+
+use crate::{
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    module::Module,
+    module_graph::{GraphTraversalAction, ModuleGraph},
+};
+
+/// Emits a [DuplicatePackageIssue] for every package that has more than one copy in a single
+/// chunk group of `graph`. Packages in `allow_list` are skipped.
+#[turbo_tasks::function]
+pub async fn detect_duplicate_packages(
+    graph: Vc<ModuleGraph>,
+    allow_list: Vec<RcStr>,
+) -> Result<()> {
+    let graph_ref = graph.await?;
+    let chunk_group_info = graph.chunk_group_info().await?;
+    let module_chunk_groups = chunk_group_info.module_chunk_groups.await?;
+
+    // package name -> package directory -> modules of that copy
+    let mut packages: FxIndexMap<
+        RcStr,
+        FxIndexMap<FileSystemPath, Vec<ResolvedVc<Box<dyn Module>>>>,
+    > = FxIndexMap::default();
+    let module_packages = graph_ref
+        .iter_nodes()
+        .map(async |module| {
+            let path = module.ident().path().owned().await?;
+            let Some((name, dir)) = package_of_path(&path.path) else {
+                return Ok(None);
+            };
+            if allow_list.iter().any(|allowed| **allowed == *name) {
+                return Ok(None);
+            }
+            let dir = path.root().owned().await?.join(dir)?;
+            Ok(Some((module, RcStr::from(name), dir)))
+        })
+        .try_flat_join()
+        .await?;
+    for (module, name, dir) in module_packages {
+        packages
+            .entry(name)
+            .or_default()
+            .entry(dir)
+            .or_default()
+            .push(module);
+    }
+
+    for (name, copies) in packages {
+        if copies.len() < 2 {
+            continue;
+        }
+
+        // Find the first chunk group that contains more than one copy
+        let mut copies_per_group: FxHashMap<u32, Vec<usize>> = FxHashMap::default();
+        for (copy_idx, modules) in copies.values().enumerate() {
+            let mut groups = RoaringBitmap::new();
+            for module in modules {
+                if let Some(module_groups) = module_chunk_groups.get(module) {
+                    groups |= &**module_groups;
+                }
+            }
+            for group in groups {
+                copies_per_group.entry(group).or_default().push(copy_idx);
+            }
+        }
+        let Some((chunk_group, duplicated)) = copies_per_group
+            .into_iter()
+            .filter(|(_, copies)| copies.len() > 1)
+            .min_by_key(|(group, _)| *group)
+        else {
+            continue;
+        };
+        let Some(chunk_group) = chunk_group_info
+            .chunk_groups
+            .get_index(chunk_group as usize)
+        else {
+            continue;
+        };
+
+        // Shortest importer chain from the chunk group entries to each copy
+        let mut parents: FxHashMap<
+            ResolvedVc<Box<dyn Module>>,
+            Option<ResolvedVc<Box<dyn Module>>>,
+        > = FxHashMap::default();
+        graph_ref.traverse_edges_bfs(chunk_group.entries(), |parent, module| {
+            if let Some((_, ref_data)) = parent
+                && !ref_data.chunking_type.is_parallel()
+            {
+                // Not part of this chunk group
+                return Ok(GraphTraversalAction::Skip);
+            }
+            parents
+                .entry(module)
+                .or_insert_with(|| parent.map(|(parent, _)| parent));
+            Ok(GraphTraversalAction::Continue)
+        })?;
+
+        let copies = copies.into_iter().collect::<Vec<_>>();
+        let copies = duplicated
+            .into_iter()
+            .map(|copy_idx| {
+                let (dir, modules) = &copies[copy_idx];
+                let parents = &parents;
+                async move {
+                    let version = match &*dir.join("package.json")?.read_json().await? {
+                        FileJsonContent::Content(json) => json
+                            .get("version")
+                            .and_then(|v| v.as_str())
+                            .map(RcStr::from),
+                        _ => None,
+                    };
+                    let mut importers = Vec::new();
+                    if let Some(&module) = modules.iter().find(|m| parents.contains_key(*m)) {
+                        let mut current = Some(module);
+                        while let Some(module) = current {
+                            importers.push(module.ident().path().await?.path.clone());
+                            current = parents.get(&module).copied().flatten();
+                        }
+                        importers.reverse();
+                    }
+                    Ok(DuplicatePackageCopy {
+                        dir: dir.clone(),
+                        version,
+                        importers,
+                    })
+                }
+            })
+            .try_join()
+            .await?;
+
+        DuplicatePackageIssue { name, copies }
+            .resolved_cell()
+            .emit();
+    }
+
+    Ok(())
+}
+
+/// Returns the name of the package `path` is part of and the directory of that package, based on
+/// the last `node_modules` segment in the path.
+fn package_of_path(path: &str) -> Option<(&str, &str)> {
+    let start = match path.rfind("/node_modules/") {
+        Some(i) => i + "/node_modules/".len(),
+        None if path.starts_with("node_modules/") => "node_modules/".len(),
+        None => return None,
+    };
+    let rest = &path[start..];
+    let mut segments = rest.splitn(3, '/');
+    let first = segments.next()?;
+    let name_len = if first.starts_with('@') {
+        first.len() + 1 + segments.next()?.len()
+    } else {
+        first.len()
+    };
+    if name_len == 0 {
+        return None;
+    }
+    let name = &rest[..name_len];
+    Some((name, &path[..start + name_len]))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+struct DuplicatePackageCopy {
+    dir: FileSystemPath,
+    version: Option<RcStr>,
+    /// Module paths from a chunk group entry to the first module of this copy.
+    importers: Vec<RcStr>,
+}
+
+#[turbo_tasks::value(shared)]
+struct DuplicatePackageIssue {
+    name: RcStr,
+    copies: Vec<DuplicatePackageCopy>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for DuplicatePackageIssue {
+    fn severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.copies[0].dir.clone().cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Analysis.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Text(rcstr!("Package ")),
+            StyledString::Code(self.name.clone()),
+            StyledString::Text(rcstr!(" is bundled multiple times")),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        let mut lines = vec![StyledString::Text(
+            format!(
+                "{} copies of {} are part of the same chunk group. Packages with module-level \
+                 state may break when loaded more than once. Deduplicate the package in your \
+                 lockfile, or add it to `turbopack.allowDuplicatePackages` if this is intended.",
+                self.copies.len(),
+                self.name
+            )
+            .into(),
+        )];
+        for copy in &self.copies {
+            lines.push(StyledString::Line(vec![
+                StyledString::Strong(match &copy.version {
+                    Some(version) => format!("{}@{version}", self.name).into(),
+                    None => self.name.clone(),
+                }),
+                StyledString::Text(rcstr!(" at ")),
+                StyledString::Code(copy.dir.path.clone()),
+            ]));
+            if !copy.importers.is_empty() {
+                lines.push(StyledString::Code(
+                    copy.importers
+                        .iter()
+                        .map(|path| path.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                        .into(),
+                ));
+            }
+        }
+        Vc::cell(Some(StyledString::Stack(lines).resolved_cell()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use turbo_rcstr::{RcStr, rcstr};
+    use turbo_tasks::{ResolvedVc, TryJoinIterExt, Vc};
+    use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
+    use turbo_tasks_fs::{DiskFileSystem, FileSystem};
+
+    use super::{DuplicatePackageIssue, detect_duplicate_packages, package_of_path};
+    use crate::{
+        issue::CollectibleIssuesExt,
+        module::Module,
+        module_graph::{
+            GraphEntries, ModuleGraph, SingleModuleGraph,
+            chunk_group_info::ChunkGroupEntry,
+            tests::{MockModule, TestRepo},
+        },
+    };
+
+    /// Runs the detection on a graph of `index.js` importing `react` and `lib` from the
+    /// `tests/duplicate_packages` fixture, which contains `react@18.3.1` and a nested
+    /// `react@19.0.0` in `lib`. `lib` imports the nested copy when `nested_react` is set, and the
+    /// top level copy otherwise. Returns every issue as `name: version at dir via importers`.
+    #[turbo_tasks::function(operation)]
+    async fn duplicate_packages_operation(nested_react: bool) -> Result<Vc<Vec<RcStr>>> {
+        let root = DiskFileSystem::new(
+            rcstr!("test"),
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/duplicate_packages")
+                .to_str()
+                .unwrap()
+                .into(),
+        )
+        .root()
+        .owned()
+        .await?;
+        let lib_react = if nested_react {
+            "node_modules/lib/node_modules/react/index.js"
+        } else {
+            "node_modules/react/index.js"
+        };
+        let repo = TestRepo {
+            repo: [
+                (
+                    "index.js",
+                    vec!["node_modules/react/index.js", "node_modules/lib/index.js"],
+                ),
+                ("node_modules/lib/index.js", vec![lib_react]),
+            ]
+            .into_iter()
+            .map(|(path, deps)| {
+                Ok((
+                    root.join(path)?,
+                    deps.into_iter()
+                        .map(|dep| root.join(dep))
+                        .collect::<Result<_>>()?,
+                ))
+            })
+            .collect::<Result<_>>()?,
+        }
+        .cell();
+        let entry = Vc::upcast::<Box<dyn Module>>(MockModule::new(root.join("index.js")?, repo))
+            .to_resolved()
+            .await?;
+        let graph = ModuleGraph::from_single_graph(SingleModuleGraph::new_with_entries(
+            GraphEntries::resolved_cell(GraphEntries(vec![ChunkGroupEntry::Entry(vec![entry])])),
+            false,
+            false,
+        ))
+        .connect();
+
+        let detection = detect_duplicate_packages(graph, vec![]);
+        detection.await?;
+        let mut issues = detection
+            .peek_issues()
+            .iter()
+            .map(async |issue| {
+                let issue = ResolvedVc::try_downcast_type::<DuplicatePackageIssue>(issue)
+                    .unwrap()
+                    .await?;
+                Ok(issue
+                    .copies
+                    .iter()
+                    .map(|copy| {
+                        format!(
+                            "{}: {} at {} via {}",
+                            issue.name,
+                            copy.version.as_deref().unwrap_or("unknown"),
+                            copy.dir.path,
+                            copy.importers.join(" -> ")
+                        )
+                        .into()
+                    })
+                    .collect::<Vec<RcStr>>())
+            })
+            .try_join()
+            .await?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        issues.sort();
+        Ok(Vc::cell(issues))
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_detect_duplicate_packages() {
+        let tt = turbo_tasks::TurboTasks::new(TurboTasksBackend::new(
+            BackendOptions::default(),
+            noop_backing_storage(),
+        ));
+        tt.run_once(async {
+            assert_eq!(
+                *duplicate_packages_operation(true)
+                    .read_strongly_consistent()
+                    .await?,
+                vec![
+                    rcstr!(
+                        "react: 18.3.1 at node_modules/react via index.js -> \
+                         node_modules/react/index.js"
+                    ),
+                    rcstr!(
+                        "react: 19.0.0 at node_modules/lib/node_modules/react via index.js -> \
+                         node_modules/lib/index.js -> node_modules/lib/node_modules/react/index.js"
+                    ),
+                ]
+            );
+            // A single version of the package
+            assert_eq!(
+                *duplicate_packages_operation(false)
+                    .read_strongly_consistent()
+                    .await?,
+                Vec::<RcStr>::new()
+            );
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_package_of_path() {
+        assert_eq!(
+            package_of_path("node_modules/three/build/three.module.js"),
+            Some(("three", "node_modules/three"))
+        );
+        assert_eq!(
+            package_of_path("app/node_modules/@react-three/fiber/dist/index.js"),
+            Some(("@react-three/fiber", "app/node_modules/@react-three/fiber"))
+        );
+        assert_eq!(
+            package_of_path("node_modules/a/node_modules/react/index.js"),
+            Some(("react", "node_modules/a/node_modules/react"))
+        );
+        assert_eq!(package_of_path("src/node_modules.js"), None);
+        assert_eq!(package_of_path("app/page.tsx"), None);
+    }
+}
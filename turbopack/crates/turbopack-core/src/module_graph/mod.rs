@@ -45,6 +45,7 @@ use crate::{
 pub mod async_module_info;
 pub mod binding_usage_info;
 pub mod chunk_group_info;
+pub mod duplicate_packages;
 pub mod merged_modules;
 pub mod module_batch;
 pub(crate) mod module_batches;
@@ -2212,18 +2213,18 @@ pub mod tests {
     }
 
     #[turbo_tasks::value(shared)]
-    struct TestRepo {
-        repo: FxHashMap<FileSystemPath, Vec<FileSystemPath>>,
+    pub(super) struct TestRepo {
+        pub(super) repo: FxHashMap<FileSystemPath, Vec<FileSystemPath>>,
     }
     #[turbo_tasks::value]
-    struct MockModule {
+    pub(super) struct MockModule {
         path: FileSystemPath,
         repo: ResolvedVc<TestRepo>,
     }
     #[turbo_tasks::value_impl]
     impl MockModule {
         #[turbo_tasks::function]
-        fn new(path: FileSystemPath, repo: ResolvedVc<TestRepo>) -> Vc<Self> {
+        pub(super) fn new(path: FileSystemPath, repo: ResolvedVc<TestRepo>) -> Vc<Self> {
             Self { path, repo }.cell()
         }
     }
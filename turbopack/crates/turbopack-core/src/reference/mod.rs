@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt::Display};
 
 use anyhow::Result;
 use bincode::{Decode, Encode};
@@ -31,20 +31,63 @@ pub use source_map::SourceMapReference;
 pub trait ModuleReference: ValueToString {
     #[turbo_tasks::function]
     fn resolve_reference(self: Vc<Self>) -> Vc<ModuleResolveResult>;
-    // TODO think about different types
-    // fn kind(&self) -> Vc<AssetReferenceType>;
 
     #[turbo_tasks::function]
     fn chunking_type(self: Vc<Self>) -> Vc<ChunkingTypeOption> {
         Vc::cell(None)
     }
 
+    /// How the referenced modules are used at runtime. This is only used to explain why a file
+    /// is traced.
+    #[turbo_tasks::function]
+    fn trace_kind(self: Vc<Self>) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Other.cell()
+    }
+
     #[turbo_tasks::function]
     fn binding_usage(self: Vc<Self>) -> Vc<BindingUsage> {
         BindingUsage::all()
     }
 }
 
+/// The kind of a [ModuleReference] when tracing the files needed at runtime, see
+/// [ModuleReference::trace_kind].
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum TraceReferenceKind {
+    /// A static or dynamic `import`.
+    Import,
+    /// A `require()`, `require.resolve()` or AMD `define()`.
+    Require,
+    /// A file read with a static path, e.g. `fs.readFile(path.join(__dirname, "data.json"))`.
+    FsRead,
+    /// All files matching a dynamic path or a directory, e.g. `fs.readdir(__dirname)`.
+    Glob,
+    /// A native addon found through `node-pre-gyp`, `node-gyp-build` or `bindings`.
+    NativeBinding,
+    /// A file that was read to resolve a request, e.g. a `package.json`.
+    Resolution,
+    /// An output asset referencing another output asset, e.g. a chunk referencing a traced
+    /// external.
+    OutputAsset,
+    Other,
+}
+
+impl Display for TraceReferenceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TraceReferenceKind::Import => "import",
+            TraceReferenceKind::Require => "require",
+            TraceReferenceKind::FsRead => "fs read",
+            TraceReferenceKind::Glob => "glob",
+            TraceReferenceKind::NativeBinding => "native binding",
+            TraceReferenceKind::Resolution => "resolution",
+            TraceReferenceKind::OutputAsset => "output asset",
+            TraceReferenceKind::Other => "reference",
+        })
+    }
+}
+
 /// Multiple [ModuleReference]s
 #[turbo_tasks::value(transparent)]
 pub struct ModuleReferences(Vec<ResolvedVc<Box<dyn ModuleReference>>>);
@@ -183,22 +226,49 @@ impl SingleOutputAssetReference {
 pub async fn referenced_modules_and_affecting_sources(
     module: Vc<Box<dyn Module>>,
 ) -> Result<Vc<Modules>> {
+    let resolved_modules: FxIndexSet<_> =
+        referenced_modules_and_affecting_sources_with_trace_kind(module)
+            .await?
+            .iter()
+            .map(|&(module, _)| module)
+            .collect();
+
+    Ok(Vc::cell(resolved_modules.into_iter().collect()))
+}
+
+/// [Module]s referenced by a [Module] with the [TraceReferenceKind] of the reference.
+#[turbo_tasks::value(transparent)]
+pub struct ModulesWithTraceKind(Vec<(ResolvedVc<Box<dyn Module>>, TraceReferenceKind)>);
+
+/// Like [referenced_modules_and_affecting_sources], but keeps the [TraceReferenceKind] of the
+/// reference to every [Module]. Affecting sources are [TraceReferenceKind::Resolution]s.
+///
+/// [Module]: crate::module::Module
+#[turbo_tasks::function]
+pub async fn referenced_modules_and_affecting_sources_with_trace_kind(
+    module: Vc<Box<dyn Module>>,
+) -> Result<Vc<ModulesWithTraceKind>> {
     let references = module.references().await?;
 
     let resolved_references = references
         .iter()
-        .map(|r| r.resolve_reference())
+        .map(async |r| Ok((r.resolve_reference().await?, *r.trace_kind().await?)))
         .try_join()
         .await?;
     let mut modules = Vec::new();
-    for resolve_result in resolved_references {
-        modules.extend(resolve_result.primary_modules_raw_iter());
+    for (resolve_result, kind) in resolved_references {
+        modules.extend(
+            resolve_result
+                .primary_modules_raw_iter()
+                .map(|module| (module, kind)),
+        );
         modules.extend(
             resolve_result
                 .affecting_sources_iter()
                 .map(|source| async move {
-                    Ok(ResolvedVc::upcast(
-                        RawModule::new(*source).to_resolved().await?,
+                    Ok((
+                        ResolvedVc::upcast(RawModule::new(*source).to_resolved().await?),
+                        TraceReferenceKind::Resolution,
                     ))
                 })
                 .try_join()
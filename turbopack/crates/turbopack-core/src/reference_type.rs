@@ -75,6 +75,9 @@ pub enum EcmaScriptModulesReferenceSubType {
     ImportPart(ModulePart),
     Import,
     ImportWithType(RcStr),
+    /// Import in the source phase, e.g. `import source mod from "./x.wasm"`, which imports the
+    /// compiled source of the module instead of evaluating it.
+    ImportSource,
     /// Import with `turbopackLoader` attribute specifying an inline loader.
     ImportWithTurbopackUse {
         loader: ResolvedWebpackLoaderItem,
@@ -268,6 +271,8 @@ pub enum WorkerReferenceSubType {
     SharedWorker,
     ServiceWorker,
     NodeWorker,
+    AudioWorklet,
+    PaintWorklet,
     Custom(u8),
     Undefined,
 }
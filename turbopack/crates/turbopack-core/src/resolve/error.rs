@@ -5,7 +5,7 @@ use turbo_tasks_fs::FileSystemPath;
 
 use crate::{
     issue::{
-        Issue, IssueExt, IssueSeverity, IssueSource, IssueStage, OptionIssueSource,
+        Issue, IssueExt, IssueFixes, IssueSeverity, IssueSource, IssueStage, OptionIssueSource,
         OptionStyledString, StyledString, resolve::ResolvingIssue,
     },
     reference_type::ReferenceType,
@@ -194,6 +194,16 @@ pub async fn resolve_error_severity(resolve_options: Vc<ResolveOptions>) -> Resu
     })
 }
 
+/// Implemented by the issues of [ImportMapping::Error](super::options::ImportMapping::Error) that
+/// can offer fixes once the import that caused them is known, e.g. adding a directive to the
+/// importing module.
+#[turbo_tasks::value_trait]
+pub trait ImportErrorFixes {
+    /// `source` is the import statement or call that caused the issue.
+    #[turbo_tasks::function]
+    fn import_fixes(self: Vc<Self>, source: IssueSource) -> Vc<IssueFixes>;
+}
+
 /// Delegates to the inner issue but overrides the file path and source information.
 #[turbo_tasks::value(shared)]
 pub struct ResolvingIssueWithLocation {
@@ -243,4 +253,14 @@ impl Issue for ResolvingIssueWithLocation {
     fn source(&self) -> Vc<OptionIssueSource> {
         Vc::cell(self.source)
     }
+
+    #[turbo_tasks::function]
+    fn fixes(&self) -> Vc<IssueFixes> {
+        if let Some(source) = self.source
+            && let Some(inner) = ResolvedVc::try_sidecast::<Box<dyn ImportErrorFixes>>(self.inner)
+        {
+            return inner.import_fixes(source);
+        }
+        self.inner.fixes()
+    }
 }
@@ -48,6 +48,7 @@ use crate::{
         parse::{Request, stringify_data_uri},
         pattern::{Pattern, PatternMatch, read_matches},
         plugin::{AfterResolvePlugin, AfterResolvePluginCondition, BeforeResolvePlugin},
+        pnp::resolve_pnp_package,
         remap::{ExportsField, ImportsField, ReplacedSubpathValueResult},
     },
     source::{OptionSource, Source, Sources},
@@ -61,6 +62,7 @@ pub mod origin;
 pub mod parse;
 pub mod pattern;
 pub mod plugin;
+mod pnp;
 pub(crate) mod remap;
 
 pub use alias_map::{
@@ -1478,6 +1480,26 @@ async fn find_package(
                     }
                 }
             }
+            ResolveModules::PnP(dir) => {
+                let Some(name) = package_name.as_constant_string() else {
+                    continue;
+                };
+                if collect_affecting_sources {
+                    for file in [".pnp.data.json", ".pnp.cjs"] {
+                        affecting_sources.push(ResolvedVc::upcast(
+                            FileSource::new(dir.join(file)?).to_resolved().await?,
+                        ));
+                    }
+                }
+                if let Some(package_dir) =
+                    &*resolve_pnp_package(dir.clone(), lookup_path.clone(), name.clone()).await?
+                {
+                    packages.push(FindPackageItem::PackageDirectory {
+                        name: name.clone(),
+                        dir: package_dir.clone(),
+                    });
+                }
+            }
         }
     }
     Ok(FindPackageResult::cell(FindPackageResult {
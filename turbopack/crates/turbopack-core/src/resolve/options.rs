@@ -41,6 +41,9 @@ pub enum ResolveModules {
         dir: FileSystemPath,
         excluded_extensions: ResolvedVc<ExcludedExtensions>,
     },
+    /// look up packages in the Yarn Plug'n'Play manifest (`.pnp.cjs` or `.pnp.data.json`) in that
+    /// directory
+    PnP(FileSystemPath),
 }
 
 #[derive(TraceRawVcs, Hash, PartialEq, Eq, Clone, Copy, Debug, NonLocalValue, Encode, Decode)]
@@ -0,0 +1,553 @@
+//! Resolution of bare specifiers through a Yarn Plug'n'Play manifest (`.pnp.data.json` or the
+//! runtime state inlined in `.pnp.cjs`).
+//!
+//! Only the package lookup is handled here. Once the package directory is known, resolving inside
+//! of it (`exports`, `main`, extensions) works the same way as for `node_modules`. Packages stored
+//! in zip archives are read through a [`ZipFileSystem`].
+
+use anyhow::{Context, Result, anyhow, bail};
+use bincode::{Decode, Encode};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{NonLocalValue, ResolvedVc, Vc, trace::TraceRawVcs};
+use turbo_tasks_fs::{
+    FileContent, FileJsonContent, FileSystem, FileSystemPath, FileSystemPathOption, ZipFileSystem,
+};
+use turbo_unix_path::join_path;
+
+use crate::issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString};
+
+/// The data file written by Yarn when `pnpEnableInlining` is disabled.
+const PNP_DATA_FILE: &str = ".pnp.data.json";
+/// The runtime file, which contains the manifest data by default.
+const PNP_RUNTIME_FILE: &str = ".pnp.cjs";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPnpData {
+    #[serde(default)]
+    enable_top_level_fallback: bool,
+    #[serde(default)]
+    fallback_exclusion_list: Vec<(RcStr, Vec<RcStr>)>,
+    #[serde(default)]
+    fallback_pool: Vec<(RcStr, RawPnpDependency)>,
+    package_registry_data: Vec<(Option<RcStr>, Vec<(Option<RcStr>, RawPnpPackage)>)>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPnpPackage {
+    package_location: RcStr,
+    #[serde(default)]
+    package_dependencies: Vec<(RcStr, RawPnpDependency)>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawPnpDependency {
+    Reference(RcStr),
+    Alias(RcStr, RcStr),
+    Missing,
+}
+
+impl From<RawPnpDependency> for PnpDependency {
+    fn from(dependency: RawPnpDependency) -> Self {
+        match dependency {
+            RawPnpDependency::Reference(reference) => PnpDependency::Reference(reference),
+            RawPnpDependency::Alias(name, reference) => PnpDependency::Alias { name, reference },
+            RawPnpDependency::Missing => PnpDependency::Missing,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+enum PnpDependency {
+    /// A reference of the package with the same name as the dependency.
+    Reference(RcStr),
+    /// A dependency on a package with a different name, e.g. `"foo": "npm:bar@1.0.0"`.
+    Alias { name: RcStr, reference: RcStr },
+    /// A peer dependency that is not provided by the parent package.
+    Missing,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+struct PnpPackage {
+    name: Option<RcStr>,
+    reference: Option<RcStr>,
+    /// The package directory, relative to the root of the file system and with virtual paths
+    /// resolved.
+    location: RcStr,
+    dependencies: FxHashMap<RcStr, PnpDependency>,
+}
+
+#[turbo_tasks::value]
+#[derive(Debug)]
+pub(super) struct PnpManifest {
+    packages: Vec<PnpPackage>,
+    /// Package name to reference to index in `packages`.
+    registry: FxHashMap<RcStr, FxHashMap<RcStr, u32>>,
+    /// Indices into `packages`, ordered by descending location length, so the first package
+    /// containing a path is the closest one.
+    by_location: Vec<u32>,
+    top_level: Option<u32>,
+    enable_top_level_fallback: bool,
+    fallback_pool: FxHashMap<RcStr, PnpDependency>,
+    fallback_exclusions: FxHashSet<(RcStr, RcStr)>,
+}
+
+#[turbo_tasks::value(transparent)]
+pub(super) struct OptionPnpManifest(Option<ResolvedVc<PnpManifest>>);
+
+impl PnpManifest {
+    /// Parses the manifest data. `dir` is the directory containing the manifest, relative to the
+    /// root of the file system.
+    fn parse(data: RawPnpData, dir: &str) -> Result<Self> {
+        let mut packages = Vec::new();
+        let mut registry: FxHashMap<RcStr, FxHashMap<RcStr, u32>> = FxHashMap::default();
+        let mut top_level = None;
+        for (name, references) in data.package_registry_data {
+            for (reference, package) in references {
+                let index = packages.len() as u32;
+                let location = devirtualize(&package.package_location);
+                let Some(location) = join_path(dir, &location) else {
+                    // Packages outside of the file system (e.g. in a global cache) can't be read,
+                    // resolving them will fail like any other missing package.
+                    continue;
+                };
+                match (&name, &reference) {
+                    (Some(name), Some(reference)) => {
+                        registry
+                            .entry(name.clone())
+                            .or_default()
+                            .insert(reference.clone(), index);
+                    }
+                    (None, None) => top_level = Some(index),
+                    _ => bail!("invalid package locator in the Plug'n'Play manifest"),
+                }
+                packages.push(PnpPackage {
+                    name: name.clone(),
+                    reference,
+                    location: location.into(),
+                    dependencies: package
+                        .package_dependencies
+                        .into_iter()
+                        .map(|(name, dependency)| (name, dependency.into()))
+                        .collect(),
+                });
+            }
+        }
+
+        // The top-level locator shares its location with the root workspace, but only serves as a
+        // fallback.
+        let mut by_location = (0..packages.len() as u32)
+            .filter(|&i| Some(i) != top_level)
+            .collect::<Vec<_>>();
+        // A stable sort keeps the registry order for packages sharing a location. Virtual
+        // packages are listed before the package they are derived from, so the variant with the
+        // resolved peer dependencies wins.
+        by_location.sort_by_key(|&i| {
+            let package = &packages[i as usize];
+            (
+                std::cmp::Reverse(package.location.len()),
+                !package
+                    .reference
+                    .as_ref()
+                    .is_some_and(|r| r.contains("virtual:")),
+            )
+        });
+
+        Ok(PnpManifest {
+            packages,
+            registry,
+            by_location,
+            top_level,
+            enable_top_level_fallback: data.enable_top_level_fallback,
+            fallback_pool: data
+                .fallback_pool
+                .into_iter()
+                .map(|(name, dependency)| (name, dependency.into()))
+                .collect(),
+            fallback_exclusions: data
+                .fallback_exclusion_list
+                .into_iter()
+                .flat_map(|(name, references)| {
+                    references
+                        .into_iter()
+                        .map(move |reference| (name.clone(), reference))
+                })
+                .collect(),
+        })
+    }
+
+    /// Finds the package that contains `path` (relative to the root of the file system).
+    fn find_owner(&self, path: &str) -> Option<&PnpPackage> {
+        self.by_location
+            .iter()
+            .map(|&i| &self.packages[i as usize])
+            .find(|package| is_inside(path, &package.location))
+    }
+
+    /// Returns the location of the dependency `name` of the package containing `issuer`. `None`
+    /// when the issuer isn't part of the dependency tree or the dependency isn't declared.
+    fn resolve(&self, issuer: &str, name: &str) -> Option<&str> {
+        let owner = self.find_owner(issuer)?;
+        let dependency = owner.dependencies.get(name).or_else(|| {
+            let excluded = match (&owner.name, &owner.reference) {
+                (Some(name), Some(reference)) => self
+                    .fallback_exclusions
+                    .contains(&(name.clone(), reference.clone())),
+                _ => false,
+            };
+            if !self.enable_top_level_fallback || excluded {
+                return None;
+            }
+            self.fallback_pool.get(name).or_else(|| {
+                self.packages[self.top_level? as usize]
+                    .dependencies
+                    .get(name)
+            })
+        })?;
+        let (name, reference) = match dependency {
+            PnpDependency::Reference(reference) => (name, reference),
+            PnpDependency::Alias { name, reference } => (&**name, reference),
+            PnpDependency::Missing => return None,
+        };
+        let index = *self.registry.get(name)?.get(reference)?;
+        Some(&self.packages[index as usize].location)
+    }
+}
+
+fn is_inside(path: &str, dir: &str) -> bool {
+    dir.is_empty()
+        || path
+            .strip_prefix(dir)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Maps a path inside of Yarn's `__virtual__` folder to the path it points to.
+///
+/// Virtual paths have the form `<base>/__virtual__/<hash>/<depth>/<subpath>` and point to
+/// `<base>/<"../" * depth>/<subpath>`.
+fn devirtualize(path: &str) -> String {
+    let segments = path.split('/').collect::<Vec<_>>();
+    let Some(pos) = segments.iter().position(|s| *s == "__virtual__") else {
+        return path.to_string();
+    };
+    let Some(depth) = segments.get(pos + 2).and_then(|d| d.parse::<usize>().ok()) else {
+        return path.to_string();
+    };
+    let mut target = segments[..pos].to_vec();
+    target.extend(std::iter::repeat_n("..", depth));
+    target.extend(&segments[pos + 3..]);
+    devirtualize(&target.join("/"))
+}
+
+/// Extracts the manifest data from the `RAW_RUNTIME_STATE` string literal of a `.pnp.cjs` file.
+fn extract_runtime_state(code: &str) -> Option<String> {
+    let start = code.find("RAW_RUNTIME_STATE")?;
+    let code = code[start + "RAW_RUNTIME_STATE".len()..]
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start();
+    let mut chars = code.chars();
+    let quote = chars.next().filter(|c| *c == '\'' || *c == '"')?;
+    let mut state = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                // Line continuation
+                '\n' => {}
+                '\r' => {
+                    let mut rest = chars.clone();
+                    if rest.next() == Some('\n') {
+                        chars = rest;
+                    }
+                }
+                'n' => state.push('\n'),
+                'r' => state.push('\r'),
+                't' => state.push('\t'),
+                c => state.push(c),
+            },
+            c if c == quote => return Some(state),
+            c => state.push(c),
+        }
+    }
+    None
+}
+
+/// Reads the Plug'n'Play manifest in `dir`, if there is one. A manifest that can't be read is
+/// reported as an issue and ignored, so packages are resolved from `node_modules` instead.
+#[turbo_tasks::function]
+pub(super) async fn pnp_manifest(dir: FileSystemPath) -> Result<Vc<OptionPnpManifest>> {
+    let Some((file_path, manifest)) = read_pnp_manifest(&dir).await? else {
+        return Ok(Vc::cell(None));
+    };
+    match manifest {
+        Ok(manifest) => Ok(Vc::cell(Some(manifest.resolved_cell()))),
+        Err(error) => {
+            PnpManifestIssue {
+                file_path,
+                error_message: format!("{error:#}").into(),
+            }
+            .resolved_cell()
+            .emit();
+            Ok(Vc::cell(None))
+        }
+    }
+}
+
+/// Returns the manifest file in `dir` with the parsed manifest. Only errors of reading the files
+/// are returned as `Err`.
+async fn read_pnp_manifest(
+    dir: &FileSystemPath,
+) -> Result<Option<(FileSystemPath, Result<PnpManifest>)>> {
+    let data_path = dir.join(PNP_DATA_FILE)?;
+    let (file_path, data) = match &*data_path.read_json().await? {
+        FileJsonContent::Content(json) => (
+            data_path,
+            serde_json::from_value::<RawPnpData>(json.clone()).map_err(anyhow::Error::from),
+        ),
+        FileJsonContent::Unparsable(e) => (data_path, Err(anyhow!("{}", e.message))),
+        FileJsonContent::NotFound => {
+            let runtime_path = dir.join(PNP_RUNTIME_FILE)?;
+            let FileContent::Content(file) = &*runtime_path.read().await? else {
+                return Ok(None);
+            };
+            let code = file.content().to_str()?;
+            let data = extract_runtime_state(&code)
+                .context("The file doesn't contain the Plug'n'Play runtime state")
+                .and_then(|state| {
+                    serde_json::from_str::<RawPnpData>(&state)
+                        .context("Unable to parse the Plug'n'Play runtime state")
+                });
+            (runtime_path, data)
+        }
+    };
+    Ok(Some((
+        file_path,
+        data.and_then(|data| PnpManifest::parse(data, &dir.path)),
+    )))
+}
+
+#[turbo_tasks::value(shared)]
+struct PnpManifestIssue {
+    file_path: FileSystemPath,
+    error_message: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for PnpManifestIssue {
+    fn severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(rcstr!("Unable to read the Plug'n'Play manifest")).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.file_path.clone().cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Stack(vec![
+                StyledString::Text(self.error_message.clone()),
+                StyledString::Text(rcstr!("Packages are resolved from node_modules instead.")),
+            ])
+            .resolved_cell(),
+        ))
+    }
+}
+
+/// Finds the directory of the package `name` as seen from `lookup_path`, using the Plug'n'Play
+/// manifest in `dir`.
+#[turbo_tasks::function]
+pub(super) async fn resolve_pnp_package(
+    dir: FileSystemPath,
+    lookup_path: FileSystemPath,
+    name: RcStr,
+) -> Result<Vc<FileSystemPathOption>> {
+    let Some(manifest) = *pnp_manifest(dir.clone()).await? else {
+        return Ok(FileSystemPathOption::none());
+    };
+    let manifest = manifest.await?;
+
+    // Files inside of zip archives are identified by the path they would have if the archive
+    // were a directory.
+    let issuer =
+        if let Some(zip_fs) = ResolvedVc::try_downcast_type::<ZipFileSystem>(lookup_path.fs) {
+            let archive = zip_fs.archive().await?;
+            if archive.fs != dir.fs {
+                return Ok(FileSystemPathOption::none());
+            }
+            RcStr::from(join_path(&archive.path, &lookup_path.path).unwrap_or_default())
+        } else if lookup_path.fs == dir.fs {
+            lookup_path.path.clone()
+        } else {
+            return Ok(FileSystemPathOption::none());
+        };
+
+    let Some(location) = manifest.resolve(&issuer, &name) else {
+        return Ok(FileSystemPathOption::none());
+    };
+
+    let root = dir.root().owned().await?;
+    let package_dir = match location.find(".zip/").map(|i| i + ".zip".len()) {
+        Some(end) => {
+            let archive = root.join(&location[..end])?;
+            let zip_root = ZipFileSystem::new(archive).root().owned().await?;
+            zip_root.join(&location[end + 1..])?
+        }
+        None if location.ends_with(".zip") => {
+            ZipFileSystem::new(root.join(location)?)
+                .root()
+                .owned()
+                .await?
+        }
+        None => root.join(location)?,
+    };
+    Ok(Vc::cell(Some(package_dir)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> PnpManifest {
+        let data = serde_json::from_str(
+            r#"{
+                "enableTopLevelFallback": true,
+                "fallbackExclusionList": [["strict", ["npm:1.0.0"]]],
+                "fallbackPool": [["hoisted", "npm:3.0.0"]],
+                "packageRegistryData": [
+                    [null, [[null, {
+                        "packageLocation": "./",
+                        "packageDependencies": [["app", "workspace:."], ["react", "npm:18.0.0"]]
+                    }]]],
+                    ["app", [["workspace:.", {
+                        "packageLocation": "./",
+                        "packageDependencies": [
+                            ["react", "npm:18.0.0"],
+                            ["lib", "virtual:abc#npm:1.0.0"],
+                            ["strict", "npm:1.0.0"],
+                            ["alias", ["react", "npm:18.0.0"]]
+                        ]
+                    }]]],
+                    ["react", [["npm:18.0.0", {
+                        "packageLocation": "./.yarn/cache/react-npm-18.0.0-abc.zip/node_modules/react/",
+                        "packageDependencies": [["react", "npm:18.0.0"]]
+                    }]]],
+                    ["lib", [
+                        ["virtual:abc#npm:1.0.0", {
+                            "packageLocation": "./.yarn/__virtual__/lib-virtual-abc/0/cache/lib-npm-1.0.0-def.zip/node_modules/lib/",
+                            "packageDependencies": [["react", "npm:18.0.0"]]
+                        }],
+                        ["npm:1.0.0", {
+                            "packageLocation": "./.yarn/cache/lib-npm-1.0.0-def.zip/node_modules/lib/",
+                            "packageDependencies": [["react", null]]
+                        }]
+                    ]],
+                    ["strict", [["npm:1.0.0", {
+                        "packageLocation": "./.yarn/unplugged/strict-npm-1.0.0/node_modules/strict/",
+                        "packageDependencies": []
+                    }]]],
+                    ["hoisted", [["npm:3.0.0", {
+                        "packageLocation": "./.yarn/cache/hoisted-npm-3.0.0-ghi.zip/node_modules/hoisted/",
+                        "packageDependencies": []
+                    }]]]
+                ]
+            }"#,
+        )
+        .unwrap();
+        PnpManifest::parse(data, "project").unwrap()
+    }
+
+    #[test]
+    fn resolves_dependencies() {
+        let manifest = manifest();
+        assert_eq!(
+            manifest.resolve("project/src", "react"),
+            Some("project/.yarn/cache/react-npm-18.0.0-abc.zip/node_modules/react")
+        );
+        assert_eq!(
+            manifest.resolve("project/src", "alias"),
+            Some("project/.yarn/cache/react-npm-18.0.0-abc.zip/node_modules/react")
+        );
+        assert_eq!(manifest.resolve("project/src", "missing"), None);
+        assert_eq!(manifest.resolve("other/src", "react"), None);
+    }
+
+    #[test]
+    fn prefers_virtual_packages() {
+        let manifest = manifest();
+        assert_eq!(
+            manifest.resolve(
+                "project/.yarn/cache/lib-npm-1.0.0-def.zip/node_modules/lib/dist",
+                "react"
+            ),
+            Some("project/.yarn/cache/react-npm-18.0.0-abc.zip/node_modules/react")
+        );
+    }
+
+    #[test]
+    fn uses_fallback() {
+        let manifest = manifest();
+        assert_eq!(
+            manifest.resolve(
+                "project/.yarn/cache/react-npm-18.0.0-abc.zip/node_modules/react",
+                "hoisted"
+            ),
+            Some("project/.yarn/cache/hoisted-npm-3.0.0-ghi.zip/node_modules/hoisted")
+        );
+        assert_eq!(
+            manifest.resolve(
+                "project/.yarn/unplugged/strict-npm-1.0.0/node_modules/strict",
+                "hoisted"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn devirtualizes_paths() {
+        assert_eq!(
+            devirtualize("./.yarn/__virtual__/lib-virtual-abc/0/cache/lib.zip/node_modules/lib/"),
+            "./.yarn/cache/lib.zip/node_modules/lib/"
+        );
+        assert_eq!(
+            devirtualize("./.yarn/__virtual__/lib-virtual-abc/2/node_modules/lib/"),
+            "./.yarn/../../node_modules/lib/"
+        );
+        assert_eq!(devirtualize("./packages/a/"), "./packages/a/");
+    }
+
+    #[test]
+    fn extracts_runtime_state() {
+        let code = r#"#!/usr/bin/env node
+/* eslint-disable */
+"use strict";
+
+const RAW_RUNTIME_STATE =
+'{\
+  "__info": ["it\'s"],\
+  "a": "b\\\\c"\
+}';
+
+function $$SETUP_STATE(hydrateRuntimeState, basePath) {}
+"#;
+        assert_eq!(
+            extract_runtime_state(code).as_deref(),
+            Some(r#"{  "__info": ["it's"],  "a": "b\\c"}"#)
+        );
+    }
+}
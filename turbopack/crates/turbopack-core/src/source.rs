@@ -1,6 +1,6 @@
 use turbo_tasks::{ResolvedVc, Vc};
 
-use crate::{asset::Asset, ident::AssetIdent};
+use crate::{asset::Asset, ident::AssetIdent, reference::ModuleReferences};
 
 /// (Unparsed) Source Code. Source Code is processed into [Module]s by the
 /// [AssetContext]. All [Source]s have content and an identifier.
@@ -17,3 +17,13 @@ pub struct OptionSource(Option<ResolvedVc<Box<dyn Source>>>);
 
 #[turbo_tasks::value(transparent)]
 pub struct Sources(Vec<ResolvedVc<Box<dyn Source>>>);
+
+/// Implemented by [Source]s whose content was generated from further files, e.g. a compiled Sass
+/// stylesheet and the partials it loaded. Sources wrapping another source, like the result of a
+/// [SourceTransform](crate::source_transform::SourceTransform), should forward the references of
+/// the wrapped source, so that modules created from them still depend on those files.
+#[turbo_tasks::value_trait]
+pub trait SourceReferences {
+    #[turbo_tasks::function]
+    fn source_references(self: Vc<Self>) -> Vc<ModuleReferences>;
+}
@@ -9,7 +9,8 @@ use serde_json::value::RawValue;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{ResolvedVc, ValueToString};
 use turbo_tasks_fs::{
-    DiskFileSystem, FileContent, FileSystemPath, rope::Rope, util::uri_from_path_buf,
+    DiskFileSystem, FileContent, FileSystemPath, disk_file_system, rope::Rope,
+    util::uri_from_path_buf,
 };
 use url::Url;
 
@@ -88,8 +89,8 @@ pub async fn resolve_source_map_sources(
     let fs_vc = origin.fs().to_resolved().await?;
     let fs_str = &*format!("[{}]", fs_vc.to_string().await?);
 
-    let disk_fs = if let Some(fs_vc) = ResolvedVc::try_downcast_type::<DiskFileSystem>(fs_vc) {
-        Some((fs_vc, fs_vc.await?))
+    let disk_fs = if let Some(disk_fs_vc) = disk_file_system(fs_vc).await? {
+        Some((disk_fs_vc, disk_fs_vc.await?))
     } else {
         None
     };
@@ -116,7 +117,18 @@ pub async fn resolve_source_map_sources(
                 // We have an absolute URL, try to parse it as a `file://` URL
                 if let Ok(sys_path) = original_source_url_obj.to_file_path() {
                     if let Some((disk_fs_vc, disk_fs)) = disk_fs {
-                        disk_fs.try_from_sys_path(*disk_fs_vc, &sys_path, Some(origin))
+                        // The origin might be in an overlay over the disk file system, so the
+                        // path is resolved on the disk file system and moved back afterwards.
+                        let relative_to = FileSystemPath {
+                            fs: ResolvedVc::upcast(*disk_fs_vc),
+                            path: origin.path.clone(),
+                        };
+                        disk_fs
+                            .try_from_sys_path(*disk_fs_vc, &sys_path, Some(&relative_to))
+                            .map(|path| FileSystemPath {
+                                fs: fs_vc,
+                                path: path.path,
+                            })
                     } else {
                         None
                     }
@@ -248,7 +260,8 @@ where
     };
 
     let context_fs = context_path.fs;
-    let context_fs = &*ResolvedVc::try_downcast_type::<DiskFileSystem>(context_fs)
+    let context_fs = &*disk_file_system(context_fs)
+        .await?
         .context("Expected the chunking context to have a DiskFileSystem")?
         .await?;
 
@@ -1,12 +1,23 @@
+use std::fmt::Display;
+
 use anyhow::{Result, bail};
-use turbo_tasks::{ResolvedVc, TryJoinIterExt, ValueToString, Vc};
+use bincode::{Decode, Encode};
+use rustc_hash::{FxHashMap, FxHashSet};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{
+    FxIndexSet, NonLocalValue, ResolvedVc, TryFlatJoinIterExt, TryJoinIterExt, ValueToString, Vc,
+    trace::TraceRawVcs,
+};
 use turbo_tasks_fs::FileSystemPath;
 
 use crate::{
     asset::{Asset, AssetContent},
     module::Module,
-    output::{OutputAsset, OutputAssetsReference, OutputAssetsWithReferenced},
-    reference::referenced_modules_and_affecting_sources,
+    output::{OutputAsset, OutputAssets, OutputAssetsReference, OutputAssetsWithReferenced},
+    reference::{
+        TraceReferenceKind, referenced_modules_and_affecting_sources,
+        referenced_modules_and_affecting_sources_with_trace_kind,
+    },
 };
 
 /// Converts a traced external [Module] graph into a graph consisting of [TracedAsset]s.
@@ -65,3 +76,157 @@ impl Asset for TracedAsset {
         }
     }
 }
+
+/// A step of a reference chain returned by [trace_chains].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, TraceRawVcs, NonLocalValue, Encode, Decode)]
+pub struct TraceChainStep {
+    /// The kind of the reference from the previous step, `None` for the entry.
+    pub kind: Option<TraceReferenceKind>,
+    pub path: RcStr,
+}
+
+impl Display for TraceChainStep {
+    /// Formats the step as a line of a chain, e.g. `  -> require: [project]/lib/index.js`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(kind) = self.kind {
+            write!(f, "  -> {kind}: {}", self.path)
+        } else {
+            f.write_str(&self.path)
+        }
+    }
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct TraceChains(Vec<Vec<TraceChainStep>>);
+
+/// The number of shortest chains can grow exponentially with the size of the graph, so only this
+/// many are returned.
+const MAX_TRACE_CHAINS: usize = 100;
+
+type AssetVc = ResolvedVc<Box<dyn OutputAsset>>;
+
+/// Explains why a file is traced: returns every shortest reference chain from one of the
+/// `entries` to an asset at `path`, including the kind of every reference along the way.
+///
+/// Below [TracedAsset]s, the module references are followed instead of the output asset
+/// references, which is where the reference kinds are known.
+#[turbo_tasks::function]
+pub async fn trace_chains(
+    entries: Vc<OutputAssets>,
+    path: FileSystemPath,
+) -> Result<Vc<TraceChains>> {
+    // A breadth-first search which keeps all the references that reached an asset from the
+    // previous level, each of them is the last step of a shortest chain.
+    let mut predecessors: FxHashMap<AssetVc, Vec<(AssetVc, TraceReferenceKind)>> =
+        FxHashMap::default();
+    let mut level: FxIndexSet<AssetVc> = entries.await?.iter().copied().collect();
+    let mut visited: FxHashSet<AssetVc> = level.iter().copied().collect();
+    let targets = loop {
+        if level.is_empty() {
+            return Ok(Vc::cell(vec![]));
+        }
+
+        let targets = level
+            .iter()
+            .map(async |&asset| Ok((*asset.path().await? == path).then_some(asset)))
+            .try_flat_join()
+            .await?;
+        if !targets.is_empty() {
+            break targets;
+        }
+
+        let references = level
+            .iter()
+            .map(async |&asset| Ok((asset, traced_references(asset).await?)))
+            .try_join()
+            .await?;
+        let mut next_level = FxIndexSet::default();
+        for (from, references) in references {
+            for (asset, kind) in references {
+                if !visited.contains(&asset) {
+                    next_level.insert(asset);
+                    predecessors.entry(asset).or_default().push((from, kind));
+                }
+            }
+        }
+        visited.extend(next_level.iter().copied());
+        level = next_level;
+    };
+
+    let mut chains = Vec::new();
+    for target in targets {
+        collect_chains(target, &predecessors, &mut Vec::new(), &mut chains);
+    }
+
+    let chains = chains
+        .into_iter()
+        .map(async |chain| {
+            chain
+                .into_iter()
+                .map(async |(asset, kind)| {
+                    Ok(TraceChainStep {
+                        kind,
+                        path: asset.path().to_string().owned().await?,
+                    })
+                })
+                .try_join()
+                .await
+        })
+        .try_join()
+        .await?;
+    // Different module variants of the same file lead to identical chains.
+    let chains: FxIndexSet<_> = chains.into_iter().collect();
+
+    Ok(Vc::cell(chains.into_iter().collect()))
+}
+
+/// The assets referenced by `asset` and the kinds of the references.
+async fn traced_references(asset: AssetVc) -> Result<Vec<(AssetVc, TraceReferenceKind)>> {
+    let Some(traced_asset) = ResolvedVc::try_downcast_type::<TracedAsset>(asset) else {
+        return Ok(asset
+            .references()
+            .all_assets()
+            .await?
+            .iter()
+            .map(|&asset| (asset, TraceReferenceKind::OutputAsset))
+            .collect());
+    };
+
+    referenced_modules_and_affecting_sources_with_trace_kind(*traced_asset.await?.module)
+        .await?
+        .iter()
+        .map(async |&(module, kind)| {
+            Ok((
+                ResolvedVc::upcast(TracedAsset::new(*module).to_resolved().await?),
+                kind,
+            ))
+        })
+        .try_join()
+        .await
+}
+
+/// Walks the `predecessors` back from `asset` to the entries and adds every chain to `chains`.
+/// `suffix` contains the steps after `asset` in reverse order.
+fn collect_chains(
+    asset: AssetVc,
+    predecessors: &FxHashMap<AssetVc, Vec<(AssetVc, TraceReferenceKind)>>,
+    suffix: &mut Vec<(AssetVc, Option<TraceReferenceKind>)>,
+    chains: &mut Vec<Vec<(AssetVc, Option<TraceReferenceKind>)>>,
+) {
+    let Some(predecessors_of_asset) = predecessors.get(&asset) else {
+        chains.push(
+            std::iter::once((asset, None))
+                .chain(suffix.iter().rev().copied())
+                .collect(),
+        );
+        return;
+    };
+    for &(from, kind) in predecessors_of_asset {
+        if chains.len() >= MAX_TRACE_CHAINS {
+            return;
+        }
+        suffix.push((asset, Some(kind)));
+        collect_chains(from, predecessors, suffix, chains);
+        suffix.pop();
+    }
+}
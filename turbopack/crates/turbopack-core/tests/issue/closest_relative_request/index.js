@@ -0,0 +1 @@
+import "./components/button";
@@ -13,7 +13,7 @@ use turbopack_core::{
     reference::{ModuleReference, ModuleReferences},
     reference_type::ImportContext,
     resolve::origin::ResolveOrigin,
-    source::{OptionSource, Source},
+    source::{OptionSource, Source, SourceReferences},
     source_map::GenerateSourceMap,
 };
 
@@ -146,11 +146,21 @@ impl Module for CssModuleAsset {
         let result = self.parse_css().await?;
         // TODO: include CSS source map
 
-        match &*result {
-            ParseCssResult::Ok { references, .. } => Ok(**references),
-            ParseCssResult::Unparsable => Ok(ModuleReferences::empty()),
-            ParseCssResult::NotFound => Ok(ModuleReferences::empty()),
+        let references = match &*result {
+            ParseCssResult::Ok { references, .. } => **references,
+            ParseCssResult::Unparsable => ModuleReferences::empty(),
+            ParseCssResult::NotFound => ModuleReferences::empty(),
+        };
+
+        // Stylesheets compiled from e.g. Sass also reference every file they loaded
+        let source = self.await?.source;
+        if let Some(source) = ResolvedVc::try_sidecast::<Box<dyn SourceReferences>>(source) {
+            let mut references = references.owned().await?;
+            references.extend(source.source_references().await?.iter().copied());
+            return Ok(Vc::cell(references));
         }
+
+        Ok(references)
     }
     #[turbo_tasks::function]
     fn side_effects(self: Vc<Self>) -> Vc<ModuleSideEffects> {
@@ -327,7 +337,10 @@ impl CssChunkItem for CssModuleChunkItem {
 
         let result = self
             .module
-            .finalize_css(*chunking_context, *chunking_context.minify_type().await?)
+            .finalize_css(
+                *chunking_context,
+                chunking_context.minify_type().owned().await?,
+            )
             .await?;
 
         if let FinalCssResult::Ok {
@@ -13,6 +13,7 @@ mod lifetime_util;
 mod module_asset;
 pub(crate) mod process;
 pub(crate) mod references;
+pub mod sass;
 
 use bincode::{Decode, Encode};
 use turbo_tasks::{NonLocalValue, TaskInput, trace::TraceRawVcs};
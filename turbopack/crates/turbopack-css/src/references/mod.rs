@@ -29,6 +29,7 @@ use crate::references::{
 pub(crate) mod compose;
 pub(crate) mod import;
 pub(crate) mod internal;
+pub(crate) mod sass;
 pub(crate) mod url;
 
 pub type AnalyzedRefs = (
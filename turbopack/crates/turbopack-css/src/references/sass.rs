@@ -0,0 +1,50 @@
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, ValueToString, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbopack_core::{
+    chunk::{ChunkingType, ChunkingTypeOption},
+    file_source::FileSource,
+    raw_module::RawModule,
+    reference::ModuleReference,
+    resolve::ModuleResolveResult,
+};
+
+use crate::sass::SassImportKind;
+
+/// A file loaded by a Sass `@use`, `@forward` or `@import` rule.
+///
+/// The content of the file is already compiled into the importing stylesheet, so this reference is
+/// only traced and never chunked.
+#[turbo_tasks::value]
+#[derive(Hash, Debug, ValueToString)]
+#[value_to_string("{} {}", self.kind.as_str(), self.url)]
+pub struct SassImportReference {
+    kind: SassImportKind,
+    url: RcStr,
+    path: FileSystemPath,
+}
+
+#[turbo_tasks::value_impl]
+impl SassImportReference {
+    #[turbo_tasks::function]
+    pub fn new(kind: SassImportKind, url: RcStr, path: FileSystemPath) -> Vc<Self> {
+        Self::cell(SassImportReference { kind, url, path })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleReference for SassImportReference {
+    #[turbo_tasks::function]
+    async fn resolve_reference(&self) -> Result<Vc<ModuleResolveResult>> {
+        let module = RawModule::new(Vc::upcast(FileSource::new(self.path.clone())))
+            .to_resolved()
+            .await?;
+        Ok(*ModuleResolveResult::module(ResolvedVc::upcast(module)))
+    }
+
+    #[turbo_tasks::function]
+    fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
+        Vc::cell(Some(ChunkingType::Traced))
+    }
+}
@@ -0,0 +1,759 @@
+//! Native compilation of Sass stylesheets (`.scss` and `.sass`).
+//!
+//! Stylesheets are compiled with [`grass_compiler`] instead of `sass-loader` in the Node.js pool.
+//! Every file loaded through `@use`, `@forward` or `@import` is read through turbo-tasks before
+//! compilation, so editing a partial invalidates the stylesheets depending on it, and the loaded
+//! files are exposed as references of the resulting CSS module.
+//!
+//! Features that can't be handled natively are compiled with the fallback transform (usually the
+//! `sass-loader` [`SourceTransform`]) instead.
+
+mod scan;
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use bincode::{Decode, Encode};
+use grass_compiler::{Fs, Logger, OutputStyle, codemap::SpanLoc};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::base::sourcemap::SourceMapBuilder;
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{NonLocalValue, ResolvedVc, TryJoinIterExt, Vc, trace::TraceRawVcs};
+use turbo_tasks_fs::{File, FileContent, FileSystemPath, rope::Rope};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    issue::{
+        Issue, IssueExt, IssueSeverity, IssueSource, IssueStage, OptionIssueSource,
+        OptionStyledString, StyledString,
+    },
+    reference::ModuleReferences,
+    source::{Source, SourceReferences},
+    source_map::{GenerateSourceMap, utils::resolve_source_map_sources},
+    source_transform::SourceTransform,
+};
+
+pub use self::scan::SassImportKind;
+use self::scan::{SassImportScan, has_relative_url, scan_sass_imports};
+use crate::references::sass::SassImportReference;
+
+const SASS_EXTENSIONS: [&str; 3] = ["scss", "sass", "css"];
+
+/// A [SourceTransform] that compiles Sass stylesheets to CSS.
+#[turbo_tasks::value]
+pub struct SassSourceTransform {
+    /// Used for stylesheets that use features that aren't supported by the native compiler. When
+    /// not set, those stylesheets fail to compile.
+    fallback: Option<ResolvedVc<Box<dyn SourceTransform>>>,
+    rename_as: RcStr,
+    /// Additional directories to look for modules in, like Sass' `loadPaths` option.
+    load_paths: Vec<FileSystemPath>,
+}
+
+#[turbo_tasks::value_impl]
+impl SassSourceTransform {
+    #[turbo_tasks::function]
+    pub fn new(
+        fallback: Option<ResolvedVc<Box<dyn SourceTransform>>>,
+        rename_as: RcStr,
+        load_paths: Vec<FileSystemPath>,
+    ) -> Vc<Self> {
+        SassSourceTransform {
+            fallback,
+            rename_as,
+            load_paths,
+        }
+        .cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl SourceTransform for SassSourceTransform {
+    #[turbo_tasks::function]
+    async fn transform(&self, source: ResolvedVc<Box<dyn Source>>) -> Result<Vc<Box<dyn Source>>> {
+        let result = compile_sass(*source, self.load_paths.clone())
+            .to_resolved()
+            .await?;
+        match &*result.await? {
+            SassCompileResult::Unsupported { reason } => {
+                if let Some(fallback) = self.fallback {
+                    return Ok(fallback.transform(*source));
+                }
+                SassCompileIssue {
+                    message: format!("{reason} is not supported by the native Sass compiler")
+                        .into(),
+                    source: IssueSource::from_source_only(source),
+                }
+                .resolved_cell()
+                .emit();
+            }
+            SassCompileResult::Error { message } => {
+                if let Some(fallback) = self.fallback {
+                    // The fallback reports the authoritative error message, and handles the few
+                    // language features which `grass` doesn't implement.
+                    return Ok(fallback.transform(*source));
+                }
+                SassCompileIssue {
+                    message: message.clone(),
+                    source: IssueSource::from_source_only(source),
+                }
+                .resolved_cell()
+                .emit();
+            }
+            SassCompileResult::Ok { warnings, .. } => {
+                for warning in warnings {
+                    SassWarningIssue {
+                        message: warning.clone(),
+                        source: IssueSource::from_source_only(source),
+                    }
+                    .resolved_cell()
+                    .emit();
+                }
+            }
+            SassCompileResult::NotFound => {}
+        }
+
+        Ok(Vc::upcast(
+            SassSource {
+                source,
+                result,
+                rename_as: self.rename_as.clone(),
+            }
+            .cell(),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, TraceRawVcs, NonLocalValue, Encode, Decode)]
+pub struct SassDependency {
+    pub kind: SassImportKind,
+    pub url: RcStr,
+    pub path: FileSystemPath,
+}
+
+#[turbo_tasks::value(shared)]
+pub enum SassCompileResult {
+    Ok {
+        css: RcStr,
+        /// Every file loaded by the compiler, in load order.
+        dependencies: Vec<SassDependency>,
+        warnings: Vec<RcStr>,
+        /// The load paths passed to the compiler, needed to compile the stylesheet again for the
+        /// source map.
+        load_paths: Vec<RcStr>,
+    },
+    /// The stylesheet uses features that can only be handled by the JavaScript implementation.
+    Unsupported {
+        reason: RcStr,
+    },
+    Error {
+        message: RcStr,
+    },
+    NotFound,
+}
+
+/// The CSS output of a Sass stylesheet compiled by [SassSourceTransform].
+#[turbo_tasks::value]
+pub struct SassSource {
+    source: ResolvedVc<Box<dyn Source>>,
+    result: ResolvedVc<SassCompileResult>,
+    rename_as: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl SourceReferences for SassSource {
+    /// References to every module loaded by the stylesheet. These are only traced, the loaded
+    /// modules are already part of the compiled CSS.
+    #[turbo_tasks::function]
+    async fn source_references(&self) -> Result<Vc<ModuleReferences>> {
+        let SassCompileResult::Ok { dependencies, .. } = &*self.result.await? else {
+            return Ok(ModuleReferences::empty());
+        };
+        let references = dependencies
+            .iter()
+            .map(|dependency| async move {
+                Ok(ResolvedVc::upcast(
+                    SassImportReference::new(
+                        dependency.kind,
+                        dependency.url.clone(),
+                        dependency.path.clone(),
+                    )
+                    .to_resolved()
+                    .await?,
+                ))
+            })
+            .try_join()
+            .await?;
+        Ok(Vc::cell(references))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Source for SassSource {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        self.source.ident().rename_as(self.rename_as.clone())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for SassSource {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        Ok(match &*self.result.await? {
+            SassCompileResult::Ok { css, .. } => {
+                AssetContent::file(FileContent::Content(File::from(css.clone())).cell())
+            }
+            _ => AssetContent::file(FileContent::NotFound.cell()),
+        })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl GenerateSourceMap for SassSource {
+    /// `grass` doesn't generate source maps, so the stylesheet is compiled again with a marker
+    /// comment before every statement of the loaded files. The markers end up next to the CSS
+    /// generated from the statement, which maps every line of the output to a line of a Sass
+    /// file.
+    #[turbo_tasks::function]
+    async fn generate_source_map(&self) -> Result<Vc<FileContent>> {
+        let SassCompileResult::Ok {
+            css,
+            dependencies,
+            load_paths,
+            ..
+        } = &*self.result.await?
+        else {
+            return Ok(FileContent::NotFound.cell());
+        };
+        let entry_path = self.source.ident().path().owned().await?;
+
+        let mut paths = vec![entry_path.clone()];
+        for dependency in dependencies {
+            if !paths.contains(&dependency.path) {
+                paths.push(dependency.path.clone());
+            }
+        }
+        let contents = paths
+            .iter()
+            .enumerate()
+            .map(async |(index, path)| {
+                let content = if index == 0 {
+                    self.source.content().file_content().await?
+                } else {
+                    path.read().await?
+                };
+                Ok(match &*content {
+                    FileContent::Content(file) => Some(file.content().to_str()?.into_owned()),
+                    _ => None,
+                })
+            })
+            .try_join()
+            .await?;
+
+        let mut fs = SassMemoryFs::default();
+        for (index, (path, content)) in paths.iter().zip(&contents).enumerate() {
+            let Some(content) = content else {
+                return Ok(FileContent::NotFound.cell());
+            };
+            let content = if path.extension() == "sass" {
+                // The indented syntax doesn't allow comments everywhere
+                content.clone()
+            } else {
+                instrument_sass(content, index)
+            };
+            fs.files.insert(sys_path(path), content);
+        }
+        let load_paths = load_paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+        let logger = SassWarningLogger::default();
+        let options = grass_compiler::Options::default()
+            .fs(&fs)
+            .logger(&logger)
+            .load_paths(&load_paths)
+            .style(OutputStyle::Expanded);
+        let Ok(instrumented) = grass_compiler::from_path(sys_path(&entry_path), &options) else {
+            // A marker in an unexpected position, the stylesheet compiled without them
+            return Ok(FileContent::NotFound.cell());
+        };
+
+        let base = entry_path.parent();
+        let mut builder = SourceMapBuilder::new(None);
+        for (path, content) in paths.iter().zip(&contents) {
+            let url = base
+                .get_relative_path_to(path)
+                .unwrap_or_else(|| path.path.clone());
+            let index = builder.add_source(url.as_str().into());
+            builder.set_source_contents(index, content.as_deref().map(Into::into));
+        }
+        for (line, (text, origin)) in css
+            .lines()
+            .zip(sass_line_origins(css, &instrumented))
+            .enumerate()
+        {
+            let Some((file, original_line)) = origin else {
+                continue;
+            };
+            let original_column = contents[file as usize]
+                .as_deref()
+                .and_then(|content| content.lines().nth(original_line as usize))
+                .map_or(0, indentation);
+            builder.add_raw(
+                line as u32,
+                indentation(text),
+                original_line,
+                original_column,
+                Some(file),
+                None,
+                false,
+            );
+        }
+        let mut map = Vec::new();
+        builder.into_sourcemap().to_writer(&mut map)?;
+
+        Ok(
+            match resolve_source_map_sources(Some(&Rope::from(map)), &entry_path).await? {
+                Some(map) => FileContent::Content(File::from(map)).cell(),
+                None => FileContent::NotFound.cell(),
+            },
+        )
+    }
+}
+
+const SOURCE_MAP_MARKER: &str = "turbopack-sass-source:";
+
+/// Inserts a loud comment with the file index and the line number before every line that starts
+/// a statement. Lines that might be inside of a selector list, a value or a comment are skipped,
+/// as a comment isn't allowed everywhere.
+fn instrument_sass(content: &str, file: usize) -> String {
+    let mut result = String::with_capacity(content.len() * 2);
+    let mut statement_start = true;
+    let mut in_comment = false;
+    // The brace depth of the `@function` that is being read, functions can't contain comments
+    let mut function_depth = None;
+    let mut depth = 0usize;
+    for (line, text) in content.lines().enumerate() {
+        let code = text.split(" //").next().unwrap_or_default().trim();
+        let code = if code.starts_with("//") { "" } else { code };
+        if !in_comment
+            && statement_start
+            && function_depth.is_none()
+            && !code.is_empty()
+            && !code.starts_with('}')
+            && !["@else", "@use", "@forward", "@import", "@charset"]
+                .iter()
+                .any(|keyword| code.starts_with(keyword))
+        {
+            let indent = &text[..text.len() - text.trim_start().len()];
+            result.push_str(&format!(
+                "{indent}/*! {SOURCE_MAP_MARKER}{file}:{line} */\n"
+            ));
+        }
+        result.push_str(text);
+        result.push('\n');
+
+        if code.starts_with("@function") {
+            function_depth = Some(depth);
+        }
+        depth += code.matches('{').count();
+        depth = depth.saturating_sub(code.matches('}').count());
+        if function_depth.is_some_and(|function_depth| depth <= function_depth)
+            && code.contains('}')
+        {
+            function_depth = None;
+        }
+        if let (Some(open), close) = (code.rfind("/*"), code.rfind("*/")) {
+            in_comment = close.is_none_or(|close| close < open);
+        } else if in_comment && code.contains("*/") {
+            in_comment = false;
+        }
+        if !code.is_empty() && !in_comment {
+            statement_start = code.ends_with(['{', '}', ';']);
+        }
+    }
+    result
+}
+
+/// Returns the file index and line of the marker preceding every line of `css`, by matching its
+/// lines with the output compiled from the instrumented files.
+fn sass_line_origins(css: &str, instrumented: &str) -> Vec<Option<(u32, u32)>> {
+    // Nested rules leave empty rules with just a marker behind, so the instrumented output has a
+    // few more lines. Give up on a line when it can't be found nearby.
+    const MAX_SKIPPED_LINES: usize = 64;
+
+    let mut origin = None;
+    let mut annotated = Vec::new();
+    for line in instrumented.lines() {
+        let trimmed = line.trim();
+        if let Some(marker) = trimmed
+            .strip_prefix("/*! ")
+            .and_then(|marker| marker.strip_suffix(" */"))
+            .and_then(|marker| marker.strip_prefix(SOURCE_MAP_MARKER))
+        {
+            origin = marker
+                .split_once(':')
+                .and_then(|(file, line)| Some((file.parse().ok()?, line.parse().ok()?)));
+            continue;
+        }
+        annotated.push((line, origin));
+    }
+
+    let mut next = 0;
+    css.lines()
+        .map(|line| {
+            if matches!(line.trim(), "" | "}") {
+                return None;
+            }
+            let found = annotated[next.min(annotated.len())..]
+                .iter()
+                .take(MAX_SKIPPED_LINES)
+                .position(|(text, _)| *text == line)?;
+            next += found + 1;
+            annotated[next - 1].1
+        })
+        .collect()
+}
+
+fn indentation(line: &str) -> u32 {
+    (line.len() - line.trim_start().len()) as u32
+}
+
+/// Reads the stylesheet and every file it loads, and compiles them with `grass`.
+#[turbo_tasks::function]
+async fn compile_sass(
+    source: Vc<Box<dyn Source>>,
+    load_paths: Vec<FileSystemPath>,
+) -> Result<Vc<SassCompileResult>> {
+    let entry_path = source.ident().path().owned().await?;
+    let FileContent::Content(entry) = &*source.content().file_content().await? else {
+        return Ok(SassCompileResult::NotFound.cell());
+    };
+    let entry = entry.content().to_str()?.into_owned();
+
+    let mut fs = SassMemoryFs::default();
+    let mut dependencies = Vec::new();
+    let mut grass_load_paths: Vec<PathBuf> = load_paths.iter().map(sys_path).collect();
+    let mut visited = FxHashSet::default();
+    let mut queue = VecDeque::from([(entry_path.clone(), entry)]);
+
+    while let Some((path, content)) = queue.pop_front() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        let SassImportScan {
+            imports,
+            unsupported,
+        } = scan_sass_imports(&content);
+        if let Some(reason) = unsupported {
+            return Ok(SassCompileResult::Unsupported {
+                reason: reason.into(),
+            }
+            .cell());
+        }
+        if path != entry_path && path.parent() != entry_path.parent() && has_relative_url(&content)
+        {
+            // Sass keeps urls as they are written, which would make them relative to the wrong
+            // file. `resolve-url-loader` in the fallback rewrites them.
+            return Ok(SassCompileResult::Unsupported {
+                reason: rcstr!("relative url() in a module from another directory"),
+            }
+            .cell());
+        }
+        fs.files.insert(sys_path(&path), content);
+
+        for import in imports {
+            let Some(found) = resolve_sass_import(&path, &import.url, &load_paths).await? else {
+                if import.url.starts_with('~') {
+                    return Ok(SassCompileResult::Unsupported {
+                        reason: rcstr!("webpack-style `~` module url"),
+                    }
+                    .cell());
+                }
+                match find_in_node_modules(&path, &import.url).await? {
+                    Some((node_modules, found)) => {
+                        let node_modules = sys_path(&node_modules);
+                        if !grass_load_paths.contains(&node_modules) {
+                            grass_load_paths.push(node_modules);
+                        }
+                        enqueue(
+                            &mut queue,
+                            &mut dependencies,
+                            import.kind,
+                            &import.url,
+                            found,
+                        );
+                    }
+                    None => {
+                        return Ok(SassCompileResult::Error {
+                            message: format!(
+                                "Can't find stylesheet to import: {} \"{}\" in {}",
+                                import.kind.as_str(),
+                                import.url,
+                                path.path
+                            )
+                            .into(),
+                        }
+                        .cell());
+                    }
+                }
+                continue;
+            };
+            enqueue(
+                &mut queue,
+                &mut dependencies,
+                import.kind,
+                &import.url,
+                found,
+            );
+        }
+    }
+
+    let logger = SassWarningLogger::default();
+    let options = grass_compiler::Options::default()
+        .fs(&fs)
+        .logger(&logger)
+        .load_paths(&grass_load_paths)
+        .style(OutputStyle::Expanded);
+
+    Ok(
+        match grass_compiler::from_path(sys_path(&entry_path), &options) {
+            Ok(css) => SassCompileResult::Ok {
+                css: css.into(),
+                dependencies,
+                warnings: logger.warnings.into_inner(),
+                load_paths: grass_load_paths
+                    .iter()
+                    .map(|path| path.to_string_lossy().into())
+                    .collect(),
+            },
+            Err(err) => SassCompileResult::Error {
+                message: err.to_string().into(),
+            },
+        }
+        .cell(),
+    )
+}
+
+fn enqueue(
+    queue: &mut VecDeque<(FileSystemPath, String)>,
+    dependencies: &mut Vec<SassDependency>,
+    kind: SassImportKind,
+    url: &str,
+    found: Vec<(FileSystemPath, String)>,
+) {
+    for (path, content) in found {
+        dependencies.push(SassDependency {
+            kind,
+            url: url.into(),
+            path: path.clone(),
+        });
+        queue.push_back((path, content));
+    }
+}
+
+/// Resolves a module url like Sass does: relative to the importing file first, then in each load
+/// path. Returns every existing candidate of the first location that has one, so the compiler can
+/// report ambiguous imports the same way the reference implementation does.
+async fn resolve_sass_import(
+    importer: &FileSystemPath,
+    url: &str,
+    load_paths: &[FileSystemPath],
+) -> Result<Option<Vec<(FileSystemPath, String)>>> {
+    let base = importer.parent();
+    for dir in std::iter::once(&base).chain(load_paths) {
+        let found = read_candidates(dir, url).await?;
+        if !found.is_empty() {
+            return Ok(Some(found));
+        }
+    }
+    Ok(None)
+}
+
+/// Looks for a bare module url in the `node_modules` directories above the importing file.
+async fn find_in_node_modules(
+    importer: &FileSystemPath,
+    url: &str,
+) -> Result<Option<(FileSystemPath, Vec<(FileSystemPath, String)>)>> {
+    if url.starts_with('.') || url.starts_with('/') {
+        return Ok(None);
+    }
+    let mut dir = importer.parent();
+    loop {
+        let node_modules = dir.join("node_modules")?;
+        let found = read_candidates(&node_modules, url).await?;
+        if !found.is_empty() {
+            return Ok(Some((node_modules, found)));
+        }
+        if dir.is_root() {
+            return Ok(None);
+        }
+        dir = dir.parent();
+    }
+}
+
+async fn read_candidates(dir: &FileSystemPath, url: &str) -> Result<Vec<(FileSystemPath, String)>> {
+    let Some(path) = dir.try_join(url) else {
+        return Ok(Vec::new());
+    };
+    let mut candidates = Vec::new();
+    if SASS_EXTENSIONS.contains(&path.extension()) {
+        candidates.extend([path.clone(), partial(&path)?]);
+    } else {
+        for extension in SASS_EXTENSIONS {
+            let file = path.append(&format!(".{extension}"))?;
+            candidates.extend([partial(&file)?, file]);
+        }
+        for extension in SASS_EXTENSIONS {
+            candidates.push(path.join(&format!("_index.{extension}"))?);
+            candidates.push(path.join(&format!("index.{extension}"))?);
+        }
+    }
+
+    let contents = candidates.iter().map(|path| path.read()).try_join().await?;
+    let mut found = Vec::new();
+    for (path, content) in candidates.into_iter().zip(contents) {
+        if let FileContent::Content(file) = &*content {
+            found.push((path, file.content().to_str()?.into_owned()));
+        }
+    }
+    // Prefer the files next to `url`, and only fall back to index files when there are none.
+    if found.len() > 1
+        && !found
+            .iter()
+            .all(|(p, _)| p.file_stem().is_some_and(is_index))
+    {
+        found.retain(|(p, _)| !p.file_stem().is_some_and(is_index));
+    }
+    Ok(found)
+}
+
+fn is_index(stem: &str) -> bool {
+    stem == "_index" || stem == "index"
+}
+
+fn partial(path: &FileSystemPath) -> Result<FileSystemPath> {
+    path.parent().join(&format!("_{}", path.file_name()))
+}
+
+fn sys_path(path: &FileSystemPath) -> PathBuf {
+    Path::new("/").join(&*path.path)
+}
+
+/// The files read through turbo-tasks, as seen by the compiler.
+#[derive(Debug, Default)]
+struct SassMemoryFs {
+    files: FxHashMap<PathBuf, String>,
+}
+
+impl Fs for SassMemoryFs {
+    fn is_dir(&self, path: &Path) -> bool {
+        self.files.keys().any(|file| file.starts_with(path))
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.files.contains_key(path)
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.files
+            .get(path)
+            .map(|content| content.clone().into_bytes())
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+/// Collects `@warn` messages so they can be reported as issues.
+#[derive(Debug, Default)]
+struct SassWarningLogger {
+    warnings: RefCell<Vec<RcStr>>,
+}
+
+impl Logger for SassWarningLogger {
+    fn debug(&self, _location: SpanLoc, _message: &str) {}
+
+    fn warn(&self, location: SpanLoc, message: &str) {
+        self.warnings
+            .borrow_mut()
+            .push(format!("{message}\n    at {location}").into());
+    }
+}
+
+#[turbo_tasks::value]
+struct SassCompileIssue {
+    message: RcStr,
+    source: IssueSource,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for SassCompileIssue {
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.source.file_path()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Transform.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(rcstr!("Compiling Sass stylesheet failed")).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> Vc<OptionIssueSource> {
+        Vc::cell(Some(self.source))
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(self.message.clone()).resolved_cell(),
+        ))
+    }
+}
+
+#[turbo_tasks::value]
+struct SassWarningIssue {
+    message: RcStr,
+    source: IssueSource,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for SassWarningIssue {
+    fn severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.source.file_path()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Transform.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(rcstr!("Sass warning")).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> Vc<OptionIssueSource> {
+        Vc::cell(Some(self.source))
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(self.message.clone()).resolved_cell(),
+        ))
+    }
+}
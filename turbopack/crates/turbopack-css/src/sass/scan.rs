@@ -0,0 +1,300 @@
+//! A lightweight scanner for the module loading rules of Sass stylesheets.
+//!
+//! This is not a Sass parser. It only finds the string arguments of `@use`, `@forward` and
+//! `@import` rules (skipping comments and unrelated strings), which is enough to know which files
+//! the compiler is going to ask for. Anything that can't be determined statically (e.g.
+//! interpolation in an import url) is reported as unsupported, so the caller can fall back to the
+//! JavaScript Sass implementation.
+
+use bincode::{Decode, Encode};
+use turbo_tasks::{NonLocalValue, TaskInput, trace::TraceRawVcs};
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, TraceRawVcs, NonLocalValue, TaskInput, Encode, Decode,
+)]
+pub enum SassImportKind {
+    Use,
+    Forward,
+    Import,
+}
+
+impl SassImportKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SassImportKind::Use => "@use",
+            SassImportKind::Forward => "@forward",
+            SassImportKind::Import => "@import",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SassImport {
+    pub kind: SassImportKind,
+    pub url: String,
+    /// Byte offset of the at-rule in the scanned source.
+    pub offset: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct SassImportScan {
+    pub imports: Vec<SassImport>,
+    /// Set when the stylesheet loads modules in a way that can't be resolved statically.
+    pub unsupported: Option<&'static str>,
+}
+
+/// Finds all module loads in a `.scss` or `.sass` stylesheet.
+///
+/// Built-in modules (`sass:math`) and plain CSS imports (`@import "foo.css"`,
+/// `@import url(...)`, remote urls) are not reported, as they are never loaded from disk by the
+/// compiler.
+pub fn scan_sass_imports(source: &str) -> SassImportScan {
+    let bytes = source.as_bytes();
+    let mut scan = SassImportScan::default();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => i = skip_line_comment(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(bytes, i),
+            b'"' | b'\'' => i = skip_string(bytes, i),
+            b'@' => {
+                let rest = &bytes[i + 1..];
+                let kind = [
+                    (SassImportKind::Use, "use"),
+                    (SassImportKind::Forward, "forward"),
+                    (SassImportKind::Import, "import"),
+                ]
+                .into_iter()
+                .find(|(_, keyword)| starts_with_keyword(rest, keyword));
+                match kind {
+                    Some((kind, keyword)) => {
+                        i = scan_arguments(source, i, i + 1 + keyword.len(), kind, &mut scan);
+                    }
+                    None => i += 1,
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    scan
+}
+
+/// Returns true if the stylesheet contains a `url()` that is resolved relative to the file it is
+/// written in. Sass doesn't rewrite those when inlining a module into another stylesheet.
+pub fn has_relative_url(source: &str) -> bool {
+    let bytes = source.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => i = skip_line_comment(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(bytes, i),
+            b'u' | b'U'
+                if bytes[i..].len() >= 4 && bytes[i..i + 4].eq_ignore_ascii_case(b"url(") =>
+            {
+                let mut start = skip_whitespace(bytes, i + 4);
+                if matches!(bytes.get(start), Some(b'"') | Some(b'\'')) {
+                    start += 1;
+                }
+                let url = &source[start..];
+                if !url.is_empty()
+                    && !url.starts_with(['/', '#', '$', ')', '"', '\''])
+                    && !url.starts_with("data:")
+                    && !url.starts_with("http:")
+                    && !url.starts_with("https:")
+                    && !url.starts_with("#{")
+                {
+                    return true;
+                }
+                i = start;
+            }
+            _ => i += 1,
+        }
+    }
+    false
+}
+
+fn scan_arguments(
+    source: &str,
+    rule_start: usize,
+    mut i: usize,
+    kind: SassImportKind,
+    scan: &mut SassImportScan,
+) -> usize {
+    let bytes = source.as_bytes();
+    loop {
+        i = skip_whitespace_and_comments(bytes, i);
+        match bytes.get(i) {
+            Some(b'"') | Some(b'\'') => {
+                let end = skip_string(bytes, i);
+                let url = &source[i + 1..end.saturating_sub(1).max(i + 1)];
+                if url.contains("#{") {
+                    scan.unsupported
+                        .get_or_insert("interpolation in a module url");
+                } else if !is_plain_css(kind, url) {
+                    scan.imports.push(SassImport {
+                        kind,
+                        url: url.to_string(),
+                        offset: rule_start,
+                    });
+                }
+                i = end;
+            }
+            Some(_) if bytes[i..].starts_with(b"url(") => return i + 4,
+            Some(_) => {
+                // The indented syntax allows unquoted `@import` urls. Those are rare enough that
+                // they are left to the JavaScript implementation.
+                scan.unsupported.get_or_insert("unquoted module url");
+                return i;
+            }
+            None => return i,
+        }
+        if kind != SassImportKind::Import {
+            return i;
+        }
+        let next = skip_whitespace_and_comments(bytes, i);
+        if bytes.get(next) != Some(&b',') {
+            return i;
+        }
+        i = next + 1;
+    }
+}
+
+fn is_plain_css(kind: SassImportKind, url: &str) -> bool {
+    if url.starts_with("sass:") {
+        return true;
+    }
+    kind == SassImportKind::Import
+        && (url.ends_with(".css")
+            || url.starts_with("http://")
+            || url.starts_with("https://")
+            || url.starts_with("//"))
+}
+
+fn starts_with_keyword(bytes: &[u8], keyword: &str) -> bool {
+    bytes.starts_with(keyword.as_bytes())
+        && matches!(
+            bytes.get(keyword.len()),
+            Some(b' ' | b'\t' | b'\n' | b'\r' | b'"' | b'\'')
+        )
+}
+
+fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while matches!(bytes.get(i), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+        i += 1;
+    }
+    i
+}
+
+fn skip_whitespace_and_comments(bytes: &[u8], mut i: usize) -> usize {
+    loop {
+        i = skip_whitespace(bytes, i);
+        match (bytes.get(i), bytes.get(i + 1)) {
+            (Some(b'/'), Some(b'/')) => i = skip_line_comment(bytes, i),
+            (Some(b'/'), Some(b'*')) => i = skip_block_comment(bytes, i),
+            _ => return i,
+        }
+    }
+}
+
+fn skip_line_comment(bytes: &[u8], i: usize) -> usize {
+    bytes[i..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |p| i + p + 1)
+}
+
+fn skip_block_comment(bytes: &[u8], i: usize) -> usize {
+    bytes[i + 2..]
+        .windows(2)
+        .position(|w| w == b"*/")
+        .map_or(bytes.len(), |p| i + 2 + p + 2)
+}
+
+/// Returns the index after the closing quote of the string starting at `i`.
+fn skip_string(bytes: &[u8], i: usize) -> usize {
+    let quote = bytes[i];
+    let mut j = i + 1;
+    while j < bytes.len() {
+        match bytes[j] {
+            b'\\' => j += 2,
+            b if b == quote => return j + 1,
+            b'\n' => return j,
+            _ => j += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(source: &str) -> Vec<(SassImportKind, String)> {
+        scan_sass_imports(source)
+            .imports
+            .into_iter()
+            .map(|i| (i.kind, i.url))
+            .collect()
+    }
+
+    #[test]
+    fn finds_module_loads() {
+        assert_eq!(
+            urls(
+                r#"
+                @use "sass:math";
+                @use 'theme' as t with ($primary: red);
+                @forward "src/list" hide list-reset;
+                @import "a", 'b/c';
+                .foo { content: "@import 'not-an-import'"; }
+                "#
+            ),
+            vec![
+                (SassImportKind::Use, "theme".to_string()),
+                (SassImportKind::Forward, "src/list".to_string()),
+                (SassImportKind::Import, "a".to_string()),
+                (SassImportKind::Import, "b/c".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_comments_and_plain_css() {
+        assert_eq!(
+            urls(
+                r#"
+                // @use "commented";
+                /* @import "also-commented"; */
+                @import "theme.css";
+                @import url(foo.scss);
+                @import "https://example.com/font";
+                @import "real";
+                "#
+            ),
+            vec![(SassImportKind::Import, "real".to_string())]
+        );
+    }
+
+    #[test]
+    fn reports_unsupported() {
+        assert!(
+            scan_sass_imports(r#"@use "themes/#{$name}";"#)
+                .unsupported
+                .is_some()
+        );
+        assert!(scan_sass_imports("@import foo\n").unsupported.is_some());
+        assert!(scan_sass_imports(r#"@use "foo";"#).unsupported.is_none());
+    }
+
+    #[test]
+    fn detects_relative_urls() {
+        assert!(has_relative_url(".a { background: url(./img.png) }"));
+        assert!(has_relative_url(".a { background: url('img.png') }"));
+        assert!(!has_relative_url(".a { background: url(/img.png) }"));
+        assert!(!has_relative_url(
+            ".a { background: url(data:image/png;base64,AA) }"
+        ));
+        assert!(!has_relative_url(".a { background: url($image) }"));
+        assert!(!has_relative_url("// url(./img.png)\n"));
+    }
+}
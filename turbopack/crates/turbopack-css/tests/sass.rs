@@ -0,0 +1,132 @@
+#![feature(arbitrary_self_types)]
+#![feature(arbitrary_self_types_pointers)]
+#![allow(clippy::needless_return)] // tokio macro-generated code doesn't respect this
+#![cfg(test)]
+
+use anyhow::Result;
+use swc_core::base::sourcemap::SourceMap;
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{ResolvedVc, TryJoinIterExt, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileContent, FileSystem};
+use turbo_tasks_testing::{Registration, register, run_once};
+use turbopack_core::{
+    asset::Asset, file_source::FileSource, module::Module, reference::ModuleReference,
+    source::SourceReferences, source_map::GenerateSourceMap, source_transform::SourceTransform,
+};
+use turbopack_css::sass::SassSourceTransform;
+
+static REGISTRATION: Registration = register!();
+
+/// Compiles `tests/sass/index.scss` with `vendor` as load path, and returns the CSS followed by
+/// the paths of the loaded modules.
+#[turbo_tasks::function(operation)]
+async fn compile_fixture() -> Result<Vc<Vec<RcStr>>> {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/sass");
+    let root = DiskFileSystem::new(rcstr!("fixture"), fixture.into())
+        .root()
+        .owned()
+        .await?;
+    let source = FileSource::new(root.join("index.scss")?);
+    let output = SassSourceTransform::new(None, rcstr!("*.css"), vec![root.join("vendor")?])
+        .transform(Vc::upcast(source))
+        .to_resolved()
+        .await?;
+
+    let FileContent::Content(css) = &*output.content().file_content().await? else {
+        anyhow::bail!("no css");
+    };
+    let mut result = vec![css.content().to_str()?.trim().into()];
+
+    let output = ResolvedVc::try_sidecast::<Box<dyn SourceReferences>>(output).unwrap();
+    let references = output.source_references().await?;
+    let loaded = references
+        .iter()
+        .map(async |reference| {
+            let modules = reference.resolve_reference().primary_modules().await?;
+            modules
+                .iter()
+                .map(async |module| Ok(module.ident().path().await?.path.clone()))
+                .try_join()
+                .await
+        })
+        .try_join()
+        .await?;
+    result.extend(loaded.into_iter().flatten());
+    Ok(Vc::cell(result))
+}
+
+/// Compiles `tests/sass/index.scss` like [compile_fixture], and returns the mappings of the source
+/// map as `generated line:column -> source:line:column`.
+#[turbo_tasks::function(operation)]
+async fn fixture_source_map() -> Result<Vc<Vec<RcStr>>> {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/sass");
+    let root = DiskFileSystem::new(rcstr!("fixture"), fixture.into())
+        .root()
+        .owned()
+        .await?;
+    let source = FileSource::new(root.join("index.scss")?);
+    let output = SassSourceTransform::new(None, rcstr!("*.css"), vec![root.join("vendor")?])
+        .transform(Vc::upcast(source))
+        .to_resolved()
+        .await?;
+    let output = ResolvedVc::try_sidecast::<Box<dyn GenerateSourceMap>>(output).unwrap();
+
+    let FileContent::Content(map) = &*output.generate_source_map().await? else {
+        anyhow::bail!("no source map");
+    };
+    let map = SourceMap::from_slice(&map.content().to_bytes())?;
+    Ok(Vc::cell(
+        map.tokens()
+            .map(|token| {
+                format!(
+                    "{}:{} -> {}:{}:{}",
+                    token.get_dst_line(),
+                    token.get_dst_col(),
+                    token.get_source().unwrap_or_default(),
+                    token.get_src_line(),
+                    token.get_src_col()
+                )
+                .into()
+            })
+            .collect(),
+    ))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn compiles_partials_from_use_and_import() {
+    run_once(&REGISTRATION, || async {
+        let result = compile_fixture().read_strongly_consistent().await?;
+        assert_eq!(
+            &*result,
+            &[
+                rcstr!(".button {\n  color: #0070f3;\n  border-radius: 4px;\n}"),
+                // Loaded through `@use` relative to the stylesheet
+                rcstr!("_colors.scss"),
+                // Loaded through `@import` from the load path
+                rcstr!("vendor/_mixins.scss"),
+            ]
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn generates_source_map_for_loaded_files() {
+    run_once(&REGISTRATION, || async {
+        let result = fixture_source_map().read_strongly_consistent().await?;
+        assert_eq!(
+            &*result,
+            &[
+                rcstr!("0:0 -> turbopack:///[fixture]/index.scss:3:0"),
+                rcstr!("1:2 -> turbopack:///[fixture]/index.scss:4:2"),
+                // Declared in the mixin, not where it's included
+                rcstr!("2:2 -> turbopack:///[fixture]/vendor/_mixins.scss:1:2"),
+            ]
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
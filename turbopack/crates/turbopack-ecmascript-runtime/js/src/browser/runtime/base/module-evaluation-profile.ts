@@ -0,0 +1,12 @@
+/* eslint-disable @typescript-eslint/no-unused-vars */
+
+/// <reference path="./runtime-base.ts" />
+/// <reference path="../../../shared/runtime/module-evaluation-profile.ts" />
+
+/**
+ * Enables module evaluation profiling in the browser runtime. The profile can
+ * be read from `globalThis.__TURBOPACK_MODULE_EVALUATION_PROFILE__`, e.g. with
+ * `copy(JSON.stringify(__TURBOPACK_MODULE_EVALUATION_PROFILE__))` in the
+ * devtools console.
+ */
+profileModuleEvaluation(moduleFactories)
@@ -0,0 +1,24 @@
+/**
+ * The client output assets of the build, for precaching them in a service worker.
+ *
+ * The list is set by the service worker entrypoint that Turbopack generates for
+ * `navigator.serviceWorker.register(new URL(...))`. It's empty when this module is
+ * imported by any other script.
+ */
+
+export type PrecacheEntry = {
+  url: string
+  /**
+   * `null` if the URL already changes whenever the content of the asset changes.
+   */
+  revision: string | null
+}
+
+declare const self: {
+  TURBOPACK_PRECACHE_MANIFEST?: PrecacheEntry[]
+}
+
+export const precacheManifest: PrecacheEntry[] =
+  self.TURBOPACK_PRECACHE_MANIFEST ?? []
+
+export default precacheManifest
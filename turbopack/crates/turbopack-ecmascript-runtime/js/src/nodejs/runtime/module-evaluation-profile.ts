@@ -0,0 +1,33 @@
+/* eslint-disable @typescript-eslint/no-unused-vars */
+
+/// <reference path="./runtime-base.ts" />
+/// <reference path="../../shared/runtime/module-evaluation-profile.ts" />
+
+/**
+ * Enables module evaluation profiling in the Node.js runtime.
+ *
+ * When `TURBOPACK_MODULE_EVALUATION_PROFILE` is set, the profile is written to
+ * that path when the process exits. `[pid]` in the path is replaced with the
+ * process id, so that profiles of worker processes don't overwrite each other.
+ */
+;(() => {
+  const isFirstRuntime =
+    globalThis.__TURBOPACK_MODULE_EVALUATION_PROFILE__ == null
+  const profile = profileModuleEvaluation(moduleFactories)
+
+  const profilePath = process.env.TURBOPACK_MODULE_EVALUATION_PROFILE
+  if (!isFirstRuntime || !profilePath) {
+    return
+  }
+  process.on('exit', () => {
+    const fs = require('fs') as typeof import('fs')
+    try {
+      fs.writeFileSync(
+        profilePath.replaceAll('[pid]', String(process.pid)),
+        JSON.stringify(profile)
+      )
+    } catch (error) {
+      console.error('Failed to write the module evaluation profile', error)
+    }
+  })
+})()
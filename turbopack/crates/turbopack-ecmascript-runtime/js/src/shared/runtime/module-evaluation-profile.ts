@@ -0,0 +1,104 @@
+/**
+ * Opt-in profiling of the time modules take to evaluate. It's appended to the
+ * runtime code when the chunking context enables module evaluation profiling.
+ *
+ * Module factories are wrapped when they are installed, so the instantiation
+ * code of the runtimes is the same with and without profiling.
+ */
+
+/* eslint-disable @typescript-eslint/no-unused-vars */
+
+/// <reference path="./runtime-utils.ts" />
+
+/**
+ * A single evaluation of a module factory. Times are in milliseconds, as
+ * returned by `performance.now()`.
+ *
+ * Only the synchronous part of the factory is measured, e.g. the time an async
+ * module spends after its first `await` is not included.
+ */
+interface ModuleEvaluationRecord {
+  id: ModuleId
+  /** The module that was evaluating when this module was required. */
+  parent?: ModuleId
+  start: number
+  /** The wall time of the factory, including the modules it required. */
+  duration: number
+  /** The wall time of the factory without the modules it required. */
+  self: number
+}
+
+interface ModuleEvaluationProfile {
+  version: 1
+  /** Milliseconds since the Unix epoch when `performance.now()` was 0. */
+  timeOrigin: number
+  records: ModuleEvaluationRecord[]
+}
+
+// All runtimes of a page or process add their records to the same profile.
+declare var __TURBOPACK_MODULE_EVALUATION_PROFILE__:
+  | ModuleEvaluationProfile
+  | undefined
+
+interface ModuleEvaluationFrame {
+  id: ModuleId
+  /** The time spent evaluating the modules this module required. */
+  childTime: number
+}
+
+const moduleEvaluationStack: ModuleEvaluationFrame[] = []
+const profiledModuleFactories = new WeakMap<Function, Function>()
+
+/**
+ * Wraps every module factory installed into `moduleFactories` to record its
+ * evaluation. Returns the profile, which is shared with other runtimes that
+ * already enabled profiling.
+ */
+function profileModuleEvaluation(
+  moduleFactories: ModuleFactories
+): ModuleEvaluationProfile {
+  let profile = globalThis.__TURBOPACK_MODULE_EVALUATION_PROFILE__
+  if (profile == null) {
+    profile = {
+      version: 1,
+      timeOrigin: performance.timeOrigin,
+      records: [],
+    }
+    globalThis.__TURBOPACK_MODULE_EVALUATION_PROFILE__ = profile
+  }
+  const records = profile.records
+
+  const setFactory = moduleFactories.set
+  moduleFactories.set = function (id: ModuleId, factory: Function) {
+    // Factories are shared between the module ids of a group, so they are
+    // wrapped once per id.
+    const original = profiledModuleFactories.get(factory) ?? factory
+    const profiled = function (this: unknown, ...args: unknown[]) {
+      const parent = moduleEvaluationStack[moduleEvaluationStack.length - 1]
+      const frame: ModuleEvaluationFrame = { id, childTime: 0 }
+      moduleEvaluationStack.push(frame)
+      const start = performance.now()
+      try {
+        return original.apply(this, args)
+      } finally {
+        const duration = performance.now() - start
+        moduleEvaluationStack.pop()
+        if (parent) {
+          parent.childTime += duration
+        }
+        records.push({
+          id,
+          parent: parent?.id,
+          start,
+          duration,
+          self: duration - frame.childTime,
+        })
+      }
+    }
+    applyModuleFactoryName(profiled)
+    profiledModuleFactories.set(profiled, original)
+    return setFactory.call(this, id, profiled)
+  }
+
+  return profile
+}
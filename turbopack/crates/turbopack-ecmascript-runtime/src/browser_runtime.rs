@@ -25,6 +25,7 @@ pub async fn get_browser_runtime_code(
     output_root_to_root_path: RcStr,
     generate_source_map: bool,
     chunk_loading_global: Vc<RcStr>,
+    profile_module_evaluation: bool,
 ) -> Result<Vc<Code>> {
     let asset_context = get_runtime_asset_context(*environment).resolve().await?;
 
@@ -200,6 +201,20 @@ pub async fn get_browser_runtime_code(
         );
     }
 
+    // Wraps module factories as they are installed, hence it must be appended before chunks are
+    // registered
+    if profile_module_evaluation {
+        for profile_code in [
+            "shared/runtime/module-evaluation-profile.ts",
+            "browser/runtime/base/module-evaluation-profile.ts",
+        ] {
+            code.push_code(
+                &*embed_static_code(asset_context, profile_code.into(), generate_source_map)
+                    .await?,
+            );
+        }
+    }
+
     // Registering chunks and chunk lists depends on the BACKEND variable, which is set by the
     // specific runtime code, hence it must be appended after it.
     writedoc!(
@@ -6,6 +6,7 @@ pub(crate) mod browser_runtime;
 #[cfg(feature = "test")]
 pub(crate) mod dummy_runtime;
 pub(crate) mod embed_js;
+pub(crate) mod module_evaluation_profile;
 pub(crate) mod nodejs_runtime;
 pub(crate) mod runtime_type;
 
@@ -13,5 +14,9 @@ pub use browser_runtime::{get_browser_runtime_code, get_worker_runtime_code};
 #[cfg(feature = "test")]
 pub use dummy_runtime::get_dummy_runtime_code;
 pub use embed_js::{embed_file, embed_file_path, embed_fs};
+pub use module_evaluation_profile::{
+    ModuleEvaluationProfile, ModuleEvaluationRecord, ModuleEvaluationReport,
+    ModuleEvaluationSummary, ModuleIdPaths, module_evaluation_trace, module_id_paths,
+};
 pub use nodejs_runtime::get_nodejs_runtime_code;
 pub use runtime_type::RuntimeType;
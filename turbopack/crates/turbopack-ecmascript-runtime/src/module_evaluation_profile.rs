@@ -0,0 +1,349 @@
+//! Reports for the profiles recorded by the runtimes when module evaluation profiling is enabled
+//! in the chunking context, see `shared/runtime/module-evaluation-profile.ts`.
+
+use std::fmt::{self, Display};
+
+use anyhow::{Context, Result, bail};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, TryFlatJoinIterExt, Vc};
+use turbopack_core::{
+    chunk::{ModuleId, chunk_id_strategy::ModuleIdStrategy},
+    module_graph::ModuleGraph,
+};
+
+const PROFILE_VERSION: u32 = 1;
+
+/// The profile written by the runtimes. Times are in milliseconds.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleEvaluationProfile {
+    version: u32,
+    /// Milliseconds since the Unix epoch that the `start` of the records is relative to.
+    pub time_origin: f64,
+    pub records: Vec<ModuleEvaluationRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ModuleEvaluationRecord {
+    #[serde(deserialize_with = "deserialize_module_id")]
+    pub id: ModuleId,
+    /// The module that was evaluating when this module was required.
+    #[serde(default, deserialize_with = "deserialize_parent_module_id")]
+    pub parent: Option<ModuleId>,
+    pub start: f64,
+    /// The wall time of the module factory, including the modules it required.
+    pub duration: f64,
+    /// The wall time of the module factory without the modules it required.
+    #[serde(rename = "self")]
+    pub self_time: f64,
+}
+
+fn deserialize_module_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ModuleId, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawModuleId {
+        Number(u64),
+        String(RcStr),
+    }
+
+    Ok(match RawModuleId::deserialize(deserializer)? {
+        RawModuleId::Number(id) => ModuleId::Number(id),
+        RawModuleId::String(id) => ModuleId::String(id),
+    })
+}
+
+fn deserialize_parent_module_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ModuleId>, D::Error> {
+    #[derive(Deserialize)]
+    struct ParentModuleId(#[serde(deserialize_with = "deserialize_module_id")] ModuleId);
+
+    Ok(Option::<ParentModuleId>::deserialize(deserializer)?.map(|ParentModuleId(id)| id))
+}
+
+impl ModuleEvaluationProfile {
+    pub fn from_json(json: &str) -> Result<Self> {
+        let profile: Self =
+            serde_json::from_str(json).context("Unable to parse the module evaluation profile")?;
+        if profile.version != PROFILE_VERSION {
+            bail!(
+                "Unsupported module evaluation profile version {}, expected {PROFILE_VERSION}",
+                profile.version
+            );
+        }
+        Ok(profile)
+    }
+}
+
+/// The paths of the modules in a module graph by their module id.
+#[turbo_tasks::value(transparent)]
+pub struct ModuleIdPaths(FxHashMap<ModuleId, RcStr>);
+
+/// Maps the module ids the runtimes use back to the paths of the modules. Modules without an id,
+/// e.g. because they are not chunked, are skipped.
+#[turbo_tasks::function]
+pub async fn module_id_paths(
+    module_graph: Vc<ModuleGraph>,
+    module_id_strategy: Vc<ModuleIdStrategy>,
+) -> Result<Vc<ModuleIdPaths>> {
+    let module_graph = module_graph.await?;
+    let module_id_strategy = module_id_strategy.await?;
+    let paths = module_graph
+        .iter_nodes()
+        .map(async |module| {
+            let Ok(id) = module_id_strategy.get_id_from_module(*module).await else {
+                return Ok(None);
+            };
+            let path = module.ident().await?.path.value_to_string().owned().await?;
+            Ok(Some((id, path)))
+        })
+        .try_flat_join()
+        .await?;
+    Ok(Vc::cell(paths.into_iter().collect()))
+}
+
+fn module_path(paths: &FxHashMap<ModuleId, RcStr>, id: &ModuleId) -> RcStr {
+    paths
+        .get(id)
+        .cloned()
+        .unwrap_or_else(|| id.to_string().into())
+}
+
+/// The evaluation time of the modules of a file. A file is evaluated more than once when it's
+/// part of multiple layers, e.g. for server and client rendering, or after an HMR update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleEvaluationSummary {
+    pub path: RcStr,
+    pub evaluations: u32,
+    pub self_time: f64,
+    pub total_time: f64,
+    pub max_time: f64,
+}
+
+/// The evaluation time of the profile aggregated by file, sorted by self time, highest first.
+pub struct ModuleEvaluationReport {
+    pub modules: Vec<ModuleEvaluationSummary>,
+}
+
+impl ModuleEvaluationReport {
+    pub fn new(profile: &ModuleEvaluationProfile, paths: &FxHashMap<ModuleId, RcStr>) -> Self {
+        let mut modules: FxIndexMap<RcStr, ModuleEvaluationSummary> = FxIndexMap::default();
+        for record in &profile.records {
+            let path = module_path(paths, &record.id);
+            let summary = modules
+                .entry(path.clone())
+                .or_insert_with(|| ModuleEvaluationSummary {
+                    path,
+                    evaluations: 0,
+                    self_time: 0.0,
+                    total_time: 0.0,
+                    max_time: 0.0,
+                });
+            summary.evaluations += 1;
+            summary.self_time += record.self_time;
+            summary.total_time += record.duration;
+            summary.max_time = summary.max_time.max(record.duration);
+        }
+        let mut modules = modules.into_values().collect::<Vec<_>>();
+        modules.sort_by(|a, b| b.self_time.total_cmp(&a.self_time));
+        Self { modules }
+    }
+}
+
+impl Display for ModuleEvaluationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>10} {:>10} {:>10} {:>6}  module",
+            "self ms", "total ms", "max ms", "count"
+        )?;
+        for module in &self.modules {
+            writeln!(
+                f,
+                "{:>10.2} {:>10.2} {:>10.2} {:>6}  {}",
+                module.self_time,
+                module.total_time,
+                module.max_time,
+                module.evaluations,
+                module.path
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A span of the Next.js trace format, which is read by the trace server. The `name` has to be
+/// the first field, as the trace server detects the format from the first bytes of the file.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceSpan<'a> {
+    name: &'a str,
+    /// Microseconds since the Unix epoch.
+    timestamp: u64,
+    /// Microseconds.
+    duration: u64,
+    id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<u64>,
+    tags: TraceTags<'a>,
+    /// Milliseconds since the Unix epoch.
+    start_time: u64,
+    trace_id: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceTags<'a> {
+    module_id: String,
+    self_time_ms: f64,
+    path: &'a str,
+}
+
+/// Converts the profile to a trace in the Next.js trace format, which can be opened with the
+/// trace server. Every module evaluation is a span, nested in the span of the module that
+/// required it.
+pub fn module_evaluation_trace(
+    profile: &ModuleEvaluationProfile,
+    paths: &FxHashMap<ModuleId, RcStr>,
+) -> Result<String> {
+    let records = &profile.records;
+
+    // Records are added when a module factory returns, so the evaluation of the parent is the
+    // next record of the parent module. The span id of a record is its index + 1.
+    let mut parent_ids = vec![None; records.len()];
+    let mut next_span_ids: FxHashMap<&ModuleId, u64> = FxHashMap::default();
+    for (index, record) in records.iter().enumerate().rev() {
+        parent_ids[index] = record
+            .parent
+            .as_ref()
+            .and_then(|parent| next_span_ids.get(parent).copied());
+        next_span_ids.insert(&record.id, index as u64 + 1);
+    }
+
+    let mut order = (0..records.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| records[a].start.total_cmp(&records[b].start));
+
+    let paths = records
+        .iter()
+        .map(|record| module_path(paths, &record.id))
+        .collect::<Vec<_>>();
+    let spans = order
+        .into_iter()
+        .map(|index| {
+            let record = &records[index];
+            let path = &paths[index];
+            let timestamp = (profile.time_origin + record.start) * 1000.0;
+            TraceSpan {
+                name: path,
+                timestamp: timestamp as u64,
+                duration: (record.duration * 1000.0) as u64,
+                id: index as u64 + 1,
+                parent_id: parent_ids[index],
+                tags: TraceTags {
+                    module_id: record.id.to_string(),
+                    self_time_ms: record.self_time,
+                    path,
+                },
+                start_time: (timestamp / 1000.0) as u64,
+                trace_id: "module-evaluation",
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut trace = serde_json::to_string(&spans)?;
+    trace.push('\n');
+    Ok(trace)
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashMap;
+    use turbopack_core::chunk::ModuleId;
+
+    use super::{ModuleEvaluationProfile, ModuleEvaluationReport, module_evaluation_trace};
+
+    fn profile() -> ModuleEvaluationProfile {
+        ModuleEvaluationProfile::from_json(
+            r#"{
+                "version": 1,
+                "timeOrigin": 1000,
+                "records": [
+                    { "id": 2, "parent": "page", "start": 1, "duration": 3, "self": 3 },
+                    { "id": "page", "start": 0, "duration": 5, "self": 2 },
+                    { "id": 2, "start": 10, "duration": 1, "self": 1 }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn paths() -> FxHashMap<ModuleId, turbo_rcstr::RcStr> {
+        FxHashMap::from_iter([(ModuleId::Number(2), "[project]/lib/heavy.js".into())])
+    }
+
+    #[test]
+    fn aggregates_by_path() {
+        let report = ModuleEvaluationReport::new(&profile(), &paths());
+        let heavy = &report.modules[0];
+        assert_eq!(heavy.path, "[project]/lib/heavy.js");
+        assert_eq!(heavy.evaluations, 2);
+        assert_eq!(heavy.self_time, 4.0);
+        assert_eq!(heavy.max_time, 3.0);
+        // Unknown module ids are reported as they are
+        assert_eq!(report.modules[1].path, "page");
+        assert!(report.to_string().contains("[project]/lib/heavy.js"));
+    }
+
+    #[test]
+    fn nests_trace_spans() {
+        let trace = module_evaluation_trace(&profile(), &paths()).unwrap();
+        assert!(trace.starts_with("[{\"name\""));
+        let spans: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        assert_eq!(spans[0]["name"], "page");
+        assert_eq!(spans[0]["timestamp"], 1_000_000);
+        assert_eq!(spans[1]["parentId"], spans[0]["id"]);
+        assert_eq!(spans[1]["duration"], 3000);
+        assert!(spans[2].get("parentId").is_none());
+    }
+
+    #[test]
+    fn nests_trace_spans_by_parent() {
+        // The second module was evaluated while the first one was running, but it wasn't
+        // required by it, e.g. because it belongs to another runtime
+        let profile = ModuleEvaluationProfile::from_json(
+            r#"{
+                "version": 1,
+                "timeOrigin": 0,
+                "records": [
+                    { "id": 3, "start": 1, "duration": 1, "self": 1 },
+                    { "id": 2, "start": 3, "duration": 1, "self": 1 },
+                    { "id": 2, "parent": 1, "start": 6, "duration": 1, "self": 1 },
+                    { "id": 1, "start": 0, "duration": 8, "self": 5 }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let trace = module_evaluation_trace(&profile, &FxHashMap::default()).unwrap();
+        let spans: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        let names = spans
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|span| span["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["1", "3", "2", "2"]);
+        assert!(spans[1].get("parentId").is_none());
+        assert!(spans[2].get("parentId").is_none());
+        assert_eq!(spans[3]["parentId"], spans[0]["id"]);
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        assert!(
+            ModuleEvaluationProfile::from_json(r#"{"version":2,"timeOrigin":0,"records":[]}"#)
+                .is_err()
+        );
+    }
+}
@@ -14,6 +14,7 @@ pub async fn get_nodejs_runtime_code(
     environment: ResolvedVc<Environment>,
     runtime_type: RuntimeType,
     generate_source_map: bool,
+    profile_module_evaluation: bool,
 ) -> Result<Vc<Code>> {
     let asset_context = get_runtime_asset_context(*environment).resolve().await?;
 
@@ -110,5 +111,19 @@ pub async fn get_nodejs_runtime_code(
         }
     }
 
+    // Wraps module factories as they are installed, hence it must be appended before any chunk
+    // is loaded
+    if profile_module_evaluation {
+        for profile_code in [
+            "shared/runtime/module-evaluation-profile.ts",
+            "nodejs/runtime/module-evaluation-profile.ts",
+        ] {
+            code.push_code(
+                &*embed_static_code(asset_context, profile_code.into(), generate_source_map)
+                    .await?,
+            );
+        }
+    }
+
     Ok(Code::cell(code.build()))
 }
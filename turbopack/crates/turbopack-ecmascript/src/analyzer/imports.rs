@@ -39,6 +39,8 @@ pub struct ImportAnnotations {
     turbopack_loader: Option<WebpackLoaderItem>,
     turbopack_rename_as: Option<RcStr>,
     turbopack_module_type: Option<RcStr>,
+    /// Whether this is a source phase import, e.g. `import source mod from "./x.wasm"`
+    source_phase: bool,
 }
 
 /// Enables a specified transition for the annotated import
@@ -129,6 +131,7 @@ impl ImportAnnotations {
             turbopack_loader,
             turbopack_rename_as,
             turbopack_module_type,
+            source_phase: false,
         }
     }
 
@@ -162,6 +165,7 @@ impl ImportAnnotations {
             turbopack_loader: None,
             turbopack_rename_as: None,
             turbopack_module_type: None,
+            source_phase: false,
         })
     }
 
@@ -196,6 +200,11 @@ impl ImportAnnotations {
         self.turbopack_module_type.as_ref()
     }
 
+    /// Returns true if this is a source phase import
+    pub fn source_phase(&self) -> bool {
+        self.source_phase
+    }
+
     /// Returns true if a turbopack loader is configured
     pub fn has_turbopack_loader(&self) -> bool {
         self.turbopack_loader.is_some()
@@ -569,11 +578,14 @@ impl Visit for Analyzer<'_> {
     fn visit_import_decl(&mut self, import: &ImportDecl) {
         self.data.has_imports = true;
 
-        let annotations = ImportAnnotations::parse(import.with.as_deref());
+        let mut annotations = ImportAnnotations::parse(import.with.as_deref());
+        // A source phase import only imports the compiled source, it doesn't evaluate the module
+        let source_phase = import.phase == ImportPhase::Source;
+        annotations.source_phase = source_phase;
 
         let internal_symbol = parse_with(import.with.as_deref());
 
-        if internal_symbol.is_none() {
+        if internal_symbol.is_none() && !source_phase {
             self.ensure_reference(
                 import.span,
                 import.src.value.clone(),
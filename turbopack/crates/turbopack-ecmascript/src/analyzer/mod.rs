@@ -1877,6 +1877,18 @@ impl JsValue {
                       "URL".to_string(),
                       "The standard URL constructor: https://developer.mozilla.org/en-US/docs/Web/API/URL/URL"
                     ),
+                    WellKnownFunctionKind::AudioWorkletAddModule => (
+                      "audioWorklet.addModule".to_string(),
+                      "The standard Worklet.addModule method: https://developer.mozilla.org/en-US/docs/Web/API/Worklet/addModule"
+                    ),
+                    WellKnownFunctionKind::PaintWorkletAddModule => (
+                      "CSS.paintWorklet.addModule".to_string(),
+                      "The standard Worklet.addModule method: https://developer.mozilla.org/en-US/docs/Web/API/Worklet/addModule"
+                    ),
+                    WellKnownFunctionKind::ServiceWorkerRegister => (
+                      "navigator.serviceWorker.register".to_string(),
+                      "The standard ServiceWorkerContainer.register method: https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/register"
+                    ),
                 };
                 if depth > 0 {
                     let i = hints.len();
@@ -3471,6 +3483,12 @@ pub enum WellKnownFunctionKind {
     // The worker_threads Worker class
     NodeWorkerConstructor,
     URLConstructor,
+    /// `audioWorklet.addModule`, e.g. on an `AudioContext`
+    AudioWorkletAddModule,
+    /// `CSS.paintWorklet.addModule`
+    PaintWorkletAddModule,
+    /// `navigator.serviceWorker.register`
+    ServiceWorkerRegister,
 }
 
 impl WellKnownFunctionKind {
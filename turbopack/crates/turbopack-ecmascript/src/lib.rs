@@ -22,6 +22,7 @@ mod merged_module;
 pub mod minify;
 pub mod parse;
 mod path_visitor;
+pub mod property_mangling;
 pub mod references;
 pub mod runtime_functions;
 pub mod side_effect_optimization;
@@ -1067,7 +1068,7 @@ impl EcmascriptModuleContent {
             ..
         } = &*input;
 
-        let minify = chunking_context.minify_type().await?;
+        let minify = chunking_context.minify_type().owned().await?;
 
         let content = process_parse_result(
             *parsed,
@@ -1075,7 +1076,7 @@ impl EcmascriptModuleContent {
             *specified_module_type,
             *generate_source_map,
             *original_source_map,
-            *minify,
+            minify,
             Some(&*input),
             None,
         )
@@ -1161,7 +1162,7 @@ impl EcmascriptModuleContent {
                         *specified_module_type,
                         *generate_source_map,
                         *original_source_map,
-                        *chunking_context.minify_type().await?,
+                        chunking_context.minify_type().owned().await?,
                         Some(&*options),
                         Some(ScopeHoistingOptions {
                             module: *module,
@@ -1200,7 +1201,7 @@ impl EcmascriptModuleContent {
                 original_source_map: CodeGenResultOriginalSourceMap::ScopeHoisting(
                     original_source_maps,
                 ),
-                minify: *options.chunking_context.minify_type().await?,
+                minify: options.chunking_context.minify_type().owned().await?,
                 scope_hoisting_syntax_contexts: None,
             };
 
@@ -11,7 +11,7 @@ use swc_core::{
     },
     ecma::{
         self,
-        ast::{EsVersion, Program},
+        ast::{EsVersion, Expr, Ident, IdentName, MemberExpr, MemberProp, Program},
         codegen::{
             Emitter,
             text_writer::{self, JsWriter, WriteJs},
@@ -26,15 +26,36 @@ use swc_core::{
     },
 };
 use tracing::instrument;
+use turbo_rcstr::RcStr;
+use turbo_tasks::FxIndexMap;
 use turbopack_core::{
-    chunk::MangleType,
+    chunk::{MangleType, MinifyOptions as TurbopackMinifyOptions},
     code_builder::{Code, CodeBuilder},
 };
 
-use crate::parse::{IdentCollector, generate_js_source_map};
+use crate::{
+    parse::{IdentCollector, generate_js_source_map},
+    property_mangling::rename_properties,
+};
 
+/// Minifies `code`.
+///
+/// `property_names` are the mangled property names of the build, see
+/// [crate::property_mangling]. They are only applied when identifiers are mangled too.
 #[instrument(level = "info", name = "minify ecmascript code", skip_all)]
-pub fn minify(code: Code, source_maps: bool, mangle: Option<MangleType>) -> Result<Code> {
+pub fn minify(
+    code: Code,
+    source_maps: bool,
+    options: &TurbopackMinifyOptions,
+    property_names: Option<&FxIndexMap<RcStr, RcStr>>,
+) -> Result<Code> {
+    let mangle = options.mangle;
+    let target = options
+        .ecma
+        .map(es_version)
+        .transpose()?
+        .unwrap_or_else(EsVersion::latest);
+
     // Pass None for the debug ID so we don't needlessly compute it for the pre-minified content, it
     // will be added by the Code object returned from this function
     let source_maps = source_maps.then(|| code.generate_source_map_ref(None));
@@ -82,12 +103,18 @@ pub fn minify(code: Code, source_maps: bool, mangle: Option<MangleType>) -> Resu
 
                     let program = program.apply(paren_remover(Some(&comments)));
 
-                    let program = program.apply(swc_core::ecma::transforms::base::resolver(
+                    let mut program = program.apply(swc_core::ecma::transforms::base::resolver(
                         unresolved_mark,
                         top_level_mark,
                         false,
                     ));
 
+                    if let Some(property_names) = property_names
+                        && mangle.is_some()
+                    {
+                        rename_properties(&mut program, property_names);
+                    }
+
                     let mut program = swc_core::ecma::minifier::optimize(
                         program,
                         cm.clone(),
@@ -95,24 +122,33 @@ pub fn minify(code: Code, source_maps: bool, mangle: Option<MangleType>) -> Resu
                         None,
                         &MinifyOptions {
                             compress: Some(CompressOptions {
-                                // Only run 2 passes, this is a tradeoff between performance and
-                                // compression size. Default is 3 passes.
-                                passes: 2,
-                                keep_classnames: mangle.is_none(),
-                                keep_fnames: mangle.is_none(),
+                                // Only run 2 passes by default, this is a tradeoff between
+                                // performance and compression size. Default is 3 passes.
+                                passes: options.passes.unwrap_or(2),
+                                keep_classnames: options.keep_classnames || mangle.is_none(),
+                                keep_fnames: options.keep_fnames || mangle.is_none(),
+                                drop_console: options.drop_console,
+                                pure_funcs: options
+                                    .pure_funcs
+                                    .iter()
+                                    .map(|name| pure_func_callee(name))
+                                    .collect(),
+                                ecma: target,
                                 ..Default::default()
                             }),
                             mangle: mangle.map(|mangle| {
                                 let reserved = vec![atom!("AbortSignal")];
+                                let mangle_options = MangleOptions {
+                                    reserved,
+                                    keep_class_names: options.keep_classnames,
+                                    keep_fn_names: options.keep_fnames,
+                                    ..Default::default()
+                                };
                                 match mangle {
-                                    MangleType::OptimalSize => MangleOptions {
-                                        reserved,
-                                        ..Default::default()
-                                    },
+                                    MangleType::OptimalSize => mangle_options,
                                     MangleType::Deterministic => MangleOptions {
-                                        reserved,
                                         disable_char_freq: true,
-                                        ..Default::default()
+                                        ..mangle_options
                                     },
                                 }
                             }),
@@ -141,7 +177,7 @@ pub fn minify(code: Code, source_maps: bool, mangle: Option<MangleType>) -> Resu
             })
             .map_err(|e| e.to_pretty_error())?;
 
-        let (src, src_map_buf) = print_program(cm.clone(), program, source_maps.is_some())?;
+        let (src, src_map_buf) = print_program(cm.clone(), program, source_maps.is_some(), target)?;
         (src, src_map_buf, source_map_names)
     };
 
@@ -168,11 +204,47 @@ pub fn minify(code: Code, source_maps: bool, mangle: Option<MangleType>) -> Resu
     Ok(builder.build())
 }
 
+/// Maps the ECMAScript version of [TurbopackMinifyOptions::ecma], e.g. `2017`, to the
+/// corresponding [EsVersion].
+fn es_version(ecma: u16) -> Result<EsVersion> {
+    Ok(match ecma {
+        3 => EsVersion::Es3,
+        5 => EsVersion::Es5,
+        2015 => EsVersion::Es2015,
+        2016 => EsVersion::Es2016,
+        2017 => EsVersion::Es2017,
+        2018 => EsVersion::Es2018,
+        2019 => EsVersion::Es2019,
+        2020 => EsVersion::Es2020,
+        2021 => EsVersion::Es2021,
+        2022 => EsVersion::Es2022,
+        _ => bail!("Unsupported ECMAScript version for minification: {ecma}"),
+    })
+}
+
+/// Creates the callee expression of a pure function name, e.g. `console.info`.
+fn pure_func_callee(name: &str) -> Box<Expr> {
+    let mut parts = name.split('.');
+    let mut expr = Box::new(Expr::Ident(Ident::new_no_ctxt(
+        parts.next().unwrap_or_default().into(),
+        Default::default(),
+    )));
+    for part in parts {
+        expr = Box::new(Expr::Member(MemberExpr {
+            span: Default::default(),
+            obj: expr,
+            prop: MemberProp::Ident(IdentName::new(part.into(), Default::default())),
+        }));
+    }
+    expr
+}
+
 // From https://github.com/swc-project/swc/blob/11efd4e7c5e8081f8af141099d3459c3534c1e1d/crates/swc/src/lib.rs#L523-L560
 fn print_program(
     cm: Arc<SwcSourceMap>,
     program: Program,
     source_maps: bool,
+    target: EsVersion,
 ) -> Result<(String, Vec<(BytePos, LineCol)>)> {
     let mut src_map_buf = vec![];
 
@@ -187,7 +259,9 @@ fn print_program(
             )))) as Box<dyn WriteJs>;
 
             let mut emitter = Emitter {
-                cfg: swc_core::ecma::codegen::Config::default().with_minify(true),
+                cfg: swc_core::ecma::codegen::Config::default()
+                    .with_minify(true)
+                    .with_target(target),
                 comments: None,
                 cm: cm.clone(),
                 wr,
@@ -204,3 +278,82 @@ fn print_program(
 
     Ok((src, src_map_buf))
 }
+
+#[cfg(test)]
+mod tests {
+    use turbo_rcstr::rcstr;
+    use turbopack_core::{
+        chunk::{MangleType, MinifyOptions},
+        code_builder::CodeBuilder,
+    };
+
+    use super::minify;
+
+    fn minify_str(code: &'static str, options: &MinifyOptions) -> String {
+        let mut builder = CodeBuilder::default();
+        builder += code;
+        minify(builder.build(), false, options, None)
+            .unwrap()
+            .source_code()
+            .to_str()
+            .unwrap()
+            .into_owned()
+    }
+
+    #[test]
+    fn drops_console_and_pure_funcs() {
+        let output = minify_str(
+            r#"
+                globalThis.sum = function sum(values) {
+                    console.log("summing", values);
+                    invariant(values.length > 0, "values must not be empty");
+                    logger.debug("summing", values.length);
+                    return values.reduce((total, value) => total + value, 0);
+                };
+            "#,
+            &MinifyOptions {
+                mangle: Some(MangleType::OptimalSize),
+                drop_console: true,
+                pure_funcs: vec![rcstr!("invariant"), rcstr!("logger.debug")],
+                passes: Some(3),
+                ..Default::default()
+            },
+        );
+        assert!(!output.contains("console"), "{output}");
+        assert!(!output.contains("invariant"), "{output}");
+        assert!(!output.contains("logger"), "{output}");
+        assert!(output.contains("reduce"), "{output}");
+    }
+
+    #[test]
+    fn keeps_class_names() {
+        let code = r#"
+            globalThis.describe = function describe() {
+                class Vector3 {
+                    constructor(x) {
+                        this.x = x;
+                    }
+                }
+                return new Vector3(0).constructor.name;
+            };
+        "#;
+        let output = minify_str(
+            code,
+            &MinifyOptions {
+                mangle: Some(MangleType::OptimalSize),
+                keep_classnames: true,
+                ..Default::default()
+            },
+        );
+        assert!(output.contains("Vector3"), "{output}");
+
+        let output = minify_str(
+            code,
+            &MinifyOptions {
+                mangle: Some(MangleType::OptimalSize),
+                ..Default::default()
+            },
+        );
+        assert!(!output.contains("Vector3"), "{output}");
+    }
+}
@@ -0,0 +1,742 @@
+//! Mangling of property names, see [ManglePropertiesOptions].
+//!
+//! Property names can't be renamed per chunk, as the same object may be accessed from modules in
+//! different chunks. Instead, the names are collected from all modules of the module graph with
+//! [module_property_names] and [assign_property_names] computes a
+//! [PropertyNameCache](turbopack_core::chunk::property_name_cache::PropertyNameCache) for the
+//! whole build, which is applied to each chunk by [rename_properties] during minification.
+//!
+//! The cache can be persisted with [PropertyNameCacheAsset] and passed back in with
+//! [read_property_name_cache], so the mangled names stay stable between builds.
+
+mod reserved;
+
+use anyhow::Result;
+use rustc_hash::FxHashSet;
+use swc_core::ecma::{
+    ast::{
+        ArrayLit, AssignPat, AssignPatProp, BinExpr, BinaryOp, CallExpr, Callee, ComputedPropName,
+        Expr, ExprOrSpread, IdentName, KeyValuePatProp, KeyValueProp, Lit, MemberExpr, MemberProp,
+        ObjectPatProp, Pat, Program, Prop, PropName, Str, SuperProp,
+    },
+    visit::{Visit, VisitMut, VisitMutWith, VisitWith, noop_visit_mut_type, noop_visit_type},
+};
+use tracing::Instrument;
+use turbo_esregex::EsRegex;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, FxIndexSet, ResolvedVc, TryFlatJoinIterExt, Vc};
+use turbo_tasks_fs::{File, FileContent, FileJsonContent, FileSystemPath};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    chunk::{
+        ManglePropertiesOptions, PropertyManglingFilter,
+        property_name_cache::{OptionPropertyNameCache, PropertyNameCache},
+    },
+    module_graph::ModuleGraph,
+    output::{OutputAsset, OutputAssetsReference},
+};
+
+use self::reserved::BUILTIN_PROPERTIES;
+use crate::{EcmascriptParsable, parse::ParseResult, runtime_functions::TURBOPACK_ESM};
+
+/// Property names that have a special meaning and are never mangled.
+const ALWAYS_RESERVED: &[&str] = &[
+    "__proto__",
+    "__esModule",
+    "__defineGetter__",
+    "__defineSetter__",
+    "__lookupGetter__",
+    "__lookupSetter__",
+];
+
+/// Properties with these prefixes are used by Next.js and the Turbopack runtime and are never
+/// mangled.
+const ALWAYS_RESERVED_PREFIXES: &[&str] = &["__next", "__NEXT", "__turbopack", "__TURBOPACK"];
+
+/// Keywords that are not used as mangled names, to keep the output valid for older targets.
+const KEYWORDS: &[&str] = &[
+    "do", "if", "in", "for", "let", "new", "try", "var", "case", "else", "enum", "eval", "null",
+    "this", "true", "void", "with",
+];
+
+/// Mangled names are never longer than this, so longer unmangled names can't collide with them.
+const MAX_MANGLED_NAME_LEN: usize = 4;
+
+/// Decides which property names are mangled.
+pub struct PropertyNameFilter {
+    filter: Filter,
+    reserved: FxHashSet<RcStr>,
+}
+
+enum Filter {
+    Prefix(RcStr),
+    Regex(EsRegex),
+}
+
+impl PropertyNameFilter {
+    pub fn new(options: &ManglePropertiesOptions) -> Result<Self> {
+        Ok(Self {
+            filter: match &options.filter {
+                PropertyManglingFilter::Prefix(prefix) => Filter::Prefix(prefix.clone()),
+                PropertyManglingFilter::Regex(regex) => Filter::Regex(EsRegex::new(regex, "")?),
+            },
+            reserved: options.reserved.iter().cloned().collect(),
+        })
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        let matches = match &self.filter {
+            Filter::Prefix(prefix) => name.starts_with(prefix.as_str()),
+            Filter::Regex(regex) => regex.is_match(name),
+        };
+        matches && !self.reserved.contains(name) && !is_always_reserved(name)
+    }
+}
+
+fn is_always_reserved(name: &str) -> bool {
+    ALWAYS_RESERVED.contains(&name)
+        || ALWAYS_RESERVED_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        || BUILTIN_PROPERTIES.binary_search(&name).is_ok()
+}
+
+/// The property names used by a module.
+#[turbo_tasks::value(shared)]
+#[derive(Default)]
+pub struct PropertyNameUsages {
+    /// The number of occurrences of each property name that is mangled.
+    #[bincode(with = "turbo_bincode::indexmap")]
+    pub mangled: FxIndexMap<RcStr, u32>,
+    /// Short property names that are not mangled. Mangled names must not collide with them.
+    #[bincode(with = "turbo_bincode::indexset")]
+    pub unmangled: FxIndexSet<RcStr>,
+}
+
+/// Collects the property names of a module that match the filter of `options`.
+#[turbo_tasks::function]
+pub async fn module_property_names(
+    module: ResolvedVc<Box<dyn EcmascriptParsable>>,
+    options: ManglePropertiesOptions,
+) -> Result<Vc<PropertyNameUsages>> {
+    let parse_result = module.failsafe_parse().await?;
+    let ParseResult::Ok { program, .. } = &*parse_result else {
+        return Ok(PropertyNameUsages::default().cell());
+    };
+
+    let filter = PropertyNameFilter::new(&options)?;
+    let mut collector = PropertyNameCollector {
+        filter: &filter,
+        usages: Default::default(),
+    };
+    program.visit_with(&mut collector);
+    Ok(collector.usages.cell())
+}
+
+/// Computes the mangled property names for all modules of `module_graph`.
+///
+/// `previous` is the cache of an earlier build, see [assign_property_names].
+#[turbo_tasks::function]
+pub async fn get_global_property_name_cache(
+    module_graph: Vc<ModuleGraph>,
+    options: ManglePropertiesOptions,
+    previous: Vc<OptionPropertyNameCache>,
+) -> Result<Vc<PropertyNameCache>> {
+    let span = tracing::info_span!("compute property name cache");
+    async move {
+        // Fail early on an invalid regex, instead of once per module
+        PropertyNameFilter::new(&options)?;
+
+        let usages = module_graph
+            .await?
+            .iter_nodes()
+            .map(async |module| {
+                let Some(module) = ResolvedVc::try_sidecast::<Box<dyn EcmascriptParsable>>(module)
+                else {
+                    return Ok(None);
+                };
+                Ok(Some(module_property_names(module, options.clone()).await?))
+            })
+            .try_flat_join()
+            .await?;
+
+        let previous = match *previous.await? {
+            Some(previous) => Some(previous.await?),
+            None => None,
+        };
+        Ok(Vc::cell(assign_property_names(
+            usages.iter().map(|usages| &**usages),
+            previous.as_deref(),
+        )))
+    }
+    .instrument(span)
+    .await
+}
+
+/// Reads a cache that was written by [PropertyNameCacheAsset]. Returns `None` when the file
+/// doesn't exist or isn't a valid cache.
+#[turbo_tasks::function]
+pub async fn read_property_name_cache(path: FileSystemPath) -> Result<Vc<OptionPropertyNameCache>> {
+    let FileJsonContent::Content(content) = &*path.read_json().await? else {
+        return Ok(Vc::cell(None));
+    };
+    let Ok(names) = serde_json::from_value::<FxIndexMap<RcStr, RcStr>>(content.clone()) else {
+        return Ok(Vc::cell(None));
+    };
+    Ok(Vc::cell(Some(ResolvedVc::cell(names))))
+}
+
+/// Writes a [PropertyNameCache] as JSON, so it can be read by [read_property_name_cache] in the
+/// next build.
+#[turbo_tasks::value]
+pub struct PropertyNameCacheAsset {
+    path: FileSystemPath,
+    cache: ResolvedVc<PropertyNameCache>,
+}
+
+#[turbo_tasks::value_impl]
+impl PropertyNameCacheAsset {
+    #[turbo_tasks::function]
+    pub fn new(path: FileSystemPath, cache: ResolvedVc<PropertyNameCache>) -> Vc<Self> {
+        PropertyNameCacheAsset { path, cache }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for PropertyNameCacheAsset {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let json = serde_json::to_string_pretty(&*self.cache.await?)?;
+        Ok(AssetContent::File(FileContent::Content(File::from(json)).resolved_cell()).cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAssetsReference for PropertyNameCacheAsset {}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for PropertyNameCacheAsset {
+    #[turbo_tasks::function]
+    fn path(&self) -> Vc<FileSystemPath> {
+        self.path.clone().cell()
+    }
+}
+
+struct PropertyNameCollector<'a> {
+    filter: &'a PropertyNameFilter,
+    usages: PropertyNameUsages,
+}
+
+impl PropertyNameCollector<'_> {
+    fn add(&mut self, name: &str) {
+        if self.filter.matches(name) {
+            *self.usages.mangled.entry(name.into()).or_default() += 1;
+        } else if name.len() <= MAX_MANGLED_NAME_LEN && !self.usages.unmangled.contains(name) {
+            self.usages.unmangled.insert(name.into());
+        }
+    }
+
+    fn add_str(&mut self, str: &Str) {
+        if let Some(name) = str.value.as_str() {
+            self.add(name);
+        }
+    }
+}
+
+impl Visit for PropertyNameCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_member_prop(&mut self, prop: &MemberProp) {
+        match prop {
+            MemberProp::Ident(ident) => self.add(&ident.sym),
+            MemberProp::Computed(ComputedPropName { expr, .. }) => {
+                if let Expr::Lit(Lit::Str(str)) = &**expr {
+                    self.add_str(str);
+                }
+            }
+            MemberProp::PrivateName(_) => {}
+        }
+        prop.visit_children_with(self);
+    }
+
+    fn visit_super_prop(&mut self, prop: &SuperProp) {
+        match prop {
+            SuperProp::Ident(ident) => self.add(&ident.sym),
+            SuperProp::Computed(ComputedPropName { expr, .. }) => {
+                if let Expr::Lit(Lit::Str(str)) = &**expr {
+                    self.add_str(str);
+                }
+            }
+        }
+        prop.visit_children_with(self);
+    }
+
+    fn visit_prop_name(&mut self, name: &PropName) {
+        match name {
+            PropName::Ident(ident) => self.add(&ident.sym),
+            PropName::Str(str) => self.add_str(str),
+            PropName::Computed(ComputedPropName { expr, .. }) => {
+                if let Expr::Lit(Lit::Str(str)) = &**expr {
+                    self.add_str(str);
+                }
+            }
+            _ => {}
+        }
+        name.visit_children_with(self);
+    }
+
+    fn visit_prop(&mut self, prop: &Prop) {
+        if let Prop::Shorthand(ident) = prop {
+            self.add(&ident.sym);
+        }
+        prop.visit_children_with(self);
+    }
+
+    fn visit_object_pat_prop(&mut self, prop: &ObjectPatProp) {
+        if let ObjectPatProp::Assign(AssignPatProp { key, .. }) = prop {
+            self.add(&key.sym);
+        }
+        prop.visit_children_with(self);
+    }
+
+    fn visit_bin_expr(&mut self, expr: &BinExpr) {
+        if expr.op == BinaryOp::In
+            && let Expr::Lit(Lit::Str(str)) = &*expr.left
+        {
+            self.add_str(str);
+        }
+        expr.visit_children_with(self);
+    }
+}
+
+/// Assigns a short name to each property name in `usages`, with the most used properties getting
+/// the shortest names.
+///
+/// Names that are in the `previous` cache keep their mangled name when possible, so that the
+/// output stays stable between builds.
+pub fn assign_property_names<'a>(
+    usages: impl IntoIterator<Item = &'a PropertyNameUsages>,
+    previous: Option<&FxIndexMap<RcStr, RcStr>>,
+) -> FxIndexMap<RcStr, RcStr> {
+    let mut counts = FxIndexMap::<RcStr, u32>::default();
+    let mut used_names = FxHashSet::<RcStr>::default();
+    for usages in usages {
+        for (name, count) in &usages.mangled {
+            *counts.entry(name.clone()).or_default() += count;
+        }
+        used_names.extend(usages.unmangled.iter().cloned());
+    }
+    used_names.extend(counts.keys().cloned());
+
+    let mut names = FxIndexMap::default();
+    if let Some(previous) = previous {
+        for (name, mangled) in previous {
+            if counts.contains_key(name)
+                && !used_names.contains(mangled)
+                && !KEYWORDS.contains(&mangled.as_str())
+            {
+                used_names.insert(mangled.clone());
+                names.insert(name.clone(), mangled.clone());
+            }
+        }
+    }
+
+    let mut remaining = counts
+        .into_iter()
+        .filter(|(name, _)| !names.contains_key(name))
+        .collect::<Vec<_>>();
+    remaining.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+
+    let mut index = 0;
+    for (name, _) in remaining {
+        let mangled = loop {
+            let candidate = mangled_name(index);
+            index += 1;
+            if !used_names.contains(candidate.as_str()) && !KEYWORDS.contains(&candidate.as_str()) {
+                break candidate;
+            }
+        };
+        names.insert(name, mangled);
+    }
+    names.sort_keys();
+    names
+}
+
+/// Returns the `index`-th short name, i.e. `a`, `b`, ..., `_`, `aa`, `ba`, ...
+fn mangled_name(mut index: usize) -> RcStr {
+    const FIRST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_";
+    const REST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+
+    let mut name = String::new();
+    name.push(FIRST[index % FIRST.len()] as char);
+    index /= FIRST.len();
+    while index > 0 {
+        index -= 1;
+        name.push(REST[index % REST.len()] as char);
+        index /= REST.len();
+    }
+    name.into()
+}
+
+/// Renames the properties of `program` according to `names`.
+///
+/// This includes the export names in `__turbopack_context__.s([...])` calls, as the exports of a
+/// module are accessed as properties of its namespace object, e.g. `ns._foo` or `ns["_foo"]`.
+pub fn rename_properties(program: &mut Program, names: &FxIndexMap<RcStr, RcStr>) {
+    program.visit_mut_with(&mut PropertyRenamer { names });
+}
+
+struct PropertyRenamer<'a> {
+    names: &'a FxIndexMap<RcStr, RcStr>,
+}
+
+impl PropertyRenamer<'_> {
+    fn get(&self, name: &str) -> Option<&RcStr> {
+        self.names.get(name)
+    }
+
+    fn rename_str(&self, str: &mut Str) {
+        if let Some(mangled) = str.value.as_str().and_then(|name| self.get(name)) {
+            str.value = mangled.as_str().into();
+            str.raw = None;
+        }
+    }
+
+    fn rename_computed(&self, computed: &mut ComputedPropName) {
+        if let Expr::Lit(Lit::Str(str)) = &mut *computed.expr {
+            self.rename_str(str);
+        }
+    }
+
+    /// Renames the export names in the bindings of a `__turbopack_context__.s(bindings)` call.
+    /// They are laid out as `name, getter`, `name, getter, setter` or `name, 0, value`.
+    fn rename_esm_bindings(&self, bindings: &mut ArrayLit) {
+        let elems = &mut bindings.elems;
+        let mut i = 0;
+        while i < elems.len() {
+            if let Some(ExprOrSpread { expr, .. }) = &mut elems[i]
+                && let Expr::Lit(Lit::Str(name)) = &mut **expr
+            {
+                self.rename_str(name);
+            }
+            i += 1;
+            match elems
+                .get(i)
+                .and_then(|elem| elem.as_ref())
+                .map(|elem| &*elem.expr)
+            {
+                // The tag is followed by the value
+                Some(Expr::Lit(Lit::Num(_))) => i += 2,
+                Some(_) => {
+                    i += 1;
+                    if let Some(Some(ExprOrSpread { expr, .. })) = elems.get(i)
+                        && matches!(&**expr, Expr::Arrow(_) | Expr::Fn(_))
+                    {
+                        // The getter is followed by a setter
+                        i += 1;
+                    }
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+/// Whether `callee` is `__turbopack_context__.s`, which defines the exports of an ES module.
+fn is_turbopack_esm(callee: &Callee) -> bool {
+    let Callee::Expr(callee) = callee else {
+        return false;
+    };
+    let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+    }) = &**callee
+    else {
+        return false;
+    };
+    matches!(&**obj, Expr::Ident(obj) if &*obj.sym == "__turbopack_context__")
+        && &*prop.sym == TURBOPACK_ESM.shortcut
+}
+
+impl VisitMut for PropertyRenamer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+        match prop {
+            MemberProp::Ident(ident) => {
+                if let Some(mangled) = self.get(&ident.sym) {
+                    ident.sym = mangled.as_str().into();
+                }
+            }
+            MemberProp::Computed(computed) => self.rename_computed(computed),
+            MemberProp::PrivateName(_) => {}
+        }
+        prop.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_super_prop(&mut self, prop: &mut SuperProp) {
+        match prop {
+            SuperProp::Ident(ident) => {
+                if let Some(mangled) = self.get(&ident.sym) {
+                    ident.sym = mangled.as_str().into();
+                }
+            }
+            SuperProp::Computed(computed) => self.rename_computed(computed),
+        }
+        prop.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_prop_name(&mut self, name: &mut PropName) {
+        match name {
+            PropName::Ident(ident) => {
+                if let Some(mangled) = self.get(&ident.sym) {
+                    ident.sym = mangled.as_str().into();
+                }
+            }
+            PropName::Str(str) => self.rename_str(str),
+            PropName::Computed(computed) => self.rename_computed(computed),
+            _ => {}
+        }
+        name.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        // `{ _foo }` needs to become `{ a: _foo }`
+        if let Prop::Shorthand(ident) = prop
+            && let Some(mangled) = self.get(&ident.sym)
+        {
+            *prop = Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(IdentName::new(mangled.as_str().into(), ident.span)),
+                value: Box::new(Expr::Ident(ident.clone())),
+            });
+        }
+        prop.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+        // `{ _foo = 1 } = obj` needs to become `{ a: _foo = 1 } = obj`
+        if let ObjectPatProp::Assign(AssignPatProp { span, key, value }) = prop
+            && let Some(mangled) = self.get(&key.sym)
+        {
+            let name = IdentName::new(mangled.as_str().into(), key.span);
+            let binding = Box::new(Pat::Ident(key.clone()));
+            *prop = ObjectPatProp::KeyValue(KeyValuePatProp {
+                key: PropName::Ident(name),
+                value: match value.take() {
+                    Some(default) => Box::new(Pat::Assign(AssignPat {
+                        span: *span,
+                        left: binding,
+                        right: default,
+                    })),
+                    None => binding,
+                },
+            });
+        }
+        prop.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_bin_expr(&mut self, expr: &mut BinExpr) {
+        if expr.op == BinaryOp::In
+            && let Expr::Lit(Lit::Str(str)) = &mut *expr.left
+        {
+            self.rename_str(str);
+        }
+        expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        if is_turbopack_esm(&call.callee)
+            && let Some(ExprOrSpread { spread: None, expr }) = call.args.first_mut()
+            && let Expr::Array(bindings) = &mut **expr
+        {
+            self.rename_esm_bindings(bindings);
+        }
+        call.visit_mut_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{
+        common::{FileName, SourceMap, sync::Lrc},
+        ecma::{
+            ast::EsVersion,
+            codegen::{Emitter, text_writer::JsWriter},
+            parser::{Syntax, parse_file_as_program},
+        },
+    };
+
+    use super::*;
+
+    fn usages(mangled: &[(&str, u32)], unmangled: &[&str]) -> PropertyNameUsages {
+        PropertyNameUsages {
+            mangled: mangled
+                .iter()
+                .map(|(name, count)| (RcStr::from(*name), *count))
+                .collect(),
+            unmangled: unmangled.iter().map(|name| RcStr::from(*name)).collect(),
+        }
+    }
+
+    fn names(names: &[(&str, &str)]) -> FxIndexMap<RcStr, RcStr> {
+        names
+            .iter()
+            .map(|(name, mangled)| (RcStr::from(*name), RcStr::from(*mangled)))
+            .collect()
+    }
+
+    #[test]
+    fn test_mangled_name() {
+        assert_eq!(mangled_name(0), "a");
+        assert_eq!(mangled_name(53), "_");
+        assert_eq!(mangled_name(54), "aa");
+        assert_eq!(mangled_name(55), "ba");
+        assert_eq!(mangled_name(54 + 54 * 64), "aaa");
+    }
+
+    #[test]
+    fn test_filter() {
+        let filter = PropertyNameFilter::new(&ManglePropertiesOptions {
+            filter: PropertyManglingFilter::Prefix(RcStr::from("_")),
+            reserved: vec![RcStr::from("_keep")],
+        })
+        .unwrap();
+        assert!(filter.matches("_private"));
+        assert!(!filter.matches("public"));
+        assert!(!filter.matches("_keep"));
+        assert!(!filter.matches("__esModule"));
+
+        let filter = PropertyNameFilter::new(&ManglePropertiesOptions {
+            filter: PropertyManglingFilter::Regex(RcStr::from("^\\$[a-z]+$")),
+            reserved: vec![],
+        })
+        .unwrap();
+        assert!(filter.matches("$private"));
+        assert!(!filter.matches("$Private"));
+    }
+
+    #[test]
+    fn test_filter_always_reserved() {
+        let filter = PropertyNameFilter::new(&ManglePropertiesOptions {
+            filter: PropertyManglingFilter::Regex(RcStr::from("^[a-zA-Z_]+$")),
+            reserved: vec![],
+        })
+        .unwrap();
+        assert!(filter.matches("myProperty"));
+        assert!(!filter.matches("__next_f"));
+        assert!(!filter.matches("__NEXT_DATA__"));
+        assert!(!filter.matches("__turbopack_context__"));
+        assert!(!filter.matches("__TURBOPACK__"));
+        assert!(!filter.matches("hasOwnProperty"));
+        assert!(!filter.matches("then"));
+        assert!(!filter.matches("addEventListener"));
+        assert!(!filter.matches("innerHTML"));
+    }
+
+    #[test]
+    fn test_builtin_properties_are_sorted() {
+        assert!(BUILTIN_PROPERTIES.is_sorted());
+    }
+
+    fn rename(code: &str, renames: &[(&str, &str)]) -> String {
+        let cm = Lrc::new(SourceMap::default());
+        let fm = cm.new_source_file(Lrc::new(FileName::Anon), code.to_string());
+        let mut program = parse_file_as_program(
+            &fm,
+            Syntax::default(),
+            EsVersion::latest(),
+            None,
+            &mut vec![],
+        )
+        .unwrap();
+
+        rename_properties(&mut program, &names(renames));
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+            };
+            emitter.emit_program(&program).unwrap();
+        }
+        String::from_utf8(buf).unwrap().trim().to_string()
+    }
+
+    /// Asserts that renaming `code` results in `expected`. Both are printed the same way, so
+    /// formatting doesn't matter.
+    #[track_caller]
+    fn assert_renamed(code: &str, expected: &str) {
+        let renames = [("_x", "a"), ("_y", "b"), ("_z", "c")];
+        assert_eq!(rename(code, &renames), rename(expected, &[]));
+    }
+
+    #[test]
+    fn test_rename_properties() {
+        assert_renamed("obj._x = obj._y;", "obj.a = obj.b;");
+        assert_renamed(r#"obj["_x"];"#, r#"obj["a"];"#);
+        assert_renamed("obj._other;", "obj._other;");
+        assert_renamed(r#""_x" in obj;"#, r#""a" in obj;"#);
+        assert_renamed(
+            "class A { _x() { super._y; } }",
+            "class A { a() { super.b; } }",
+        );
+        assert_renamed("const o = { _x: 1, _y };", "const o = { a: 1, b: _y };");
+        assert_renamed(
+            "const { _x = 1, _y: y } = o;",
+            "const { a: _x = 1, b: y } = o;",
+        );
+        // Plain strings and identifiers are left alone
+        assert_renamed(r#"const _x = "_y";"#, r#"const _x = "_y";"#);
+    }
+
+    #[test]
+    fn test_rename_esm_exports() {
+        // Getters, setters and values, where the value is a string that isn't renamed
+        assert_renamed(
+            r#"__turbopack_context__.s(["_x", () => x, "_y", () => y, (v) => y = v, "_z", 0, "_x"]);"#,
+            r#"__turbopack_context__.s(["a", () => x, "b", () => y, (v) => y = v, "c", 0, "_x"]);"#,
+        );
+        assert_renamed(
+            r#"__turbopack_context__.s(["_x", () => x], "module-id");"#,
+            r#"__turbopack_context__.s(["a", () => x], "module-id");"#,
+        );
+        // Only the export names of `__turbopack_context__.s` are renamed
+        assert_renamed(
+            r#"other.s(["_x", () => x]);"#,
+            r#"other.s(["_x", () => x]);"#,
+        );
+    }
+
+    #[test]
+    fn test_assign_by_usage() {
+        let a = usages(&[("_rare", 1), ("_common", 3)], &[]);
+        let b = usages(&[("_common", 2), ("_other", 1)], &[]);
+        assert_eq!(
+            assign_property_names([&a, &b], None),
+            names(&[("_common", "a"), ("_other", "b"), ("_rare", "c")])
+        );
+    }
+
+    #[test]
+    fn test_assign_avoids_unmangled_names() {
+        let a = usages(&[("_x", 2), ("_y", 1)], &["a", "c"]);
+        assert_eq!(
+            assign_property_names([&a], None),
+            names(&[("_x", "b"), ("_y", "d")])
+        );
+    }
+
+    #[test]
+    fn test_assign_keeps_previous_names() {
+        let a = usages(&[("_new", 5), ("_old", 1), ("_clash", 1)], &["b"]);
+        let previous = names(&[("_old", "c"), ("_clash", "b"), ("_removed", "a")]);
+        assert_eq!(
+            assign_property_names([&a], Some(&previous)),
+            names(&[("_clash", "d"), ("_new", "a"), ("_old", "c")])
+        );
+    }
+}
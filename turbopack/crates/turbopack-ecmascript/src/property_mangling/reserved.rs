@@ -0,0 +1,958 @@
+//! Property names of JavaScript builtins and common DOM APIs. They are never mangled, even when
+//! they match the filter, as renaming them would break code that uses these APIs.
+
+/// Sorted, so it can be searched with [`slice::binary_search`].
+pub(super) const BUILTIN_PROPERTIES: &[&str] = &[
+    "$1",
+    "$2",
+    "$3",
+    "$4",
+    "$5",
+    "$6",
+    "$7",
+    "$8",
+    "$9",
+    "$_",
+    "AbortController",
+    "AbortSignal",
+    "AggregateError",
+    "Array",
+    "ArrayBuffer",
+    "BYTES_PER_ELEMENT",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Blob",
+    "Boolean",
+    "BroadcastChannel",
+    "Buffer",
+    "ByteLengthQueuingStrategy",
+    "Collator",
+    "CompressionStream",
+    "CountQueuingStrategy",
+    "Crypto",
+    "CryptoKey",
+    "CustomEvent",
+    "DOMException",
+    "DataView",
+    "Date",
+    "DateTimeFormat",
+    "DecompressionStream",
+    "DisplayNames",
+    "E",
+    "EPSILON",
+    "Error",
+    "EvalError",
+    "Event",
+    "EventTarget",
+    "File",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "FormData",
+    "Function",
+    "Headers",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "LN10",
+    "LN2",
+    "LOG10E",
+    "LOG2E",
+    "ListFormat",
+    "Locale",
+    "MAX_SAFE_INTEGER",
+    "MAX_VALUE",
+    "MIN_SAFE_INTEGER",
+    "MIN_VALUE",
+    "Map",
+    "MessageChannel",
+    "MessageEvent",
+    "MessagePort",
+    "NEGATIVE_INFINITY",
+    "NaN",
+    "Number",
+    "NumberFormat",
+    "Object",
+    "PI",
+    "POSITIVE_INFINITY",
+    "Performance",
+    "PerformanceEntry",
+    "PerformanceMark",
+    "PerformanceMeasure",
+    "PerformanceObserver",
+    "PerformanceObserverEntryList",
+    "PerformanceResourceTiming",
+    "PluralRules",
+    "Promise",
+    "Proxy",
+    "RangeError",
+    "ReadableByteStreamController",
+    "ReadableStream",
+    "ReadableStreamBYOBReader",
+    "ReadableStreamBYOBRequest",
+    "ReadableStreamDefaultController",
+    "ReadableStreamDefaultReader",
+    "ReferenceError",
+    "RegExp",
+    "RelativeTimeFormat",
+    "Request",
+    "Response",
+    "SQRT1_2",
+    "SQRT2",
+    "Segmenter",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "SubtleCrypto",
+    "Symbol",
+    "SyntaxError",
+    "TextDecoder",
+    "TextDecoderStream",
+    "TextEncoder",
+    "TextEncoderStream",
+    "TransformStream",
+    "TransformStreamDefaultController",
+    "TypeError",
+    "URIError",
+    "URL",
+    "URLSearchParams",
+    "UTC",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+    "WritableStream",
+    "WritableStreamDefaultController",
+    "WritableStreamDefaultWriter",
+    "abort",
+    "aborted",
+    "abs",
+    "accept",
+    "accessKey",
+    "acos",
+    "acosh",
+    "action",
+    "activeElement",
+    "add",
+    "addEventListener",
+    "adoptNode",
+    "after",
+    "alert",
+    "all",
+    "allSettled",
+    "alt",
+    "altKey",
+    "anchor",
+    "and",
+    "animate",
+    "any",
+    "append",
+    "appendChild",
+    "apply",
+    "arguments",
+    "arrayBuffer",
+    "asIntN",
+    "asUintN",
+    "asin",
+    "asinh",
+    "assign",
+    "asyncDispose",
+    "asyncIterator",
+    "at",
+    "atan",
+    "atan2",
+    "atanh",
+    "atob",
+    "attachShadow",
+    "attributes",
+    "autocomplete",
+    "autofocus",
+    "autoplay",
+    "back",
+    "baseName",
+    "before",
+    "big",
+    "bind",
+    "blink",
+    "blob",
+    "blur",
+    "body",
+    "bodyUsed",
+    "bold",
+    "borderBoxSize",
+    "boundingClientRect",
+    "btoa",
+    "bubbles",
+    "buffer",
+    "buffered",
+    "button",
+    "buttons",
+    "byteLength",
+    "byteOffset",
+    "cache",
+    "caches",
+    "calendar",
+    "calendars",
+    "call",
+    "caller",
+    "canPlayType",
+    "canShare",
+    "cancelAnimationFrame",
+    "cancelIdleCallback",
+    "cancelable",
+    "captureStackTrace",
+    "caseFirst",
+    "catch",
+    "cbrt",
+    "ceil",
+    "changedTouches",
+    "charAt",
+    "charCode",
+    "charCodeAt",
+    "characterSet",
+    "checkValidity",
+    "checked",
+    "childElementCount",
+    "childNodes",
+    "children",
+    "classList",
+    "className",
+    "clear",
+    "clearInterval",
+    "clearTimeout",
+    "click",
+    "clientHeight",
+    "clientLeft",
+    "clientTop",
+    "clientWidth",
+    "clientX",
+    "clientY",
+    "clipboard",
+    "clipboardData",
+    "clone",
+    "cloneNode",
+    "close",
+    "closest",
+    "clz32",
+    "code",
+    "codePointAt",
+    "collation",
+    "collations",
+    "compare",
+    "compareDocumentPosition",
+    "compareExchange",
+    "compile",
+    "complete",
+    "composed",
+    "composedPath",
+    "concat",
+    "confirm",
+    "console",
+    "construct",
+    "constructor",
+    "contains",
+    "content",
+    "contentBoxSize",
+    "contentEditable",
+    "contentRect",
+    "contentType",
+    "controls",
+    "cookie",
+    "cookieEnabled",
+    "copyWithin",
+    "cos",
+    "cosh",
+    "create",
+    "createComment",
+    "createDocumentFragment",
+    "createElement",
+    "createElementNS",
+    "createEvent",
+    "createRange",
+    "createTextNode",
+    "createTreeWalker",
+    "credentials",
+    "crossOrigin",
+    "crypto",
+    "cssRules",
+    "cssText",
+    "ctrlKey",
+    "currentScript",
+    "currentSrc",
+    "currentTarget",
+    "currentTime",
+    "customElements",
+    "data",
+    "dataTransfer",
+    "dataset",
+    "decode",
+    "decodeURI",
+    "decodeURIComponent",
+    "decoding",
+    "defaultChecked",
+    "defaultPrevented",
+    "defaultValue",
+    "defaultView",
+    "defineProperties",
+    "defineProperty",
+    "delete",
+    "deleteProperty",
+    "deleteRule",
+    "deltaMode",
+    "deltaX",
+    "deltaY",
+    "deltaZ",
+    "deref",
+    "description",
+    "detail",
+    "devicePixelRatio",
+    "dir",
+    "disabled",
+    "disconnect",
+    "dispatchEvent",
+    "dispose",
+    "document",
+    "documentElement",
+    "domain",
+    "dotAll",
+    "download",
+    "draggable",
+    "duration",
+    "elementFromPoint",
+    "elements",
+    "elementsFromPoint",
+    "encodeURI",
+    "encodeURIComponent",
+    "enctype",
+    "ended",
+    "endsWith",
+    "entries",
+    "escape",
+    "eval",
+    "eventPhase",
+    "every",
+    "exchange",
+    "exec",
+    "execCommand",
+    "exitFullscreen",
+    "exp",
+    "expm1",
+    "fetch",
+    "files",
+    "fill",
+    "filter",
+    "finally",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "firstChild",
+    "firstElementChild",
+    "fixed",
+    "flags",
+    "flat",
+    "flatMap",
+    "floor",
+    "focus",
+    "fontcolor",
+    "fonts",
+    "fontsize",
+    "for",
+    "forEach",
+    "form",
+    "formData",
+    "format",
+    "formatRange",
+    "formatRangeToParts",
+    "formatToParts",
+    "forms",
+    "forward",
+    "frameElement",
+    "frames",
+    "freeze",
+    "from",
+    "fromCharCode",
+    "fromCodePoint",
+    "fromEntries",
+    "fround",
+    "fullscreenElement",
+    "geolocation",
+    "get",
+    "getAnimations",
+    "getAttribute",
+    "getAttributeNS",
+    "getAttributeNames",
+    "getBigInt64",
+    "getBigUint64",
+    "getBoundingClientRect",
+    "getCanonicalLocales",
+    "getClientRects",
+    "getComputedStyle",
+    "getContext",
+    "getDate",
+    "getDay",
+    "getElementById",
+    "getElementsByClassName",
+    "getElementsByName",
+    "getElementsByTagName",
+    "getEntries",
+    "getEntriesByName",
+    "getEntriesByType",
+    "getFloat32",
+    "getFloat64",
+    "getFullYear",
+    "getHours",
+    "getInt16",
+    "getInt32",
+    "getInt8",
+    "getItem",
+    "getMilliseconds",
+    "getMinutes",
+    "getModifierState",
+    "getMonth",
+    "getOwnPropertyDescriptor",
+    "getOwnPropertyDescriptors",
+    "getOwnPropertyNames",
+    "getOwnPropertySymbols",
+    "getPropertyValue",
+    "getPrototypeOf",
+    "getRootNode",
+    "getSeconds",
+    "getSelection",
+    "getTime",
+    "getTimezoneOffset",
+    "getUTCDate",
+    "getUTCDay",
+    "getUTCFullYear",
+    "getUTCHours",
+    "getUTCMilliseconds",
+    "getUTCMinutes",
+    "getUTCMonth",
+    "getUTCSeconds",
+    "getUint16",
+    "getUint32",
+    "getUint8",
+    "getYear",
+    "global",
+    "globalThis",
+    "go",
+    "grow",
+    "growable",
+    "hardwareConcurrency",
+    "has",
+    "hasAttribute",
+    "hasAttributes",
+    "hasChildNodes",
+    "hasFocus",
+    "hasIndices",
+    "hasInstance",
+    "hasOwn",
+    "hasOwnProperty",
+    "hasPointerCapture",
+    "hash",
+    "head",
+    "headers",
+    "height",
+    "hidden",
+    "hidePopover",
+    "history",
+    "host",
+    "hostname",
+    "hourCycle",
+    "hourCycles",
+    "href",
+    "hypot",
+    "id",
+    "ignoreCase",
+    "images",
+    "importNode",
+    "imul",
+    "includes",
+    "indexOf",
+    "indexedDB",
+    "inert",
+    "innerHTML",
+    "innerHeight",
+    "innerText",
+    "innerWidth",
+    "input",
+    "inputType",
+    "insertAdjacentElement",
+    "insertAdjacentHTML",
+    "insertAdjacentText",
+    "insertBefore",
+    "insertRule",
+    "integrity",
+    "intersectionRatio",
+    "is",
+    "isArray",
+    "isComposing",
+    "isConcatSpreadable",
+    "isConnected",
+    "isContentEditable",
+    "isEqualNode",
+    "isExtensible",
+    "isFinite",
+    "isFrozen",
+    "isInteger",
+    "isIntersecting",
+    "isLockFree",
+    "isNaN",
+    "isPrimary",
+    "isPrototypeOf",
+    "isSafeInteger",
+    "isSameNode",
+    "isSealed",
+    "isTrusted",
+    "isView",
+    "isWellFormed",
+    "italics",
+    "iterator",
+    "join",
+    "json",
+    "keepalive",
+    "key",
+    "keyCode",
+    "keyFor",
+    "keys",
+    "labels",
+    "lang",
+    "language",
+    "languages",
+    "lastChild",
+    "lastElementChild",
+    "lastIndexOf",
+    "lastMatch",
+    "lastParen",
+    "leftContext",
+    "length",
+    "link",
+    "links",
+    "load",
+    "loading",
+    "localName",
+    "localStorage",
+    "localeCompare",
+    "location",
+    "log",
+    "log10",
+    "log1p",
+    "log2",
+    "loop",
+    "map",
+    "mark",
+    "match",
+    "matchAll",
+    "matchMedia",
+    "matches",
+    "max",
+    "maxByteLength",
+    "maxLength",
+    "maxTouchPoints",
+    "maximize",
+    "measure",
+    "media",
+    "mediaDevices",
+    "message",
+    "metaKey",
+    "method",
+    "min",
+    "minLength",
+    "minimize",
+    "mode",
+    "movementX",
+    "movementY",
+    "multiline",
+    "multiple",
+    "muted",
+    "name",
+    "namespaceURI",
+    "naturalHeight",
+    "naturalWidth",
+    "navigator",
+    "next",
+    "nextElementSibling",
+    "nextSibling",
+    "nodeName",
+    "nodeType",
+    "nodeValue",
+    "nonce",
+    "normalize",
+    "notify",
+    "now",
+    "numberingSystem",
+    "numberingSystems",
+    "numeric",
+    "observe",
+    "of",
+    "offsetHeight",
+    "offsetLeft",
+    "offsetParent",
+    "offsetTop",
+    "offsetWidth",
+    "offsetX",
+    "offsetY",
+    "ok",
+    "onLine",
+    "onabort",
+    "onbeforeunload",
+    "onblur",
+    "onchange",
+    "onclick",
+    "onclose",
+    "oncontextmenu",
+    "onerror",
+    "onfocus",
+    "onhashchange",
+    "oninput",
+    "oninvalid",
+    "onkeydown",
+    "onkeypress",
+    "onkeyup",
+    "onload",
+    "onmessage",
+    "onmousedown",
+    "onmouseenter",
+    "onmouseleave",
+    "onmousemove",
+    "onmouseout",
+    "onmouseover",
+    "onmouseup",
+    "onpointerdown",
+    "onpointermove",
+    "onpointerup",
+    "onpopstate",
+    "onreset",
+    "onresize",
+    "onscroll",
+    "onselect",
+    "onstorage",
+    "onsubmit",
+    "ontouchend",
+    "ontouchmove",
+    "ontouchstart",
+    "onunload",
+    "onvisibilitychange",
+    "onwheel",
+    "open",
+    "opener",
+    "options",
+    "or",
+    "origin",
+    "outerHTML",
+    "outerHeight",
+    "outerText",
+    "outerWidth",
+    "ownKeys",
+    "ownerDocument",
+    "padEnd",
+    "padStart",
+    "pageX",
+    "pageXOffset",
+    "pageY",
+    "pageYOffset",
+    "parent",
+    "parentElement",
+    "parentNode",
+    "parse",
+    "parseFloat",
+    "parseInt",
+    "part",
+    "password",
+    "pathname",
+    "pattern",
+    "pause",
+    "paused",
+    "performance",
+    "permissions",
+    "placeholder",
+    "platform",
+    "play",
+    "playbackRate",
+    "pointerId",
+    "pointerLockElement",
+    "pointerType",
+    "pop",
+    "port",
+    "postMessage",
+    "poster",
+    "pow",
+    "prefix",
+    "preload",
+    "prepareStackTrace",
+    "prepend",
+    "pressure",
+    "preventDefault",
+    "preventExtensions",
+    "previousElementSibling",
+    "previousSibling",
+    "print",
+    "prompt",
+    "propertyIsEnumerable",
+    "protocol",
+    "prototype",
+    "push",
+    "pushState",
+    "querySelector",
+    "querySelectorAll",
+    "queueMicrotask",
+    "race",
+    "random",
+    "raw",
+    "readOnly",
+    "readyState",
+    "reason",
+    "redirect",
+    "redirected",
+    "reduce",
+    "reduceRight",
+    "referrer",
+    "region",
+    "register",
+    "reject",
+    "rel",
+    "relatedTarget",
+    "releasePointerCapture",
+    "reload",
+    "remove",
+    "removeAttribute",
+    "removeAttributeNS",
+    "removeChild",
+    "removeEventListener",
+    "removeItem",
+    "removeProperty",
+    "repeat",
+    "replace",
+    "replaceAll",
+    "replaceChild",
+    "replaceChildren",
+    "replaceState",
+    "replaceWith",
+    "reportValidity",
+    "requestAnimationFrame",
+    "requestFullscreen",
+    "requestIdleCallback",
+    "requestPointerLock",
+    "requestSubmit",
+    "required",
+    "reset",
+    "resizable",
+    "resize",
+    "resolve",
+    "resolvedOptions",
+    "return",
+    "reverse",
+    "rightContext",
+    "round",
+    "screen",
+    "screenX",
+    "screenY",
+    "script",
+    "scripts",
+    "scroll",
+    "scrollBy",
+    "scrollHeight",
+    "scrollIntoView",
+    "scrollIntoViewIfNeeded",
+    "scrollLeft",
+    "scrollTo",
+    "scrollTop",
+    "scrollWidth",
+    "scrollX",
+    "scrollY",
+    "seal",
+    "search",
+    "searchParams",
+    "seekable",
+    "segment",
+    "select",
+    "selectRange",
+    "selected",
+    "selectedIndex",
+    "selectedOptions",
+    "selectionDirection",
+    "selectionEnd",
+    "selectionStart",
+    "self",
+    "sendBeacon",
+    "serviceWorker",
+    "sessionStorage",
+    "set",
+    "setAttribute",
+    "setAttributeNS",
+    "setBigInt64",
+    "setBigUint64",
+    "setCustomValidity",
+    "setDate",
+    "setFloat32",
+    "setFloat64",
+    "setFullYear",
+    "setHours",
+    "setInt16",
+    "setInt32",
+    "setInt8",
+    "setInterval",
+    "setItem",
+    "setMilliseconds",
+    "setMinutes",
+    "setMonth",
+    "setPointerCapture",
+    "setProperty",
+    "setPrototypeOf",
+    "setSeconds",
+    "setSelectionRange",
+    "setTime",
+    "setTimeout",
+    "setUTCDate",
+    "setUTCFullYear",
+    "setUTCHours",
+    "setUTCMilliseconds",
+    "setUTCMinutes",
+    "setUTCMonth",
+    "setUTCSeconds",
+    "setUint16",
+    "setUint32",
+    "setUint8",
+    "setYear",
+    "shadowRoot",
+    "share",
+    "sheet",
+    "shift",
+    "shiftKey",
+    "show",
+    "showModal",
+    "showPopover",
+    "sign",
+    "signal",
+    "sin",
+    "sinh",
+    "size",
+    "sizes",
+    "slice",
+    "slot",
+    "small",
+    "some",
+    "sort",
+    "source",
+    "species",
+    "spellcheck",
+    "splice",
+    "split",
+    "sqrt",
+    "src",
+    "srcObject",
+    "srcset",
+    "stackTraceLimit",
+    "startViewTransition",
+    "startsWith",
+    "state",
+    "status",
+    "statusText",
+    "step",
+    "sticky",
+    "stopImmediatePropagation",
+    "stopPropagation",
+    "store",
+    "strike",
+    "stringify",
+    "structuredClone",
+    "style",
+    "styleSheets",
+    "sub",
+    "subarray",
+    "submit",
+    "substr",
+    "substring",
+    "sup",
+    "supportedLocalesOf",
+    "supportedValuesOf",
+    "tabIndex",
+    "tagName",
+    "takeRecords",
+    "tan",
+    "tanh",
+    "target",
+    "targetTouches",
+    "test",
+    "text",
+    "textContent",
+    "textInfo",
+    "then",
+    "throw",
+    "throwIfAborted",
+    "timeOrigin",
+    "timeStamp",
+    "timeZones",
+    "title",
+    "toBlob",
+    "toDataURL",
+    "toDateString",
+    "toExponential",
+    "toFixed",
+    "toGMTString",
+    "toISOString",
+    "toJSON",
+    "toLocaleDateString",
+    "toLocaleLowerCase",
+    "toLocaleString",
+    "toLocaleTimeString",
+    "toLocaleUpperCase",
+    "toLowerCase",
+    "toPrecision",
+    "toPrimitive",
+    "toReversed",
+    "toSorted",
+    "toSpliced",
+    "toString",
+    "toStringTag",
+    "toTimeString",
+    "toUTCString",
+    "toUpperCase",
+    "toWellFormed",
+    "toggleAttribute",
+    "togglePopover",
+    "top",
+    "touches",
+    "transferControlToOffscreen",
+    "translate",
+    "trim",
+    "trimEnd",
+    "trimLeft",
+    "trimRight",
+    "trimStart",
+    "trunc",
+    "type",
+    "undefined",
+    "unescape",
+    "unicode",
+    "unicodeSets",
+    "unobserve",
+    "unregister",
+    "unscopables",
+    "unshift",
+    "userAgent",
+    "username",
+    "validationMessage",
+    "validity",
+    "value",
+    "valueOf",
+    "values",
+    "vibrate",
+    "visibilityState",
+    "visualViewport",
+    "volume",
+    "wait",
+    "waitAsync",
+    "weekInfo",
+    "which",
+    "width",
+    "willValidate",
+    "window",
+    "with",
+    "xor",
+];
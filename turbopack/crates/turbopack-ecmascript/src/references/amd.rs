@@ -17,7 +17,7 @@ use turbo_tasks::{
 use turbopack_core::{
     chunk::{ChunkingContext, ChunkingType, ChunkingTypeOption},
     issue::IssueSource,
-    reference::ModuleReference,
+    reference::{ModuleReference, TraceReferenceKind},
     reference_type::CommonJsReferenceSubType,
     resolve::{ModuleResolveResult, ResolveErrorMode, origin::ResolveOrigin, parse::Request},
 };
@@ -81,6 +81,11 @@ impl ModuleReference for AmdDefineAssetReference {
             hoisted: false,
         }))
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Require.cell()
+    }
 }
 
 #[derive(
@@ -11,7 +11,7 @@ use turbo_tasks::{
 use turbopack_core::{
     chunk::{ChunkingContext, ChunkingType, ChunkingTypeOption},
     issue::IssueSource,
-    reference::ModuleReference,
+    reference::{ModuleReference, TraceReferenceKind},
     reference_type::CommonJsReferenceSubType,
     resolve::{ModuleResolveResult, ResolveErrorMode, origin::ResolveOrigin, parse::Request},
 };
@@ -75,6 +75,11 @@ impl ModuleReference for CjsAssetReference {
             hoisted: false,
         }))
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Require.cell()
+    }
 }
 
 #[turbo_tasks::value]
@@ -123,6 +128,11 @@ impl ModuleReference for CjsRequireAssetReference {
             hoisted: false,
         }))
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Require.cell()
+    }
 }
 
 impl IntoCodeGenReference for CjsRequireAssetReference {
@@ -246,6 +256,11 @@ impl ModuleReference for CjsRequireResolveAssetReference {
             hoisted: false,
         }))
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Require.cell()
+    }
 }
 
 impl IntoCodeGenReference for CjsRequireResolveAssetReference {
@@ -21,7 +21,7 @@ use turbopack_core::{
     loader::ResolvedWebpackLoaderItem,
     module::{Module, ModuleSideEffects},
     module_graph::binding_usage_info::ModuleExportUsageInfo,
-    reference::ModuleReference,
+    reference::{ModuleReference, TraceReferenceKind},
     reference_type::{EcmaScriptModulesReferenceSubType, ReferenceType},
     resolve::{
         BindingUsage, ExportUsage, ExternalType, ImportUsage, ModulePart, ModuleResolveResult,
@@ -436,6 +436,8 @@ impl ModuleReference for EsmAssetReference {
                 rename_as: self.annotations.turbopack_rename_as().cloned(),
                 module_type: self.annotations.turbopack_module_type().cloned(),
             }
+        } else if self.annotations.source_phase() {
+            EcmaScriptModulesReferenceSubType::ImportSource
         } else if let Some(module_type) = self.annotations.module_type() {
             EcmaScriptModulesReferenceSubType::ImportWithType(RcStr::from(
                 &*module_type.to_string_lossy(),
@@ -539,6 +541,11 @@ impl ModuleReference for EsmAssetReference {
         }
         .cell()
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Import.cell()
+    }
 }
 
 impl EsmAssetReference {
@@ -12,7 +12,7 @@ use turbopack_core::{
     chunk::{ChunkingContext, ChunkingType, ChunkingTypeOption},
     environment::ChunkLoading,
     issue::IssueSource,
-    reference::ModuleReference,
+    reference::{ModuleReference, TraceReferenceKind},
     reference_type::EcmaScriptModulesReferenceSubType,
     resolve::{
         BindingUsage, ExportUsage, ModuleResolveResult, ResolveErrorMode,
@@ -107,6 +107,11 @@ impl ModuleReference for EsmAsyncAssetReference {
         }
         .cell()
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Import.cell()
+    }
 }
 
 impl IntoCodeGenReference for EsmAsyncAssetReference {
@@ -157,6 +157,7 @@ use crate::{
         WebpackChunkAssetReference, WebpackEntryAssetReference, WebpackRuntimeAssetReference,
         parse::{WebpackRuntime, webpack_runtime},
     },
+    worker_chunk::WorkerType,
 };
 
 #[turbo_tasks::value(shared)]
@@ -1366,12 +1367,16 @@ async fn analyze_ecmascript_module_internal(
                         continue;
                     }
 
-                    let func = analysis_state
-                        .link_value(
-                            JsValue::member(obj.clone(), prop),
-                            eval_context.imports.get_attributes(span),
-                        )
-                        .await?;
+                    let func = if !new && let Some(kind) = url_worker_function(&obj, &prop) {
+                        JsValue::WellKnownFunction(kind)
+                    } else {
+                        analysis_state
+                            .link_value(
+                                JsValue::member(obj.clone(), prop),
+                                eval_context.imports.get_attributes(span),
+                            )
+                            .await?
+                    };
 
                     if !new
                         && matches!(
@@ -1723,6 +1728,21 @@ async fn compile_time_info_for_module_options(
     .cell())
 }
 
+/// `audioWorklet`, `paintWorklet` and `serviceWorker` are properties of values that can't be
+/// analyzed, e.g. an `AudioContext` instance, the `CSS` namespace or `navigator`, so calls to their
+/// `addModule` and `register` methods are detected by the property names.
+fn url_worker_function(obj: &JsValue, prop: &JsValue) -> Option<WellKnownFunctionKind> {
+    let JsValue::Member(_, _, container) = obj else {
+        return None;
+    };
+    match (container.as_str()?, prop.as_str()?) {
+        ("audioWorklet", "addModule") => Some(WellKnownFunctionKind::AudioWorkletAddModule),
+        ("paintWorklet", "addModule") => Some(WellKnownFunctionKind::PaintWorkletAddModule),
+        ("serviceWorker", "register") => Some(WellKnownFunctionKind::ServiceWorkerRegister),
+        _ => None,
+    }
+}
+
 async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
     ast_path: &[AstParentKind],
     span: Span,
@@ -2192,6 +2212,52 @@ where
     }
 
     match func {
+        WellKnownFunctionKind::AudioWorkletAddModule
+        | WellKnownFunctionKind::PaintWorkletAddModule
+        | WellKnownFunctionKind::ServiceWorkerRegister => {
+            let (worker_type, method) = match func {
+                WellKnownFunctionKind::AudioWorkletAddModule => {
+                    (WorkerType::AudioWorklet, "addModule")
+                }
+                WellKnownFunctionKind::PaintWorkletAddModule => {
+                    (WorkerType::PaintWorklet, "addModule")
+                }
+                _ => (WorkerType::ServiceWorker, "register"),
+            };
+            let args = linked_args().await?;
+            if let Some(url @ JsValue::Url(_, JsValueUrlKind::Relative)) = args.first() {
+                let pat = js_value_to_pattern(url);
+                if !pat.has_constant_parts() {
+                    let (args, hints) = explain_args(args);
+                    handler.span_warn_with_code(
+                        span,
+                        &format!("{method}({args}) is very dynamic{hints}",),
+                        DiagnosticId::Lint(
+                            errors::failed_to_analyze::ecmascript::NEW_WORKER.to_string(),
+                        ),
+                    );
+                    if ignore_dynamic_requests {
+                        return Ok(());
+                    }
+                }
+
+                if *compile_time_info.environment().rendering().await? == Rendering::Client {
+                    analysis.add_reference_code_gen(
+                        WorkerAssetReference::new_url_worker(
+                            worker_type,
+                            origin,
+                            Request::parse(pat).to_resolved().await?,
+                            get_issue_source(),
+                            error_mode,
+                            tracing_only,
+                        ),
+                        ast_path.to_vec().into(),
+                    );
+                }
+            }
+            // Ignore other URLs (e.g. absolute URLs or blob URLs), just as for new Worker()
+            return Ok(());
+        }
         WellKnownFunctionKind::Import => {
             let args = linked_args().await?;
             let export_usage = match &attributes.export_names {
@@ -8,7 +8,7 @@ use turbopack_core::{
     file_source::FileSource,
     issue::IssueSource,
     raw_module::RawModule,
-    reference::ModuleReference,
+    reference::{ModuleReference, TraceReferenceKind},
     resolve::{
         ModuleResolveResult, RequestKey,
         pattern::{Pattern, PatternMatch, read_matches},
@@ -82,6 +82,16 @@ impl ModuleReference for FileSourceReference {
     fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
         Vc::cell(Some(ChunkingType::Traced))
     }
+
+    #[turbo_tasks::function]
+    async fn trace_kind(&self) -> Result<Vc<TraceReferenceKind>> {
+        Ok(if self.path.await?.has_dynamic_parts() {
+            TraceReferenceKind::Glob
+        } else {
+            TraceReferenceKind::FsRead
+        }
+        .cell())
+    }
 }
 
 #[turbo_tasks::value]
@@ -211,4 +221,9 @@ impl ModuleReference for DirAssetReference {
     fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
         Vc::cell(Some(ChunkingType::Traced))
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Glob.cell()
+    }
 }
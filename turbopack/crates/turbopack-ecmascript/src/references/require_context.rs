@@ -29,7 +29,7 @@ use turbopack_core::{
     issue::IssueSource,
     module::{Module, ModuleSideEffects},
     module_graph::ModuleGraph,
-    reference::{ModuleReference, ModuleReferences},
+    reference::{ModuleReference, ModuleReferences, TraceReferenceKind},
     reference_type::CommonJsReferenceSubType,
     resolve::{ModuleResolveResult, ResolveErrorMode, origin::ResolveOrigin, parse::Request},
     source::Source,
@@ -302,6 +302,11 @@ impl ModuleReference for RequireContextAssetReference {
             hoisted: false,
         }))
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::Glob.cell()
+    }
 }
 
 impl IntoCodeGenReference for RequireContextAssetReference {
@@ -34,8 +34,8 @@ use crate::{
     worker_chunk::{WorkerType, module::WorkerLoaderModule},
 };
 
-/// A unified reference to a Worker (web or Node.js) that creates an isolated chunk group
-/// for the worker module.
+/// A unified reference to a Worker (web or Node.js), a worklet or a service worker that creates an
+/// isolated chunk group for the worker module.
 #[turbo_tasks::value]
 #[derive(Hash, Debug)]
 pub struct WorkerAssetReference {
@@ -53,7 +53,7 @@ pub struct WorkerAssetReference {
 #[turbo_tasks::value]
 #[derive(Hash, Debug, Clone)]
 pub enum WorkerRequest {
-    /// Web workers use Request (URLs)
+    /// Web workers, worklets and service workers use Request (URLs)
     Url(ResolvedVc<Request>),
     /// Node.js workers use Pattern (file paths) with a context directory that should be the server
     /// working directory
@@ -87,6 +87,27 @@ impl WorkerAssetReference {
         }
     }
 
+    /// Creates a reference to a worklet or service worker, which is loaded by passing the URL of
+    /// a single script to `addModule` or `register`.
+    pub fn new_url_worker(
+        worker_type: WorkerType,
+        origin: ResolvedVc<Box<dyn ResolveOrigin>>,
+        request: ResolvedVc<Request>,
+        issue_source: IssueSource,
+        error_mode: ResolveErrorMode,
+        tracing_only: bool,
+    ) -> Self {
+        debug_assert!(worker_type.exports_url());
+        WorkerAssetReference {
+            worker_type,
+            origin,
+            request: WorkerRequest::Url(request),
+            issue_source,
+            error_mode,
+            tracing_only,
+        }
+    }
+
     pub fn new_node_worker_thread(
         origin: ResolvedVc<Box<dyn ResolveOrigin>>,
         context_dir: FileSystemPath,
@@ -118,8 +139,15 @@ impl ModuleReference for WorkerAssetReference {
         let asset_context = self.origin.asset_context().to_resolved().await?;
 
         let result = match (&self.worker_type, &self.request) {
-            (WorkerType::WebWorker | WorkerType::SharedWebWorker, WorkerRequest::Url(request)) => {
-                // Web worker resolution uses url_resolve
+            (
+                WorkerType::WebWorker
+                | WorkerType::SharedWebWorker
+                | WorkerType::AudioWorklet
+                | WorkerType::PaintWorklet
+                | WorkerType::ServiceWorker,
+                WorkerRequest::Url(request),
+            ) => {
+                // Web worker, worklet and service worker resolution uses url_resolve
                 url_resolve(
                     *self.origin,
                     **request,
@@ -285,19 +313,21 @@ impl WorkerAssetReference {
 impl ValueToString for WorkerAssetReference {
     #[turbo_tasks::function]
     async fn to_string(&self) -> Result<Vc<RcStr>> {
+        let request = match &self.request {
+            WorkerRequest::Url(request) => request.to_string().await?,
+            WorkerRequest::Pattern { path, .. } => path.to_string().await?,
+        };
         Ok(Vc::cell(
-            format!(
-                "new {}({})",
-                match self.worker_type {
-                    WorkerType::WebWorker => "WebWorker",
-                    WorkerType::SharedWebWorker => "SharedWorker",
-                    WorkerType::NodeWorkerThread => "NodeWorkerThread",
-                },
-                match &self.request {
-                    WorkerRequest::Url(request) => request.to_string().await?,
-                    WorkerRequest::Pattern { path, .. } => path.to_string().await?,
+            match self.worker_type {
+                WorkerType::WebWorker => format!("new WebWorker({request})"),
+                WorkerType::SharedWebWorker => format!("new SharedWorker({request})"),
+                WorkerType::NodeWorkerThread => format!("new NodeWorkerThread({request})"),
+                WorkerType::AudioWorklet => format!("audioWorklet.addModule({request})"),
+                WorkerType::PaintWorklet => format!("paintWorklet.addModule({request})"),
+                WorkerType::ServiceWorker => {
+                    format!("navigator.serviceWorker.register({request})")
                 }
-            )
+            }
             .into(),
         ))
     }
@@ -347,6 +377,42 @@ impl WorkerAssetReferenceCodeGen {
         )
         .await?;
 
+        if reference.worker_type.exports_url() {
+            // Transform `worklet.addModule(url, opts)` into `worklet.addModule(require(id), opts)`
+            // and `serviceWorker.register(url, opts)` into `serviceWorker.register(require(id),
+            // opts)`. The loader module exports the URL of the script containing the whole worker.
+            let method = if reference.worker_type == WorkerType::ServiceWorker {
+                "register"
+            } else {
+                "addModule"
+            };
+            let visitor = create_visitor!(self.path, visit_mut_expr, |expr: &mut Expr| {
+                let message = if let Expr::Call(call_expr) = expr {
+                    match call_expr.args.first_mut() {
+                        Some(ExprOrSpread {
+                            spread: None,
+                            expr: url_expr,
+                        }) => {
+                            *url_expr = Box::new(pm.create_require(*url_expr.take()));
+                            return;
+                        }
+                        Some(ExprOrSpread {
+                            spread: Some(_),
+                            expr: _,
+                        }) => format!("spread operator is illegal in {method}() expressions."),
+                        _ => format!("{method}() expressions require at least 1 argument"),
+                    }
+                } else {
+                    "visitor must be executed on a CallExpr".to_string()
+                };
+                *expr = *quote_expr!(
+                    "(() => { throw new Error($message); })()",
+                    message: Expr = Expr::Lit(Lit::Str(message.as_str().into()))
+                );
+            });
+            return Ok(CodeGeneration::visitors(vec![visitor]));
+        }
+
         // Transform `new Worker(url, opts)` into `require(id)(Worker, opts)`
         // The loader module exports a function that creates the worker with all necessary
         // configuration (entrypoint, chunks, forwarded globals, etc.)
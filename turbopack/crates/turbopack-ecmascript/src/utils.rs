@@ -11,11 +11,17 @@ use swc_core::{
 };
 use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{NonLocalValue, TaskInput, trace::TraceRawVcs};
-use turbopack_core::{chunk::ModuleId, resolve::pattern::Pattern};
+use turbopack_core::{
+    chunk::{AssetSuffix, ModuleId},
+    resolve::pattern::Pattern,
+};
 
-use crate::analyzer::{
-    ConstantNumber, ConstantValue, JsValue, JsValueUrlKind, ModuleValue, WellKnownFunctionKind,
-    WellKnownObjectKind,
+use crate::{
+    analyzer::{
+        ConstantNumber, ConstantValue, JsValue, JsValueUrlKind, ModuleValue, WellKnownFunctionKind,
+        WellKnownObjectKind,
+    },
+    runtime_functions::{TURBOPACK_EXPORT_URL, TURBOPACK_EXPORT_VALUE},
 };
 
 pub fn unparen(expr: &Expr) -> &Expr {
@@ -294,6 +300,37 @@ pub fn inline_source_map_comment(original_path: &str, original_content: &str) ->
     )
 }
 
+/// Returns the code of a chunk item that exports the URL of an asset, with the `suffix` of the
+/// chunking context's [`UrlBehavior`](turbopack_core::chunk::UrlBehavior) appended.
+pub fn asset_url_export_code(url: &str, suffix: &AssetSuffix) -> String {
+    match suffix {
+        AssetSuffix::None => {
+            // No suffix, export as-is
+            format!("{TURBOPACK_EXPORT_VALUE}({path});", path = StringifyJs(url))
+        }
+        AssetSuffix::Constant(suffix) => {
+            // Append constant suffix
+            format!(
+                "{TURBOPACK_EXPORT_VALUE}({path} + {suffix});",
+                path = StringifyJs(url),
+                suffix = StringifyJs(suffix.as_str())
+            )
+        }
+        AssetSuffix::Inferred => {
+            // The runtime logic will infer the suffix
+            format!("{TURBOPACK_EXPORT_URL}({path});", path = StringifyJs(url))
+        }
+        AssetSuffix::FromGlobal(global_name) => {
+            // Read suffix from global at runtime
+            format!(
+                "{TURBOPACK_EXPORT_VALUE}({path} + (globalThis[{global}] || ''));",
+                path = StringifyJs(url),
+                global = StringifyJs(global_name)
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use turbo_rcstr::rcstr;
@@ -23,13 +23,14 @@ use crate::{
         EcmascriptChunkItemContent, EcmascriptChunkPlaceable, EcmascriptExports,
         data::EcmascriptChunkData, ecmascript_chunk_item,
     },
-    runtime_functions::{TURBOPACK_CREATE_WORKER, TURBOPACK_EXPORT_VALUE},
+    runtime_functions::{TURBOPACK_CREATE_WORKER, TURBOPACK_EXPORT_URL, TURBOPACK_EXPORT_VALUE},
     utils::StringifyJs,
 };
 
 /// The WorkerLoaderModule is a module that creates a separate root chunk group for the given module
 /// and exports a URL (for web workers) or file path (for Node.js workers) to pass to the worker
-/// constructor.
+/// constructor. For worklets and service workers, it exports the URL of a single script to pass to
+/// `addModule` or `register`.
 #[turbo_tasks::value]
 pub struct WorkerLoaderModule {
     pub inner: ResolvedVc<Box<dyn ChunkableModule>>,
@@ -69,6 +70,36 @@ impl WorkerLoaderModule {
                     module_graph,
                     AvailabilityInfo::root(),
                 ),
+            // Worklets can't load chunks and service workers are updated by the browser based on
+            // a single script, so the whole chunk group is inlined into a single script.
+            WorkerType::AudioWorklet | WorkerType::PaintWorklet | WorkerType::ServiceWorker => {
+                let ident = this
+                    .inner
+                    .ident()
+                    .with_modifier(this.worker_type.chunk_modifier_str());
+                let chunk_group = chunking_context
+                    .evaluated_chunk_group_assets(
+                        ident,
+                        ChunkGroup::Isolated(ResolvedVc::upcast(this.inner)),
+                        module_graph,
+                        AvailabilityInfo::root(),
+                    )
+                    .await?;
+                let entrypoint = if this.worker_type == WorkerType::ServiceWorker {
+                    chunking_context.service_worker_entrypoint(ident, *chunk_group.assets)
+                } else {
+                    chunking_context.worklet_entrypoint(ident, *chunk_group.assets)
+                }
+                .to_resolved()
+                .await?;
+
+                OutputAssetsWithReferenced {
+                    assets: ResolvedVc::cell(vec![entrypoint]),
+                    referenced_assets: chunk_group.referenced_assets,
+                    references: chunk_group.references,
+                }
+                .cell()
+            }
             // WorkerThreads are treated as an entry point, webworkers probably should too but
             // currently it would lead to a cascade that we need to address.
             WorkerType::NodeWorkerThread => {
@@ -135,8 +166,12 @@ impl WorkerLoaderModule {
             WorkerType::WebWorker | WorkerType::SharedWebWorker => self
                 .chunk_group(chunking_context, module_graph)
                 .concatenate_asset(chunking_context.worker_entrypoint()),
-            WorkerType::NodeWorkerThread => {
-                // Node.js workers don't need a separate entrypoint asset
+            WorkerType::NodeWorkerThread
+            | WorkerType::AudioWorklet
+            | WorkerType::PaintWorklet
+            | WorkerType::ServiceWorker => {
+                // Node.js workers, worklets and service workers don't need a separate entrypoint
+                // asset
                 self.chunk_group(chunking_context, module_graph)
             }
         })
@@ -254,6 +289,34 @@ impl EcmascriptChunkPlaceable for WorkerLoaderModule {
                     chunks = StringifyJs(&chunks_data),
                 }
             }
+            WorkerType::AudioWorklet | WorkerType::PaintWorklet | WorkerType::ServiceWorker => {
+                // For worklets and service workers, export the URL of the script to pass to
+                // `addModule` or `register`.
+                let chunk_group = self.chunk_group(chunking_context, module_graph).await?;
+                let assets = chunk_group.assets.await?;
+                let Some(entrypoint) = assets.first() else {
+                    bail!(
+                        "cannot find {} entry point asset",
+                        this.worker_type.friendly_str()
+                    );
+                };
+                // Service workers are served at a configured path, as their scope is limited to
+                // the path they are served at.
+                let url = if this.worker_type == WorkerType::ServiceWorker {
+                    chunking_context.service_worker_url().await?
+                } else {
+                    chunking_context
+                        .asset_url(entrypoint.path().owned().await?, None)
+                        .await?
+                };
+
+                formatdoc! {
+                    r#"
+                        {TURBOPACK_EXPORT_URL}({url});
+                    "#,
+                    url = StringifyJs(&url),
+                }
+            }
             WorkerType::NodeWorkerThread => {
                 // For Node.js workers, export a function to create the worker.
                 // The function takes (WorkerConstructor, workerOptions) and calls createWorker
@@ -335,7 +398,11 @@ impl ModuleReference for WorkerModuleReference {
     fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
         Vc::cell(Some(ChunkingType::Isolated {
             _ty: match self.worker_type {
-                WorkerType::SharedWebWorker | WorkerType::WebWorker => ChunkGroupType::Evaluated,
+                WorkerType::SharedWebWorker
+                | WorkerType::WebWorker
+                | WorkerType::AudioWorklet
+                | WorkerType::PaintWorklet
+                | WorkerType::ServiceWorker => ChunkGroupType::Evaluated,
                 WorkerType::NodeWorkerThread => ChunkGroupType::Entry,
             },
             merge_tag: None,
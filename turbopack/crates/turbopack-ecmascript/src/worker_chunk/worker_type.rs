@@ -10,6 +10,12 @@ pub enum WorkerType {
     WebWorker,
     SharedWebWorker,
     NodeWorkerThread,
+    /// A module loaded with `audioWorklet.addModule(new URL(...))`.
+    AudioWorklet,
+    /// A module loaded with `CSS.paintWorklet.addModule(new URL(...))`.
+    PaintWorklet,
+    /// A module registered with `navigator.serviceWorker.register(new URL(...))`.
+    ServiceWorker,
 }
 
 impl WorkerType {
@@ -17,6 +23,8 @@ impl WorkerType {
         match self {
             WorkerType::SharedWebWorker | WorkerType::WebWorker => rcstr!("worker loader"),
             WorkerType::NodeWorkerThread => rcstr!("node worker thread loader"),
+            WorkerType::AudioWorklet | WorkerType::PaintWorklet => rcstr!("worklet loader"),
+            WorkerType::ServiceWorker => rcstr!("service worker loader"),
         }
     }
 
@@ -24,6 +32,8 @@ impl WorkerType {
         match self {
             WorkerType::SharedWebWorker | WorkerType::WebWorker => rcstr!("worker"),
             WorkerType::NodeWorkerThread => rcstr!("node worker thread"),
+            WorkerType::AudioWorklet | WorkerType::PaintWorklet => rcstr!("worklet"),
+            WorkerType::ServiceWorker => rcstr!("service worker"),
         }
     }
 
@@ -32,6 +42,9 @@ impl WorkerType {
             WorkerType::WebWorker => rcstr!("web worker"),
             WorkerType::SharedWebWorker => rcstr!("shared web worker"),
             WorkerType::NodeWorkerThread => rcstr!("node worker thread"),
+            WorkerType::AudioWorklet => rcstr!("audio worklet"),
+            WorkerType::PaintWorklet => rcstr!("paint worklet"),
+            WorkerType::ServiceWorker => rcstr!("service worker"),
         }
     }
 
@@ -40,6 +53,21 @@ impl WorkerType {
             WorkerType::WebWorker => WorkerReferenceSubType::WebWorker,
             WorkerType::SharedWebWorker => WorkerReferenceSubType::SharedWorker,
             WorkerType::NodeWorkerThread => WorkerReferenceSubType::NodeWorker,
+            WorkerType::AudioWorklet => WorkerReferenceSubType::AudioWorklet,
+            WorkerType::PaintWorklet => WorkerReferenceSubType::PaintWorklet,
+            WorkerType::ServiceWorker => WorkerReferenceSubType::ServiceWorker,
         })
     }
+
+    /// Worklets are added to an existing worklet global scope with `addModule(url)` instead of
+    /// being constructed, and can't load any further chunks.
+    pub fn is_worklet(&self) -> bool {
+        matches!(self, WorkerType::AudioWorklet | WorkerType::PaintWorklet)
+    }
+
+    /// Worklets and service workers are loaded by passing the URL of a single script to the
+    /// browser, so their loader module exports that URL instead of a worker constructor.
+    pub fn exports_url(&self) -> bool {
+        self.is_worklet() || matches!(self, WorkerType::ServiceWorker)
+    }
 }
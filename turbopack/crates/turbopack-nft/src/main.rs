@@ -6,6 +6,7 @@ use std::env::current_dir;
 use anyhow::Result;
 use clap::Parser;
 use tracing_subscriber::{Registry, layer::SubscriberExt, util::SubscriberInitExt};
+use turbo_rcstr::RcStr;
 use turbo_tasks::TurboTasks;
 use turbo_tasks_backend::{BackendOptions, TurboTasksBackend, noop_backing_storage};
 use turbo_tasks_malloc::TurboMalloc;
@@ -34,6 +35,11 @@ pub struct Arguments {
 
     #[clap(long)]
     pub depth: Option<usize>,
+
+    /// Print every shortest reference chain from the entry to this traced file instead of the
+    /// file list.
+    #[clap(long)]
+    pub why: Option<String>,
 }
 
 #[global_allocator]
@@ -97,6 +103,7 @@ async fn main_inner(args: Arguments) -> Result<()> {
             args.graph,
             args.show_issues,
             args.depth,
+            args.why.map(RcStr::from),
         )
         .await?;
         Ok(())
@@ -3,7 +3,7 @@ use std::{collections::HashSet, env::current_dir, path::PathBuf};
 use anyhow::Result;
 use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{ResolvedVc, TransientInstance, TryJoinIterExt, ValueToString, Vc};
-use turbo_tasks_fs::{DiskFileSystem, FileSystem};
+use turbo_tasks_fs::{DiskFileSystem, FileSystem, FileSystemPath};
 use turbopack::{
     ModuleAssetContext,
     module_options::{
@@ -22,7 +22,7 @@ use turbopack_core::{
     output::{OutputAsset, OutputAssetsReference},
     reference::all_assets_from_entries,
     reference_type::ReferenceType,
-    traced_asset::TracedAsset,
+    traced_asset::{TracedAsset, trace_chains},
 };
 use turbopack_ecmascript::AnalyzeMode;
 use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
@@ -33,8 +33,15 @@ pub async fn node_file_trace(
     graph: bool,
     show_issues: bool,
     max_depth: Option<usize>,
+    why: Option<RcStr>,
 ) -> Result<()> {
-    let op = node_file_trace_operation(project_root.clone(), input.clone(), graph, max_depth);
+    let op = node_file_trace_operation(
+        project_root.clone(),
+        input.clone(),
+        graph,
+        max_depth,
+        why.clone(),
+    );
     let result = op.resolve_strongly_consistent().await?;
 
     if show_issues {
@@ -50,7 +57,11 @@ pub async fn node_file_trace(
         handle_issues(op, issue_reporter, IssueSeverity::Error, None, None).await?;
     }
 
-    println!("FILELIST:");
+    if let Some(why) = why {
+        println!("WHY {why}:");
+    } else {
+        println!("FILELIST:");
+    }
     for a in result.await? {
         println!("{a}");
     }
@@ -64,6 +75,7 @@ async fn node_file_trace_operation(
     input: RcStr,
     graph: bool,
     max_depth: Option<usize>,
+    why: Option<RcStr>,
 ) -> Result<Vc<Vec<RcStr>>> {
     let workspace_fs: Vc<Box<dyn FileSystem>> = Vc::upcast(DiskFileSystem::new(
         rcstr!("workspace"),
@@ -71,6 +83,7 @@ async fn node_file_trace_operation(
     ));
     let input_dir = workspace_fs.root().owned().await?;
     let input = input_dir.join(&format!("{input}"))?;
+    let why = why.map(|why| input_dir.join(&why)).transpose()?;
 
     let source = FileSource::new(input);
     let environment = Environment::new(ExecutionEnvironment::NodeJsLambda(
@@ -123,7 +136,9 @@ async fn node_file_trace_operation(
 
     let asset = TracedAsset::new(module).to_resolved().await?;
 
-    Ok(Vc::cell(if graph {
+    Ok(Vc::cell(if let Some(why) = why {
+        to_why(ResolvedVc::upcast(asset), why).await?
+    } else if graph {
         to_graph(ResolvedVc::upcast(asset), max_depth.unwrap_or(usize::MAX)).await?
     } else {
         to_list(ResolvedVc::upcast(asset)).await?
@@ -143,6 +158,27 @@ async fn to_list(asset: ResolvedVc<Box<dyn OutputAsset>>) -> Result<Vec<RcStr>>
     Ok(assets)
 }
 
+async fn to_why(
+    asset: ResolvedVc<Box<dyn OutputAsset>>,
+    path: FileSystemPath,
+) -> Result<Vec<RcStr>> {
+    let chains = trace_chains(Vc::cell(vec![asset]), path.clone()).await?;
+    if chains.is_empty() {
+        return Ok(vec![
+            format!("{} is not traced", path.value_to_string().await?).into(),
+        ]);
+    }
+
+    let mut result = vec![];
+    for chain in chains.iter() {
+        for step in chain {
+            result.push(step.to_string().into());
+        }
+        result.push("".into());
+    }
+    Ok(result)
+}
+
 async fn to_graph(asset: ResolvedVc<Box<dyn OutputAsset>>, max_depth: usize) -> Result<Vec<RcStr>> {
     let mut visited = HashSet::new();
     let mut queue = Vec::new();
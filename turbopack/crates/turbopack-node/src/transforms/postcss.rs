@@ -17,9 +17,10 @@ use turbopack_core::{
     file_source::FileSource,
     ident::AssetIdent,
     module_graph::{ModuleGraph, SingleModuleGraph},
+    reference::ModuleReferences,
     reference_type::{EntryReferenceSubType, InnerAssets, ReferenceType},
     resolve::{FindContextFileResult, find_context_file_or_package_key, options::ImportMapping},
-    source::Source,
+    source::{Source, SourceReferences},
     source_map::GenerateSourceMap,
     source_transform::SourceTransform,
     virtual_source::VirtualSource,
@@ -441,6 +442,17 @@ async fn find_config_in_location(
     Ok(None)
 }
 
+#[turbo_tasks::value_impl]
+impl SourceReferences for PostCssTransformedAsset {
+    #[turbo_tasks::function]
+    fn source_references(&self) -> Vc<ModuleReferences> {
+        match ResolvedVc::try_sidecast::<Box<dyn SourceReferences>>(self.source) {
+            Some(source) => source.source_references(),
+            None => ModuleReferences::empty(),
+        }
+    }
+}
+
 #[turbo_tasks::value_impl]
 impl GenerateSourceMap for PostCssTransformedAsset {
     #[turbo_tasks::function]
@@ -31,6 +31,7 @@ use turbopack_core::{
         OptionStyledString, StyledString,
     },
     module_graph::{ModuleGraph, SingleModuleGraph},
+    reference::ModuleReferences,
     reference_type::{InnerAssets, ReferenceType},
     resolve::{
         options::{ConditionValue, ResolveInPackage, ResolveIntoPackage, ResolveOptions},
@@ -38,7 +39,7 @@ use turbopack_core::{
         pattern::Pattern,
         resolve,
     },
-    source::Source,
+    source::{Source, SourceReferences},
     source_map::{GenerateSourceMap, utils::resolve_source_map_sources},
     source_transform::SourceTransform,
     virtual_source::VirtualSource,
@@ -162,6 +163,17 @@ impl Asset for WebpackLoadersProcessedAsset {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl SourceReferences for WebpackLoadersProcessedAsset {
+    #[turbo_tasks::function]
+    fn source_references(&self) -> Vc<ModuleReferences> {
+        match ResolvedVc::try_sidecast::<Box<dyn SourceReferences>>(self.source) {
+            Some(source) => source.source_references(),
+            None => ModuleReferences::empty(),
+        }
+    }
+}
+
 #[turbo_tasks::value_impl]
 impl GenerateSourceMap for WebpackLoadersProcessedAsset {
     #[turbo_tasks::function]
@@ -12,6 +12,7 @@ use turbopack_core::{
         availability_info::AvailabilityInfo,
         chunk_group::{MakeChunkGroupResult, make_chunk_group},
         chunk_id_strategy::ModuleIdStrategy,
+        property_name_cache::{OptionPropertyNameCache, PropertyNameCache},
     },
     environment::Environment,
     ident::AssetIdent,
@@ -75,6 +76,14 @@ impl NodeJsChunkingContextBuilder {
         self
     }
 
+    pub fn property_name_cache(
+        mut self,
+        property_name_cache: Option<ResolvedVc<PropertyNameCache>>,
+    ) -> Self {
+        self.chunking_context.property_name_cache = property_name_cache;
+        self
+    }
+
     pub fn source_maps(mut self, source_maps: SourceMapsType) -> Self {
         self.chunking_context.source_maps_type = source_maps;
         self
@@ -149,6 +158,11 @@ impl NodeJsChunkingContextBuilder {
         self
     }
 
+    pub fn profile_module_evaluation(mut self, profile_module_evaluation: bool) -> Self {
+        self.chunking_context.profile_module_evaluation = profile_module_evaluation;
+        self
+    }
+
     pub fn worker_forwarded_globals(mut self, globals: Vec<RcStr>) -> Self {
         self.chunking_context
             .worker_forwarded_globals
@@ -208,6 +222,8 @@ pub struct NodeJsChunkingContext {
     enable_dynamic_chunk_content_loading: bool,
     /// Whether to minify resulting chunks
     minify_type: MinifyType,
+    /// The mangled property names of the build, if properties are mangled
+    property_name_cache: Option<ResolvedVc<PropertyNameCache>>,
     /// Whether to generate source maps
     source_maps_type: SourceMapsType,
     /// Whether to use manifest chunks for lazy compilation
@@ -224,6 +240,8 @@ pub struct NodeJsChunkingContext {
     chunking_configs: Vec<(ResolvedVc<Box<dyn ChunkType>>, ChunkingConfig)>,
     /// Enable debug IDs for chunks and source maps.
     debug_ids: bool,
+    /// Whether the runtime records the evaluation time of each module
+    profile_module_evaluation: bool,
     /// Global variable names to forward to workers (e.g. NEXT_DEPLOYMENT_ID)
     worker_forwarded_globals: Vec<RcStr>,
 }
@@ -261,6 +279,7 @@ impl NodeJsChunkingContext {
                 environment,
                 runtime_type,
                 minify_type: MinifyType::NoMinify,
+                property_name_cache: None,
                 source_maps_type: SourceMapsType::Full,
                 manifest_chunks: false,
                 source_map_source_type: SourceMapSourceType::TurbopackUri,
@@ -269,6 +288,7 @@ impl NodeJsChunkingContext {
                 unused_references: None,
                 chunking_configs: Default::default(),
                 debug_ids: false,
+                profile_module_evaluation: false,
                 worker_forwarded_globals: vec![],
             },
         }
@@ -289,7 +309,19 @@ impl NodeJsChunkingContext {
     /// Returns the minify type.
     #[turbo_tasks::function]
     pub fn minify_type(&self) -> Vc<MinifyType> {
-        self.minify_type.cell()
+        self.minify_type.clone().cell()
+    }
+
+    /// Returns the mangled property names, if properties are mangled.
+    #[turbo_tasks::function]
+    pub fn property_name_cache(&self) -> Vc<OptionPropertyNameCache> {
+        Vc::cell(self.property_name_cache)
+    }
+
+    /// Returns whether the runtime records the evaluation time of each module.
+    #[turbo_tasks::function]
+    pub fn profile_module_evaluation(&self) -> Vc<bool> {
+        Vc::cell(self.profile_module_evaluation)
     }
 
     #[turbo_tasks::function]
@@ -371,7 +403,7 @@ impl ChunkingContext for NodeJsChunkingContext {
 
     #[turbo_tasks::function]
     pub fn minify_type(&self) -> Vc<MinifyType> {
-        self.minify_type.cell()
+        self.minify_type.clone().cell()
     }
 
     #[turbo_tasks::function]
@@ -72,8 +72,14 @@ impl EcmascriptBuildNodeChunkContent {
 
         let mut code = code.build();
 
-        if let MinifyType::Minify { mangle } = *self.chunking_context.minify_type().await? {
-            code = minify(code, source_maps, mangle)?;
+        if let MinifyType::Minify(options) = &*self.chunking_context.minify_type().await? {
+            let property_names =
+                if let Some(cache) = *self.chunking_context.property_name_cache().await? {
+                    Some(cache.await?)
+                } else {
+                    None
+                };
+            code = minify(code, source_maps, options, property_names.as_deref())?;
         }
 
         Ok(code.cell())
@@ -85,7 +91,7 @@ impl EcmascriptBuildNodeChunkContent {
             self.chunking_context.output_root().owned().await?,
             self.chunk.path().owned().await?,
             *self.content,
-            *self.chunking_context.minify_type().await?,
+            self.chunking_context.minify_type().owned().await?,
         ))
     }
 }
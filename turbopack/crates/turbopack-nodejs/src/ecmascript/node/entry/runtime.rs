@@ -97,12 +97,14 @@ impl EcmascriptBuildNodeRuntimeChunk {
             )?;
         }
 
+        let profile_module_evaluation = *this.chunking_context.profile_module_evaluation().await?;
         match *this.chunking_context.runtime_type().await? {
             RuntimeType::Development => {
                 let runtime_code = turbopack_ecmascript_runtime::get_nodejs_runtime_code(
                     this.chunking_context.environment(),
                     RuntimeType::Development,
                     generate_source_map,
+                    profile_module_evaluation,
                 );
                 code.push_code(&*runtime_code.await?);
             }
@@ -111,6 +113,7 @@ impl EcmascriptBuildNodeRuntimeChunk {
                     this.chunking_context.environment(),
                     RuntimeType::Production,
                     generate_source_map,
+                    profile_module_evaluation,
                 );
                 code.push_code(&*runtime_code.await?);
             }
@@ -0,0 +1,179 @@
+//! The lock directory stores a copy of every module that was imported over HTTP(S).
+//!
+//! Each URL is stored as two files: the response body, and a `.json` file next to it with the
+//! URL, the content type and the [subresource integrity] hash of the body. Once an entry exists,
+//! the URL is never requested again, so builds work offline and can't be affected by changes on
+//! the remote server. Deleting an entry causes it to be fetched again.
+//!
+//! [subresource integrity]: https://developer.mozilla.org/docs/Web/Security/Subresource_Integrity
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{File, FileContent, FileJsonContent, FileSystemPath};
+use turbo_tasks_hash::{DeterministicHasher, ShaHasher, encode_hex, hash_xxh3_hash64};
+use turbopack_core::issue::{Issue, IssueSeverity};
+use url::Url;
+
+use super::{HttpImportIssue, HttpImportIssueReason, HttpImportsOptions};
+
+/// The contents of the `.json` file of a lock entry.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LockEntryMetadata {
+    url: String,
+    integrity: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+}
+
+#[turbo_tasks::value(shared)]
+pub enum HttpImportLockEntry {
+    Content {
+        content: ResolvedVc<FileContent>,
+        content_type: Option<RcStr>,
+    },
+    Error(ResolvedVc<Box<dyn Issue>>),
+}
+
+/// Returns the content of `url` from the lock directory, fetching and storing it first when the
+/// lock directory doesn't contain it yet.
+#[turbo_tasks::function]
+pub async fn http_import_lock_entry(
+    options: Vc<HttpImportsOptions>,
+    url: RcStr,
+) -> Result<Vc<HttpImportLockEntry>> {
+    let options = options.await?;
+    let content_path = lock_entry_path(&options.lock_dir, &Url::parse(&url)?)?;
+    let metadata_path = content_path.append(".json")?;
+
+    let error = |reason| -> Result<Vc<HttpImportLockEntry>> {
+        Ok(HttpImportLockEntry::Error(ResolvedVc::upcast(
+            HttpImportIssue {
+                file_path: metadata_path.clone(),
+                url: url.clone(),
+                reason,
+            }
+            .resolved_cell(),
+        ))
+        .cell())
+    };
+
+    match &*metadata_path.read_json().await? {
+        FileJsonContent::Content(json) => {
+            let metadata = match LockEntryMetadata::deserialize(json) {
+                Ok(metadata) => metadata,
+                Err(err) => {
+                    return error(HttpImportIssueReason::InvalidLockEntry(
+                        err.to_string().into(),
+                    ));
+                }
+            };
+            let content = content_path.read();
+            let FileContent::Content(file) = &*content.await? else {
+                return error(HttpImportIssueReason::MissingLockContent);
+            };
+            let actual = integrity(&file.content().to_bytes());
+            if actual != metadata.integrity {
+                return error(HttpImportIssueReason::IntegrityMismatch {
+                    expected: metadata.integrity.into(),
+                    actual: actual.into(),
+                });
+            }
+            Ok(HttpImportLockEntry::Content {
+                content: content.to_resolved().await?,
+                content_type: metadata.content_type.map(RcStr::from),
+            }
+            .cell())
+        }
+        FileJsonContent::Unparsable(err) => error(HttpImportIssueReason::InvalidLockEntry(
+            err.to_string().into(),
+        )),
+        FileJsonContent::NotFound => {
+            if options.frozen {
+                return error(HttpImportIssueReason::NotInLock);
+            }
+            let response = match &*options.fetch_client.fetch(url.clone(), None).await? {
+                Ok(response) => response.await?,
+                Err(err) => {
+                    return Ok(HttpImportLockEntry::Error(ResolvedVc::upcast(
+                        err.to_issue(IssueSeverity::Error, metadata_path.clone())
+                            .to_resolved()
+                            .await?,
+                    ))
+                    .cell());
+                }
+            };
+            let body = response.body.await?;
+            let metadata = LockEntryMetadata {
+                url: url.to_string(),
+                integrity: integrity(&body.0),
+                content_type: response.content_type.as_ref().map(|t| t.to_string()),
+            };
+            let content = FileContent::Content(File::from(body.0.clone())).resolved_cell();
+            content_path.write(*content).as_side_effect().await?;
+            metadata_path
+                .write(
+                    FileContent::Content(File::from(serde_json::to_string_pretty(&metadata)?))
+                        .cell(),
+                )
+                .as_side_effect()
+                .await?;
+            Ok(HttpImportLockEntry::Content {
+                content,
+                content_type: response.content_type.clone(),
+            }
+            .cell())
+        }
+    }
+}
+
+/// The path of the file storing the body of `url`. The file name is derived from the last path
+/// segment of the URL for readability, and a hash of the full URL to make it unique.
+fn lock_entry_path(lock_dir: &FileSystemPath, url: &Url) -> Result<FileSystemPath> {
+    let host = match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{host}_{port}"),
+        (Some(host), None) => host.to_string(),
+        (None, _) => url.scheme().to_string(),
+    };
+    let name = url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("index");
+    let name = sanitize_file_name(name);
+    let hash = encode_hex(hash_xxh3_hash64(url.as_str()));
+    lock_dir.join(&format!("{}/{name}.{hash}", sanitize_file_name(&host)))
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .take(64)
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '@') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn integrity(content: &[u8]) -> String {
+    let mut hasher = ShaHasher::new_sha512();
+    hasher.write_bytes(content);
+    format!("sha512-{}", hasher.finish_base64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sanitize_file_name;
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(sanitize_file_name("react@18.3.1.mjs"), "react@18.3.1.mjs");
+        assert_eq!(sanitize_file_name("a b%20c"), "a_b_20c");
+        assert_eq!(sanitize_file_name(&"x".repeat(100)).len(), 64);
+    }
+}
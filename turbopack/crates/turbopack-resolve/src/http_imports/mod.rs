@@ -0,0 +1,429 @@
+//! Support for importing modules from HTTP(S) URLs, e.g. `import confetti from
+//! "https://esm.sh/canvas-confetti@1.6.0"`.
+//!
+//! Fetched modules are placed in a separate [HttpImportFileSystem] at `<scheme>/<host>/<path>`, so
+//! relative and server-relative imports inside of them are resolved against their URL by the
+//! [HttpImportsResolvePlugin]. The content of every URL is read from a lock directory, see
+//! [lock].
+
+mod lock;
+
+use anyhow::{Result, bail};
+use bincode::{Decode, Encode};
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{NonLocalValue, ResolvedVc, ValueToString, Vc, trace::TraceRawVcs};
+use turbo_tasks_fetch::FetchClientConfig;
+use turbo_tasks_fs::{
+    FileContent, FileMeta, FileSystem, FileSystemPath, LinkContent, RawDirectoryContent,
+};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    reference_type::ReferenceType,
+    resolve::{
+        RequestKey, ResolveResult, ResolveResultItem, ResolveResultOption,
+        parse::Request,
+        pattern::Pattern,
+        plugin::{BeforeResolvePlugin, BeforeResolvePluginCondition},
+    },
+    source::Source,
+};
+use url::Url;
+
+use self::lock::{HttpImportLockEntry, http_import_lock_entry};
+
+#[turbo_tasks::value(shared)]
+#[derive(Debug)]
+pub struct HttpImportsOptions {
+    /// URL prefixes that are allowed to be imported. Other URLs are left as external URL
+    /// imports.
+    pub allowed_uris: Vec<RcStr>,
+    /// The directory storing the content of every imported URL.
+    pub lock_dir: FileSystemPath,
+    /// Fail for URLs that are missing from the lock directory instead of fetching them.
+    pub frozen: bool,
+    pub fetch_client: ResolvedVc<FetchClientConfig>,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionHttpImportsOptions(Option<ResolvedVc<HttpImportsOptions>>);
+
+/// A file system containing all modules imported over HTTP(S). It doesn't have any content on its
+/// own, the content of the modules is provided by [HttpImportSource]. It only exists to give these
+/// modules a path that relative imports can be resolved against.
+#[derive(ValueToString)]
+#[value_to_string("http")]
+#[turbo_tasks::value]
+pub struct HttpImportFileSystem {}
+
+#[turbo_tasks::value_impl]
+impl HttpImportFileSystem {
+    #[turbo_tasks::function]
+    pub fn new() -> Vc<Self> {
+        HttpImportFileSystem {}.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystem for HttpImportFileSystem {
+    #[turbo_tasks::function]
+    fn read(&self, _fs_path: FileSystemPath) -> Vc<FileContent> {
+        FileContent::NotFound.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn read_link(&self, _fs_path: FileSystemPath) -> Vc<LinkContent> {
+        LinkContent::NotFound.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn raw_read_dir(&self, _fs_path: FileSystemPath) -> Vc<RawDirectoryContent> {
+        RawDirectoryContent::not_found()
+    }
+
+    #[turbo_tasks::function]
+    fn write(&self, _fs_path: FileSystemPath, _content: Vc<FileContent>) -> Result<Vc<()>> {
+        bail!("Writing is not possible to modules imported over HTTP")
+    }
+
+    #[turbo_tasks::function]
+    fn write_link(&self, _fs_path: FileSystemPath, _target: Vc<LinkContent>) -> Result<Vc<()>> {
+        bail!("Writing is not possible to modules imported over HTTP")
+    }
+
+    #[turbo_tasks::function]
+    fn metadata(&self, _fs_path: FileSystemPath) -> Result<Vc<FileMeta>> {
+        bail!("Modules imported over HTTP don't have metadata")
+    }
+}
+
+/// Resolves absolute HTTP(S) URLs, and all requests made from modules that were imported over
+/// HTTP(S).
+#[turbo_tasks::value]
+pub struct HttpImportsResolvePlugin {
+    options: ResolvedVc<HttpImportsOptions>,
+    file_system: ResolvedVc<HttpImportFileSystem>,
+}
+
+#[turbo_tasks::value_impl]
+impl HttpImportsResolvePlugin {
+    #[turbo_tasks::function]
+    pub async fn new(options: ResolvedVc<HttpImportsOptions>) -> Result<Vc<Self>> {
+        Ok(HttpImportsResolvePlugin {
+            options,
+            file_system: HttpImportFileSystem::new().to_resolved().await?,
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl BeforeResolvePlugin for HttpImportsResolvePlugin {
+    #[turbo_tasks::function]
+    fn before_resolve_condition(&self) -> Vc<BeforeResolvePluginCondition> {
+        // Relative requests need to be handled when they come from a module imported over HTTP,
+        // which is only known from the lookup path.
+        BeforeResolvePluginCondition::Always.cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn before_resolve(
+        &self,
+        lookup_path: FileSystemPath,
+        _reference_type: ReferenceType,
+        request: Vc<Request>,
+    ) -> Result<Vc<ResolveResultOption>> {
+        let base = if lookup_path.fs == ResolvedVc::upcast(self.file_system) {
+            dir_to_url(&lookup_path.path)
+        } else {
+            None
+        };
+
+        let request = &*request.await?;
+        let request_str: RcStr = match (request, &base) {
+            (
+                Request::Uri {
+                    protocol,
+                    remainder,
+                    query,
+                    ..
+                },
+                _,
+            ) => format!("{protocol}{remainder}{query}").into(),
+            (
+                Request::Relative {
+                    path: Pattern::Constant(path),
+                    query,
+                    ..
+                }
+                | Request::ServerRelative {
+                    path: Pattern::Constant(path),
+                    query,
+                    ..
+                },
+                Some(_),
+            ) => format!("{path}{query}").into(),
+            (_, Some(_)) => {
+                // Bare specifiers and dynamic requests can't be resolved inside of URL modules
+                return Ok(ResolveResultOption::some(
+                    ResolveResult::unresolvable().resolved_cell(),
+                ));
+            }
+            (_, None) => return Ok(ResolveResultOption::none()),
+        };
+
+        let url = match &base {
+            Some(base) => base.join(&request_str),
+            None => Url::parse(&request_str),
+        };
+        let Ok(mut url) = url else {
+            return Ok(ResolveResultOption::none());
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            return Ok(ResolveResultOption::none());
+        }
+        url.set_fragment(None);
+
+        let options = self.options.await?;
+        let request_key = RequestKey::new(request_str);
+        if !options
+            .allowed_uris
+            .iter()
+            .any(|allowed| url.as_str().starts_with(allowed.as_str()))
+        {
+            if base.is_none() {
+                return Ok(ResolveResultOption::none());
+            }
+            let issue = HttpImportIssue {
+                file_path: lookup_path,
+                url: url.as_str().into(),
+                reason: HttpImportIssueReason::NotAllowed,
+            }
+            .resolved_cell();
+            return Ok(ResolveResultOption::some(
+                ResolveResult::primary_with_key(
+                    request_key,
+                    ResolveResultItem::Error(ResolvedVc::upcast(issue)),
+                )
+                .resolved_cell(),
+            ));
+        }
+
+        let Some(path) = url_to_path(&url) else {
+            return Ok(ResolveResultOption::none());
+        };
+        let url: RcStr = url.as_str().into();
+        let entry = http_import_lock_entry(*self.options, url.clone())
+            .to_resolved()
+            .await?;
+        let item = match &*entry.await? {
+            HttpImportLockEntry::Content { .. } => ResolveResultItem::Source(ResolvedVc::upcast(
+                HttpImportSource {
+                    url,
+                    path: self.file_system.root().await?.join(&path)?,
+                    entry,
+                }
+                .resolved_cell(),
+            )),
+            HttpImportLockEntry::Error(issue) => ResolveResultItem::Error(*issue),
+        };
+        Ok(ResolveResultOption::some(
+            ResolveResult::primary_with_key(request_key, item).resolved_cell(),
+        ))
+    }
+}
+
+/// The path of a URL in the [HttpImportFileSystem], without query.
+fn url_to_path(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+    let mut path = match url.port() {
+        Some(port) => format!("{}/{host}:{port}", url.scheme()),
+        None => format!("{}/{host}", url.scheme()),
+    };
+    path.push_str(url.path());
+    if path.ends_with('/') {
+        path.push_str("index");
+    }
+    Some(path)
+}
+
+/// The URL a directory in the [HttpImportFileSystem] represents, with a trailing slash so it can
+/// be used as a base URL.
+fn dir_to_url(dir: &str) -> Option<Url> {
+    let (scheme, rest) = dir.split_once('/')?;
+    Url::parse(&format!("{scheme}://{rest}/")).ok()
+}
+
+/// A module imported over HTTP(S).
+#[turbo_tasks::value]
+pub struct HttpImportSource {
+    url: RcStr,
+    path: FileSystemPath,
+    entry: ResolvedVc<HttpImportLockEntry>,
+}
+
+#[turbo_tasks::value_impl]
+impl HttpImportSource {
+    #[turbo_tasks::function]
+    pub fn url(&self) -> Vc<RcStr> {
+        Vc::cell(self.url.clone())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Source for HttpImportSource {
+    #[turbo_tasks::function]
+    async fn ident(&self) -> Result<Vc<AssetIdent>> {
+        let mut ident = AssetIdent::from_path(self.path.clone());
+        if let Some(query) = Url::parse(&self.url)?.query() {
+            ident = ident.with_query(format!("?{query}").into());
+        }
+        if let HttpImportLockEntry::Content {
+            content_type: Some(content_type),
+            ..
+        } = &*self.entry.await?
+        {
+            ident = ident.with_content_type(content_type.clone());
+        }
+        Ok(ident)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for HttpImportSource {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        Ok(match &*self.entry.await? {
+            HttpImportLockEntry::Content { content, .. } => AssetContent::file(*content)?,
+            HttpImportLockEntry::Error(_) => {
+                AssetContent::file(FileContent::NotFound.resolved_cell())?
+            }
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, TraceRawVcs, NonLocalValue, Encode, Decode)]
+enum HttpImportIssueReason {
+    NotAllowed,
+    NotInLock,
+    MissingLockContent,
+    InvalidLockEntry(RcStr),
+    IntegrityMismatch { expected: RcStr, actual: RcStr },
+}
+
+#[turbo_tasks::value(shared)]
+struct HttpImportIssue {
+    file_path: FileSystemPath,
+    url: RcStr,
+    reason: HttpImportIssueReason,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for HttpImportIssue {
+    fn severity(&self) -> IssueSeverity {
+        IssueSeverity::Error
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.file_path.clone().cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Text(rcstr!("Unable to import ")),
+            StyledString::Code(self.url.clone()),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        let description = match &self.reason {
+            HttpImportIssueReason::NotAllowed => StyledString::Text(rcstr!(
+                "The URL is not part of the allowed URLs for HTTP imports."
+            )),
+            HttpImportIssueReason::NotInLock => StyledString::Text(rcstr!(
+                "The URL is missing from the lock directory, and fetching new URLs is disabled."
+            )),
+            HttpImportIssueReason::MissingLockContent => StyledString::Text(rcstr!(
+                "The lock directory has an entry for this URL, but its content is missing. Delete \
+                 the entry to fetch the URL again."
+            )),
+            HttpImportIssueReason::InvalidLockEntry(message) => StyledString::Stack(vec![
+                StyledString::Text(rcstr!(
+                    "The lock directory entry for this URL is invalid. Delete the entry to fetch \
+                     the URL again."
+                )),
+                StyledString::Text(message.clone()),
+            ]),
+            HttpImportIssueReason::IntegrityMismatch { expected, actual } => {
+                StyledString::Stack(vec![
+                    StyledString::Text(rcstr!(
+                        "The content in the lock directory doesn't match its integrity hash. \
+                         Delete the entry to fetch the URL again."
+                    )),
+                    StyledString::Line(vec![
+                        StyledString::Text(rcstr!("Expected: ")),
+                        StyledString::Code(expected.clone()),
+                    ]),
+                    StyledString::Line(vec![
+                        StyledString::Text(rcstr!("Actual: ")),
+                        StyledString::Code(actual.clone()),
+                    ]),
+                ])
+            }
+        };
+        Vc::cell(Some(description.resolved_cell()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::{dir_to_url, url_to_path};
+
+    #[test]
+    fn test_url_to_path() {
+        let path = |url: &str| url_to_path(&Url::parse(url).unwrap());
+        assert_eq!(
+            path("https://esm.sh/react@18.3.1?target=es2022").as_deref(),
+            Some("https/esm.sh/react@18.3.1")
+        );
+        assert_eq!(
+            path("http://localhost:8080/lib/").as_deref(),
+            Some("http/localhost:8080/lib/index")
+        );
+    }
+
+    #[test]
+    fn test_dir_to_url() {
+        let base = dir_to_url("https/esm.sh/v135/react@18.3.1/es2022").unwrap();
+        assert_eq!(
+            base.join("./react.mjs").unwrap().as_str(),
+            "https://esm.sh/v135/react@18.3.1/es2022/react.mjs"
+        );
+        assert_eq!(
+            base.join("../../scheduler@0.23.2/index.mjs")
+                .unwrap()
+                .as_str(),
+            "https://esm.sh/v135/scheduler@0.23.2/index.mjs"
+        );
+        assert_eq!(
+            base.join("/v135/scheduler").unwrap().as_str(),
+            "https://esm.sh/v135/scheduler"
+        );
+        assert_eq!(
+            dir_to_url("http/localhost:8080").unwrap().as_str(),
+            "http://localhost:8080/"
+        );
+    }
+}
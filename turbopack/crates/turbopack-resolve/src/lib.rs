@@ -2,6 +2,7 @@
 #![feature(arbitrary_self_types_pointers)]
 
 pub mod ecmascript;
+pub mod http_imports;
 pub mod node_native_binding;
 pub mod resolve;
 pub mod resolve_options_context;
@@ -13,7 +13,7 @@ use turbopack_core::{
     asset::{Asset, AssetContent},
     file_source::FileSource,
     raw_module::RawModule,
-    reference::ModuleReference,
+    reference::{ModuleReference, TraceReferenceKind},
     resolve::{ModuleResolveResult, RequestKey, ResolveResultItem, pattern::Pattern, resolve_raw},
     source::Source,
     target::{CompileTarget, Platform},
@@ -71,6 +71,11 @@ impl ModuleReference for NodePreGypConfigReference {
         )
         .await
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::NativeBinding.cell()
+    }
 }
 
 async fn resolve_node_pre_gyp_files(
@@ -251,6 +256,11 @@ impl ModuleReference for NodeGypBuildReference {
         )
         .await
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::NativeBinding.cell()
+    }
 }
 
 async fn resolve_node_gyp_build_files(
@@ -373,6 +383,11 @@ impl ModuleReference for NodeBindingsReference {
         )
         .await
     }
+
+    #[turbo_tasks::function]
+    fn trace_kind(&self) -> Vc<TraceReferenceKind> {
+        TraceReferenceKind::NativeBinding.cell()
+    }
 }
 
 async fn resolve_node_bindings_files(
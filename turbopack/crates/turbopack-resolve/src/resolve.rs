@@ -12,6 +12,7 @@ use turbopack_core::resolve::{
 };
 
 use crate::{
+    http_imports::HttpImportsResolvePlugin,
     resolve_options_context::{ResolveOptionsContext, TsConfigHandling},
     typescript::{apply_tsconfig_resolve_options, tsconfig, tsconfig_resolve_options},
 };
@@ -160,6 +161,9 @@ async fn base_resolve_options(
             }
         } else {
             let mut mods = Vec::new();
+            if let Some(dir) = &opt.enable_pnp {
+                mods.push(ResolveModules::PnP(dir.clone()));
+            }
             if let Some(dir) = &opt.enable_node_modules {
                 mods.push(ResolveModules::Nested(
                     dir.clone(),
@@ -202,7 +206,17 @@ async fn base_resolve_options(
         import_map: Some(import_map),
         resolved_map: opt.resolved_map,
         after_resolve_plugins: opt.after_resolve_plugins.clone(),
-        before_resolve_plugins: opt.before_resolve_plugins.clone(),
+        before_resolve_plugins: {
+            let mut plugins = opt.before_resolve_plugins.clone();
+            if let Some(http_imports) = opt.http_imports {
+                plugins.push(ResolvedVc::upcast(
+                    HttpImportsResolvePlugin::new(*http_imports)
+                        .to_resolved()
+                        .await?,
+                ));
+            }
+            plugins
+        },
         loose_errors: opt.loose_errors,
         collect_affecting_sources: opt.collect_affecting_sources,
         ..Default::default()
@@ -12,6 +12,8 @@ use turbopack_core::{
     },
 };
 
+use crate::http_imports::HttpImportsOptions;
+
 #[derive(Default, Debug, Clone, TraceRawVcs, PartialEq, Eq, NonLocalValue, Encode, Decode)]
 pub enum TsConfigHandling {
     /// Ignore tsconfig and jsconfig files
@@ -37,6 +39,11 @@ pub struct ResolveOptionsContext {
     /// Enable resolving of the node_modules folder when within the provided
     /// directory
     pub enable_node_modules: Option<FileSystemPath>,
+    /// Enable resolving packages through a Yarn Plug'n'Play manifest (`.pnp.cjs` or
+    /// `.pnp.data.json`) in the provided directory. Takes precedence over node_modules.
+    pub enable_pnp: Option<FileSystemPath>,
+    /// Enable importing modules from HTTP(S) URLs. Their content is stored in a lock directory.
+    pub http_imports: Option<ResolvedVc<HttpImportsOptions>>,
     /// A specific path to a tsconfig.json file to use for resolving modules. If `None`, one will
     /// be looked up through the filesystem
     pub tsconfig_path: TsConfigHandling,
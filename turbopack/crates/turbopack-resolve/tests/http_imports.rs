@@ -0,0 +1,212 @@
+#![feature(arbitrary_self_types)]
+#![feature(arbitrary_self_types_pointers)]
+#![allow(clippy::needless_return)] // tokio macro-generated code doesn't respect this
+#![cfg(test)]
+
+use anyhow::Result;
+use turbo_rcstr::{RcStr, rcstr};
+use turbo_tasks::{Vc, apply_effects};
+use turbo_tasks_fetch::FetchClientConfig;
+use turbo_tasks_fs::{DiskFileSystem, FileContent, FileSystem};
+use turbo_tasks_testing::{Registration, register, run_once};
+use turbopack_core::{
+    asset::Asset,
+    reference_type::ReferenceType,
+    resolve::{ResolveResultItem, parse::Request, plugin::BeforeResolvePlugin},
+    source::Source,
+};
+use turbopack_resolve::http_imports::{
+    HttpImportFileSystem, HttpImportsOptions, HttpImportsResolvePlugin,
+};
+
+static REGISTRATION: Registration = register!();
+
+/// Resolves `request` from `base_dir` in the [HttpImportFileSystem] (or from the lock directory
+/// when there is no base directory), and returns the content type and content of the resolved
+/// module, or the title of the issue.
+#[turbo_tasks::function(operation)]
+async fn resolve_http_import(
+    lock_dir: RcStr,
+    allowed_uri: RcStr,
+    frozen: bool,
+    base_dir: Option<RcStr>,
+    request: RcStr,
+) -> Result<Vc<RcStr>> {
+    let lock_dir = DiskFileSystem::new(rcstr!("lock"), lock_dir)
+        .root()
+        .owned()
+        .await?;
+    let options = HttpImportsOptions {
+        allowed_uris: vec![allowed_uri],
+        lock_dir: lock_dir.clone(),
+        frozen,
+        fetch_client: FetchClientConfig::default().resolved_cell(),
+    }
+    .cell();
+    let lookup_path = match base_dir {
+        Some(base_dir) => HttpImportFileSystem::new().root().await?.join(&base_dir)?,
+        None => lock_dir,
+    };
+    let result = HttpImportsResolvePlugin::new(options)
+        .before_resolve(
+            lookup_path,
+            ReferenceType::Undefined,
+            Request::parse_string(request),
+        )
+        .await?;
+    let Some(result) = *result else {
+        return Ok(Vc::cell(rcstr!("not handled")));
+    };
+    Ok(Vc::cell(match &result.await?.primary[..] {
+        [(_, ResolveResultItem::Source(source))] => {
+            let ident = source.ident().await?;
+            let FileContent::Content(file) = &*source.content().file_content().await? else {
+                return Ok(Vc::cell(rcstr!("no content")));
+            };
+            format!(
+                "{} {}",
+                ident.content_type.clone().unwrap_or_default(),
+                file.content().to_str()?
+            )
+            .into()
+        }
+        [(_, ResolveResultItem::Error(issue))] => issue.title().await?.to_unstyled_string().into(),
+        [] => rcstr!("unresolvable"),
+        _ => rcstr!("unexpected result"),
+    }))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fetches_into_lock_dir_once() {
+    run_once(&REGISTRATION, || async {
+        let mut server = mockito::Server::new_async().await;
+        let resource_mock = server
+            .mock("GET", "/pkg/index.mjs")
+            .with_header("Content-Type", "application/javascript")
+            .with_body("export * from './util.mjs'")
+            .expect(1)
+            .create_async()
+            .await;
+        let lock_dir = tempfile::tempdir()?;
+        let lock_dir_path: RcStr = lock_dir.path().to_str().unwrap().into();
+        let url: RcStr = format!("{}/pkg/index.mjs", server.url()).into();
+
+        let op = resolve_http_import(
+            lock_dir_path.clone(),
+            server.url().into(),
+            false,
+            None,
+            url.clone(),
+        );
+        assert_eq!(
+            &*op.read_strongly_consistent().await?,
+            "application/javascript export * from './util.mjs'"
+        );
+        apply_effects(op).await?;
+
+        let host_dir = lock_dir
+            .path()
+            .join(server.host_with_port().replace(':', "_"));
+        let entries = std::fs::read_dir(&host_dir)?
+            .map(|entry| Ok(entry?.file_name().into_string().unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(entries.len(), 2);
+        let metadata = entries.iter().find(|name| name.ends_with(".json")).unwrap();
+        let metadata: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(host_dir.join(metadata))?)?;
+        assert_eq!(metadata["url"], url.as_str());
+        assert!(
+            metadata["integrity"]
+                .as_str()
+                .unwrap()
+                .starts_with("sha512-")
+        );
+
+        // A frozen build only reads from the lock directory
+        let op = resolve_http_import(lock_dir_path, server.url().into(), true, None, url);
+        assert_eq!(
+            &*op.read_strongly_consistent().await?,
+            "application/javascript export * from './util.mjs'"
+        );
+
+        resource_mock.assert_async().await;
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resolves_relative_to_url() {
+    run_once(&REGISTRATION, || async {
+        let mut server = mockito::Server::new_async().await;
+        let resource_mock = server
+            .mock("GET", "/pkg/util.mjs")
+            .with_header("Content-Type", "text/javascript")
+            .with_body("export const util = 1")
+            .create_async()
+            .await;
+        let lock_dir = tempfile::tempdir()?;
+        let base_dir: RcStr = format!("http/{}/pkg/nested", server.host_with_port()).into();
+
+        let result = resolve_http_import(
+            lock_dir.path().to_str().unwrap().into(),
+            server.url().into(),
+            false,
+            Some(base_dir.clone()),
+            rcstr!("../util.mjs"),
+        )
+        .read_strongly_consistent()
+        .await?;
+        assert_eq!(&*result, "text/javascript export const util = 1");
+        resource_mock.assert_async().await;
+
+        let result = resolve_http_import(
+            lock_dir.path().to_str().unwrap().into(),
+            server.url().into(),
+            false,
+            Some(base_dir),
+            rcstr!("react"),
+        )
+        .read_strongly_consistent()
+        .await?;
+        assert_eq!(&*result, "unresolvable");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn reports_lock_errors() {
+    run_once(&REGISTRATION, || async {
+        let lock_dir = tempfile::tempdir()?;
+        let lock_dir_path: RcStr = lock_dir.path().to_str().unwrap().into();
+
+        // Not allowed URLs are left to the default resolving
+        let result = resolve_http_import(
+            lock_dir_path.clone(),
+            rcstr!("https://esm.sh/"),
+            true,
+            None,
+            rcstr!("https://example.com/index.js"),
+        )
+        .read_strongly_consistent()
+        .await?;
+        assert_eq!(&*result, "not handled");
+
+        let result = resolve_http_import(
+            lock_dir_path,
+            rcstr!("https://esm.sh/"),
+            true,
+            None,
+            rcstr!("https://esm.sh/react@18.3.1"),
+        )
+        .read_strongly_consistent()
+        .await?;
+        assert_eq!(&*result, "Unable to import https://esm.sh/react@18.3.1");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
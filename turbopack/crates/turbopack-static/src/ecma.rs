@@ -2,7 +2,7 @@ use anyhow::Result;
 use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{ResolvedVc, Vc};
 use turbopack_core::{
-    chunk::{AssetSuffix, AsyncModuleInfo, ChunkableModule, ChunkingContext},
+    chunk::{AsyncModuleInfo, ChunkableModule, ChunkingContext},
     ident::AssetIdent,
     module::{Module, ModuleSideEffects},
     module_graph::ModuleGraph,
@@ -14,8 +14,7 @@ use turbopack_ecmascript::{
         EcmascriptChunkItemContent, EcmascriptChunkPlaceable, EcmascriptExports,
         ecmascript_chunk_item,
     },
-    runtime_functions::{TURBOPACK_EXPORT_URL, TURBOPACK_EXPORT_VALUE},
-    utils::StringifyJs,
+    utils::asset_url_export_code,
 };
 
 use crate::output_asset::StaticOutputAsset;
@@ -103,35 +102,7 @@ impl EcmascriptChunkPlaceable for StaticUrlJsModule {
 
         let url_behavior = chunking_context.url_behavior(this.tag.clone()).await?;
 
-        let inner_code = match &url_behavior.suffix {
-            AssetSuffix::None => {
-                // No suffix, export as-is
-                format!(
-                    "{TURBOPACK_EXPORT_VALUE}({path});",
-                    path = StringifyJs(&url)
-                )
-            }
-            AssetSuffix::Constant(suffix) => {
-                // Append constant suffix
-                format!(
-                    "{TURBOPACK_EXPORT_VALUE}({path} + {suffix});",
-                    path = StringifyJs(&url),
-                    suffix = StringifyJs(suffix.as_str())
-                )
-            }
-            AssetSuffix::Inferred => {
-                // The runtime logic will infer the suffix
-                format!("{TURBOPACK_EXPORT_URL}({path});", path = StringifyJs(&url))
-            }
-            AssetSuffix::FromGlobal(global_name) => {
-                // Read suffix from global at runtime
-                format!(
-                    "{TURBOPACK_EXPORT_VALUE}({path} + (globalThis[{global}] || ''));",
-                    path = StringifyJs(&url),
-                    global = StringifyJs(global_name)
-                )
-            }
-        };
+        let inner_code = asset_url_export_code(&url, &url_behavior.suffix);
 
         Ok(EcmascriptChunkItemContent {
             inner_code: inner_code.into(),
@@ -29,7 +29,7 @@ use turbopack::{
     module_options::{EcmascriptOptionsContext, ModuleOptionsContext, TypescriptTransformOptions},
 };
 use turbopack_core::{
-    chunk::{ChunkingConfig, MangleType, MinifyType},
+    chunk::{ChunkingConfig, MangleType, MinifyOptions, MinifyType},
     compile_time_defines,
     compile_time_info::CompileTimeInfo,
     condition::ContextCondition,
@@ -520,9 +520,10 @@ async fn run_test_operation(prepared_test: ResolvedVc<PreparedTest>) -> Result<V
     .source_map_source_type(turbopack_core::chunk::SourceMapSourceType::RelativeUri)
     .module_merging(options.scope_hoisting)
     .minify_type(if options.minify {
-        MinifyType::Minify {
+        MinifyType::Minify(MinifyOptions {
             mangle: Some(MangleType::OptimalSize),
-        }
+            ..Default::default()
+        })
     } else {
         MinifyType::NoMinify
     })
@@ -0,0 +1,8 @@
+import source addModule from './add.wasm'
+
+it('should compile wasm modules imported in the source phase without instantiating them', async () => {
+  expect(addModule).toBeInstanceOf(WebAssembly.Module)
+
+  const { exports } = await WebAssembly.instantiate(addModule, {})
+  expect(exports.add(22, 2200)).toEqual(22 + 2200)
+})
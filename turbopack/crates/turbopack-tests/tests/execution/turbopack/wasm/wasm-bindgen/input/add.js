@@ -0,0 +1,21 @@
+// A trimmed down version of the glue code that `wasm-bindgen --target web` generates.
+let wasm
+
+export function add(a, b) {
+  return wasm.add(a, b)
+}
+
+export function defaultInput() {
+  return new URL('add_bg.wasm', import.meta.url)
+}
+
+export default async function init(input) {
+  if (typeof input === 'undefined') {
+    input = defaultInput()
+  }
+  const bytes =
+    input instanceof URL ? await (await fetch(input)).arrayBuffer() : input
+  const { instance } = await WebAssembly.instantiate(bytes, {})
+  wasm = instance.exports
+  return wasm
+}
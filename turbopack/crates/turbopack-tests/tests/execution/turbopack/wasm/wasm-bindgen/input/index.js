@@ -0,0 +1,13 @@
+import { defaultInput } from './add.js'
+
+it('should emit the wasm file referenced by wasm-bindgen glue code', () => {
+  const url = defaultInput()
+  expect(url).toBeInstanceOf(URL)
+  expect(url.pathname).toMatch(/\.wasm$/)
+
+  // The file is referenced by URL instead of being compiled and instantiated
+  const modules = Array.from(__turbopack_modules__.keys())
+  expect(modules).toContainEqual(
+    expect.stringMatching(/add_bg\.wasm.*wasm url/)
+  )
+})
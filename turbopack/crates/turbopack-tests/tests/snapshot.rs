@@ -25,12 +25,15 @@ use turbopack::{
         ModuleRuleEffect, RuleCondition, TypescriptTransformOptions,
     },
 };
-use turbopack_browser::BrowserChunkingContext;
+use turbopack_browser::{
+    BrowserChunkingContext, ecmascript::with_service_worker_precache_manifests,
+};
 use turbopack_core::{
     asset::Asset,
     chunk::{
         ChunkingConfig, ChunkingContext, ChunkingContextExt, EvaluatableAsset, EvaluatableAssetExt,
-        EvaluatableAssets, MinifyType, SourceMapSourceType, availability_info::AvailabilityInfo,
+        EvaluatableAssets, MinifyOptions, MinifyType, SourceMapSourceType,
+        availability_info::AvailabilityInfo,
     },
     compile_time_defines,
     compile_time_info::{
@@ -55,6 +58,7 @@ use turbopack_core::{
 };
 use turbopack_ecmascript::{
     AnalyzeMode, EcmascriptInputTransform, TreeShakingMode, chunk::EcmascriptChunkType,
+    property_mangling::get_global_property_name_cache,
 };
 use turbopack_ecmascript_plugins::transform::{
     emotion::{EmotionTransformConfig, EmotionTransformer},
@@ -483,6 +487,21 @@ async fn run_test_operation(resource: RcStr) -> Result<Vc<FileSystemPath>> {
     }
     let module_graph = module_graph.connect();
 
+    let property_name_cache = if let MinifyType::Minify(MinifyOptions {
+        mangle: Some(_),
+        mangle_properties: Some(mangle_properties),
+        ..
+    }) = &options.minify_type
+    {
+        Some(
+            get_global_property_name_cache(module_graph, mangle_properties.clone(), Vc::cell(None))
+                .to_resolved()
+                .await?,
+        )
+    } else {
+        None
+    };
+
     let chunk_root_path = project_path.join("output")?;
     let static_root_path = project_path.join("static")?;
     let expected_paths = expected(chunk_root_path.clone())
@@ -504,6 +523,7 @@ async fn run_test_operation(resource: RcStr) -> Result<Vc<FileSystemPath>> {
                 options.runtime_type,
             )
             .minify_type(options.minify_type)
+            .property_name_cache(property_name_cache)
             .module_merging(options.scope_hoisting)
             .export_usage(if options.remove_unused_exports {
                 Some(binding_usage.unwrap().connect().to_resolved().await?)
@@ -512,7 +532,9 @@ async fn run_test_operation(resource: RcStr) -> Result<Vc<FileSystemPath>> {
             })
             .debug_ids(options.enable_debug_ids)
             .source_map_source_type(options.source_map_source_type)
-            .chunk_loading_global(options.chunk_loading_global.into());
+            .chunk_loading_global(options.chunk_loading_global.into())
+            // `expected` expects a completely flat output directory.
+            .service_worker_path(chunk_root_path.join("sw.js")?, rcstr!("/sw.js"));
 
             if options.remove_unused_imports {
                 builder = builder.unused_references(
@@ -552,6 +574,7 @@ async fn run_test_operation(resource: RcStr) -> Result<Vc<FileSystemPath>> {
                 options.runtime_type,
             )
             .minify_type(options.minify_type)
+            .property_name_cache(property_name_cache)
             .module_merging(options.scope_hoisting)
             .export_usage(if options.remove_unused_exports {
                 Some(binding_usage.unwrap().connect().to_resolved().await?)
@@ -623,7 +646,12 @@ async fn run_test_operation(resource: RcStr) -> Result<Vc<FileSystemPath>> {
     };
 
     let mut seen = FxHashSet::default();
-    let mut queue: VecDeque<_> = chunks.expand_all_assets().await?.iter().copied().collect();
+    // Service workers precache the other assets, which are only known at this point.
+    let mut queue: VecDeque<_> = with_service_worker_precache_manifests(chunks.expand_all_assets())
+        .await?
+        .iter()
+        .copied()
+        .collect();
 
     let output_path = project_path.clone();
     while let Some(asset) = queue.pop_front() {
@@ -0,0 +1,24 @@
+function invariant(condition, message) {
+  if (!condition) {
+    throw new Error(message)
+  }
+}
+
+const logger = {
+  debug(...args) {
+    console.debug(...args)
+  },
+}
+
+export function sum(values) {
+  console.log('summing', values)
+  invariant(Array.isArray(values), 'values must be an array')
+  logger.debug('summing', values.length)
+  let total = 0
+  for (const value of values) {
+    total += value ?? 0
+  }
+  return total
+}
+
+globalThis.total = sum([1, 2, 3])
@@ -0,0 +1,24 @@
+class Vector3 {
+  constructor(x, y, z) {
+    this.x = x
+    this.y = y
+    this.z = z
+  }
+}
+
+class Euler {
+  constructor(x, y, z) {
+    this.x = x
+    this.y = y
+    this.z = z
+  }
+}
+
+function describe(value) {
+  if (value instanceof Vector3 || value instanceof Euler) {
+    return value.constructor.name
+  }
+  return typeof value
+}
+
+console.log(describe(new Vector3(0, 1, 0)), describe(new Euler(0, 0, 0)))
@@ -0,0 +1,6 @@
+import { RenderLoop } from './render-loop.js'
+
+const loop = new RenderLoop()
+loop.add(() => console.log('frame'))
+loop.tick()
+console.log(loop._frame, loop.frames)
@@ -0,0 +1,22 @@
+export class RenderLoop {
+  constructor() {
+    this._callbacks = []
+    this._frame = 0
+    this._keep = true
+  }
+
+  get frames() {
+    return this._frame
+  }
+
+  add(callback) {
+    this._callbacks.push(callback)
+  }
+
+  tick() {
+    const { _callbacks, _keep = false } = this
+    if (!_keep || !('_frame' in this)) return
+    this._frame++
+    for (const callback of _callbacks) callback()
+  }
+}
@@ -0,0 +1,4 @@
+console.log('index.js')
+navigator.serviceWorker.register(new URL('./sw.js', import.meta.url), {
+  scope: '/',
+})
@@ -0,0 +1 @@
+export const CACHE_NAME = 'precache-v1'
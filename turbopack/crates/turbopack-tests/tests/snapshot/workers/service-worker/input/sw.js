@@ -0,0 +1,19 @@
+import { CACHE_NAME } from './shared.js'
+
+const precacheManifest = self.TURBOPACK_PRECACHE_MANIFEST
+
+self.addEventListener('install', (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE_NAME)
+      .then((cache) => cache.addAll(precacheManifest.map(({ url }) => url)))
+  )
+})
+
+self.addEventListener('fetch', (event) => {
+  event.respondWith(
+    caches
+      .match(event.request)
+      .then((response) => response ?? fetch(event.request))
+  )
+})
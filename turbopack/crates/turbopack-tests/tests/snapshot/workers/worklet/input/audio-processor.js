@@ -0,0 +1,10 @@
+import { gain } from './shared.js'
+
+class GainProcessor extends AudioWorkletProcessor {
+  process(inputs, outputs) {
+    outputs[0][0].set(inputs[0][0].map((sample) => sample * gain))
+    return true
+  }
+}
+
+registerProcessor('gain-processor', GainProcessor)
@@ -0,0 +1,6 @@
+console.log('index.js')
+const context = new AudioContext()
+context.audioWorklet.addModule(
+  new URL('./audio-processor.js', import.meta.url)
+)
+CSS.paintWorklet.addModule(new URL('./paint.js', import.meta.url))
@@ -0,0 +1,11 @@
+import { gain } from './shared.js'
+
+registerPaint(
+  'checkerboard',
+  class {
+    paint(ctx, size) {
+      ctx.globalAlpha = gain
+      ctx.fillRect(0, 0, size.width / 2, size.height / 2)
+    }
+  }
+)
@@ -0,0 +1,8 @@
+;[
+  'package.json',
+  'test/unit/why-traced/a.js',
+  'test/unit/why-traced/b.js',
+  'test/unit/why-traced/data.txt',
+  'test/unit/why-traced/input.js',
+  'test/unit/why-traced/shared.js',
+]
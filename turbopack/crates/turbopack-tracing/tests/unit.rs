@@ -12,7 +12,7 @@ use rstest::*;
 use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{FxIndexSet, ResolvedVc, TryJoinIterExt, TurboTasks, Vc};
 use turbo_tasks_backend::TurboTasksBackend;
-use turbo_tasks_fs::{DiskFileSystem, FileSystem};
+use turbo_tasks_fs::{DiskFileSystem, FileSystem, FileSystemPath};
 use turbopack::{
     ModuleAssetContext,
     module_options::{
@@ -31,7 +31,7 @@ use turbopack_core::{
     output::OutputAsset,
     reference::all_assets_from_entries,
     reference_type::ReferenceType,
-    traced_asset::TracedAsset,
+    traced_asset::{TraceChains, TracedAsset, trace_chains},
 };
 use turbopack_ecmascript::AnalyzeMode;
 use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
@@ -178,6 +178,7 @@ static ALLOC: turbo_tasks_malloc::TurboMalloc = turbo_tasks_malloc::TurboMalloc;
 // #[case::webpack_wrapper_strs_namespaces("webpack-wrapper-strs-namespaces")]
 // #[case::webpack_wrapper_strs_namespaces_large("webpack-wrapper-strs-namespaces-large")]
 // #[case::when_wrapper("when-wrapper")]
+#[case::why_traced("why-traced")]
 #[case::wildcard("wildcard")]
 // #[case::wildcard_require("wildcard-require")]
 // #[case::wildcard2("wildcard2")]
@@ -189,8 +190,75 @@ fn unit_test(#[case] input: &str) -> Result<()> {
     node_file_trace(input)
 }
 
+/// Compares the shortest reference chains to a traced file with `why.txt`, which uses the `--why`
+/// format of `turbopack-nft`.
+#[test]
+fn why_traced_test() -> Result<()> {
+    let package_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/node-file-trace");
+    let expected = std::fs::read_to_string(package_root.join("test/unit/why-traced/why.txt"))?;
+    let package_root: RcStr = package_root.to_string_lossy().into();
+
+    run(async move {
+        let chains = why_traced_operation(
+            package_root,
+            rcstr!("test/unit/why-traced/input.js"),
+            rcstr!("test/unit/why-traced/data.txt"),
+        )
+        .read_strongly_consistent()
+        .await?;
+        let actual = chains
+            .iter()
+            .map(|chain| {
+                chain
+                    .iter()
+                    .map(|step| format!("{step}\n"))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if expected.trim() == actual.trim() {
+            anyhow::Ok(())
+        } else {
+            println!(
+                "{}",
+                print_changeset(&Changeset::new(expected.trim(), actual.trim(), "\n"))
+            );
+            anyhow::bail!("trace chains do not match reference");
+        }
+    })
+}
+
+#[turbo_tasks::function(operation)]
+async fn why_traced_operation(
+    package_root: RcStr,
+    input: RcStr,
+    path: RcStr,
+) -> Result<Vc<TraceChains>> {
+    let (input_dir, module) = entry_module(package_root, input).await?;
+    let entry = ResolvedVc::upcast(TracedAsset::new(module).to_resolved().await?);
+    Ok(trace_chains(Vc::cell(vec![entry]), input_dir.join(&path)?))
+}
+
 #[turbo_tasks::function(operation)]
 async fn node_file_trace_operation(package_root: RcStr, input: RcStr) -> Result<Vc<Vec<RcStr>>> {
+    let (_, module) = entry_module(package_root, input).await?;
+
+    // We treat the entry as an external
+    let mut paths = to_list(vec![ResolvedVc::upcast(
+        TracedAsset::new(module).to_resolved().await?,
+    )])
+    .await?;
+    paths.push(module.ident().path().await?.path.clone());
+
+    Ok(Vc::cell(paths))
+}
+
+/// Processes `input` as a traced Node.js module and returns it with the root of `package_root`.
+async fn entry_module(
+    package_root: RcStr,
+    input: RcStr,
+) -> Result<(FileSystemPath, Vc<Box<dyn Module>>)> {
     let workspace_fs: Vc<Box<dyn FileSystem>> = Vc::upcast(DiskFileSystem::new(
         rcstr!("workspace"),
         package_root.clone(),
@@ -252,14 +320,7 @@ async fn node_file_trace_operation(package_root: RcStr, input: RcStr) -> Result<
         .process(Vc::upcast(source), ReferenceType::Undefined)
         .module();
 
-    // We treat the entry as an external
-    let mut paths = to_list(vec![ResolvedVc::upcast(
-        TracedAsset::new(module).to_resolved().await?,
-    )])
-    .await?;
-    paths.push(module.ident().path().await?.path.clone());
-
-    Ok(Vc::cell(paths))
+    Ok((input_dir, module))
 }
 
 async fn to_list(assets: Vec<ResolvedVc<Box<dyn OutputAsset>>>) -> Result<Vec<RcStr>> {
@@ -280,55 +341,56 @@ static LINE_COMMENTS_COMMA: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"(?m)^\s*//.*$").unwrap());
 
 fn node_file_trace(input_path: &str) -> Result<()> {
-    let r = &mut {
-        let mut builder = tokio::runtime::Builder::new_multi_thread();
-        builder.enable_all();
-        builder.max_blocking_threads(20);
-        builder.build().unwrap()
-    };
-
     let package_root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let package_root = package_root.join("tests/node-file-trace");
     let input: RcStr = format!("test/unit/{input_path}/input.js").into();
     let reference = package_root.join(format!("test/unit/{input_path}/output.js"));
 
-    r.block_on(async move {
-        let future = async move {
-            let op =
-                node_file_trace_operation(package_root.to_string_lossy().into(), input.clone());
-            let list = op
-                .read_strongly_consistent()
-                .await?
-                .into_iter()
-                .map(|s| s.to_string())
-                .collect::<FxIndexSet<_>>();
+    run(async move {
+        let op = node_file_trace_operation(package_root.to_string_lossy().into(), input.clone());
+        let list = op
+            .read_strongly_consistent()
+            .await?
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect::<FxIndexSet<_>>();
+
+        // println!("issues: {:#?}", op.peek_issues().get_plain_issues().await?);
 
-            // println!("issues: {:#?}", op.peek_issues().get_plain_issues().await?);
+        let reference = std::fs::read_to_string(reference)?;
+        // crude JS -> JSON conversion
+        let reference = TRAILING_COMMA.replace(&reference, "]");
+        let reference = LINE_COMMENTS_COMMA
+            .replace_all(&reference, "")
+            .replace(";", "")
+            .replace('\'', "\"");
+        let reference = serde_json::from_str::<Vec<String>>(&reference)?
+            .into_iter()
+            .collect::<FxIndexSet<_>>();
 
-            let reference = std::fs::read_to_string(reference)?;
-            // crude JS -> JSON conversion
-            let reference = TRAILING_COMMA.replace(&reference, "]");
-            let reference = LINE_COMMENTS_COMMA
-                .replace_all(&reference, "")
-                .replace(";", "")
-                .replace('\'', "\"");
-            let reference = serde_json::from_str::<Vec<String>>(&reference)?
-                .into_iter()
-                .collect::<FxIndexSet<_>>();
+        if reference == list {
+            anyhow::Ok(())
+        } else {
+            let reference = reference.into_iter().collect::<Vec<_>>().join("\n");
+            let list = list.into_iter().collect::<Vec<_>>().join("\n");
+            println!(
+                "{}",
+                print_changeset(&Changeset::new(reference.trim(), list.trim(), "\n"))
+            );
+            anyhow::bail!("file trace does not match reference");
+        }
+    })
+}
 
-            if reference == list {
-                anyhow::Ok(())
-            } else {
-                let reference = reference.into_iter().collect::<Vec<_>>().join("\n");
-                let list = list.into_iter().collect::<Vec<_>>().join("\n");
-                println!(
-                    "{}",
-                    print_changeset(&Changeset::new(reference.trim(), list.trim(), "\n"))
-                );
-                anyhow::bail!("file trace does not match reference");
-            }
-        };
+fn run(future: impl Future<Output = Result<()>> + Send + 'static) -> Result<()> {
+    let r = &mut {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        builder.enable_all();
+        builder.max_blocking_threads(20);
+        builder.build().unwrap()
+    };
 
+    r.block_on(async move {
         let tt = TurboTasks::new(TurboTasksBackend::new(
             turbo_tasks_backend::BackendOptions::default(),
             turbo_tasks_backend::noop_backing_storage(),
@@ -3,7 +3,10 @@
 //! WASM assets are copied directly to the output folder.
 //!
 //! When imported from ES modules, they produce a thin module that loads and
-//! instantiates the WebAssembly module.
+//! instantiates the WebAssembly module. Source phase imports
+//! (`import source mod from "./x.wasm"`) only compile it and export the
+//! `WebAssembly.Module`, and `new URL("./x.wasm", import.meta.url)` exports the
+//! URL of the emitted file.
 
 #![feature(min_specialization)]
 #![feature(arbitrary_self_types)]
@@ -21,6 +24,7 @@ pub mod module_asset;
 pub(crate) mod output_asset;
 pub mod raw;
 pub mod source;
+pub mod url;
 
 #[turbo_tasks::function]
 pub async fn wasm_edge_var_name(asset: Vc<Box<dyn Asset>>) -> Result<Vc<RcStr>> {
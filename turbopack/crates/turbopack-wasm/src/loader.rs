@@ -80,7 +80,7 @@ pub(crate) async fn compiling_loader_source(
     .into();
 
     Ok(Vc::upcast(VirtualSource::new(
-        source.ident().path().await?.append("_.loader.mjs")?,
+        source.ident().path().await?.append("_.module.loader.mjs")?,
         AssetContent::file(FileContent::Content(File::from(code)).cell()),
     )))
 }
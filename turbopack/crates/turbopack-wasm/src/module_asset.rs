@@ -1,6 +1,9 @@
 use anyhow::{Result, bail};
+use bincode::{Decode, Encode};
 use turbo_rcstr::rcstr;
-use turbo_tasks::{IntoTraitRef, ResolvedVc, Vc, fxindexmap};
+use turbo_tasks::{
+    IntoTraitRef, NonLocalValue, ResolvedVc, TaskInput, Vc, fxindexmap, trace::TraceRawVcs,
+};
 use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::{
     chunk::{
@@ -31,13 +34,42 @@ use crate::{
     source::WebAssemblySource,
 };
 
+#[derive(
+    PartialOrd,
+    Ord,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    TaskInput,
+    TraceRawVcs,
+    NonLocalValue,
+    Encode,
+    Decode,
+)]
+pub enum WebAssemblyImportType {
+    /// Instantiates the WebAssembly module with its imports and re-exports its
+    /// exports, like the WebAssembly ESM integration.
+    #[default]
+    Instance,
+    /// Compiles the WebAssembly module without instantiating it and exports the
+    /// `WebAssembly.Module` as default export, like a source phase import
+    /// (`import source mod from "./x.wasm"`).
+    Source,
+}
+
 /// Creates a javascript loader which instantiates the WebAssembly source and
-/// re-exports its exports.
+/// re-exports its exports, or which only compiles it, depending on the
+/// [WebAssemblyImportType].
 #[turbo_tasks::value]
 #[derive(Clone)]
 pub struct WebAssemblyModuleAsset {
     source: ResolvedVc<WebAssemblySource>,
     asset_context: ResolvedVc<Box<dyn AssetContext>>,
+    import_ty: WebAssemblyImportType,
 }
 
 #[turbo_tasks::value_impl]
@@ -46,10 +78,12 @@ impl WebAssemblyModuleAsset {
     pub fn new(
         source: ResolvedVc<WebAssemblySource>,
         asset_context: ResolvedVc<Box<dyn AssetContext>>,
+        import_ty: WebAssemblyImportType,
     ) -> Vc<Self> {
         Self::cell(WebAssemblyModuleAsset {
             source,
             asset_context,
+            import_ty,
         })
     }
 
@@ -62,7 +96,8 @@ impl WebAssemblyModuleAsset {
     async fn loader_as_module(&self) -> Result<Vc<Box<dyn Module>>> {
         let query = &self.source.ident().await?.query;
 
-        let loader_source = if query == "?module" {
+        let loader_source = if self.import_ty == WebAssemblyImportType::Source || query == "?module"
+        {
             compiling_loader_source(*self.source)
         } else {
             instantiating_loader_source(*self.source)
@@ -121,10 +156,14 @@ impl WebAssemblyModuleAsset {
 impl Module for WebAssemblyModuleAsset {
     #[turbo_tasks::function]
     async fn ident(&self) -> Result<Vc<AssetIdent>> {
+        let modifier = match self.import_ty {
+            WebAssemblyImportType::Instance => rcstr!("wasm module"),
+            WebAssemblyImportType::Source => rcstr!("wasm module source"),
+        };
         Ok(self
             .source
             .ident()
-            .with_modifier(rcstr!("wasm module"))
+            .with_modifier(modifier)
             .with_layer(self.asset_context.into_trait_ref().await?.layer()))
     }
 
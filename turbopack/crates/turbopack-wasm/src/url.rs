@@ -0,0 +1,119 @@
+use anyhow::Result;
+use turbo_rcstr::rcstr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbopack_core::{
+    chunk::{AsyncModuleInfo, ChunkableModule, ChunkingContext},
+    ident::AssetIdent,
+    module::{Module, ModuleSideEffects},
+    module_graph::ModuleGraph,
+    output::{OutputAsset, OutputAssetsWithReferenced},
+    source::{OptionSource, Source},
+};
+use turbopack_ecmascript::{
+    chunk::{
+        EcmascriptChunkItemContent, EcmascriptChunkPlaceable, EcmascriptExports,
+        ecmascript_chunk_item,
+    },
+    utils::asset_url_export_code,
+};
+
+use crate::{output_asset::WebAssemblyAsset, source::WebAssemblySource};
+
+/// Exports the URL of the WebAssembly file, e.g. for the
+/// `new URL("x_bg.wasm", import.meta.url)` emitted by wasm-bindgen.
+///
+/// Unlike a static asset, the file is emitted as a WebAssembly chunk, so it's
+/// listed together with the other WebAssembly files of the chunk group.
+#[turbo_tasks::value]
+#[derive(Clone)]
+pub struct WebAssemblyUrlModuleAsset {
+    source: ResolvedVc<WebAssemblySource>,
+}
+
+#[turbo_tasks::value_impl]
+impl WebAssemblyUrlModuleAsset {
+    #[turbo_tasks::function]
+    pub fn new(source: ResolvedVc<WebAssemblySource>) -> Vc<Self> {
+        Self::cell(WebAssemblyUrlModuleAsset { source })
+    }
+
+    #[turbo_tasks::function]
+    fn wasm_asset(&self, chunking_context: Vc<Box<dyn ChunkingContext>>) -> Vc<WebAssemblyAsset> {
+        WebAssemblyAsset::new(*self.source, chunking_context)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Module for WebAssemblyUrlModuleAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        self.source.ident().with_modifier(rcstr!("wasm url"))
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> Vc<OptionSource> {
+        Vc::cell(Some(ResolvedVc::upcast(self.source)))
+    }
+
+    #[turbo_tasks::function]
+    fn side_effects(self: Vc<Self>) -> Vc<ModuleSideEffects> {
+        // this just exports a URL
+        ModuleSideEffects::SideEffectFree.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableModule for WebAssemblyUrlModuleAsset {
+    #[turbo_tasks::function]
+    fn as_chunk_item(
+        self: ResolvedVc<Self>,
+        module_graph: ResolvedVc<ModuleGraph>,
+        chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
+    ) -> Vc<Box<dyn turbopack_core::chunk::ChunkItem>> {
+        ecmascript_chunk_item(ResolvedVc::upcast(self), module_graph, chunking_context)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkPlaceable for WebAssemblyUrlModuleAsset {
+    #[turbo_tasks::function]
+    fn get_exports(self: Vc<Self>) -> Vc<EcmascriptExports> {
+        EcmascriptExports::Value.cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn chunk_item_content(
+        self: Vc<Self>,
+        chunking_context: Vc<Box<dyn ChunkingContext>>,
+        _module_graph: Vc<ModuleGraph>,
+        _async_module_info: Option<Vc<AsyncModuleInfo>>,
+        _estimated: bool,
+    ) -> Result<Vc<EcmascriptChunkItemContent>> {
+        let wasm_asset = self.wasm_asset(chunking_context);
+        let url = chunking_context
+            .asset_url(wasm_asset.path().owned().await?, None)
+            .await?;
+
+        let url_behavior = chunking_context.url_behavior(None).await?;
+
+        let inner_code = asset_url_export_code(&url, &url_behavior.suffix);
+
+        Ok(EcmascriptChunkItemContent {
+            inner_code: inner_code.into(),
+            ..Default::default()
+        }
+        .cell())
+    }
+
+    #[turbo_tasks::function]
+    async fn chunk_item_output_assets(
+        self: Vc<Self>,
+        chunking_context: Vc<Box<dyn ChunkingContext>>,
+        _module_graph: Vc<ModuleGraph>,
+    ) -> Result<Vc<OutputAssetsWithReferenced>> {
+        let wasm_asset = self.wasm_asset(chunking_context).to_resolved().await?;
+        Ok(OutputAssetsWithReferenced::from_assets(Vc::cell(vec![
+            ResolvedVc::upcast(wasm_asset),
+        ])))
+    }
+}
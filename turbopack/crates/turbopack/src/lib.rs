@@ -63,7 +63,9 @@ use turbopack_resolve::{
     typescript::type_resolve,
 };
 use turbopack_static::{css::StaticUrlCssModule, ecma::StaticUrlJsModule};
-use turbopack_wasm::{module_asset::WebAssemblyModuleAsset, source::WebAssemblySource};
+use turbopack_wasm::{
+    module_asset::WebAssemblyModuleAsset, source::WebAssemblySource, url::WebAssemblyUrlModuleAsset,
+};
 
 use crate::{
     evaluate_context::node_evaluate_asset_context,
@@ -251,14 +253,23 @@ async fn apply_module_type(
                 .to_resolved()
                 .await?,
         ),
-        ModuleType::WebAssembly { source_ty } => ResolvedVc::upcast(
+        ModuleType::WebAssembly {
+            source_ty,
+            import_ty,
+        } => ResolvedVc::upcast(
             WebAssemblyModuleAsset::new(
                 WebAssemblySource::new(*source, *source_ty),
                 Vc::upcast(module_asset_context),
+                *import_ty,
             )
             .to_resolved()
             .await?,
         ),
+        ModuleType::WebAssemblyUrl { source_ty } => ResolvedVc::upcast(
+            WebAssemblyUrlModuleAsset::new(WebAssemblySource::new(*source, *source_ty))
+                .to_resolved()
+                .await?,
+        ),
         ModuleType::Custom(custom) => {
             custom
                 .create_module(*source, module_asset_context, part)
@@ -18,13 +18,14 @@ use turbo_tasks_fs::{
 };
 use turbopack_core::{
     chunk::SourceMapsType,
+    environment::Rendering,
     ident::Layer,
     reference_type::{
         CssReferenceSubType, EcmaScriptModulesReferenceSubType, ReferenceType, UrlReferenceSubType,
     },
     resolve::options::{ImportMap, ImportMapping},
 };
-use turbopack_css::CssModuleAssetType;
+use turbopack_css::{CssModuleAssetType, sass::SassSourceTransform};
 use turbopack_ecmascript::{
     AnalyzeMode, EcmascriptInputTransform, EcmascriptInputTransforms, EcmascriptOptions,
     SpecifiedModuleType, bytes_source_transform::BytesSourceTransform,
@@ -36,7 +37,7 @@ use turbopack_node::{
     transforms::{postcss::PostCssTransform, webpack::WebpackLoaders},
 };
 use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
-use turbopack_wasm::source::WebAssemblySourceType;
+use turbopack_wasm::{module_asset::WebAssemblyImportType, source::WebAssemblySourceType};
 
 use crate::evaluate_context::{config_tracing_module_context, node_evaluate_asset_context};
 
@@ -252,6 +253,7 @@ impl ModuleOptions {
                     enable_raw_css,
                     source_maps: css_source_maps,
                     ref module_css_condition,
+                    ref sass_load_paths,
                     ..
                 },
             ref static_url_tag,
@@ -452,34 +454,58 @@ impl ModuleOptions {
                 if !matches!(all_rule_condition, RuleCondition::False) {
                     let mut effects = Vec::new();
 
+                    let module_type = rule
+                        .module_type
+                        .as_deref()
+                        .map(ConfiguredModuleType::parse)
+                        .transpose()?;
+
                     // Add source transforms if loaders are specified
                     if !rule.loaders.await?.is_empty() {
-                        effects.push(ModuleRuleEffect::SourceTransforms(ResolvedVc::cell(vec![
-                            ResolvedVc::upcast(
-                                WebpackLoaders::new(
-                                    node_evaluate_asset_context(
-                                        *execution_context,
-                                        Some(import_map),
-                                        None,
-                                        Layer::new(rcstr!("webpack_loaders")),
-                                        false,
-                                    ),
+                        let webpack_loaders = ResolvedVc::upcast(
+                            WebpackLoaders::new(
+                                node_evaluate_asset_context(
                                     *execution_context,
-                                    *rule.loaders,
-                                    rule.rename_as.clone(),
-                                    resolve_options_context,
-                                    matches!(ecmascript_source_maps, SourceMapsType::Full),
-                                )
-                                .to_resolved()
-                                .await?,
-                            ),
+                                    Some(import_map),
+                                    None,
+                                    Layer::new(rcstr!("webpack_loaders")),
+                                    false,
+                                ),
+                                *execution_context,
+                                *rule.loaders,
+                                rule.rename_as.clone(),
+                                resolve_options_context,
+                                matches!(ecmascript_source_maps, SourceMapsType::Full),
+                            )
+                            .to_resolved()
+                            .await?,
+                        );
+                        if module_type == Some(ConfiguredModuleType::Sass) {
+                            // Sass is compiled natively, the loaders only handle the stylesheets
+                            // that the native compiler doesn't support.
+                            effects.push(ModuleRuleEffect::SourceTransforms(ResolvedVc::cell(
+                                vec![ResolvedVc::upcast(
+                                    SassSourceTransform::new(
+                                        Some(webpack_loaders),
+                                        rule.rename_as.clone().unwrap_or(rcstr!("*.css")),
+                                        sass_load_paths.clone(),
+                                    )
+                                    .to_resolved()
+                                    .await?,
+                                )],
+                            )));
+                            rules.push(ModuleRule::new(all_rule_condition, effects));
+                            continue;
+                        }
+                        effects.push(ModuleRuleEffect::SourceTransforms(ResolvedVc::cell(vec![
+                            webpack_loaders,
                         ])));
                     }
 
                     // Add module type if specified
-                    if let Some(type_str) = rule.module_type.as_ref() {
+                    if let Some(module_type) = module_type {
                         effects.push(
-                            ConfiguredModuleType::parse(type_str)?
+                            module_type
                                 .into_effect(
                                     ecma_preprocess,
                                     main,
@@ -542,6 +568,55 @@ impl ModuleOptions {
             ));
         }
 
+        // WebAssembly source phase imports and URLs must come before the generic URL rules and
+        // the WebAssembly rules below.
+        for (condition, source_ty) in [
+            (
+                RuleCondition::any(vec![
+                    RuleCondition::ResourcePathEndsWith(".wasm".to_string()),
+                    RuleCondition::ContentTypeStartsWith("application/wasm".to_string()),
+                ]),
+                WebAssemblySourceType::Binary,
+            ),
+            (
+                RuleCondition::ResourcePathEndsWith(".wat".to_string()),
+                WebAssemblySourceType::Text,
+            ),
+        ] {
+            rules.push(ModuleRule::new_all(
+                RuleCondition::all(vec![
+                    RuleCondition::ReferenceType(ReferenceType::EcmaScriptModules(
+                        EcmaScriptModulesReferenceSubType::ImportSource,
+                    )),
+                    condition,
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssembly {
+                    source_ty,
+                    import_ty: WebAssemblyImportType::Source,
+                })],
+            ));
+        }
+        // The URL of the emitted file is only known for client assets, e.g. for the
+        // `new URL("x_bg.wasm", import.meta.url)` emitted by wasm-bindgen.
+        let is_client = if let Some(environment) = environment {
+            *environment.rendering().await? == Rendering::Client
+        } else {
+            false
+        };
+        if is_client && !is_tracing {
+            rules.push(ModuleRule::new_all(
+                RuleCondition::all(vec![
+                    RuleCondition::ReferenceType(ReferenceType::Url(
+                        UrlReferenceSubType::EcmaScriptNewUrl,
+                    )),
+                    RuleCondition::ResourcePathEndsWith(".wasm".to_string()),
+                ]),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssemblyUrl {
+                    source_ty: WebAssemblySourceType::Binary,
+                })],
+            ));
+        }
+
         // Rules that apply for certains references
         rules.extend([
             ModuleRule::new(
@@ -660,6 +735,7 @@ impl ModuleOptions {
                 ]),
                 vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssembly {
                     source_ty: WebAssemblySourceType::Binary,
+                    import_ty: WebAssemblyImportType::Instance,
                 })],
             ),
             ModuleRule::new(
@@ -668,6 +744,7 @@ impl ModuleOptions {
                 )]),
                 vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssembly {
                     source_ty: WebAssemblySourceType::Text,
+                    import_ty: WebAssemblyImportType::Instance,
                 })],
             ),
             ModuleRule::new(
@@ -294,6 +294,10 @@ pub struct CssOptionsContext {
     /// `Any(ResourcePathEndsWith(".module.css"), ContentTypeStartsWith("text/css+module"))`
     pub module_css_condition: Option<RuleCondition>,
 
+    /// Directories in which the native Sass compiler looks for modules that can't be found
+    /// relative to the importing stylesheet, like Sass' `loadPaths` option.
+    pub sass_load_paths: Vec<FileSystemPath>,
+
     pub placeholder_for_future_extensions: (),
 }
 
@@ -2,19 +2,19 @@ use std::fmt::Display;
 
 use anyhow::{Result, bail};
 use bincode::{Decode, Encode};
-use turbo_rcstr::RcStr;
+use turbo_rcstr::{RcStr, rcstr};
 use turbo_tasks::{NonLocalValue, ResolvedVc, trace::TraceRawVcs};
 use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::{
     environment::Environment, reference_type::ReferenceType, source::Source,
     source_transform::SourceTransforms,
 };
-use turbopack_css::CssModuleAssetType;
+use turbopack_css::{CssModuleAssetType, sass::SassSourceTransform};
 use turbopack_ecmascript::{
     EcmascriptInputTransforms, EcmascriptOptions, bytes_source_transform::BytesSourceTransform,
     json_source_transform::JsonSourceTransform,
 };
-use turbopack_wasm::source::WebAssemblySourceType;
+use turbopack_wasm::{module_asset::WebAssemblyImportType, source::WebAssemblySourceType};
 
 use crate::module_options::{CustomModuleType, RuleCondition, match_mode::MatchMode};
 
@@ -153,6 +153,11 @@ pub enum ModuleType {
     },
     WebAssembly {
         source_ty: WebAssemblySourceType,
+        import_ty: WebAssemblyImportType,
+    },
+    /// Exports the URL of the emitted WebAssembly file
+    WebAssemblyUrl {
+        source_ty: WebAssemblySourceType,
     },
     Custom(ResolvedVc<Box<dyn CustomModuleType>>),
 }
@@ -171,6 +176,7 @@ impl Display for ModuleType {
             ModuleType::StaticUrlJs { .. } => write!(f, "StaticUrlJs"),
             ModuleType::StaticUrlCss { .. } => write!(f, "StaticUrlCss"),
             ModuleType::WebAssembly { .. } => write!(f, "WebAssembly"),
+            ModuleType::WebAssemblyUrl { .. } => write!(f, "WebAssemblyUrl"),
             ModuleType::Custom(_) => write!(f, "Custom"),
         }
     }
@@ -188,6 +194,9 @@ pub enum ConfiguredModuleType {
     Typescript,
     Css,
     CssModule,
+    /// Compiles Sass to CSS natively. Configured loaders are only used as a fallback for
+    /// stylesheets the native compiler can't handle.
+    Sass,
     /// Parses JSON and exports it as an ES module default export.
     /// Implemented as a source transform, not a ModuleType.
     Json,
@@ -208,6 +217,7 @@ impl ConfiguredModuleType {
             "typescript" => ConfiguredModuleType::Typescript,
             "css" => ConfiguredModuleType::Css,
             "css-module" => ConfiguredModuleType::CssModule,
+            "sass" => ConfiguredModuleType::Sass,
             "json" => ConfiguredModuleType::Json,
             "wasm" => ConfiguredModuleType::Wasm,
             "raw" => ConfiguredModuleType::Raw,
@@ -215,7 +225,7 @@ impl ConfiguredModuleType {
             "bytes" => ConfiguredModuleType::Bytes,
             _ => bail!(
                 "Unknown module type: {type_str:?}. Valid types are: asset, ecmascript, \
-                 typescript, css, css-module, json, wasm, raw, node, bytes"
+                 typescript, css, css-module, sass, json, wasm, raw, node, bytes"
             ),
         })
     }
@@ -266,6 +276,13 @@ impl ConfiguredModuleType {
                 environment,
             }),
             ConfiguredModuleType::CssModule => ModuleRuleEffect::ModuleType(ModuleType::CssModule),
+            ConfiguredModuleType::Sass => {
+                ModuleRuleEffect::SourceTransforms(ResolvedVc::cell(vec![ResolvedVc::upcast(
+                    SassSourceTransform::new(None, rcstr!("*.css"), Vec::new())
+                        .to_resolved()
+                        .await?,
+                )]))
+            }
             ConfiguredModuleType::Json => {
                 ModuleRuleEffect::SourceTransforms(ResolvedVc::cell(vec![ResolvedVc::upcast(
                     // TODO: can we switch this to `new_esm`?
@@ -274,6 +291,7 @@ impl ConfiguredModuleType {
             }
             ConfiguredModuleType::Wasm => ModuleRuleEffect::ModuleType(ModuleType::WebAssembly {
                 source_ty: WebAssemblySourceType::Binary,
+                import_ty: WebAssemblyImportType::Instance,
             }),
             ConfiguredModuleType::Raw => ModuleRuleEffect::ModuleType(ModuleType::Raw),
             ConfiguredModuleType::Node => ModuleRuleEffect::ModuleType(ModuleType::NodeAddon),